@@ -13,6 +13,7 @@
 //!
 //! - `SSS_RPC_URL`: Solana RPC endpoint (default: https://api.devnet.solana.com)
 //! - `SSS_KEYPAIR_PATH`: Path to keypair file (default: ~/.config/solana/id.json)
+//! - `SSS_WS_URL`: Solana WebSocket endpoint for live updates (default: derived from `SSS_RPC_URL`)
 
 use anyhow::Result;
 use crossterm::{
@@ -24,6 +25,7 @@ use ratatui::{
     prelude::*,
     widgets::{Block, Borders, Paragraph, List, ListItem, Wrap},
 };
+use sss_token::math::format_token_amount;
 use std::{
     io,
     time::{Duration, Instant},
@@ -32,13 +34,21 @@ use std::{
 #[cfg(feature = "solana")]
 use {
     anchor_client::{Client, Cluster, Program},
+    anchor_lang::AnchorDeserialize,
+    solana_client::{
+        pubsub_client::PubsubClient,
+        rpc_client::RpcClient,
+        rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+        rpc_filter::{Memcmp, RpcFilterType},
+    },
     solana_sdk::{
         commitment_config::CommitmentConfig,
+        hash::hash,
         pubkey::Pubkey,
         signature::{read_keypair_file, Keypair},
         signer::Signer,
     },
-    std::rc::Rc,
+    std::{rc::Rc, sync::mpsc},
 };
 
 
@@ -58,11 +68,25 @@ const STABLECOIN_SEED: &[u8] = b"stablecoin";
 const MINTER_SEED: &[u8] = b"minter";
 #[allow(dead_code)]
 const BLACKLIST_SEED: &[u8] = b"blacklist";
+#[allow(dead_code)]
+const ROLE_SEED: &[u8] = b"role";
 
 /// Refresh interval for blockchain data (in milliseconds)
 #[allow(dead_code)]
 const REFRESH_INTERVAL_MS: u64 = 5000;
 
+/// Delay before resubscribing after the WebSocket drops (in milliseconds)
+#[cfg(feature = "solana")]
+const RESUBSCRIBE_INTERVAL_MS: u64 = 10000;
+
+/// Offset of `decimals` in a mint account, shared by SPL Token and Token-2022
+#[cfg(feature = "solana")]
+const MINT_DECIMALS_OFFSET: usize = 44;
+
+/// Size of the state's `pause_reason` buffer (the program's `MAX_REASON_LENGTH`)
+#[cfg(feature = "solana")]
+const MAX_REASON_LENGTH: usize = 200;
+
 // ============================================================================
 // Mock Types (for non-Solana builds)
 // ============================================================================
@@ -124,6 +148,70 @@ struct StablecoinState {
     preset: u8,
     compliance_enabled: bool,
     bump: u8,
+    
+    #[cfg(feature = "solana")]
+    paused_by: Pubkey,
+    #[cfg(not(feature = "solana"))]
+    paused_by: MockPubkey,
+    
+    paused_at: i64,
+    pause_reason: String,
+    /// When the pause lifts on its own (0 = until unpaused)
+    pause_until: i64,
+    /// Decimals of the asset mint, for displaying amounts
+    decimals: u8,
+}
+
+/// On-chain `StablecoinState` layout, following the 8-byte discriminator
+#[cfg(feature = "solana")]
+#[derive(AnchorDeserialize)]
+#[allow(dead_code)]
+struct StablecoinStateAccount {
+    authority: Pubkey,
+    asset_mint: Pubkey,
+    total_supply: u64,
+    paused: bool,
+    preset: u8,
+    compliance_enabled: bool,
+    bump: u8,
+    max_transfer_amount: u64,
+    fee_bps: u16,
+    fee_treasury: Pubkey,
+    minter_count: u32,
+    blacklist_count: u32,
+    max_supply: u64,
+    min_burn_amount: u64,
+    min_mint_amount: u64,
+    reserve_backed: bool,
+    allowlist_mode: bool,
+    paused_by: Pubkey,
+    paused_at: i64,
+    pause_until: i64,
+    pause_reason_len: u8,
+    pause_reason: [u8; MAX_REASON_LENGTH],
+}
+
+#[cfg(feature = "solana")]
+impl From<StablecoinStateAccount> for StablecoinState {
+    fn from(account: StablecoinStateAccount) -> Self {
+        Self {
+            authority: account.authority,
+            asset_mint: account.asset_mint,
+            total_supply: account.total_supply,
+            paused: account.paused,
+            preset: account.preset,
+            compliance_enabled: account.compliance_enabled,
+            bump: account.bump,
+            paused_by: account.paused_by,
+            paused_at: account.paused_at,
+            pause_reason: String::from_utf8_lossy(
+                &account.pause_reason[..usize::from(account.pause_reason_len).min(MAX_REASON_LENGTH)],
+            )
+            .into_owned(),
+            pause_until: account.pause_until,
+            decimals: 0,
+        }
+    }
 }
 
 /// Represents a minter info account
@@ -161,6 +249,7 @@ struct BlacklistEntry {
 
 /// Role types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "solana", derive(AnchorDeserialize))]
 #[allow(dead_code)]
 enum Role {
     Master,
@@ -171,6 +260,21 @@ enum Role {
     Seizer,
 }
 
+impl Role {
+    /// Last seed of the role's RoleAssignment PDA
+    #[cfg(feature = "solana")]
+    fn seed(&self) -> &'static [u8] {
+        match self {
+            Role::Master => b"master",
+            Role::Minter => b"minter",
+            Role::Burner => b"burner",
+            Role::Blacklister => b"blacklister",
+            Role::Pauser => b"pauser",
+            Role::Seizer => b"seizer",
+        }
+    }
+}
+
 impl std::fmt::Display for Role {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -184,6 +288,48 @@ impl std::fmt::Display for Role {
     }
 }
 
+/// Messages from the account subscription thread
+#[cfg(feature = "solana")]
+#[derive(Debug)]
+enum LiveUpdate {
+    /// Subscribed to the stablecoin account
+    Subscribed,
+    /// The stablecoin account changed at this slot
+    AccountChanged(u64),
+    /// The WebSocket dropped or could not connect; polling takes over
+    Disconnected(String),
+}
+
+/// Represents a role assignment account
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+struct RoleInfo {
+    #[cfg(feature = "solana")]
+    account: Pubkey,
+    #[cfg(not(feature = "solana"))]
+    account: MockPubkey,
+    
+    role: Role,
+    
+    #[cfg(feature = "solana")]
+    assigned_by: Pubkey,
+    #[cfg(not(feature = "solana"))]
+    assigned_by: MockPubkey,
+    
+    assigned_at: i64,
+}
+
+/// On-chain `RoleAssignment` layout, following the 8-byte discriminator
+#[cfg(feature = "solana")]
+#[derive(AnchorDeserialize)]
+struct RoleAssignmentAccount {
+    role: Role,
+    account: Pubkey,
+    assigned_by: Pubkey,
+    assigned_at: i64,
+    bump: u8,
+}
+
 /// TUI screen/views
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum View {
@@ -212,6 +358,13 @@ struct App {
     connected: bool,
     connecting: bool,
     rpc_url: String,
+    /// Account subscription active, so polling is paused
+    live: bool,
+    /// Fetch on-chain state on the next loop iteration
+    needs_refresh: bool,
+    
+    #[cfg(feature = "solana")]
+    live_updates: Option<mpsc::Receiver<LiveUpdate>>,
     
     #[cfg(feature = "solana")]
     authority: Option<Pubkey>,
@@ -232,6 +385,7 @@ struct App {
     stablecoin_state: Option<StablecoinState>,
     minters: Vec<MinterInfo>,
     blacklist: Vec<BlacklistEntry>,
+    roles: Vec<RoleInfo>,
     
     // Stats
     last_refresh: Option<Instant>,
@@ -252,6 +406,10 @@ impl Default for App {
             connected: false,
             connecting: false,
             rpc_url: String::from("https://api.devnet.solana.com"),
+            live: false,
+            needs_refresh: false,
+            #[cfg(feature = "solana")]
+            live_updates: None,
             authority: None,
             #[cfg(feature = "solana")]
             program_id: Pubkey::try_from(PROGRAM_ID).unwrap_or_default(),
@@ -261,6 +419,7 @@ impl Default for App {
             stablecoin_state: None,
             minters: Vec::new(),
             blacklist: Vec::new(),
+            roles: Vec::new(),
             last_refresh: None,
             refresh_count: 0,
             error_count: 0,
@@ -271,7 +430,7 @@ impl Default for App {
 impl App {
     fn format_supply(&self) -> String {
         if let Some(state) = &self.stablecoin_state {
-            format_number(state.total_supply)
+            format_token_amount(state.total_supply, state.decimals)
         } else {
             "---".to_string()
         }
@@ -300,24 +459,53 @@ impl App {
             }
         }
     }
+    
+    /// Drain messages from the subscription thread
+    #[cfg(feature = "solana")]
+    fn apply_live_updates(&mut self) {
+        let updates: Vec<LiveUpdate> = match &self.live_updates {
+            Some(updates) => updates.try_iter().collect(),
+            None => return,
+        };
+        
+        for update in updates {
+            match update {
+                LiveUpdate::Subscribed => {
+                    self.live = true;
+                    // Catch up on anything missed while polling
+                    self.needs_refresh = true;
+                }
+                LiveUpdate::AccountChanged(_slot) => {
+                    self.needs_refresh = true;
+                }
+                LiveUpdate::Disconnected(reason) => {
+                    if self.live {
+                        self.set_status(format!("Live updates lost ({}), polling instead", reason));
+                    }
+                    self.live = false;
+                }
+            }
+        }
+    }
+    
+    /// Whether on-chain state should be fetched now: on request, or on the
+    /// polling interval while no subscription is active
+    #[cfg(feature = "solana")]
+    fn refresh_due(&self) -> bool {
+        if self.needs_refresh {
+            return true;
+        }
+        match self.last_refresh {
+            Some(t) => !self.live && t.elapsed() >= Duration::from_millis(REFRESH_INTERVAL_MS),
+            None => true,
+        }
+    }
 }
 
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
-fn format_number(n: u64) -> String {
-    let s = n.to_string();
-    let mut result = String::new();
-    for (i, c) in s.chars().enumerate() {
-        if i > 0 && (s.len() - i) % 3 == 0 {
-            result.push(',');
-        }
-        result.push(c);
-    }
-    result
-}
-
 #[cfg(feature = "solana")]
 fn shorten_pubkey(pubkey: &Pubkey) -> String {
     let s = pubkey.to_string();
@@ -334,6 +522,26 @@ fn shorten_pubkey(pubkey: &MockPubkey) -> String {
     }
 }
 
+/// Pause status line, including who paused, why, and when a scheduled pause lifts
+fn format_pause_status(state: &StablecoinState) -> String {
+    if !state.paused {
+        return "NO".to_string();
+    }
+    if state.pause_until != 0 && chrono::Utc::now().timestamp() >= state.pause_until {
+        return "NO (scheduled pause ended)".to_string();
+    }
+    let mut status = format!("YES (by {})", shorten_pubkey(&state.paused_by));
+    if state.pause_until != 0 {
+        if let Some(until) = chrono::DateTime::from_timestamp(state.pause_until, 0) {
+            status.push_str(&format!(" until {}", until.format("%Y-%m-%d %H:%M UTC")));
+        }
+    }
+    if !state.pause_reason.is_empty() {
+        status.push_str(&format!(" - {}", state.pause_reason));
+    }
+    status
+}
+
 #[cfg(feature = "solana")]
 fn expand_tilde(path: &str) -> String {
     if path.starts_with('~') {
@@ -344,6 +552,19 @@ fn expand_tilde(path: &str) -> String {
     path.to_string()
 }
 
+/// WebSocket endpoint matching an RPC URL (the local validator serves it on the next port)
+#[cfg(feature = "solana")]
+fn websocket_url(rpc_url: &str) -> String {
+    let ws_url = if let Some(rest) = rpc_url.strip_prefix("https://") {
+        format!("wss://{}", rest)
+    } else if let Some(rest) = rpc_url.strip_prefix("http://") {
+        format!("ws://{}", rest)
+    } else {
+        rpc_url.to_string()
+    };
+    ws_url.replace(":8899", ":8900")
+}
+
 #[cfg(feature = "solana")]
 fn derive_stablecoin_pda(authority: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(
@@ -360,6 +581,15 @@ fn derive_minter_pda(stablecoin: &Pubkey, minter: &Pubkey, program_id: &Pubkey)
     )
 }
 
+#[cfg(feature = "solana")]
+fn derive_role_pda(stablecoin: &Pubkey, account: &Pubkey, role: Role, bump: u8, program_id: &Pubkey) -> Option<Pubkey> {
+    Pubkey::create_program_address(
+        &[ROLE_SEED, stablecoin.to_bytes().as_ref(), account.to_bytes().as_ref(), role.seed(), &[bump]],
+        program_id,
+    )
+    .ok()
+}
+
 #[cfg(feature = "solana")]
 fn derive_blacklist_pda(stablecoin: &Pubkey, account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(
@@ -397,6 +627,119 @@ fn setup_solana_client(
     Ok((program, authority))
 }
 
+/// Fetch and decode the stablecoin state, `None` if the account doesn't exist
+#[cfg(feature = "solana")]
+fn fetch_stablecoin_state(rpc: &RpcClient, stablecoin: &Pubkey) -> Result<Option<StablecoinState>> {
+    let Some(account) = rpc
+        .get_account_with_commitment(stablecoin, CommitmentConfig::confirmed())?
+        .value
+    else {
+        return Ok(None);
+    };
+    
+    let mut data = account.data.get(8..)
+        .ok_or_else(|| anyhow::anyhow!("Stablecoin account data too short"))?;
+    let state = StablecoinStateAccount::deserialize(&mut data)?;
+    let mut state = StablecoinState::from(state);
+    
+    // Decimals live on the mint; fall back to raw base units if it can't be read
+    state.decimals = rpc
+        .get_account_with_commitment(&state.asset_mint, CommitmentConfig::confirmed())?
+        .value
+        .and_then(|mint| mint.data.get(MINT_DECIMALS_OFFSET).copied())
+        .unwrap_or(0);
+    
+    Ok(Some(state))
+}
+
+/// Fetch every role assignment for a stablecoin, newest first.
+///
+/// `RoleAssignment` doesn't store its stablecoin, so accounts are filtered by
+/// discriminator on the RPC side and matched to the stablecoin by PDA here.
+#[cfg(feature = "solana")]
+fn fetch_roles(rpc: &RpcClient, stablecoin: &Pubkey, program_id: &Pubkey) -> Result<Vec<RoleInfo>> {
+    let discriminator = &hash(b"account:RoleAssignment").to_bytes()[..8];
+    let accounts = rpc.get_program_accounts_with_config(
+        program_id,
+        RpcProgramAccountsConfig {
+            filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, discriminator.to_vec()))]),
+            ..Default::default()
+        },
+    )?;
+    
+    let mut roles: Vec<RoleInfo> = accounts
+        .into_iter()
+        .filter_map(|(address, account)| {
+            let assignment = RoleAssignmentAccount::deserialize(&mut account.data.get(8..)?).ok()?;
+            let expected = derive_role_pda(stablecoin, &assignment.account, assignment.role, assignment.bump, program_id)?;
+            (expected == address).then_some(RoleInfo {
+                account: assignment.account,
+                role: assignment.role,
+                assigned_by: assignment.assigned_by,
+                assigned_at: assignment.assigned_at,
+            })
+        })
+        .collect();
+    roles.sort_by(|a, b| b.assigned_at.cmp(&a.assigned_at));
+    
+    Ok(roles)
+}
+
+#[cfg(feature = "solana")]
+fn refresh_state(app: &mut App, rpc: &RpcClient, stablecoin: &Pubkey) {
+    app.needs_refresh = false;
+    app.last_refresh = Some(Instant::now());
+    
+    let fetched = fetch_stablecoin_state(rpc, stablecoin)
+        .and_then(|state| fetch_roles(rpc, stablecoin, &app.program_id).map(|roles| (state, roles)));
+    match fetched {
+        Ok((state, roles)) => {
+            app.stablecoin_state = state;
+            app.roles = roles;
+            app.refresh_count += 1;
+        }
+        Err(e) => {
+            app.error_count += 1;
+            app.set_status(format!("Refresh failed: {}", e));
+        }
+    }
+}
+
+/// Subscribe to `account` on a background thread, sending a message per change.
+/// Resubscribes after `RESUBSCRIBE_INTERVAL_MS` whenever the WebSocket drops,
+/// and exits once the receiving `App` is gone.
+#[cfg(feature = "solana")]
+fn spawn_account_subscription(ws_url: String, account: Pubkey, updates: mpsc::Sender<LiveUpdate>) {
+    std::thread::spawn(move || loop {
+        let config = RpcAccountInfoConfig {
+            commitment: Some(CommitmentConfig::confirmed()),
+            ..Default::default()
+        };
+        
+        // Notifications only trigger a refresh, so the encoding doesn't matter
+        let reason = match PubsubClient::account_subscribe(&ws_url, &account, Some(config)) {
+            Ok((_subscription, notifications)) => {
+                if updates.send(LiveUpdate::Subscribed).is_err() {
+                    return;
+                }
+                // `recv` fails once the socket closes
+                while let Ok(notification) = notifications.recv() {
+                    if updates.send(LiveUpdate::AccountChanged(notification.context.slot)).is_err() {
+                        return;
+                    }
+                }
+                "connection closed".to_string()
+            }
+            Err(e) => e.to_string(),
+        };
+        
+        if updates.send(LiveUpdate::Disconnected(reason)).is_err() {
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(RESUBSCRIBE_INTERVAL_MS));
+    });
+}
+
 // ============================================================================
 // UI Rendering
 // ============================================================================
@@ -436,7 +779,9 @@ fn render_header(f: &mut Frame, app: &App, area: Rect) {
         "Demo"
     };
     
-    let title = if app.connected {
+    let title = if app.connected && app.live {
+        format!("SSS Token Admin [{}] - Connected to {} (live)", mode, app.rpc_url)
+    } else if app.connected {
         format!("SSS Token Admin [{}] - Connected to {}", mode, app.rpc_url)
     } else if app.connecting {
         format!("SSS Token Admin [{}] - Connecting to {}...", mode, app.rpc_url)
@@ -477,7 +822,7 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect) {
              Authority: {}",
             app.format_supply(),
             app.get_preset_name(),
-            if state.paused { "YES" } else { "NO" },
+            format_pause_status(state),
             if state.compliance_enabled { "ENABLED" } else { "DISABLED" },
             shorten_pubkey(&state.authority)
         )
@@ -512,6 +857,7 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect) {
 }
 
 fn render_minters(f: &mut Frame, app: &App, area: Rect) {
+    let decimals = app.stablecoin_state.as_ref().map_or(0, |state| state.decimals);
     let items: Vec<ListItem> = if app.minters.is_empty() {
         vec![ListItem::new("No minters registered")]
     } else {
@@ -519,9 +865,9 @@ fn render_minters(f: &mut Frame, app: &App, area: Rect) {
             ListItem::new(format!(
                 "{}: Quota {} | Minted {} | Available {}",
                 shorten_pubkey(&m.minter),
-                format_number(m.quota),
-                format_number(m.minted_amount),
-                format_number(m.quota.saturating_sub(m.minted_amount))
+                format_token_amount(m.quota, decimals),
+                format_token_amount(m.minted_amount, decimals),
+                format_token_amount(m.quota.saturating_sub(m.minted_amount), decimals)
             ))
         }).collect()
     };
@@ -557,7 +903,28 @@ fn render_blacklist(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(list, area);
 }
 
-fn render_roles(f: &mut Frame, _app: &App, area: Rect) {
+fn render_roles(f: &mut Frame, app: &App, area: Rect) {
+    if !app.roles.is_empty() {
+        let items: Vec<ListItem> = app.roles.iter().map(|r| {
+            ListItem::new(format!(
+                "{}: {} (by {} at {})",
+                shorten_pubkey(&r.account),
+                r.role,
+                shorten_pubkey(&r.assigned_by),
+                chrono::DateTime::from_timestamp(r.assigned_at, 0)
+                    .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+                    .unwrap_or_else(|| "?".to_string())
+            ))
+        }).collect();
+        
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(format_roles_title(&app.roles)))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        
+        f.render_widget(list, area);
+        return;
+    }
+    
     let roles_text = "Available Roles:\n\
         \n\
         * Master     - Full administrative control\n\
@@ -574,6 +941,19 @@ fn render_roles(f: &mut Frame, _app: &App, area: Rect) {
     f.render_widget(roles, area);
 }
 
+/// Panel title with the total and a count per assigned role
+fn format_roles_title(roles: &[RoleInfo]) -> String {
+    let all = [Role::Master, Role::Minter, Role::Burner, Role::Blacklister, Role::Pauser, Role::Seizer];
+    let counts: Vec<String> = all
+        .iter()
+        .filter_map(|role| {
+            let count = roles.iter().filter(|r| r.role == *role).count();
+            (count > 0).then(|| format!("{} {}", role, count))
+        })
+        .collect();
+    format!("Roles ({}) - {}", roles.len(), counts.join(", "))
+}
+
 fn render_actions(f: &mut Frame, _app: &App, area: Rect) {
     let actions = vec![
         "[i] Initialize new stablecoin",
@@ -618,6 +998,8 @@ fn render_help(f: &mut Frame, _app: &App, area: Rect) {
         Connection:\n\
         * Press 'c' to connect to Solana RPC\n\
         * Press 'r' to refresh blockchain data\n\
+        * Updates arrive live over WebSocket (LIVE in the status bar), with\n\
+          polling every 5s while the subscription is down\n\
         \n\
         Actions:\n\
         * Available actions depend on your role permissions\n\
@@ -642,7 +1024,8 @@ fn render_status_bar(f: &mut Frame, app: &App, area: Rect) {
         msg.clone()
     } else if app.connected {
         format!(
-            "Connected | Refresh: {} | Last update: {}",
+            "Connected | {} | Refresh: {} | Last update: {}",
+            if app.live { "LIVE" } else { "Polling" },
             app.refresh_count,
             app.last_refresh
                 .map(|t| format!("{:.1}s ago", t.elapsed().as_secs_f32()))
@@ -697,6 +1080,7 @@ fn handle_input(key: KeyCode, modifiers: KeyModifiers, app: &mut App) -> Result<
             app.set_status("Initiating connection...");
         }
         KeyCode::Char('r') if modifiers == KeyModifiers::NONE => {
+            app.needs_refresh = app.connected;
             app.set_status("Refreshing data...");
         }
         KeyCode::Char('?') if modifiers == KeyModifiers::NONE => {
@@ -763,6 +1147,9 @@ fn main() -> Result<()> {
         app.rpc_url = rpc_url;
     }
     
+    #[cfg(feature = "solana")]
+    let mut rpc_client: Option<RpcClient> = None;
+    
     // Main event loop
     loop {
         // Draw UI
@@ -799,8 +1186,18 @@ fn main() -> Result<()> {
                         let (stablecoin_pda, _) = derive_stablecoin_pda(&authority, &app.program_id);
                         app.stablecoin_pda = Some(stablecoin_pda);
                         
-                        app.last_refresh = Some(Instant::now());
-                        app.refresh_count = 1;
+                        rpc_client = Some(RpcClient::new_with_commitment(
+                            app.rpc_url.clone(),
+                            CommitmentConfig::confirmed(),
+                        ));
+                        app.needs_refresh = true;
+                        
+                        let ws_url = std::env::var("SSS_WS_URL")
+                            .unwrap_or_else(|_| websocket_url(&app.rpc_url));
+                        let (updates_tx, updates_rx) = mpsc::channel();
+                        spawn_account_subscription(ws_url, stablecoin_pda, updates_tx);
+                        app.live_updates = Some(updates_rx);
+                        
                         app.set_status(format!("Connected as {}", shorten_pubkey(&authority)));
                     }
                     Err(e) => {
@@ -828,6 +1225,11 @@ fn main() -> Result<()> {
                     preset: 2,
                     compliance_enabled: true,
                     bump: 254,
+                    paused_by: MockPubkey::default(),
+                    paused_at: 0,
+                    pause_reason: String::new(),
+                    pause_until: 0,
+                    decimals: 6,
                 });
                 
                 app.minters = vec![
@@ -839,11 +1241,29 @@ fn main() -> Result<()> {
                     },
                 ];
                 
+                app.roles = vec![
+                    RoleInfo {
+                        account: MockPubkey::new_unique(),
+                        role: Role::Minter,
+                        assigned_by: app.authority.unwrap(),
+                        assigned_at: chrono::Utc::now().timestamp(),
+                    },
+                ];
+                
                 app.last_refresh = Some(Instant::now());
                 app.refresh_count = 1;
                 app.set_status("Connected (Demo Mode)".to_string());
             }
         }
+        
+        // Refresh on-chain state on changes, on request, or by polling
+        #[cfg(feature = "solana")]
+        if let (Some(rpc), Some(stablecoin_pda)) = (&rpc_client, app.stablecoin_pda) {
+            app.apply_live_updates();
+            if app.refresh_due() {
+                refresh_state(&mut app, rpc, &stablecoin_pda);
+            }
+        }
     }
     
     // Cleanup terminal
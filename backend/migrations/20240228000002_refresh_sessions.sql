@@ -0,0 +1,22 @@
+-- Solana Stablecoin Standard - Refresh Token Sessions Migration
+-- Tracks issued refresh tokens for rotation and reuse detection
+
+--------------------------------------------------------------------------------
+-- Refresh sessions table (one row per issued refresh token, keyed by jti)
+--------------------------------------------------------------------------------
+CREATE TABLE refresh_sessions (
+    jti UUID PRIMARY KEY,
+    family_id UUID NOT NULL,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    rotated_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+--------------------------------------------------------------------------------
+-- Indexes
+--------------------------------------------------------------------------------
+CREATE INDEX idx_refresh_sessions_family ON refresh_sessions(family_id);
+CREATE INDEX idx_refresh_sessions_user ON refresh_sessions(user_id);
+CREATE INDEX idx_refresh_sessions_expires ON refresh_sessions(expires_at);
@@ -0,0 +1,7 @@
+-- Solana Stablecoin Standard - Mint Screening Migration
+-- Lets issuers opt out of pre-mint compliance screening for SSS-2 stablecoins
+
+--------------------------------------------------------------------------------
+-- Per-stablecoin screening flag (enabled by default)
+--------------------------------------------------------------------------------
+ALTER TABLE stablecoins ADD COLUMN screen_on_mint BOOLEAN NOT NULL DEFAULT true;
@@ -0,0 +1,9 @@
+-- Solana Stablecoin Standard - Audit Request ID Migration
+-- Correlates audit entries with the HTTP request (x-request-id) that produced them
+
+--------------------------------------------------------------------------------
+-- Request correlation ID (NULL for entries written outside a request)
+--------------------------------------------------------------------------------
+ALTER TABLE audit_log ADD COLUMN request_id TEXT;
+
+CREATE INDEX idx_audit_log_request_id ON audit_log(request_id);
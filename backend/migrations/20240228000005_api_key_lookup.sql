@@ -0,0 +1,7 @@
+-- Solana Stablecoin Standard - API Key Lookup Migration
+-- API keys are authenticated by the SHA-256 hash of the presented key
+
+--------------------------------------------------------------------------------
+-- One key per hash, looked up on every X-API-Key request
+--------------------------------------------------------------------------------
+CREATE UNIQUE INDEX idx_api_keys_key_hash ON api_keys(key_hash);
@@ -0,0 +1,7 @@
+-- Solana Stablecoin Standard - Max Supply Migration
+-- Authorized supply ceiling, mirrored from the on-chain StablecoinState
+
+--------------------------------------------------------------------------------
+-- Per-stablecoin supply cap in base units (0 = uncapped)
+--------------------------------------------------------------------------------
+ALTER TABLE stablecoins ADD COLUMN max_supply BIGINT NOT NULL DEFAULT 0;
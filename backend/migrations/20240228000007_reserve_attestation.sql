@@ -0,0 +1,8 @@
+-- Solana Stablecoin Standard - Reserve Attestation Migration
+-- Off-chain collateral figure behind the proof-of-reserves endpoint
+
+--------------------------------------------------------------------------------
+-- Attested reserve in base units; NULL until the issuer first reports it
+--------------------------------------------------------------------------------
+ALTER TABLE stablecoins ADD COLUMN reserve_amount BIGINT;
+ALTER TABLE stablecoins ADD COLUMN reserve_attested_at TIMESTAMPTZ;
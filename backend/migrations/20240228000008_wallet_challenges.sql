@@ -0,0 +1,17 @@
+-- Solana Stablecoin Standard - Wallet Challenges Migration
+-- Single-use nonces for signing in with a Solana wallet
+
+--------------------------------------------------------------------------------
+-- Outstanding challenges (deleted when verified or once expired)
+--------------------------------------------------------------------------------
+CREATE TABLE wallet_challenges (
+    nonce VARCHAR(64) PRIMARY KEY,
+    pubkey VARCHAR(44) NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+--------------------------------------------------------------------------------
+-- Indexes
+--------------------------------------------------------------------------------
+CREATE INDEX idx_wallet_challenges_expires ON wallet_challenges(expires_at);
@@ -0,0 +1,20 @@
+-- Solana Stablecoin Standard - Role Snapshot Migration
+-- Indexed copy of on-chain RoleAssignment accounts, so listing roles skips the program-wide scan
+
+--------------------------------------------------------------------------------
+-- When the snapshot was seeded from a full scan; NULL until the first listing
+--------------------------------------------------------------------------------
+ALTER TABLE stablecoins ADD COLUMN roles_snapshot_at TIMESTAMPTZ;
+
+--------------------------------------------------------------------------------
+-- On-chain role assignments (kept current from RoleAssigned / RoleRevoked events)
+--------------------------------------------------------------------------------
+CREATE TABLE onchain_role_snapshots (
+    stablecoin_id UUID NOT NULL REFERENCES stablecoins(id) ON DELETE CASCADE,
+    role VARCHAR(50) NOT NULL,
+    account VARCHAR(44) NOT NULL,
+    assigned_by VARCHAR(44) NOT NULL,
+    assigned_at BIGINT NOT NULL,
+    expires_at BIGINT,
+    PRIMARY KEY (stablecoin_id, role, account)
+);
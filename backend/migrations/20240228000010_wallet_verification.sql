@@ -0,0 +1,23 @@
+-- Solana Stablecoin Standard - Wallet Verification Migration
+-- Separates a user's signature-verified wallet from a self-declared solana_pubkey
+
+--------------------------------------------------------------------------------
+-- When the user proved control of solana_pubkey by signing a wallet challenge;
+-- NULL while the pubkey is only claimed (set at registration or profile update)
+--------------------------------------------------------------------------------
+ALTER TABLE users ADD COLUMN wallet_verified_at TIMESTAMPTZ;
+
+--------------------------------------------------------------------------------
+-- Users created by wallet sign-in already signed for their pubkey. The audit
+-- entry tells them apart from a password registration using the same email.
+--------------------------------------------------------------------------------
+UPDATE users
+SET wallet_verified_at = created_at
+WHERE solana_pubkey IS NOT NULL
+  AND email = solana_pubkey || '@wallet.invalid'
+  AND EXISTS (
+      SELECT 1 FROM audit_log
+      WHERE audit_log.user_id = users.id
+        AND audit_log.action = 'user.register'
+        AND audit_log.details->>'method' = 'wallet'
+  );
@@ -0,0 +1,18 @@
+-- Solana Stablecoin Standard - Unique Verified Wallet Migration
+-- A wallet can be verified by one user only, so wallet sign-in has one account to land in
+
+--------------------------------------------------------------------------------
+-- Drop unverified claims to wallets another user has verified
+--------------------------------------------------------------------------------
+UPDATE users
+SET solana_pubkey = NULL, updated_at = NOW()
+WHERE wallet_verified_at IS NULL
+  AND solana_pubkey IN (
+      SELECT solana_pubkey FROM users WHERE wallet_verified_at IS NOT NULL
+  );
+
+--------------------------------------------------------------------------------
+-- Indexes
+--------------------------------------------------------------------------------
+CREATE UNIQUE INDEX idx_users_verified_wallet ON users(solana_pubkey)
+    WHERE wallet_verified_at IS NOT NULL;
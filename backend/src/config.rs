@@ -1,5 +1,5 @@
 use anyhow::{Context, Result};
-use solana_sdk::pubkey::Pubkey;
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
 use std::env;
 
 /// Application environment
@@ -30,6 +30,33 @@ impl std::fmt::Display for Environment {
     }
 }
 
+/// Per-route-group request limits layered over the global limit.
+///
+/// A group without its own limit shares the global per-client bucket, so
+/// leaving everything unset keeps the single global limit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitGroups {
+    /// Login, registration and token refresh
+    pub auth: Option<u32>,
+    /// Mint and burn
+    pub mint: Option<u32>,
+    /// GET requests
+    pub read: Option<u32>,
+    /// Factor applied to every limit for admin-role JWTs (1 = same as everyone)
+    pub admin_multiplier: u32,
+}
+
+impl Default for RateLimitGroups {
+    fn default() -> Self {
+        Self {
+            auth: None,
+            mint: None,
+            read: None,
+            admin_multiplier: 1,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AppConfig {
     pub server_addr: String,
@@ -37,12 +64,28 @@ pub struct AppConfig {
     pub redis_url: Option<String>,
     pub solana_rpc_url: String,
     pub program_id: Pubkey,
+    /// Default commitment for reads, sends and confirmations
+    pub solana_commitment: CommitmentConfig,
     /// Authority keypair in base58 format (optional - can be set via API)
     pub authority_keypair: Option<String>,
+    /// Minimum authority SOL balance (lamports) for the service to report ready
+    pub min_authority_balance: u64,
+    /// Compute unit limit prepended to transactions (unset = runtime default)
+    pub compute_unit_limit: Option<u32>,
+    /// Priority fee in micro-lamports per compute unit (unset = no priority fee)
+    pub priority_fee_microlamports: Option<u64>,
+    /// Retries for RPC calls that hit a 429 or time out (0 = fail immediately)
+    pub rpc_max_retries: u32,
+    /// Seconds to wait for a transaction to confirm before resubmitting it
+    pub confirmation_timeout_secs: u64,
+    /// Resubmissions with a fresh blockhash before giving up (0 = never resubmit)
+    pub confirmation_max_resubmits: u32,
     pub jwt_secret: String,
     pub jwt_expiry: u64,
     pub rate_limit_requests: u32,
     pub rate_limit_window_secs: u64,
+    /// Route-group and admin overrides of `rate_limit_requests`
+    pub rate_limit_groups: RateLimitGroups,
     pub log_level: String,
     /// Cluster name for explorer URLs (devnet, testnet, mainnet)
     pub cluster: String,
@@ -87,6 +130,17 @@ impl AppConfig {
             .parse::<Pubkey>()
             .with_context(|| format!("Invalid PROGRAM_ID: {}", program_id_str))?;
         
+        let commitment_str = env::var("SOLANA_COMMITMENT")
+            .unwrap_or_else(|_| "confirmed".to_string())
+            .to_lowercase();
+        
+        let solana_commitment = crate::solana::parse_commitment(&commitment_str)
+            .with_context(|| format!(
+                "Invalid SOLANA_COMMITMENT: {} (expected one of: {})",
+                commitment_str,
+                crate::solana::ALLOWED_COMMITMENTS.join(", "),
+            ))?;
+        
         // Authority keypair is optional - can be loaded dynamically
         let authority_keypair = env::var("AUTHORITY_KEYPAIR").ok();
         
@@ -140,6 +194,44 @@ impl AppConfig {
             .parse()
             .unwrap_or(60);
         
+        let rate_limit_groups = RateLimitGroups {
+            auth: parse_limit("RATE_LIMIT_AUTH_REQUESTS"),
+            mint: parse_limit("RATE_LIMIT_MINT_REQUESTS"),
+            read: parse_limit("RATE_LIMIT_READ_REQUESTS"),
+            admin_multiplier: parse_limit("RATE_LIMIT_ADMIN_MULTIPLIER").unwrap_or(1),
+        };
+        
+        let min_authority_balance = env::var("MIN_AUTHORITY_BALANCE_LAMPORTS")
+            .unwrap_or_else(|_| "10000000".to_string())
+            .parse()
+            .unwrap_or(10_000_000);
+        
+        let compute_unit_limit = env::var("COMPUTE_UNIT_LIMIT")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|v| *v > 0);
+        
+        let priority_fee_microlamports = env::var("PRIORITY_FEE_MICROLAMPORTS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|v| *v > 0);
+        
+        let rpc_max_retries = env::var("RPC_MAX_RETRIES")
+            .unwrap_or_else(|_| "3".to_string())
+            .parse()
+            .unwrap_or(3);
+        
+        let confirmation_timeout_secs = env::var("CONFIRMATION_TIMEOUT_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(60);
+        
+        let confirmation_max_resubmits = env::var("CONFIRMATION_MAX_RESUBMITS")
+            .unwrap_or_else(|_| "2".to_string())
+            .parse()
+            .unwrap_or(2);
+        
         let log_level = env::var("LOG_LEVEL")
             .unwrap_or_else(|_| "info".to_string());
         
@@ -189,11 +281,19 @@ impl AppConfig {
             redis_url,
             solana_rpc_url,
             program_id,
+            solana_commitment,
             authority_keypair,
+            min_authority_balance,
+            compute_unit_limit,
+            priority_fee_microlamports,
+            rpc_max_retries,
+            confirmation_timeout_secs,
+            confirmation_max_resubmits,
             jwt_secret,
             jwt_expiry,
             rate_limit_requests,
             rate_limit_window_secs,
+            rate_limit_groups,
             log_level,
             cluster,
             environment,
@@ -203,3 +303,11 @@ impl AppConfig {
         })
     }
 }
+
+/// A positive request count from the environment; unset, invalid or 0 means no override
+fn parse_limit(var: &str) -> Option<u32> {
+    env::var(var)
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .filter(|v| *v > 0)
+}
@@ -94,10 +94,11 @@ impl Database {
         tx_signature: Option<&str>,
         details: Option<serde_json::Value>,
         ip_address: Option<&str>,
+        request_id: Option<&str>,
     ) -> Result<()> {
         sqlx::query(r#"
-            INSERT INTO audit_log (stablecoin_id, user_id, action, tx_signature, details, ip_address)
-            VALUES ($1, $2, $3, $4, $5, $6::inet)
+            INSERT INTO audit_log (stablecoin_id, user_id, action, tx_signature, details, ip_address, request_id)
+            VALUES ($1, $2, $3, $4, $5, $6::inet, $7)
         "#)
         .bind(stablecoin_id)
         .bind(user_id)
@@ -105,12 +106,383 @@ impl Database {
         .bind(tx_signature)
         .bind(details)
         .bind(ip_address)
+        .bind(request_id)
         .execute(&self.pool)
         .await?;
         
         Ok(())
     }
 
+    /// Insert a stablecoin found on chain, owned by the active user whose
+    /// verified wallet is its authority. Returns `None`, inserting nothing, when
+    /// the PDA is already registered or no user has verified the authority key.
+    /// A `solana_pubkey` that was only claimed never matches.
+    pub async fn register_discovered_stablecoin(
+        &self,
+        stablecoin: &crate::models::DiscoveredStablecoin,
+    ) -> Result<Option<uuid::Uuid>> {
+        let id = sqlx::query_scalar(r#"
+            INSERT INTO stablecoins
+                (owner_id, name, symbol, decimals, preset, asset_mint, stablecoin_pda, authority_pubkey)
+            SELECT id, $1, $2, $3, $4, $5, $6, $7
+            FROM users
+            WHERE solana_pubkey = $7 AND wallet_verified_at IS NOT NULL AND is_active = true
+            ORDER BY created_at
+            LIMIT 1
+            ON CONFLICT (stablecoin_pda) DO NOTHING
+            RETURNING id
+        "#)
+        .bind(&stablecoin.name)
+        .bind(&stablecoin.symbol)
+        .bind(stablecoin.decimals)
+        .bind(stablecoin.preset)
+        .bind(&stablecoin.asset_mint)
+        .bind(&stablecoin.stablecoin_pda)
+        .bind(&stablecoin.authority)
+        .fetch_optional(&self.pool)
+        .await?;
+        
+        Ok(id)
+    }
+
+    /// Record the confirmation outcome of a transaction in its audit rows' details
+    pub async fn update_audit_tx_status(&self, tx_signature: &str, status: &str) -> Result<()> {
+        sqlx::query(r#"
+            UPDATE audit_log
+            SET details = COALESCE(details, '{}'::jsonb) || jsonb_build_object('status', $2::text)
+            WHERE tx_signature = $1
+        "#)
+        .bind(tx_signature)
+        .bind(status)
+        .execute(&self.pool)
+        .await?;
+        
+        Ok(())
+    }
+
+    /// Records a newly issued refresh token
+    pub async fn create_refresh_session(
+        &self,
+        jti: uuid::Uuid,
+        family_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<()> {
+        sqlx::query(r#"
+            INSERT INTO refresh_sessions (jti, family_id, user_id, expires_at)
+            VALUES ($1, $2, $3, $4)
+        "#)
+        .bind(jti)
+        .bind(family_id)
+        .bind(user_id)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await?;
+        
+        Ok(())
+    }
+
+    /// Stores a wallet sign-in challenge, clearing out expired ones
+    pub async fn create_wallet_challenge(
+        &self,
+        nonce: &str,
+        pubkey: &str,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<()> {
+        sqlx::query("DELETE FROM wallet_challenges WHERE expires_at <= NOW()")
+            .execute(&self.pool)
+            .await?;
+        sqlx::query(r#"
+            INSERT INTO wallet_challenges (nonce, pubkey, expires_at)
+            VALUES ($1, $2, $3)
+        "#)
+        .bind(nonce)
+        .bind(pubkey)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await?;
+        
+        Ok(())
+    }
+
+    /// Deletes an unexpired challenge issued to `pubkey`.
+    /// Returns false if there was none, so each challenge verifies at most once.
+    pub async fn consume_wallet_challenge(&self, nonce: &str, pubkey: &str) -> Result<bool> {
+        let consumed = sqlx::query(r#"
+            DELETE FROM wallet_challenges
+            WHERE nonce = $1 AND pubkey = $2 AND expires_at > NOW()
+        "#)
+        .bind(nonce)
+        .bind(pubkey)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+        
+        Ok(consumed > 0)
+    }
+
+    /// The user whose verified wallet is `pubkey`, active or not. There is at
+    /// most one, since verified wallets are unique.
+    pub async fn verified_wallet_owner(&self, pubkey: &str) -> Result<Option<uuid::Uuid>> {
+        let id = sqlx::query_scalar(
+            "SELECT id FROM users WHERE solana_pubkey = $1 AND wallet_verified_at IS NOT NULL"
+        )
+        .bind(pubkey)
+        .fetch_optional(&self.pool)
+        .await?;
+        
+        Ok(id)
+    }
+
+    /// Drop every unverified claim to `pubkey`, once its owner has signed for it.
+    /// Returns how many users lost the claim.
+    pub async fn clear_unverified_wallet_claims(&self, pubkey: &str) -> Result<u64> {
+        let cleared = sqlx::query(r#"
+            UPDATE users
+            SET solana_pubkey = NULL, updated_at = NOW()
+            WHERE solana_pubkey = $1 AND wallet_verified_at IS NULL
+        "#)
+        .bind(pubkey)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+        
+        Ok(cleared)
+    }
+
+    /// Load the role snapshot of a stablecoin, or `None` if it was never seeded
+    pub async fn load_role_snapshot(
+        &self,
+        stablecoin_id: uuid::Uuid,
+    ) -> Result<Option<Vec<crate::models::OnchainRoleAssignment>>> {
+        let seeded: Option<chrono::DateTime<chrono::Utc>> = sqlx::query_scalar(
+            "SELECT roles_snapshot_at FROM stablecoins WHERE id = $1"
+        )
+        .bind(stablecoin_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .flatten();
+        if seeded.is_none() {
+            return Ok(None);
+        }
+        
+        let roles = sqlx::query_as(r#"
+            SELECT role, account, assigned_by, assigned_at, expires_at
+            FROM onchain_role_snapshots
+            WHERE stablecoin_id = $1
+            ORDER BY assigned_at DESC
+        "#)
+        .bind(stablecoin_id)
+        .fetch_all(&self.pool)
+        .await?;
+        
+        Ok(Some(roles))
+    }
+
+    /// Replace the role snapshot of a stablecoin with the result of a full scan
+    pub async fn replace_role_snapshot(
+        &self,
+        stablecoin_id: uuid::Uuid,
+        roles: &[crate::models::OnchainRoleAssignment],
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        
+        sqlx::query("DELETE FROM onchain_role_snapshots WHERE stablecoin_id = $1")
+            .bind(stablecoin_id)
+            .execute(&mut *tx)
+            .await?;
+        for role in roles {
+            sqlx::query(r#"
+                INSERT INTO onchain_role_snapshots
+                    (stablecoin_id, role, account, assigned_by, assigned_at, expires_at)
+                VALUES ($1, $2, $3, $4, $5, $6)
+            "#)
+            .bind(stablecoin_id)
+            .bind(&role.role)
+            .bind(&role.account)
+            .bind(&role.assigned_by)
+            .bind(role.assigned_at)
+            .bind(role.expires_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+        sqlx::query("UPDATE stablecoins SET roles_snapshot_at = NOW() WHERE id = $1")
+            .bind(stablecoin_id)
+            .execute(&mut *tx)
+            .await?;
+        
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Apply an indexed account change to the stablecoin at `stablecoin_pda`.
+    /// Role changes only touch seeded snapshots (an unseeded one picks them up
+    /// in its first scan); blacklistings found on chain are attributed to the
+    /// stablecoin's owner. Unregistered stablecoins are ignored.
+    pub async fn apply_account_change(
+        &self,
+        stablecoin_pda: &str,
+        change: &crate::models::AccountChange,
+    ) -> Result<()> {
+        use crate::models::AccountChange;
+        
+        match change {
+            AccountChange::RoleAssigned(role) => {
+                sqlx::query(r#"
+                    INSERT INTO onchain_role_snapshots
+                        (stablecoin_id, role, account, assigned_by, assigned_at, expires_at)
+                    SELECT id, $2, $3, $4, $5, $6
+                    FROM stablecoins
+                    WHERE stablecoin_pda = $1 AND roles_snapshot_at IS NOT NULL
+                    ON CONFLICT (stablecoin_id, role, account) DO UPDATE
+                    SET assigned_by = $4, assigned_at = $5, expires_at = $6
+                "#)
+                .bind(stablecoin_pda)
+                .bind(&role.role)
+                .bind(&role.account)
+                .bind(&role.assigned_by)
+                .bind(role.assigned_at)
+                .bind(role.expires_at)
+                .execute(&self.pool)
+                .await?;
+            }
+            AccountChange::RoleRemoved { role, account } => {
+                sqlx::query(r#"
+                    DELETE FROM onchain_role_snapshots
+                    WHERE stablecoin_id = (SELECT id FROM stablecoins WHERE stablecoin_pda = $1)
+                      AND role = $2 AND account = $3
+                "#)
+                .bind(stablecoin_pda)
+                .bind(role)
+                .bind(account)
+                .execute(&self.pool)
+                .await?;
+            }
+            AccountChange::MinterQuota { minter, quota } => {
+                sqlx::query(r#"
+                    INSERT INTO minter_quotas (stablecoin_id, minter_pubkey, quota, minted_amount)
+                    SELECT id, $2, $3, 0 FROM stablecoins WHERE stablecoin_pda = $1
+                    ON CONFLICT (stablecoin_id, minter_pubkey)
+                    DO UPDATE SET quota = $3, updated_at = NOW()
+                "#)
+                .bind(stablecoin_pda)
+                .bind(minter)
+                .bind(*quota as i64)
+                .execute(&self.pool)
+                .await?;
+            }
+            AccountChange::MinterRemoved { minter } => {
+                sqlx::query(r#"
+                    DELETE FROM minter_quotas
+                    WHERE stablecoin_id = (SELECT id FROM stablecoins WHERE stablecoin_pda = $1)
+                      AND minter_pubkey = $2
+                "#)
+                .bind(stablecoin_pda)
+                .bind(minter)
+                .execute(&self.pool)
+                .await?;
+            }
+            AccountChange::MinterRotated { old_minter, new_minter, quota, minted_amount } => {
+                let mut tx = self.pool.begin().await?;
+                sqlx::query(r#"
+                    DELETE FROM minter_quotas
+                    WHERE stablecoin_id = (SELECT id FROM stablecoins WHERE stablecoin_pda = $1)
+                      AND minter_pubkey = $2
+                "#)
+                .bind(stablecoin_pda)
+                .bind(old_minter)
+                .execute(&mut *tx)
+                .await?;
+                sqlx::query(r#"
+                    INSERT INTO minter_quotas (stablecoin_id, minter_pubkey, quota, minted_amount)
+                    SELECT id, $2, $3, $4 FROM stablecoins WHERE stablecoin_pda = $1
+                    ON CONFLICT (stablecoin_id, minter_pubkey)
+                    DO UPDATE SET quota = $3, minted_amount = $4, updated_at = NOW()
+                "#)
+                .bind(stablecoin_pda)
+                .bind(new_minter)
+                .bind(*quota as i64)
+                .bind(*minted_amount as i64)
+                .execute(&mut *tx)
+                .await?;
+                tx.commit().await?;
+            }
+            AccountChange::Blacklisted { account, reason } => {
+                sqlx::query(r#"
+                    INSERT INTO blacklist_entries (stablecoin_id, account_pubkey, reason, blacklisted_by)
+                    SELECT id, $2, $3, owner_id FROM stablecoins WHERE stablecoin_pda = $1
+                    ON CONFLICT (stablecoin_id, account_pubkey)
+                    DO UPDATE SET is_active = true, reason = $3
+                "#)
+                .bind(stablecoin_pda)
+                .bind(account)
+                .bind(reason)
+                .execute(&self.pool)
+                .await?;
+            }
+            AccountChange::Unblacklisted { account } => {
+                sqlx::query(r#"
+                    UPDATE blacklist_entries SET is_active = false
+                    WHERE stablecoin_id = (SELECT id FROM stablecoins WHERE stablecoin_pda = $1)
+                      AND account_pubkey = $2
+                "#)
+                .bind(stablecoin_pda)
+                .bind(account)
+                .execute(&self.pool)
+                .await?;
+            }
+        }
+        
+        Ok(())
+    }
+
+    /// Fetches a refresh session by token ID
+    pub async fn get_refresh_session(
+        &self,
+        jti: uuid::Uuid,
+    ) -> Result<Option<crate::models::RefreshSession>> {
+        let session = sqlx::query_as("SELECT * FROM refresh_sessions WHERE jti = $1")
+            .bind(jti)
+            .fetch_optional(&self.pool)
+            .await?;
+        
+        Ok(session)
+    }
+
+    /// Marks an active refresh session as rotated.
+    /// Returns `None` if the session was already rotated, revoked, or expired,
+    /// so concurrent refreshes with the same token cannot both succeed.
+    pub async fn rotate_refresh_session(
+        &self,
+        jti: uuid::Uuid,
+    ) -> Result<Option<crate::models::RefreshSession>> {
+        let session = sqlx::query_as(r#"
+            UPDATE refresh_sessions
+            SET rotated_at = NOW()
+            WHERE jti = $1 AND rotated_at IS NULL AND revoked_at IS NULL AND expires_at > NOW()
+            RETURNING *
+        "#)
+        .bind(jti)
+        .fetch_optional(&self.pool)
+        .await?;
+        
+        Ok(session)
+    }
+
+    /// Revokes every refresh token in a family, returning the number revoked
+    pub async fn revoke_refresh_family(&self, family_id: uuid::Uuid) -> Result<u64> {
+        let result = sqlx::query(r#"
+            UPDATE refresh_sessions
+            SET revoked_at = NOW()
+            WHERE family_id = $1 AND revoked_at IS NULL
+        "#)
+        .bind(family_id)
+        .execute(&self.pool)
+        .await?;
+        
+        Ok(result.rows_affected())
+    }
+
     /// Gets the current schema version from _sqlx_migrations table
     pub async fn get_schema_version(&self) -> Result<Option<i64>> {
         let result: Option<(i64,)> = sqlx::query_as(
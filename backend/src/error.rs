@@ -3,17 +3,100 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
+use serde::Serialize;
 use serde_json::json;
+use std::collections::BTreeMap;
 use thiserror::Error;
 
+use crate::solana::{ConfirmationTimeout, InsufficientBalance, RpcRateLimited};
+
+/// Stable, machine-readable error codes returned in the `error.code` field.
+/// Clients should match on these rather than on messages or HTTP statuses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ErrorCode {
+    #[serde(rename = "resource.not_found")]
+    NotFound,
+    #[serde(rename = "stablecoin.not_found")]
+    StablecoinNotFound,
+    #[serde(rename = "auth.unauthorized")]
+    Unauthorized,
+    #[serde(rename = "auth.token_expired")]
+    TokenExpired,
+    #[serde(rename = "auth.forbidden")]
+    Forbidden,
+    #[serde(rename = "request.bad_request")]
+    BadRequest,
+    #[serde(rename = "request.validation_failed")]
+    ValidationFailed,
+    #[serde(rename = "resource.conflict")]
+    Conflict,
+    #[serde(rename = "server.internal")]
+    Internal,
+    #[serde(rename = "server.database")]
+    Database,
+    #[serde(rename = "solana.error")]
+    Solana,
+    #[serde(rename = "solana.rate_limited")]
+    RpcRateLimited,
+    #[serde(rename = "request.rate_limited")]
+    RateLimited,
+    #[serde(rename = "server.unavailable")]
+    ServiceUnavailable,
+    #[serde(rename = "token.insufficient_balance")]
+    InsufficientBalance,
+    #[serde(rename = "solana.confirmation_timeout")]
+    ConfirmationTimeout,
+}
+
+impl ErrorCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::NotFound => "resource.not_found",
+            ErrorCode::StablecoinNotFound => "stablecoin.not_found",
+            ErrorCode::Unauthorized => "auth.unauthorized",
+            ErrorCode::TokenExpired => "auth.token_expired",
+            ErrorCode::Forbidden => "auth.forbidden",
+            ErrorCode::BadRequest => "request.bad_request",
+            ErrorCode::ValidationFailed => "request.validation_failed",
+            ErrorCode::Conflict => "resource.conflict",
+            ErrorCode::Internal => "server.internal",
+            ErrorCode::Database => "server.database",
+            ErrorCode::Solana => "solana.error",
+            ErrorCode::RpcRateLimited => "solana.rate_limited",
+            ErrorCode::RateLimited => "request.rate_limited",
+            ErrorCode::ServiceUnavailable => "server.unavailable",
+            ErrorCode::InsufficientBalance => "token.insufficient_balance",
+            ErrorCode::ConfirmationTimeout => "solana.confirmation_timeout",
+        }
+    }
+}
+
+/// Validation failures keyed by request field, in field order
+pub type FieldErrors = BTreeMap<String, Vec<String>>;
+
+/// One-line summary of `errors`, e.g. `amount: must be positive; recipient: invalid`
+fn summarize_field_errors(errors: &FieldErrors) -> String {
+    errors
+        .iter()
+        .flat_map(|(field, messages)| messages.iter().map(move |message| format!("{}: {}", field, message)))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 #[derive(Error, Debug)]
 pub enum ApiError {
     #[error("Not found: {0}")]
     NotFound(String),
     
+    #[error("Stablecoin not found")]
+    StablecoinNotFound,
+    
     #[error("Unauthorized: {0}")]
     Unauthorized(String),
     
+    #[error("Token expired: {0}")]
+    TokenExpired(String),
+    
     #[error("Forbidden: {0}")]
     Forbidden(String),
     
@@ -23,6 +106,10 @@ pub enum ApiError {
     #[error("Validation error: {0}")]
     Validation(String),
     
+    /// Request body or query failed `validator` checks on one or more fields
+    #[error("Validation error: {}", summarize_field_errors(.0))]
+    InvalidFields(FieldErrors),
+    
     #[error("Conflict: {0}")]
     Conflict(String),
     
@@ -35,46 +122,166 @@ pub enum ApiError {
     #[error("Solana error: {0}")]
     Solana(String),
     
+    #[error("Solana RPC rate limited: {0}")]
+    RpcRateLimited(String),
+    
     #[error("Rate limit exceeded")]
     RateLimited,
     
     #[error("Service unavailable: {0}")]
     ServiceUnavailable(String),
+    
+    /// A burn or seize for more than the source token account holds
+    #[error("Insufficient balance: {available} available, {requested} requested")]
+    InsufficientBalance { available: u64, requested: u64 },
+    
+    /// A transaction still unconfirmed after every resubmission
+    #[error("Confirmation timeout: {0}")]
+    ConfirmationTimeout(String),
+}
+
+impl ApiError {
+    /// Map a `SolanaService` error, keeping exhausted RPC retries apart
+    /// from other blockchain failures
+    pub fn solana(err: anyhow::Error) -> Self {
+        if let Some(limited) = err.downcast_ref::<RpcRateLimited>() {
+            return ApiError::RpcRateLimited(limited.to_string());
+        }
+        if let Some(short) = err.downcast_ref::<InsufficientBalance>() {
+            return ApiError::InsufficientBalance { available: short.available, requested: short.requested };
+        }
+        if let Some(timeout) = err.downcast_ref::<ConfirmationTimeout>() {
+            return ApiError::ConfirmationTimeout(timeout.to_string());
+        }
+        ApiError::Solana(err.to_string())
+    }
+
+    /// HTTP status returned for this error
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ApiError::NotFound(_) | ApiError::StablecoinNotFound => StatusCode::NOT_FOUND,
+            ApiError::Unauthorized(_) | ApiError::TokenExpired(_) => StatusCode::UNAUTHORIZED,
+            ApiError::Forbidden(_) => StatusCode::FORBIDDEN,
+            ApiError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            ApiError::Validation(_) | ApiError::InvalidFields(_) | ApiError::InsufficientBalance { .. } => {
+                StatusCode::UNPROCESSABLE_ENTITY
+            }
+            ApiError::Conflict(_) => StatusCode::CONFLICT,
+            ApiError::Internal(_) | ApiError::Database(_) | ApiError::Solana(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+            ApiError::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+            ApiError::RpcRateLimited(_) | ApiError::ServiceUnavailable(_) => {
+                StatusCode::SERVICE_UNAVAILABLE
+            }
+            ApiError::ConfirmationTimeout(_) => StatusCode::GATEWAY_TIMEOUT,
+        }
+    }
+
+    /// Stable error code returned for this error
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ApiError::NotFound(_) => ErrorCode::NotFound,
+            ApiError::StablecoinNotFound => ErrorCode::StablecoinNotFound,
+            ApiError::Unauthorized(_) => ErrorCode::Unauthorized,
+            ApiError::TokenExpired(_) => ErrorCode::TokenExpired,
+            ApiError::Forbidden(_) => ErrorCode::Forbidden,
+            ApiError::BadRequest(_) => ErrorCode::BadRequest,
+            ApiError::Validation(_) | ApiError::InvalidFields(_) => ErrorCode::ValidationFailed,
+            ApiError::Conflict(_) => ErrorCode::Conflict,
+            ApiError::Internal(_) => ErrorCode::Internal,
+            ApiError::Database(_) => ErrorCode::Database,
+            ApiError::Solana(_) => ErrorCode::Solana,
+            ApiError::RpcRateLimited(_) => ErrorCode::RpcRateLimited,
+            ApiError::RateLimited => ErrorCode::RateLimited,
+            ApiError::ServiceUnavailable(_) => ErrorCode::ServiceUnavailable,
+            ApiError::InsufficientBalance { .. } => ErrorCode::InsufficientBalance,
+            ApiError::ConfirmationTimeout(_) => ErrorCode::ConfirmationTimeout,
+        }
+    }
 }
 
 impl IntoResponse for ApiError {
     fn into_response(self) -> Response {
-        let (status, error_message) = match self {
-            ApiError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
-            ApiError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg),
-            ApiError::Forbidden(msg) => (StatusCode::FORBIDDEN, msg),
-            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
-            ApiError::Validation(msg) => (StatusCode::UNPROCESSABLE_ENTITY, msg),
-            ApiError::Conflict(msg) => (StatusCode::CONFLICT, msg),
-            ApiError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+        let status = self.status();
+        let code = self.code();
+        let fields = match &self {
+            ApiError::InvalidFields(errors) => Some(errors.clone()),
+            _ => None,
+        };
+        let balance = match &self {
+            ApiError::InsufficientBalance { available, requested } => Some((*available, *requested)),
+            _ => None,
+        };
+        let error_message = match self {
+            ApiError::NotFound(msg) => msg,
+            ApiError::StablecoinNotFound => "Stablecoin not found".to_string(),
+            ApiError::Unauthorized(msg) => msg,
+            ApiError::TokenExpired(msg) => msg,
+            ApiError::Forbidden(msg) => msg,
+            ApiError::BadRequest(msg) => msg,
+            ApiError::Validation(msg) => msg,
+            ApiError::InvalidFields(errors) => summarize_field_errors(&errors),
+            ApiError::Conflict(msg) => msg,
+            ApiError::Internal(msg) => msg,
             ApiError::Database(msg) => {
                 tracing::error!("Database error: {}", msg);
-                (StatusCode::INTERNAL_SERVER_ERROR, "Database error".to_string())
+                "Database error".to_string()
             },
             ApiError::Solana(msg) => {
                 tracing::error!("Solana error: {}", msg);
-                (StatusCode::INTERNAL_SERVER_ERROR, "Blockchain error".to_string())
+                "Blockchain error".to_string()
             },
-            ApiError::RateLimited => (StatusCode::TOO_MANY_REQUESTS, "Rate limit exceeded".to_string()),
-            ApiError::ServiceUnavailable(msg) => (StatusCode::SERVICE_UNAVAILABLE, msg),
+            ApiError::RpcRateLimited(msg) => {
+                tracing::warn!("{}", msg);
+                "Solana RPC is rate limiting requests, try again shortly".to_string()
+            },
+            ApiError::RateLimited => "Rate limit exceeded".to_string(),
+            ApiError::ServiceUnavailable(msg) => msg,
+            ApiError::InsufficientBalance { available, requested } => {
+                format!("Insufficient balance: {} available, {} requested", available, requested)
+            }
+            ApiError::ConfirmationTimeout(msg) => {
+                tracing::warn!("{}", msg);
+                "Transaction was not confirmed in time; check its status before retrying".to_string()
+            }
         };
 
-        let body = Json(json!({
-            "error": {
-                "code": status.as_u16(),
-                "message": error_message,
-            }
-        }));
+        let mut error = json!({
+            "code": code,
+            "status": status.as_u16(),
+            "message": error_message,
+        });
+        if let Some(fields) = fields {
+            error["fields"] = json!(fields);
+        }
+        if let Some((available, requested)) = balance {
+            error["available"] = json!(available);
+            error["requested"] = json!(requested);
+        }
+        let body = Json(json!({ "error": error }));
 
         (status, body).into_response()
     }
 }
 
+impl From<validator::ValidationErrors> for ApiError {
+    fn from(err: validator::ValidationErrors) -> Self {
+        let fields = err
+            .field_errors()
+            .into_iter()
+            .map(|(field, errors)| {
+                let messages = errors
+                    .iter()
+                    .map(|e| e.message.as_deref().unwrap_or("invalid").to_string())
+                    .collect();
+                (field.to_string(), messages)
+            })
+            .collect();
+        ApiError::InvalidFields(fields)
+    }
+}
+
 impl From<sqlx::Error> for ApiError {
     fn from(err: sqlx::Error) -> Self {
         match err {
@@ -99,7 +306,12 @@ impl From<argon2::password_hash::Error> for ApiError {
 
 impl From<jsonwebtoken::errors::Error> for ApiError {
     fn from(err: jsonwebtoken::errors::Error) -> Self {
-        ApiError::Unauthorized(err.to_string())
+        match err.kind() {
+            jsonwebtoken::errors::ErrorKind::ExpiredSignature => {
+                ApiError::TokenExpired("Token has expired".to_string())
+            }
+            _ => ApiError::Unauthorized(err.to_string()),
+        }
     }
 }
 
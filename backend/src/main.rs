@@ -15,7 +15,7 @@ use tower_http::{
     set_header::SetResponseHeaderLayer,
     util::option_layer,
 };
-use http::{header, HeaderValue, Method};
+use http::{header, HeaderName, HeaderValue, Method};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 use tokio::signal;
 
@@ -33,9 +33,10 @@ mod utils;
 #[cfg(test)]
 mod tests;
 
+use app_middleware::rate_limit::RateLimiter;
 use config::AppConfig;
 use db::Database;
-use services::{SolanaService, MintBurnService, ComplianceService};
+use services::{SolanaService, ComputeBudgetConfig, ConfirmationConfig, RpcRetryConfig, MintBurnService, ComplianceService, RoleService, EventIndexer};
 
 /// Application version - set at compile time
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -50,6 +51,9 @@ pub struct AppState {
     pub solana: Arc<SolanaService>,
     pub mint_burn: Arc<MintBurnService>,
     pub compliance: Arc<ComplianceService>,
+    pub roles: Arc<RoleService>,
+    pub events: Arc<EventIndexer>,
+    pub rate_limiter: RateLimiter,
 }
 
 #[tokio::main]
@@ -81,7 +85,23 @@ async fn main() -> anyhow::Result<()> {
     tracing::info!("Database migrations completed");
 
     // Initialize Solana service
-    let solana = Arc::new(SolanaService::new(&config.solana_rpc_url, config.program_id).await?);
+    let solana = Arc::new(
+        SolanaService::new(&config.solana_rpc_url, config.program_id)
+            .await?
+            .with_commitment(config.solana_commitment)
+            .with_compute_budget(ComputeBudgetConfig {
+                unit_limit: config.compute_unit_limit,
+                unit_price_microlamports: config.priority_fee_microlamports,
+            })
+            .with_retry(RpcRetryConfig {
+                max_retries: config.rpc_max_retries,
+                ..Default::default()
+            })
+            .with_confirmation(ConfirmationConfig {
+                timeout: std::time::Duration::from_secs(config.confirmation_timeout_secs),
+                max_resubmits: config.confirmation_max_resubmits,
+            }),
+    );
     tracing::info!("Solana service initialized");
 
     // Initialize Mint/Burn service
@@ -118,6 +138,16 @@ async fn main() -> anyhow::Result<()> {
     let mint_burn = Arc::new(mint_burn);
     let compliance = Arc::new(compliance);
 
+    // Initialize on-chain role checks
+    let roles = Arc::new(RoleService::new(solana.clone()));
+
+    // Start the event indexer; live subscribers are served over WebSocket
+    let events = Arc::new(EventIndexer::new(&config.solana_rpc_url, config.program_id.to_string()));
+    tokio::spawn({
+        let events = events.clone();
+        async move { events.start_polling().await }
+    });
+
     // Create app state
     let state = AppState {
         config: config.clone(),
@@ -125,6 +155,9 @@ async fn main() -> anyhow::Result<()> {
         solana,
         mint_burn,
         compliance,
+        roles,
+        events,
+        rate_limiter: RateLimiter::from_config(&config),
     };
 
     // Build router with middleware
@@ -140,6 +173,12 @@ async fn main() -> anyhow::Result<()> {
         .route("/api/v1/auth/register", post(routes::auth::register))
         .route("/api/v1/auth/login", post(routes::auth::login))
         .route("/api/v1/auth/refresh", post(routes::auth::refresh))
+        .route("/api/v1/auth/wallet/challenge", post(routes::auth::wallet_challenge))
+        .route("/api/v1/auth/wallet/verify", post(routes::auth::wallet_verify))
+        .route("/api/v1/csrf-token", get(routes::csrf::token))
+        
+        // Live event stream (authenticates the upgrade handshake itself)
+        .route("/api/v1/stablecoin/:id/events/ws", get(routes::events::ws))
         
         // Protected routes (require authentication)
         .nest("/api/v1", 
@@ -149,18 +188,24 @@ async fn main() -> anyhow::Result<()> {
                 .route("/stablecoin/:id", get(routes::stablecoin::get))
                 .route("/stablecoin/:id", put(routes::stablecoin::update))
                 .route("/stablecoin/:id/status", get(routes::stablecoin::status))
+                .route("/stablecoin/:id/reconcile", get(routes::stablecoin::reconcile))
+                .route("/stablecoin/:id/supply-history", get(routes::stablecoin::supply_history))
+                .route("/stablecoin/:id/proofs/reserves", get(routes::proofs::reserves))
                 .route("/stablecoin", get(routes::stablecoin::list))
                 
                 // Mint/Burn operations
                 .route("/stablecoin/:id/mint", post(routes::operations::mint))
                 .route("/stablecoin/:id/burn", post(routes::operations::burn))
                 .route("/stablecoin/:id/transfer", post(routes::operations::transfer))
+                .route("/stablecoin/:id/estimate", post(routes::operations::estimate))
                 
                 // Compliance (SSS-2)
                 .route("/stablecoin/:id/blacklist", post(routes::compliance::blacklist_add))
                 .route("/stablecoin/:id/blacklist/:account", delete(routes::compliance::blacklist_remove))
                 .route("/stablecoin/:id/blacklist", get(routes::compliance::blacklist_list))
+                .route("/stablecoin/:id/screen/batch", post(routes::compliance::screen_batch))
                 .route("/stablecoin/:id/screen/:address", get(routes::compliance::screen))
+                .route("/stablecoin/:id/allowlist/:account", get(routes::compliance::allowlist_status))
                 
                 // Admin operations
                 .route("/stablecoin/:id/pause", post(routes::admin::pause))
@@ -168,6 +213,10 @@ async fn main() -> anyhow::Result<()> {
                 .route("/stablecoin/:id/freeze/:account", post(routes::admin::freeze))
                 .route("/stablecoin/:id/thaw/:account", post(routes::admin::thaw))
                 .route("/stablecoin/:id/seize", post(routes::admin::seize))
+                .route("/stablecoin/:id/max-transfer", put(routes::admin::set_max_transfer))
+                .route("/stablecoin/:id/tx/build", post(routes::transactions::build))
+                .route("/stablecoin/:id/tx/submit", post(routes::transactions::submit))
+                .route("/tx/:signature/status", get(routes::transactions::status))
                 
                 // Role management
                 .route("/stablecoin/:id/roles", post(routes::roles::assign))
@@ -192,15 +241,23 @@ async fn main() -> anyhow::Result<()> {
                 // User management
                 .route("/users/me", get(routes::users::me))
                 .route("/users/me", put(routes::users::update))
+                .route("/users/me/wallet", post(routes::users::link_wallet))
+                
+                // API keys (session-only; keys cannot manage keys)
+                .route("/api-keys", post(routes::api_keys::create))
+                .route("/api-keys", get(routes::api_keys::list))
+                .route("/api-keys/:key_id", delete(routes::api_keys::delete))
                 
                 .layer(middleware::from_fn_with_state(state.clone(), app_middleware::auth::auth_middleware))
+                // Runs first: X-API-Key requests are authenticated and permission-checked here
+                .layer(middleware::from_fn_with_state(state.clone(), app_middleware::api_key::api_key_middleware))
         )
         
         // Webhook receiver (no auth)
         .route("/webhooks", post(routes::webhooks::handler))
         
         // Global middleware
-        .layer(middleware::from_fn(app_middleware::rate_limit::rate_limit_middleware))
+        .layer(middleware::from_fn_with_state(state.clone(), app_middleware::rate_limit::rate_limit_middleware))
         .layer(middleware::from_fn(app_middleware::request_id::request_id_middleware))
         
         // CSRF protection - enabled in staging/production
@@ -264,7 +321,7 @@ async fn main() -> anyhow::Result<()> {
                 CorsLayer::new()
                     .allow_origin(Any)
                     .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE, Method::PATCH, Method::OPTIONS])
-                    .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE, header::ACCEPT, header::X_REQUESTED_WITH])
+                    .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE, header::ACCEPT, header::X_REQUESTED_WITH, HeaderName::from_static(app_middleware::api_key::API_KEY_HEADER)])
                     .allow_credentials(true)
             } else {
                 // Production/Staging: Restrict to configured origins
@@ -276,7 +333,7 @@ async fn main() -> anyhow::Result<()> {
                 CorsLayer::new()
                     .allow_origin(AllowOrigin::list(origins))
                     .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE, Method::PATCH, Method::OPTIONS])
-                    .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE, header::ACCEPT, header::X_REQUESTED_WITH])
+                    .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE, header::ACCEPT, header::X_REQUESTED_WITH, HeaderName::from_static(app_middleware::api_key::API_KEY_HEADER)])
                     .allow_credentials(true)
                     .max_age(std::time::Duration::from_secs(3600))
             };
@@ -365,6 +422,9 @@ async fn shutdown_signal(state: AppState) {
     tracing::info!("Initiating graceful shutdown with {:?} timeout...", shutdown_timeout);
 
     let cleanup_result = tokio::time::timeout(shutdown_timeout, async {
+        // Stop the event indexer
+        state.events.stop().await;
+        
         // Close database connections
         tracing::info!("Closing database connections...");
         state.db.close().await;
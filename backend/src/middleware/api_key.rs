@@ -0,0 +1,122 @@
+use axum::{
+    extract::{Request, State},
+    http::Method,
+    middleware::Next,
+    response::Response,
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::error::ApiError;
+use crate::models::{ApiKey, User};
+use crate::utils::hash_api_key;
+use crate::AppState;
+
+/// Header carrying a raw API key
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Stablecoin sub-resources that change authorities or halt operations
+/// (`tx` builds and relays offline-signed admin transactions)
+const ADMIN_ACTIONS: &[&str] = &[
+    "pause", "unpause", "freeze", "thaw", "seize", "max-transfer", "roles", "minters", "tx",
+];
+
+/// The API key a request was authenticated with, set by `api_key_middleware`
+#[derive(Debug, Clone)]
+pub struct ApiKeyAuth {
+    pub key_id: Uuid,
+    pub permissions: Vec<String>,
+}
+
+/// Authenticate requests carrying an `X-API-Key` header.
+///
+/// The key's owner is added to the request extensions just like a bearer
+/// token would, but only if the key holds the permission the route needs.
+/// Requests without the header are left to `auth_middleware`.
+pub async fn api_key_middleware(
+    State(state): State<AppState>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    let Some(header) = request.headers().get(API_KEY_HEADER) else {
+        return Ok(next.run(request).await);
+    };
+    let raw_key = header
+        .to_str()
+        .map_err(|_| ApiError::Unauthorized("Invalid API key".to_string()))?;
+
+    let required = required_permission(request.method(), request.uri().path())
+        .ok_or_else(|| ApiError::Forbidden("API keys cannot access this endpoint".to_string()))?;
+
+    let key: ApiKey = sqlx::query_as(
+        "SELECT * FROM api_keys WHERE key_hash = $1 AND is_active = true"
+    )
+    .bind(hash_api_key(raw_key))
+    .fetch_optional(state.db.pool())
+    .await?
+    .ok_or_else(|| ApiError::Unauthorized("Invalid API key".to_string()))?;
+
+    if key.is_expired(Utc::now()) {
+        return Err(ApiError::TokenExpired("API key expired".to_string()));
+    }
+
+    let permissions = key.permission_list();
+    if !permits(&permissions, required) {
+        return Err(ApiError::Forbidden(format!("API key lacks the '{}' permission", required)));
+    }
+
+    let user: User = sqlx::query_as(
+        "SELECT * FROM users WHERE id = $1 AND is_active = true"
+    )
+    .bind(key.user_id)
+    .fetch_optional(state.db.pool())
+    .await?
+    .ok_or_else(|| ApiError::Unauthorized("User not found or inactive".to_string()))?;
+
+    // Best effort - a failed timestamp update shouldn't fail the request
+    if let Err(e) = sqlx::query("UPDATE api_keys SET last_used_at = NOW() WHERE id = $1")
+        .bind(key.id)
+        .execute(state.db.pool())
+        .await
+    {
+        tracing::warn!("Failed to update last_used_at for API key {}: {}", key.id, e);
+    }
+
+    request.extensions_mut().insert(user);
+    request.extensions_mut().insert(ApiKeyAuth {
+        key_id: key.id,
+        permissions,
+    });
+
+    Ok(next.run(request).await)
+}
+
+/// Permission an API key needs for a route, or `None` if keys may not use it at all.
+///
+/// Reads, including fee estimates, need `read`; mint and burn need their own permission; pausing,
+/// freezing, seizing and role/minter management need `admin`; any other
+/// write needs `write`. Key management itself requires a user session.
+pub fn required_permission(method: &Method, path: &str) -> Option<&'static str> {
+    let path = path.strip_prefix("/api/v1").unwrap_or(path);
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    if segments.first() == Some(&"api-keys") {
+        return None;
+    }
+    if method == Method::GET {
+        return Some("read");
+    }
+
+    match segments.as_slice() {
+        ["stablecoin", _, "mint"] => Some("mint"),
+        ["stablecoin", _, "burn"] => Some("burn"),
+        ["stablecoin", _, "estimate"] => Some("read"),
+        ["stablecoin", _, action, ..] if ADMIN_ACTIONS.contains(action) => Some("admin"),
+        _ => Some("write"),
+    }
+}
+
+/// Whether granted permissions cover `required` (`admin` covers everything)
+pub fn permits(granted: &[String], required: &str) -> bool {
+    granted.iter().any(|p| p == "admin" || p == required)
+}
@@ -1,16 +1,21 @@
 use axum::{
     async_trait,
     extract::{FromRequestParts, Request, State},
-    http::{header, request::Parts, StatusCode},
+    http::{header, request::Parts, HeaderMap, StatusCode},
     middleware::Next,
     response::Response,
 };
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::warn;
 use uuid::Uuid;
 
+use crate::app_middleware::api_key::ApiKeyAuth;
 use crate::error::ApiError;
-use crate::models::User;
+use crate::models::{RefreshSessionStatus, User};
+use crate::utils::{validate_token, Claims as UtilClaims, ACCESS_TOKEN_TYPE, REFRESH_TOKEN_TYPE};
 use crate::AppState;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,6 +26,8 @@ pub struct Claims {
     pub exp: usize,
     pub iat: usize,
     pub jti: String,       // JWT ID for revocation
+    #[serde(default)]
+    pub token_type: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -44,6 +51,7 @@ pub fn generate_access_token(user: &User, jwt_secret: &str, expiry_secs: u64) ->
         exp: now + expiry_secs as usize,
         iat: now,
         jti: Uuid::new_v4().to_string(),
+        token_type: ACCESS_TOKEN_TYPE.to_string(),
     };
     
     jsonwebtoken::encode(
@@ -86,23 +94,100 @@ pub fn verify_token(token: &str, jwt_secret: &str) -> Result<Claims, ApiError> {
     Ok(token_data.claims)
 }
 
+/// Verify a refresh token and rotate its session.
+///
+/// Each refresh token can be exchanged once. Presenting an already-rotated
+/// token means it was replayed (likely stolen), so the whole token family is
+/// revoked and the legitimate holder has to log in again.
+pub async fn verify_refresh_token(state: &AppState, token: &str) -> Result<UtilClaims, ApiError> {
+    let claims = validate_token(token, &state.config.jwt_secret)
+        .map_err(|_| ApiError::Unauthorized("Invalid refresh token".to_string()))?;
+    
+    if claims.token_type != REFRESH_TOKEN_TYPE {
+        return Err(ApiError::Unauthorized("Invalid refresh token".to_string()));
+    }
+    
+    if state.db.rotate_refresh_session(claims.jti).await
+        .map_err(|e| ApiError::Database(e.to_string()))?
+        .is_some()
+    {
+        return Ok(claims);
+    }
+    
+    // Rotation failed - find out why
+    let session = state.db.get_refresh_session(claims.jti).await
+        .map_err(|e| ApiError::Database(e.to_string()))?
+        .ok_or_else(|| ApiError::Unauthorized("Invalid refresh token".to_string()))?;
+    
+    match session.status(Utc::now()) {
+        RefreshSessionStatus::Reused => {
+            let revoked = state.db.revoke_refresh_family(session.family_id).await
+                .map_err(|e| ApiError::Database(e.to_string()))?;
+            warn!(
+                "Refresh token reuse detected for user {} (family {}), revoked {} sessions",
+                session.user_id, session.family_id, revoked
+            );
+            let _ = state.db.log_audit(
+                None,
+                Some(session.user_id),
+                "auth.refresh_reuse",
+                None,
+                Some(json!({"family_id": session.family_id, "revoked_sessions": revoked})),
+                None,
+                None,
+            ).await;
+            Err(ApiError::Unauthorized("Refresh token has been revoked".to_string()))
+        }
+        RefreshSessionStatus::Revoked => {
+            Err(ApiError::Unauthorized("Refresh token has been revoked".to_string()))
+        }
+        RefreshSessionStatus::Expired | RefreshSessionStatus::Active => {
+            Err(ApiError::TokenExpired("Refresh token expired".to_string()))
+        }
+    }
+}
+
 pub async fn auth_middleware(
     State(state): State<AppState>,
     mut request: Request,
     next: Next,
 ) -> Result<Response, ApiError> {
-    let auth_header = request
-        .headers()
+    // Already authenticated (and permission-checked) by `api_key_middleware`
+    if request.extensions().get::<ApiKeyAuth>().is_some() {
+        return Ok(next.run(request).await);
+    }
+    
+    let token = bearer_token(request.headers())?;
+    let (user, claims) = authenticate_token(&state, token).await?;
+    
+    // Add user to request extensions
+    request.extensions_mut().insert(user);
+    request.extensions_mut().insert(claims);
+    
+    Ok(next.run(request).await)
+}
+
+/// Extract the token from an `Authorization: Bearer <token>` header
+pub fn bearer_token(headers: &HeaderMap) -> Result<&str, ApiError> {
+    let auth_header = headers
         .get(header::AUTHORIZATION)
         .and_then(|h| h.to_str().ok())
         .ok_or_else(|| ApiError::Unauthorized("Missing authorization header".to_string()))?;
     
-    let token = auth_header
+    auth_header
         .strip_prefix("Bearer ")
-        .ok_or_else(|| ApiError::Unauthorized("Invalid authorization header format".to_string()))?;
-    
+        .ok_or_else(|| ApiError::Unauthorized("Invalid authorization header format".to_string()))
+}
+
+/// Verify an access token and load its (still active) user
+pub async fn authenticate_token(state: &AppState, token: &str) -> Result<(User, Claims), ApiError> {
     let claims = verify_token(token, &state.config.jwt_secret)?;
     
+    // Refresh tokens are only accepted by /auth/refresh
+    if claims.token_type == REFRESH_TOKEN_TYPE {
+        return Err(ApiError::Unauthorized("Refresh token cannot be used for API access".to_string()));
+    }
+    
     // Check if user is still active
     let user: User = sqlx::query_as::<_, User>(
         "SELECT * FROM users WHERE id = $1 AND is_active = true"
@@ -112,11 +197,7 @@ pub async fn auth_middleware(
     .await?
     .ok_or_else(|| ApiError::Unauthorized("User not found or inactive".to_string()))?;
     
-    // Add user to request extensions
-    request.extensions_mut().insert(user);
-    request.extensions_mut().insert(claims);
-    
-    Ok(next.run(request).await)
+    Ok((user, claims))
 }
 
 #[derive(Debug, Clone)]
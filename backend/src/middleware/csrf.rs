@@ -5,20 +5,21 @@
 
 use axum::{
     extract::{Request, State},
-    http::{HeaderMap, Method, StatusCode, Uri},
+    http::{header, HeaderMap, Method, StatusCode},
     middleware::Next,
     response::Response,
 };
 use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
 use sha2::{Digest, Sha256};
-use std::sync::Arc;
-
-use crate::config::AppConfig;
+use crate::app_middleware::api_key::API_KEY_HEADER;
 use crate::AppState;
 
 /// CSRF token header name
 pub const CSRF_HEADER: &str = "x-csrf-token";
 
+/// Cookie set by `GET /api/v1/csrf-token` alongside the token in the body
+pub const CSRF_COOKIE: &str = "sss_csrf";
+
 /// Safe methods that don't require CSRF protection
 const SAFE_METHODS: [Method; 3] = [Method::GET, Method::HEAD, Method::OPTIONS];
 
@@ -36,16 +37,7 @@ pub async fn csrf_middleware(
     request: Request,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    let method = request.method().clone();
-    
-    // Skip CSRF check for safe methods
-    if SAFE_METHODS.contains(&method) {
-        return Ok(next.run(request).await);
-    }
-    
-    // Skip CSRF for health/metrics endpoints
-    let path = request.uri().path();
-    if path.starts_with("/health") || path.starts_with("/metrics") {
+    if is_exempt(request.method(), request.uri().path(), request.headers()) {
         return Ok(next.run(request).await);
     }
     
@@ -53,26 +45,63 @@ pub async fn csrf_middleware(
     if state.config.environment.is_development() {
         let origin = get_origin_or_referer(&request.headers());
         tracing::debug!(
-            method = %method,
-            path = %path,
+            method = %request.method(),
+            path = %request.uri().path(),
             origin = ?origin,
             "CSRF check (development mode - not enforced)"
         );
         return Ok(next.run(request).await);
     }
     
+    check_request(
+        request.method(),
+        request.uri().path(),
+        request.headers(),
+        &state.config.cors_origins,
+        &state.config.csrf_secret,
+    )?;
+    
+    Ok(next.run(request).await)
+}
+
+/// Requests that never need a CSRF check
+fn is_exempt(method: &Method, path: &str, headers: &HeaderMap) -> bool {
+    // Skip CSRF check for safe methods
+    if SAFE_METHODS.contains(method) {
+        return true;
+    }
+    
+    // Skip CSRF for health/metrics endpoints
+    if path.starts_with("/health") || path.starts_with("/metrics") {
+        return true;
+    }
+    
+    // API key clients are not browsers, and a cross-site page cannot attach
+    // a custom header without passing a CORS preflight
+    headers.contains_key(API_KEY_HEADER)
+}
+
+/// Check a state-changing request: its Origin/Referer must be allowed or,
+/// when it has neither, it must carry a valid CSRF token
+pub fn check_request(
+    method: &Method,
+    path: &str,
+    headers: &HeaderMap,
+    allowed_origins: &[String],
+    secret: &str,
+) -> Result<(), StatusCode> {
     // Validate Origin/Referer header
-    let origin = get_origin_or_referer(&request.headers());
+    let origin = get_origin_or_referer(headers);
     
     match origin {
         Some(ref origin_str) => {
             // Check if origin is in allowed list
-            if !is_origin_allowed(&origin_str, &state.config.cors_origins) {
+            if !is_origin_allowed(origin_str, allowed_origins) {
                 tracing::warn!(
                     method = %method,
                     path = %path,
                     origin = %origin_str,
-                    allowed_origins = ?state.config.cors_origins,
+                    allowed_origins = ?allowed_origins,
                     "CSRF validation failed: origin not allowed"
                 );
                 return Err(StatusCode::FORBIDDEN);
@@ -87,7 +116,7 @@ pub async fn csrf_middleware(
         }
         None => {
             // No Origin or Referer header - check for CSRF token
-            if !validate_csrf_token(&request, &state.config) {
+            if !validate_csrf_token(headers, secret) {
                 tracing::warn!(
                     method = %method,
                     path = %path,
@@ -98,7 +127,7 @@ pub async fn csrf_middleware(
         }
     }
     
-    Ok(next.run(request).await)
+    Ok(())
 }
 
 /// Extract Origin or Referer header from request
@@ -138,23 +167,40 @@ fn is_origin_allowed(origin: &str, allowed_origins: &[String]) -> bool {
     })
 }
 
-/// Validate CSRF token from request
-fn validate_csrf_token(request: &Request, config: &AppConfig) -> bool {
+/// Validate CSRF token from request. When the CSRF cookie is present the
+/// header must also match it (double-submit cookie).
+fn validate_csrf_token(headers: &HeaderMap, secret: &str) -> bool {
     // Check for CSRF token in header
-    let token = request
-        .headers()
+    let token = headers
         .get(CSRF_HEADER)
         .and_then(|v| v.to_str().ok());
     
     match token {
         Some(token) => {
+            if let Some(cookie) = get_cookie(headers, CSRF_COOKIE) {
+                if !constant_time_eq(&cookie, token) {
+                    return false;
+                }
+            }
             // Validate token structure (should be timestamped hash)
-            validate_token_structure(token, &config.csrf_secret)
+            validate_token_structure(token, secret)
         }
         None => false,
     }
 }
 
+/// Value of a cookie sent with the request
+fn get_cookie(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value.to_string())
+}
+
 /// Validate CSRF token structure
 fn validate_token_structure(token: &str, secret: &str) -> bool {
     // Token format: base64(timestamp:hash)
@@ -1,3 +1,4 @@
+pub mod api_key;
 pub mod auth;
 pub mod csrf;
 pub mod https;
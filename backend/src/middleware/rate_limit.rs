@@ -1,19 +1,21 @@
 use axum::{
-    extract::{Request, ConnectInfo},
-    http::StatusCode,
+    extract::{Request, State},
+    http::{HeaderMap, HeaderValue, Method},
     middleware::Next,
-    response::Response,
+    response::{IntoResponse, Response},
 };
 use std::{
     collections::HashMap,
-    net::SocketAddr,
     sync::Arc,
-    time::{Duration, Instant},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 use tokio::sync::RwLock;
-use std::sync::atomic::{AtomicU64, Ordering};
 
+use crate::app_middleware::auth::{bearer_token, verify_token};
+use crate::config::{AppConfig, RateLimitGroups};
 use crate::error::ApiError;
+use crate::utils::ACCESS_TOKEN_TYPE;
+use crate::AppState;
 
 #[derive(Debug, Clone)]
 struct RateLimitEntry {
@@ -21,11 +23,64 @@ struct RateLimitEntry {
     window_start: Instant,
 }
 
+/// Route groups that can be given their own limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteGroup {
+    Auth,
+    Mint,
+    Read,
+    /// Everything else, always on the global limit
+    Default,
+}
+
+impl RouteGroup {
+    pub fn classify(method: &Method, path: &str) -> Self {
+        let path = path.strip_prefix("/api/v1").unwrap_or(path);
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+        match segments.as_slice() {
+            ["auth", ..] => RouteGroup::Auth,
+            ["stablecoin", _, "mint" | "burn"] if method == Method::POST => RouteGroup::Mint,
+            _ if method == Method::GET => RouteGroup::Read,
+            _ => RouteGroup::Default,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            RouteGroup::Auth => "auth",
+            RouteGroup::Mint => "mint",
+            RouteGroup::Read => "read",
+            RouteGroup::Default => "default",
+        }
+    }
+}
+
+/// Result of counting one request against a bucket
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    pub allowed: bool,
+    pub limit: u32,
+    pub remaining: u32,
+    /// Unix time (seconds) at which the current window ends
+    pub reset_at: u64,
+}
+
+impl RateLimitStatus {
+    /// Add `X-RateLimit-Limit`, `X-RateLimit-Remaining` and `X-RateLimit-Reset`
+    pub fn apply_headers(&self, headers: &mut HeaderMap) {
+        headers.insert("x-ratelimit-limit", HeaderValue::from(self.limit));
+        headers.insert("x-ratelimit-remaining", HeaderValue::from(self.remaining));
+        headers.insert("x-ratelimit-reset", HeaderValue::from(self.reset_at));
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct RateLimiter {
     entries: Arc<RwLock<HashMap<String, RateLimitEntry>>>,
     max_requests: u32,
     window_duration: Duration,
+    groups: RateLimitGroups,
 }
 
 impl RateLimiter {
@@ -34,63 +89,109 @@ impl RateLimiter {
             entries: Arc::new(RwLock::new(HashMap::new())),
             max_requests,
             window_duration: Duration::from_secs(window_secs),
+            groups: RateLimitGroups::default(),
         }
     }
-    
+
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self::new(config.rate_limit_requests, config.rate_limit_window_secs)
+            .with_groups(config.rate_limit_groups.clone())
+    }
+
+    pub fn with_groups(mut self, groups: RateLimitGroups) -> Self {
+        self.groups = groups;
+        self
+    }
+
+    /// Requests allowed per window for a group, raised for admin JWTs
+    pub fn limit_for(&self, group: RouteGroup, is_admin: bool) -> u32 {
+        let base = self.group_limit(group).unwrap_or(self.max_requests);
+        if is_admin {
+            base.saturating_mul(self.groups.admin_multiplier.max(1))
+        } else {
+            base
+        }
+    }
+
+    /// Bucket a client's request counts toward. Groups with their own limit
+    /// get their own bucket; the rest share the global one.
+    pub fn bucket_key(&self, group: RouteGroup, client: &str) -> String {
+        match self.group_limit(group) {
+            Some(_) => format!("{}:{}", group.name(), client),
+            None => client.to_string(),
+        }
+    }
+
+    fn group_limit(&self, group: RouteGroup) -> Option<u32> {
+        match group {
+            RouteGroup::Auth => self.groups.auth,
+            RouteGroup::Mint => self.groups.mint,
+            RouteGroup::Read => self.groups.read,
+            RouteGroup::Default => None,
+        }
+    }
+
     pub async fn check(&self, key: &str) -> Result<(), ApiError> {
+        if self.acquire(key, self.max_requests).await.allowed {
+            Ok(())
+        } else {
+            Err(ApiError::RateLimited)
+        }
+    }
+
+    /// Count a request against `key` if it is under `limit`
+    pub async fn acquire(&self, key: &str, limit: u32) -> RateLimitStatus {
         let mut entries = self.entries.write().await;
         let now = Instant::now();
-        
+
         let entry = entries.entry(key.to_string()).or_insert(RateLimitEntry {
             requests: 0,
             window_start: now,
         });
-        
+
         // Reset window if expired
         if now.duration_since(entry.window_start) > self.window_duration {
             entry.requests = 0;
             entry.window_start = now;
         }
-        
-        // Check limit
-        if entry.requests >= self.max_requests {
-            return Err(ApiError::RateLimited);
+
+        let allowed = entry.requests < limit;
+        if allowed {
+            entry.requests += 1;
+        }
+
+        let window_left = self.window_duration.saturating_sub(now.duration_since(entry.window_start));
+        let reset_at = (SystemTime::now() + window_left)
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        RateLimitStatus {
+            allowed,
+            limit,
+            remaining: limit.saturating_sub(entry.requests),
+            reset_at,
         }
-        
-        entry.requests += 1;
-        Ok(())
     }
-    
+
     // Cleanup old entries periodically
     pub async fn cleanup(&self) {
         let mut entries = self.entries.write().await;
         let now = Instant::now();
-        
+
         entries.retain(|_, entry| {
             now.duration_since(entry.window_start) < self.window_duration * 2
         });
     }
 }
 
-// Global rate limiter
-static RATE_LIMITER: once_cell::sync::Lazy<RateLimiter> = once_cell::sync::Lazy::new(|| {
-    let max_requests = std::env::var("RATE_LIMIT_REQUESTS")
-        .ok()
-        .and_then(|s| s.parse().ok())
-        .unwrap_or(100);
-    
-    let window_secs = std::env::var("RATE_LIMIT_WINDOW_SECS")
-        .ok()
-        .and_then(|s| s.parse().ok())
-        .unwrap_or(60);
-    
-    RateLimiter::new(max_requests, window_secs)
-});
-
 pub async fn rate_limit_middleware(
+    State(state): State<AppState>,
     request: Request,
     next: Next,
 ) -> Result<Response, ApiError> {
+    let limiter = &state.rate_limiter;
+
     // Get client IP from various sources
     let client_ip = request
         .headers()
@@ -102,17 +203,45 @@ pub async fn rate_limit_middleware(
                 .get("x-real-ip")
                 .and_then(|h| h.to_str().ok())
         })
-        .unwrap_or("unknown");
-    
-    // Rate limit by IP
-    RATE_LIMITER.check(client_ip).await?;
-    
-    // Also check by API key if present
-    if let Some(api_key) = request.headers().get("x-api-key") {
-        if let Ok(key) = api_key.to_str() {
-            RATE_LIMITER.check(&format!("api:{}", key)).await?;
+        .unwrap_or("unknown")
+        .to_string();
+
+    let group = RouteGroup::classify(request.method(), request.uri().path());
+
+    // Admin JWTs get the raised limit, counted per user instead of per IP.
+    // Revocation is checked later by `auth_middleware`.
+    let admin_id = bearer_token(request.headers())
+        .ok()
+        .and_then(|token| verify_token(token, &state.config.jwt_secret).ok())
+        .filter(|claims| claims.role == "admin" && claims.token_type == ACCESS_TOKEN_TYPE)
+        .map(|claims| claims.sub);
+    let client = match &admin_id {
+        Some(sub) => format!("user:{}", sub),
+        None => client_ip,
+    };
+    let limit = limiter.limit_for(group, admin_id.is_some());
+
+    // Rate limit by client
+    let mut status = limiter.acquire(&limiter.bucket_key(group, &client), limit).await;
+
+    // Also check by API key if present; the headers report the tighter bucket
+    if status.allowed {
+        if let Some(key) = request.headers().get("x-api-key").and_then(|h| h.to_str().ok()) {
+            let key_status = limiter
+                .acquire(&limiter.bucket_key(group, &format!("api:{}", key)), limit)
+                .await;
+            if !key_status.allowed || key_status.remaining < status.remaining {
+                status = key_status;
+            }
         }
     }
-    
-    Ok(next.run(request).await)
+
+    let mut response = if status.allowed {
+        next.run(request).await
+    } else {
+        ApiError::RateLimited.into_response()
+    };
+    status.apply_headers(response.headers_mut());
+
+    Ok(response)
 }
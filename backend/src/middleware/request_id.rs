@@ -1,11 +1,17 @@
 use axum::{
-    extract::Request,
-    http::header,
+    async_trait,
+    extract::{FromRequestParts, Request},
+    http::{header, request::Parts},
     middleware::Next,
     response::Response,
 };
+use std::convert::Infallible;
 use uuid::Uuid;
 
+/// Correlation ID of the current request, set by `request_id_middleware`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
 pub async fn request_id_middleware(
     mut request: Request,
     next: Next,
@@ -20,6 +26,7 @@ pub async fn request_id_middleware(
     
     // Add to request extensions
     request.extensions_mut().insert(request_id.clone());
+    request.extensions_mut().insert(RequestId(request_id.clone()));
     
     // Process request
     let mut response = next.run(request).await;
@@ -32,3 +39,23 @@ pub async fn request_id_middleware(
     
     response
 }
+
+// Falls back to a fresh ID when the middleware did not run, so handlers can always record one
+#[async_trait]
+impl<S> FromRequestParts<S> for RequestId
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+    
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        Ok(parts
+            .extensions
+            .get::<RequestId>()
+            .cloned()
+            .unwrap_or_else(|| RequestId(Uuid::new_v4().to_string())))
+    }
+}
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::{ParsePubkeyError, Pubkey};
 use uuid::Uuid;
 use validator::Validate;
 
@@ -15,6 +16,15 @@ pub struct User {
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// When the user signed a wallet challenge for `solana_pubkey`; `None` if only claimed
+    pub wallet_verified_at: Option<DateTime<Utc>>,
+}
+
+impl User {
+    /// The linked wallet, if the user has proven control of it by signature
+    pub fn verified_wallet(&self) -> Option<&str> {
+        self.wallet_verified_at.and(self.solana_pubkey.as_deref())
+    }
 }
 
 /// Custom validator function for password complexity
@@ -34,20 +44,22 @@ pub fn validate_password_complexity(password: &str) -> Result<(), validator::Val
     Ok(())
 }
 
-/// Custom validator for Solana pubkey format
+/// Custom validator for Solana pubkeys: must decode to 32 bytes and not be the
+/// all-zeros default. Off-curve addresses (PDAs) are valid.
 pub fn validate_solana_pubkey(pubkey: &str) -> Result<(), validator::ValidationError> {
-    // Base58 encoded Solana pubkeys are typically 32-44 characters
-    if pubkey.len() < 32 || pubkey.len() > 44 {
-        return Err(validator::ValidationError::new("solana_pubkey")
-            .with_message(std::borrow::Cow::Borrowed("Invalid Solana pubkey length")));
-    }
-    // Check for valid Base58 characters
-    const BASE58_CHARS: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
-    if !pubkey.chars().all(|c| BASE58_CHARS.contains(c)) {
-        return Err(validator::ValidationError::new("solana_pubkey")
-            .with_message(std::borrow::Cow::Borrowed("Invalid Base58 character in pubkey")));
-    }
-    Ok(())
+    // Base58 encoded Solana pubkeys are 32-44 characters
+    let message = if pubkey.len() < 32 || pubkey.len() > 44 {
+        "Invalid Solana pubkey length"
+    } else {
+        match pubkey.parse::<Pubkey>() {
+            Ok(key) if key == Pubkey::default() => "Zero pubkey is not a valid address",
+            Ok(_) => return Ok(()),
+            Err(ParsePubkeyError::WrongSize) => "Invalid Solana pubkey length",
+            Err(_) => "Invalid Base58 character in pubkey",
+        }
+    };
+    Err(validator::ValidationError::new("solana_pubkey")
+        .with_message(std::borrow::Cow::Borrowed(message)))
 }
 
 #[derive(Debug, Deserialize, Validate)]
@@ -72,6 +84,35 @@ pub struct LoginRequest {
 }
 
 
+/// Ask for a nonce to sign with a Solana wallet
+#[derive(Debug, Deserialize, Validate)]
+pub struct WalletChallengeRequest {
+    #[validate(custom = "validate_solana_pubkey")]
+    pub pubkey: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WalletChallengeResponse {
+    pub nonce: String,
+    /// Exact text the wallet must sign
+    pub message: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Sign in with a wallet's ed25519 signature of a challenge message
+#[derive(Debug, Deserialize, Validate)]
+pub struct WalletVerifyRequest {
+    #[validate(custom = "validate_solana_pubkey")]
+    pub pubkey: String,
+    
+    #[validate(length(min = 1, max = 64, message = "Nonce is required"))]
+    pub nonce: String,
+    
+    /// Base58 signature of the challenge `message`
+    #[validate(length(min = 1, max = 128, message = "Signature is required"))]
+    pub signature: String,
+}
+
 #[derive(Debug, Serialize)]
 pub struct AuthResponse {
     pub access_token: String,
@@ -81,19 +122,60 @@ pub struct AuthResponse {
     pub user: UserPublic,
 }
 
+/// Issued refresh token, tracked for rotation and reuse detection
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct RefreshSession {
+    pub jti: Uuid,
+    pub family_id: Uuid,
+    pub user_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+    pub rotated_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshSessionStatus {
+    /// Token may be exchanged exactly once
+    Active,
+    /// Token was already exchanged; presenting it again indicates theft
+    Reused,
+    /// Token family was revoked
+    Revoked,
+    Expired,
+}
+
+impl RefreshSession {
+    pub fn status(&self, now: DateTime<Utc>) -> RefreshSessionStatus {
+        if self.revoked_at.is_some() {
+            RefreshSessionStatus::Revoked
+        } else if self.rotated_at.is_some() {
+            RefreshSessionStatus::Reused
+        } else if self.expires_at <= now {
+            RefreshSessionStatus::Expired
+        } else {
+            RefreshSessionStatus::Active
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct UserPublic {
     pub id: Uuid,
     pub email: String,
     pub solana_pubkey: Option<String>,
+    /// Whether `solana_pubkey` was verified by a wallet signature
+    pub wallet_verified: bool,
     pub role: String,
 }
 
 impl From<User> for UserPublic {
     fn from(user: User) -> Self {
+        let wallet_verified = user.verified_wallet().is_some();
         Self {
             id: user.id,
             email: user.email,
+            wallet_verified,
             solana_pubkey: user.solana_pubkey,
             role: user.role,
         }
@@ -115,6 +197,21 @@ pub struct Stablecoin {
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Screen mint recipients before minting (SSS-2 only)
+    pub screen_on_mint: bool,
+    /// Authorized supply ceiling in base units (0 = uncapped)
+    pub max_supply: i64,
+    /// Attested off-chain collateral in base units, if reported
+    pub reserve_amount: Option<i64>,
+    /// When `reserve_amount` was last attested
+    pub reserve_attested_at: Option<DateTime<Utc>>,
+}
+
+impl Stablecoin {
+    /// Whether mint recipients must pass compliance screening
+    pub fn requires_mint_screening(&self) -> bool {
+        self.preset >= 2 && self.screen_on_mint
+    }
 }
 
 /// Custom validator for stablecoin name (alphanumeric with spaces, dashes, underscores)
@@ -157,6 +254,10 @@ pub struct CreateStablecoinRequest {
     pub asset_mint: String,
     
     pub authority_keypair: Option<String>, // Base58 encoded keypair (encrypted)
+    
+    /// Authorized supply ceiling in base units (0 or unset = uncapped)
+    #[validate(custom = "validate_max_supply")]
+    pub max_supply: Option<u64>,
 }
 
 #[derive(Debug, Deserialize, Validate)]
@@ -165,15 +266,159 @@ pub struct UpdateStablecoinRequest {
     pub name: Option<String>,
     
     pub is_active: Option<bool>,
+    
+    pub screen_on_mint: Option<bool>,
+    
+    /// New supply ceiling in base units (0 = uncapped); cannot be below the current supply
+    #[validate(custom = "validate_max_supply")]
+    pub max_supply: Option<u64>,
+    
+    /// Attested off-chain collateral in base units; also stamps `reserve_attested_at`
+    #[validate(custom = "validate_reserve_amount")]
+    pub reserve_amount: Option<u64>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct StablecoinStatus {
     pub stablecoin: Stablecoin,
     pub total_supply: u64,
+    /// `total_supply` in whole tokens, e.g. `1,000.000000`
+    pub total_supply_display: String,
     pub paused: bool,
     pub compliance_enabled: bool,
     pub holder_count: u64,
+    pub blacklist_count: u32,
+    /// On-chain supply ceiling (0 = uncapped)
+    pub max_supply: u64,
+    /// Smallest amount a single mint or burn may request (0 = no minimum)
+    pub min_mint_amount: u64,
+    pub min_burn_amount: u64,
+    /// Who paused the stablecoin and why; only present while paused
+    pub pause: Option<PauseDetails>,
+}
+
+/// Signed proof of reserves: attested collateral against on-chain supply.
+/// `signature` is the `signer`'s ed25519 signature over the UTF-8 `message`.
+#[derive(Debug, Serialize)]
+pub struct ReserveProof {
+    pub stablecoin_pda: String,
+    /// Attested off-chain collateral in base units
+    pub collateral: u64,
+    /// On-chain `total_supply` in base units
+    pub supply: u64,
+    /// Collateral / supply in basis points; `None` while supply is zero
+    pub ratio_bps: Option<u64>,
+    pub attested_at: DateTime<Utc>,
+    pub timestamp: DateTime<Utc>,
+    pub message: String,
+    pub signer: String,
+    pub signature: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PauseDetails {
+    pub paused_by: String,
+    pub paused_at: Option<DateTime<Utc>>,
+    pub reason: String,
+    /// When the pause lifts on its own; `None` until unpaused
+    pub until: Option<DateTime<Utc>>,
+}
+
+/// Stablecoin fields compared during reconciliation, as seen by one source
+#[derive(Debug, Clone, Serialize)]
+pub struct StablecoinSnapshot {
+    pub total_supply: u64,
+    pub paused: bool,
+    pub preset: u8,
+    pub compliance_enabled: bool,
+    pub authority: String,
+}
+
+/// A field whose database value differs from the on-chain value
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldMismatch {
+    pub field: String,
+    pub database: serde_json::Value,
+    pub onchain: serde_json::Value,
+}
+
+impl StablecoinSnapshot {
+    /// List the fields where `self` (database) differs from `onchain`
+    pub fn diff(&self, onchain: &StablecoinSnapshot) -> Vec<FieldMismatch> {
+        let mut mismatches = Vec::new();
+        let mut compare = |field: &str, database: serde_json::Value, onchain: serde_json::Value| {
+            if database != onchain {
+                mismatches.push(FieldMismatch {
+                    field: field.to_string(),
+                    database,
+                    onchain,
+                });
+            }
+        };
+
+        compare("total_supply", self.total_supply.into(), onchain.total_supply.into());
+        compare("paused", self.paused.into(), onchain.paused.into());
+        compare("preset", self.preset.into(), onchain.preset.into());
+        compare("compliance_enabled", self.compliance_enabled.into(), onchain.compliance_enabled.into());
+        compare("authority", self.authority.clone().into(), onchain.authority.clone().into());
+
+        mismatches
+    }
+}
+
+/// Result of comparing a stablecoin's database record with its on-chain state
+#[derive(Debug, Serialize)]
+pub struct ReconciliationReport {
+    pub stablecoin_id: Uuid,
+    pub in_sync: bool,
+    pub database: StablecoinSnapshot,
+    pub onchain: StablecoinSnapshot,
+    pub mismatches: Vec<FieldMismatch>,
+    pub checked_at: DateTime<Utc>,
+}
+
+/// Bucket width for supply history series
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SupplyInterval {
+    Hour,
+    #[default]
+    Day,
+    Week,
+}
+
+impl SupplyInterval {
+    /// Postgres `date_trunc` field name
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SupplyInterval::Hour => "hour",
+            SupplyInterval::Day => "day",
+            SupplyInterval::Week => "week",
+        }
+    }
+}
+
+/// Mints and burns recorded in one supply history bucket
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct SupplyHistoryPoint {
+    /// Start of the bucket
+    pub bucket: DateTime<Utc>,
+    pub minted: i64,
+    pub burned: i64,
+    pub net_change: i64,
+    /// Net supply recorded up to the end of the bucket
+    pub supply: i64,
+}
+
+/// Supply over time for a stablecoin, derived from the audit log
+#[derive(Debug, Serialize)]
+pub struct SupplyHistory {
+    pub stablecoin_id: Uuid,
+    pub interval: SupplyInterval,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    /// Buckets with at least one mint or burn, oldest first
+    pub series: Vec<SupplyHistoryPoint>,
 }
 
 // ==================== Operation Models ====================
@@ -198,11 +443,16 @@ pub fn validate_amount(amount: &u64) -> Result<(), validator::ValidationError> {
 
 #[derive(Debug, Deserialize, Validate)]
 pub struct MintRequest {
+    /// Recipient wallet; tokens go to its associated token account
     #[validate(custom = "validate_solana_pubkey")]
     pub recipient: String,
     
     #[validate(custom = "validate_amount")]
     pub amount: u64,
+    
+    /// Treat `recipient` as an existing token account instead of a wallet
+    #[serde(default)]
+    pub recipient_is_token_account: bool,
 }
 
 #[derive(Debug, Deserialize, Validate)]
@@ -224,6 +474,65 @@ pub struct TransferRequest {
     
     #[validate(custom = "validate_amount")]
     pub amount: u64,
+    
+    /// Attached to the transfer with the SPL Memo program, e.g. for compliance reporting
+    #[validate(custom = "validate_memo")]
+    pub memo: Option<String>,
+}
+
+/// Operation to estimate the fee of, tagged by `operation`, with the same
+/// parameters as the endpoint that performs it
+#[derive(Debug, Deserialize)]
+#[serde(tag = "operation", rename_all = "snake_case")]
+pub enum EstimateRequest {
+    Mint(MintRequest),
+    Burn(BurnRequest),
+    Transfer(TransferRequest),
+}
+
+impl EstimateRequest {
+    /// Operation name echoed in the response
+    pub fn name(&self) -> &'static str {
+        match self {
+            EstimateRequest::Mint(_) => "mint",
+            EstimateRequest::Burn(_) => "burn",
+            EstimateRequest::Transfer(_) => "transfer",
+        }
+    }
+}
+
+impl Validate for EstimateRequest {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        match self {
+            EstimateRequest::Mint(req) => req.validate(),
+            EstimateRequest::Burn(req) => req.validate(),
+            EstimateRequest::Transfer(req) => req.validate(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct FeeEstimateResponse {
+    pub operation: &'static str,
+    #[serde(flatten)]
+    pub estimate: crate::solana::FeeEstimate,
+}
+
+/// Longest memo the SPL Memo program accepts, in bytes
+pub const MAX_MEMO_BYTES: usize = 566;
+
+/// Custom validator for transfer memos: at most `MAX_MEMO_BYTES` of UTF-8,
+/// without control characters
+pub fn validate_memo(memo: &str) -> Result<(), validator::ValidationError> {
+    let message = if memo.len() > MAX_MEMO_BYTES {
+        "Memo exceeds 566 bytes"
+    } else if memo.chars().any(char::is_control) {
+        "Memo cannot contain control characters"
+    } else {
+        return Ok(());
+    };
+    Err(validator::ValidationError::new("memo")
+        .with_message(std::borrow::Cow::Borrowed(message)))
 }
 
 #[derive(Debug, Serialize)]
@@ -254,6 +563,14 @@ pub struct BlacklistEntry {
     pub created_at: DateTime<Utc>,
 }
 
+/// Most addresses one batch screening request may carry
+pub const MAX_BATCH_SCREEN_ADDRESSES: usize = 100;
+
+#[derive(Debug, Deserialize)]
+pub struct BatchScreenRequest {
+    pub addresses: Vec<String>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct ScreeningResult {
     pub address: String,
@@ -298,6 +615,47 @@ pub struct RoleAssignment {
     pub created_at: DateTime<Utc>,
 }
 
+/// Role assignment as recorded on-chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, sqlx::FromRow)]
+pub struct OnchainRoleAssignment {
+    pub role: String,
+    pub account: String,
+    pub assigned_by: String,
+    /// Unix timestamp of the assignment
+    pub assigned_at: i64,
+    /// Unix timestamp after which the role no longer applies (`null` = never)
+    pub expires_at: Option<i64>,
+}
+
+/// A change to a stablecoin's minter, role or blacklist accounts, read from an indexed event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountChange {
+    RoleAssigned(OnchainRoleAssignment),
+    RoleRemoved { role: String, account: String },
+    /// New minter or new quota; usage is left as it was
+    MinterQuota { minter: String, quota: u64 },
+    MinterRemoved { minter: String },
+    MinterRotated { old_minter: String, new_minter: String, quota: u64, minted_amount: u64 },
+    Blacklisted { account: String, reason: String },
+    Unblacklisted { account: String },
+}
+
+impl AccountChange {
+    /// Apply a role change to a list of assignments; other changes leave it alone
+    pub fn apply_to_roles(&self, roles: &mut Vec<OnchainRoleAssignment>) {
+        match self {
+            AccountChange::RoleAssigned(assignment) => {
+                roles.retain(|r| !(r.role == assignment.role && r.account == assignment.account));
+                roles.push(assignment.clone());
+            }
+            AccountChange::RoleRemoved { role, account } => {
+                roles.retain(|r| !(&r.role == role && &r.account == account));
+            }
+            _ => {}
+        }
+    }
+}
+
 // ==================== Minter Models ====================
 #[derive(Debug, Deserialize, Validate)]
 pub struct AddMinterRequest {
@@ -326,6 +684,17 @@ pub struct MinterQuota {
 }
 
 // ==================== Admin Models ====================
+/// Custom validator for attested reserves, which are stored as BIGINT
+pub fn validate_reserve_amount(amount: &u64) -> Result<(), validator::ValidationError> {
+    if *amount > i64::MAX as u64 {
+        return Err(validator::ValidationError::new("reserve_amount")
+            .with_message(std::borrow::Cow::Borrowed(
+                "Reserve amount exceeds maximum allowed value"
+            )));
+    }
+    Ok(())
+}
+
 #[derive(Debug, Deserialize, Validate)]
 pub struct SeizeRequest {
     #[validate(custom = "validate_solana_pubkey")]
@@ -338,6 +707,178 @@ pub struct SeizeRequest {
     pub amount: u64,
 }
 
+/// Custom validator for the per-transfer cap (0 disables the cap)
+pub fn validate_max_transfer_amount(amount: &u64) -> Result<(), validator::ValidationError> {
+    if *amount > MAX_OPERATION_AMOUNT {
+        return Err(validator::ValidationError::new("amount")
+            .with_message(std::borrow::Cow::Borrowed(
+                "Amount exceeds maximum allowed value (1 quadrillion)"
+            )));
+    }
+    Ok(())
+}
+
+/// Custom validator for the supply cap (0 disables the cap)
+pub fn validate_max_supply(amount: &u64) -> Result<(), validator::ValidationError> {
+    if *amount > MAX_OPERATION_AMOUNT {
+        return Err(validator::ValidationError::new("max_supply")
+            .with_message(std::borrow::Cow::Borrowed(
+                "Max supply exceeds maximum allowed value (1 quadrillion)"
+            )));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct SetMaxTransferRequest {
+    /// Maximum amount per transfer in base units (0 = unlimited)
+    #[validate(custom = "validate_max_transfer_amount")]
+    pub amount: u64,
+}
+
+// ==================== Offline Signing Models ====================
+/// Longest pause reason the program stores
+pub const MAX_PAUSE_REASON_LENGTH: usize = 200;
+
+/// Operation to build for offline signing, tagged by `action`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum OfflineAction {
+    Pause {
+        #[serde(default)]
+        reason: String,
+        /// Unix time the pause lifts on its own (0 = until unpaused)
+        #[serde(default)]
+        until: i64,
+    },
+    Unpause,
+    Seize {
+        from_account: String,
+        to_account: String,
+        amount: u64,
+    },
+    TransferAuthority {
+        new_authority: String,
+    },
+    SetMaxTransfer {
+        amount: u64,
+    },
+    SetMaxSupply {
+        max_supply: u64,
+    },
+    /// Turn compliance on or off; `upgrade_preset` also marks the coin SSS-2 when enabling
+    SetCompliance {
+        enabled: bool,
+        #[serde(default)]
+        upgrade_preset: bool,
+    },
+    AddToAllowlist {
+        account: String,
+    },
+    RemoveFromAllowlist {
+        account: String,
+    },
+    /// While on, the transfer hook only moves tokens between allowlisted accounts
+    SetAllowlistMode {
+        enabled: bool,
+    },
+}
+
+impl OfflineAction {
+    /// Action name used in audit entries
+    pub fn name(&self) -> &'static str {
+        match self {
+            OfflineAction::Pause { .. } => "pause",
+            OfflineAction::Unpause => "unpause",
+            OfflineAction::Seize { .. } => "seize",
+            OfflineAction::TransferAuthority { .. } => "transfer_authority",
+            OfflineAction::SetMaxTransfer { .. } => "set_max_transfer",
+            OfflineAction::SetMaxSupply { .. } => "set_max_supply",
+            OfflineAction::SetCompliance { .. } => "set_compliance",
+            OfflineAction::AddToAllowlist { .. } => "add_to_allowlist",
+            OfflineAction::RemoveFromAllowlist { .. } => "remove_from_allowlist",
+            OfflineAction::SetAllowlistMode { .. } => "set_allowlist_mode",
+        }
+    }
+}
+
+/// Custom validator for the parameters of an offline action
+pub fn validate_offline_action(action: &OfflineAction) -> Result<(), validator::ValidationError> {
+    match action {
+        OfflineAction::Pause { reason, .. } if reason.len() > MAX_PAUSE_REASON_LENGTH => {
+            Err(validator::ValidationError::new("reason")
+                .with_message(std::borrow::Cow::Borrowed("Reason too long (max 200 chars)")))
+        }
+        OfflineAction::Pause { until, .. } if *until < 0 => {
+            Err(validator::ValidationError::new("until")
+                .with_message(std::borrow::Cow::Borrowed("until must be a Unix timestamp, or 0 for no expiry")))
+        }
+        OfflineAction::Seize { from_account, to_account, amount } => {
+            validate_solana_pubkey(from_account)?;
+            validate_solana_pubkey(to_account)?;
+            validate_amount(amount)
+        }
+        OfflineAction::TransferAuthority { new_authority } => validate_solana_pubkey(new_authority),
+        OfflineAction::AddToAllowlist { account } | OfflineAction::RemoveFromAllowlist { account } => {
+            validate_solana_pubkey(account)
+        }
+        OfflineAction::SetMaxTransfer { amount } => validate_max_transfer_amount(amount),
+        OfflineAction::SetMaxSupply { max_supply } => validate_max_supply(max_supply),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct BuildTransactionRequest {
+    #[serde(flatten)]
+    #[validate(custom = "validate_offline_action")]
+    pub action: OfflineAction,
+    
+    /// Offline key that signs the operation
+    #[validate(custom = "validate_solana_pubkey")]
+    pub authority: String,
+    
+    /// Fee payer, defaults to `authority`
+    #[validate(custom = "validate_solana_pubkey")]
+    pub fee_payer: Option<String>,
+    
+    /// Durable nonce account; without one the message expires with its blockhash
+    #[validate(custom = "validate_solana_pubkey")]
+    pub nonce_account: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BuildTransactionResponse {
+    /// Base64-encoded serialized message to sign
+    pub message: String,
+    /// Pubkeys whose signatures the submitted transaction must carry, in order
+    pub required_signers: Vec<String>,
+    /// Recent blockhash or durable nonce the message was built with
+    pub blockhash: String,
+    pub nonce_account: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SubmitTransactionRequest {
+    /// Base64-encoded, fully signed transaction
+    pub transaction: String,
+}
+
+// ==================== Pagination Models ====================
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Pagination {
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+/// Paginated list envelope: `{"data": [...], "pagination": {...}}`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaginatedResponse<T> {
+    pub data: Vec<T>,
+    pub pagination: Pagination,
+}
+
 // ==================== Audit Models ====================
 #[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
 pub struct AuditLogEntry {
@@ -348,9 +889,59 @@ pub struct AuditLogEntry {
     pub tx_signature: Option<String>,
     pub details: Option<serde_json::Value>,
     pub ip_address: Option<String>,
+    /// `x-request-id` of the API call that wrote the entry
+    pub request_id: Option<String>,
     pub created_at: DateTime<Utc>,
 }
 
+// ==================== Event Stream Models ====================
+/// An on-chain program event picked up by the indexer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexedEvent {
+    /// Stablecoin state PDA the event belongs to
+    pub stablecoin: String,
+    /// Anchor event name, e.g. `TokensMinted`
+    pub event_type: String,
+    pub signature: Option<String>,
+    pub slot: u64,
+    pub data: serde_json::Value,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// A stablecoin announced by a `StablecoinInitialized` event, to register in the DB
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscoveredStablecoin {
+    pub stablecoin_pda: String,
+    pub authority: String,
+    pub asset_mint: String,
+    pub preset: i16,
+    pub decimals: i16,
+    pub name: String,
+    pub symbol: String,
+}
+
+/// Messages a client sends on the event WebSocket
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventStreamRequest {
+    /// Only forward these event types; an empty list forwards everything
+    Subscribe {
+        #[serde(default)]
+        events: Vec<String>,
+    },
+}
+
+/// Messages the server sends on the event WebSocket
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventStreamMessage {
+    Event(IndexedEvent),
+    Subscribed { events: Vec<String> },
+    /// The connection fell behind and this many events were dropped
+    Lagged { skipped: u64 },
+    Error { message: String },
+}
+
 // ==================== Webhook Models ====================
 
 /// Valid webhook events
@@ -461,10 +1052,55 @@ pub struct CreateApiKeyRequest {
     pub expires_at: Option<DateTime<Utc>>,
 }
 
+/// Normalize requested permissions: lowercase, deduplicated, and read-only when none are given
+pub fn normalize_permissions(permissions: Option<&[String]>) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for perm in permissions.unwrap_or_default() {
+        let perm = perm.to_lowercase();
+        if !normalized.contains(&perm) {
+            normalized.push(perm);
+        }
+    }
+    if normalized.is_empty() {
+        normalized.push("read".to_string());
+    }
+    normalized
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ApiKey {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    #[serde(skip_serializing)]
+    pub key_hash: String,
+    pub name: Option<String>,
+    pub permissions: Option<serde_json::Value>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ApiKey {
+    /// Permissions granted to this key
+    pub fn permission_list(&self) -> Vec<String> {
+        self.permissions
+            .as_ref()
+            .and_then(|p| serde_json::from_value(p.clone()).ok())
+            .unwrap_or_default()
+    }
+    
+    /// Whether the key has passed its expiry time
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct ApiKeyResponse {
     pub id: Uuid,
     pub key: String, // Only shown once!
     pub name: Option<String>,
+    pub permissions: Vec<String>,
     pub expires_at: Option<DateTime<Utc>>,
 }
@@ -1,45 +1,63 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
 };
+use serde::Deserialize;
 use serde_json::json;
-use solana_sdk::pubkey::Pubkey;
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
 use sqlx::query_as;
 use uuid::Uuid;
 use validator::Validate;
 
 use crate::{
     error::{ApiError, ApiResult},
-    models::{SeizeRequest, TransactionResponse, User},
-    app_middleware::auth::AuthUser,
-    solana::explorer_url,
+    models::{SeizeRequest, SetMaxTransferRequest, TransactionResponse, User},
+    app_middleware::{auth::AuthUser, request_id::RequestId},
+    solana::{explorer_url, parse_commitment, Role, ALLOWED_COMMITMENTS},
     utils::audit,
     AppState,
 };
 
-/// Helper function to convert validation errors to API error
-fn validation_error_to_api_error(e: validator::ValidationErrors) -> ApiError {
-    let error_messages: Vec<String> = e.field_errors()
-        .into_iter()
-        .flat_map(|(field, errors)| {
-            errors.iter().map(move |err| {
-                format!("{}: {}", field, err.message.as_ref().map(|m| m.as_ref()).unwrap_or("invalid"))
-            })
-        })
-        .collect();
-    ApiError::Validation(error_messages.join("; "))
+/// `?commitment=` override for admin transactions
+#[derive(Debug, Default, Deserialize)]
+pub struct CommitmentQuery {
+    /// One of `processed`, `confirmed` or `finalized`
+    pub commitment: Option<String>,
+}
+
+impl CommitmentQuery {
+    /// The requested commitment, or `default` when none was given
+    pub fn resolve(&self, default: CommitmentConfig) -> ApiResult<CommitmentConfig> {
+        match self.commitment.as_deref() {
+            None => Ok(default),
+            Some(value) => parse_commitment(value).ok_or_else(|| {
+                ApiError::Validation(format!(
+                    "Invalid commitment '{}': expected one of {}",
+                    value,
+                    ALLOWED_COMMITMENTS.join(", "),
+                ))
+            }),
+        }
+    }
 }
 
 /// Pause all operations on a stablecoin
 pub async fn pause(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Path(id): Path<Uuid>,
+    Query(query): Query<CommitmentQuery>,
 ) -> ApiResult<impl IntoResponse> {
+    let commitment = query.resolve(state.solana.commitment())?;
+    
     // Get stablecoin and check ownership
-    let _stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
+    let stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
+    
+    // Check the caller holds the Pauser role on-chain
+    require_onchain_role(&state, &stablecoin, &user, Role::Pauser).await?;
     
     // Build pause transaction
     let tx_signature = format!("pause_{}", id);
@@ -51,8 +69,9 @@ pub async fn pause(
         Some(user.id),
         "stablecoin.pause",
         Some(&tx_signature),
+        Some(json!({"commitment": commitment.commitment.to_string()})),
         None,
-        None,
+        Some(&request_id),
     ).await;
     
     Ok(Json(TransactionResponse {
@@ -65,11 +84,18 @@ pub async fn pause(
 /// Unpause all operations on a stablecoin
 pub async fn unpause(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Path(id): Path<Uuid>,
+    Query(query): Query<CommitmentQuery>,
 ) -> ApiResult<impl IntoResponse> {
+    let commitment = query.resolve(state.solana.commitment())?;
+    
     // Get stablecoin and check ownership
-    let _stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
+    let stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
+    
+    // Check the caller holds the Pauser role on-chain
+    require_onchain_role(&state, &stablecoin, &user, Role::Pauser).await?;
     
     // Build unpause transaction
     let tx_signature = format!("unpause_{}", id);
@@ -81,8 +107,9 @@ pub async fn unpause(
         Some(user.id),
         "stablecoin.unpause",
         Some(&tx_signature),
+        Some(json!({"commitment": commitment.commitment.to_string()})),
         None,
-        None,
+        Some(&request_id),
     ).await;
     
     Ok(Json(TransactionResponse {
@@ -95,9 +122,13 @@ pub async fn unpause(
 /// Freeze an account
 pub async fn freeze(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Path((id, account)): Path<(Uuid, String)>,
+    Query(query): Query<CommitmentQuery>,
 ) -> ApiResult<impl IntoResponse> {
+    let commitment = query.resolve(state.solana.commitment())?;
+    
     // Validate account pubkey format
     crate::models::validate_solana_pubkey(&account)
         .map_err(|_| ApiError::Validation("Invalid account pubkey".to_string()))?;
@@ -107,7 +138,10 @@ pub async fn freeze(
         .map_err(|_| ApiError::Validation("Invalid account pubkey".to_string()))?;
     
     // Get stablecoin and check ownership
-    let _stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
+    let stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
+    
+    // Check the caller holds the Blacklister role on-chain
+    require_onchain_role(&state, &stablecoin, &user, Role::Blacklister).await?;
     
     // Build freeze transaction
     let tx_signature = format!("freeze_{}_{}", id, &account[..8]);
@@ -119,8 +153,9 @@ pub async fn freeze(
         Some(user.id),
         "stablecoin.freeze",
         Some(&tx_signature),
-        Some(json!({"account": account})),
+        Some(json!({"account": account, "commitment": commitment.commitment.to_string()})),
         None,
+        Some(&request_id),
     ).await;
     
     Ok(Json(TransactionResponse {
@@ -133,9 +168,13 @@ pub async fn freeze(
 /// Thaw a frozen account
 pub async fn thaw(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Path((id, account)): Path<(Uuid, String)>,
+    Query(query): Query<CommitmentQuery>,
 ) -> ApiResult<impl IntoResponse> {
+    let commitment = query.resolve(state.solana.commitment())?;
+    
     // Validate account pubkey format
     crate::models::validate_solana_pubkey(&account)
         .map_err(|_| ApiError::Validation("Invalid account pubkey".to_string()))?;
@@ -145,7 +184,10 @@ pub async fn thaw(
         .map_err(|_| ApiError::Validation("Invalid account pubkey".to_string()))?;
     
     // Get stablecoin and check ownership
-    let _stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
+    let stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
+    
+    // Check the caller holds the Blacklister role on-chain
+    require_onchain_role(&state, &stablecoin, &user, Role::Blacklister).await?;
     
     // Build thaw transaction
     let tx_signature = format!("thaw_{}_{}", id, &account[..8]);
@@ -157,8 +199,9 @@ pub async fn thaw(
         Some(user.id),
         "stablecoin.thaw",
         Some(&tx_signature),
-        Some(json!({"account": account})),
+        Some(json!({"account": account, "commitment": commitment.commitment.to_string()})),
         None,
+        Some(&request_id),
     ).await;
     
     Ok(Json(TransactionResponse {
@@ -171,15 +214,19 @@ pub async fn thaw(
 /// Seize tokens from an account
 pub async fn seize(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Path(id): Path<Uuid>,
+    Query(query): Query<CommitmentQuery>,
     Json(req): Json<SeizeRequest>,
 ) -> ApiResult<impl IntoResponse> {
+    let commitment = query.resolve(state.solana.commitment())?;
+    
     // Validate input using validator crate (includes pubkey and amount validation)
-    req.validate().map_err(validation_error_to_api_error)?;
+    req.validate()?;
     
     // Parse and validate pubkeys (additional validation)
-    let _from_pubkey: Pubkey = req.from_account.parse()
+    let from_pubkey: Pubkey = req.from_account.parse()
         .map_err(|_| ApiError::Validation("Invalid from_account pubkey".to_string()))?;
     let _to_pubkey: Pubkey = req.to_account.parse()
         .map_err(|_| ApiError::Validation("Invalid to_account pubkey".to_string()))?;
@@ -187,11 +234,31 @@ pub async fn seize(
     // Get stablecoin and check ownership
     let stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
     
+    // Check the caller holds the Seizer role on-chain
+    require_onchain_role(&state, &stablecoin, &user, Role::Seizer).await?;
+    
     // Check SSS-2 preset for seizure
     if stablecoin.preset < 1 {
         return Err(ApiError::BadRequest("Seizure only available for SSS-2 or higher".to_string()));
     }
     
+    // Seizure is only allowed from blacklisted accounts (enforced on-chain as well)
+    let is_blacklisted: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM blacklist_entries WHERE stablecoin_id = $1 AND account_pubkey = $2 AND is_active = true)"
+    )
+    .bind(id)
+    .bind(&req.from_account)
+    .fetch_one(state.db.pool())
+    .await
+    .map_err(|e| ApiError::Database(e.to_string()))?;
+    
+    if !is_blacklisted {
+        return Err(ApiError::BadRequest("Account is not blacklisted".to_string()));
+    }
+    
+    // Report a short balance clearly instead of as a failed token transfer
+    state.solana.require_token_balance(&from_pubkey, req.amount).await.map_err(ApiError::solana)?;
+    
     // Build seize transaction
     let tx_signature = format!("seize_{}_{}_{}", id, &req.from_account[..8], req.amount);
     
@@ -202,8 +269,9 @@ pub async fn seize(
         Some(user.id),
         "stablecoin.seize",
         Some(&tx_signature),
-        Some(json!({"from": req.from_account, "to": req.to_account, "amount": req.amount})),
+        Some(json!({"from": req.from_account, "to": req.to_account, "amount": req.amount, "commitment": commitment.commitment.to_string()})),
         None,
+        Some(&request_id),
     ).await;
     
     Ok(Json(TransactionResponse {
@@ -213,7 +281,58 @@ pub async fn seize(
     }))
 }
 
-// Helper function
+/// Set the maximum amount allowed per transfer (0 = unlimited)
+pub async fn set_max_transfer(
+    State(state): State<AppState>,
+    RequestId(request_id): RequestId,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+    Query(query): Query<CommitmentQuery>,
+    Json(req): Json<SetMaxTransferRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let commitment = query.resolve(state.solana.commitment())?;
+    
+    // Validate input using validator crate
+    req.validate()?;
+    
+    // Get stablecoin and check ownership
+    let _stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
+    
+    // Build set_max_transfer transaction
+    let tx_signature = format!("max_transfer_{}_{}", id, req.amount);
+    
+    // Log audit
+    audit(
+        &state.db,
+        Some(id),
+        Some(user.id),
+        "stablecoin.set_max_transfer",
+        Some(&tx_signature),
+        Some(json!({"max_transfer_amount": req.amount, "commitment": commitment.commitment.to_string()})),
+        None,
+        Some(&request_id),
+    ).await;
+    
+    Ok(Json(TransactionResponse {
+        tx_signature: tx_signature.clone(),
+        status: "pending".to_string(),
+        explorer_url: explorer_url(&tx_signature, "devnet"),
+    }))
+}
+
+// Helper functions
+async fn require_onchain_role(
+    state: &AppState,
+    stablecoin: &crate::models::Stablecoin,
+    user: &User,
+    role: Role,
+) -> ApiResult<()> {
+    let stablecoin_pda: Pubkey = stablecoin.stablecoin_pda.parse()
+        .map_err(|_| ApiError::Internal("Invalid stablecoin PDA".to_string()))?;
+    
+    state.roles.authorize_user(&stablecoin_pda, user, role).await
+}
+
 async fn get_stablecoin_for_admin(
     state: &AppState, 
     id: Uuid, 
@@ -226,7 +345,7 @@ async fn get_stablecoin_for_admin(
     .fetch_optional(state.db.pool())
     .await
     .map_err(|e| ApiError::Database(e.to_string()))?
-    .ok_or_else(|| ApiError::NotFound("Stablecoin not found".to_string()))?;
+    .ok_or(ApiError::StablecoinNotFound)?;
     
     // Check ownership or admin role
     if stablecoin.owner_id != user.id && user.role != "admin" {
@@ -0,0 +1,128 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use chrono::Utc;
+use serde_json::json;
+use sqlx::query_as;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    models::{normalize_permissions, ApiKey, ApiKeyResponse, CreateApiKeyRequest},
+    app_middleware::{auth::AuthUser, request_id::RequestId},
+    utils::{audit, generate_api_key, hash_api_key},
+    AppState,
+};
+
+/// Prefix that makes leaked keys easy to recognize (and to scan for)
+const API_KEY_PREFIX: &str = "sss_";
+
+/// Create an API key for the current user.
+/// The raw key is only returned here; only its hash is stored.
+pub async fn create(
+    State(state): State<AppState>,
+    RequestId(request_id): RequestId,
+    AuthUser(user): AuthUser,
+    Json(req): Json<CreateApiKeyRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Validate input using validator crate
+    req.validate()?;
+    
+    if req.expires_at.is_some_and(|expires_at| expires_at <= Utc::now()) {
+        return Err(ApiError::Validation("expires_at must be in the future".to_string()));
+    }
+    
+    let permissions = normalize_permissions(req.permissions.as_deref());
+    let raw_key = format!("{}{}", API_KEY_PREFIX, generate_api_key());
+    
+    let key: ApiKey = query_as(
+        r#"
+        INSERT INTO api_keys (user_id, key_hash, name, permissions, expires_at)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING *
+        "#
+    )
+    .bind(user.id)
+    .bind(hash_api_key(&raw_key))
+    .bind(&req.name)
+    .bind(json!(permissions))
+    .bind(req.expires_at)
+    .fetch_one(state.db.pool())
+    .await
+    .map_err(|e| ApiError::Database(e.to_string()))?;
+    
+    // Log audit
+    audit(
+        &state.db,
+        None,
+        Some(user.id),
+        "api_key.create",
+        None,
+        Some(json!({"api_key_id": key.id, "permissions": permissions})),
+        None,
+        Some(&request_id),
+    ).await;
+    
+    Ok((StatusCode::CREATED, Json(ApiKeyResponse {
+        id: key.id,
+        key: raw_key,
+        name: key.name,
+        permissions,
+        expires_at: key.expires_at,
+    })))
+}
+
+/// List the current user's API keys (without the keys themselves)
+pub async fn list(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+) -> ApiResult<impl IntoResponse> {
+    let keys: Vec<ApiKey> = query_as(
+        "SELECT * FROM api_keys WHERE user_id = $1 ORDER BY created_at DESC"
+    )
+    .bind(user.id)
+    .fetch_all(state.db.pool())
+    .await
+    .map_err(|e| ApiError::Database(e.to_string()))?;
+    
+    Ok(Json(keys))
+}
+
+/// Revoke one of the current user's API keys
+pub async fn delete(
+    State(state): State<AppState>,
+    RequestId(request_id): RequestId,
+    AuthUser(user): AuthUser,
+    Path(key_id): Path<Uuid>,
+) -> ApiResult<impl IntoResponse> {
+    let result = sqlx::query(
+        "UPDATE api_keys SET is_active = false WHERE id = $1 AND user_id = $2 AND is_active = true"
+    )
+    .bind(key_id)
+    .bind(user.id)
+    .execute(state.db.pool())
+    .await
+    .map_err(|e| ApiError::Database(e.to_string()))?;
+    
+    if result.rows_affected() == 0 {
+        return Err(ApiError::NotFound("API key not found".to_string()));
+    }
+    
+    // Log audit
+    audit(
+        &state.db,
+        None,
+        Some(user.id),
+        "api_key.revoke",
+        None,
+        Some(json!({"api_key_id": key_id})),
+        None,
+        Some(&request_id),
+    ).await;
+    
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -3,31 +3,63 @@ use axum::{
     response::IntoResponse,
     Json,
 };
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
-use sqlx::query_as;
+use sqlx::{query_as, query_scalar};
 use uuid::Uuid;
+use validator::Validate;
 
 use crate::{
     error::{ApiError, ApiResult},
-    models::{AuditLogEntry, User},
+    models::{AuditLogEntry, PaginatedResponse, Pagination},
     app_middleware::auth::AuthUser,
     AppState,
 };
 
-#[derive(Debug, Deserialize)]
-pub struct AuditQuery {
-    pub action: Option<String>,
+/// Default page size for audit log queries
+pub const DEFAULT_AUDIT_PAGE_SIZE: i64 = 50;
+
+/// Columns selected for audit log responses (`ip_address` is INET in the schema)
+const AUDIT_COLUMNS: &str =
+    "id, stablecoin_id, user_id, action, tx_signature, details, ip_address::text AS ip_address, request_id, created_at";
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ListAuditQuery {
+    #[validate(range(min = 1, max = 200, message = "limit must be between 1 and 200"))]
     pub limit: Option<i64>,
+    #[validate(range(min = 0, message = "offset must not be negative"))]
     pub offset: Option<i64>,
+    pub action: Option<String>,
+    /// Inclusive lower bound on `created_at`
+    pub from: Option<DateTime<Utc>>,
+    /// Exclusive upper bound on `created_at`
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl ListAuditQuery {
+    /// Validate the query, including the `from`/`to` ordering
+    pub fn check(&self) -> ApiResult<()> {
+        self.validate()?;
+        if let (Some(from), Some(to)) = (self.from, self.to) {
+            if from > to {
+                return Err(ApiError::Validation("from must be before to".to_string()));
+            }
+        }
+        Ok(())
+    }
 }
 
 /// List audit logs for a stablecoin
+///
+/// Supports `?limit=&offset=&action=&from=&to=` and returns a paginated envelope.
 pub async fn list(
     State(state): State<AppState>,
     AuthUser(user): AuthUser,
     Path(id): Path<Uuid>,
-    Query(query): Query<AuditQuery>,
+    Query(query): Query<ListAuditQuery>,
 ) -> ApiResult<impl IntoResponse> {
+    query.check()?;
+    
     // Check stablecoin ownership
     let stablecoin: crate::models::Stablecoin = query_as(
         "SELECT * FROM stablecoins WHERE id = $1"
@@ -36,49 +68,50 @@ pub async fn list(
     .fetch_optional(state.db.pool())
     .await
     .map_err(|e| ApiError::Database(e.to_string()))?
-    .ok_or_else(|| ApiError::NotFound("Stablecoin not found".to_string()))?;
+    .ok_or(ApiError::StablecoinNotFound)?;
     
     if stablecoin.owner_id != user.id && user.role != "admin" {
         return Err(ApiError::Forbidden("Not authorized to view audit logs".to_string()));
     }
     
-    let limit = query.limit.unwrap_or(100).min(1000);
+    let limit = query.limit.unwrap_or(DEFAULT_AUDIT_PAGE_SIZE);
     let offset = query.offset.unwrap_or(0);
     
-    let logs: Vec<AuditLogEntry> = if let Some(action) = &query.action {
-        query_as(
-            r#"
-            SELECT * FROM audit_log 
-            WHERE stablecoin_id = $1 AND action = $2
-            ORDER BY created_at DESC
-            LIMIT $3 OFFSET $4
-            "#
-        )
-        .bind(id)
-        .bind(action)
-        .bind(limit)
-        .bind(offset)
-        .fetch_all(state.db.pool())
-        .await
-        .map_err(|e| ApiError::Database(e.to_string()))?
-    } else {
-        query_as(
-            r#"
-            SELECT * FROM audit_log 
-            WHERE stablecoin_id = $1
-            ORDER BY created_at DESC
-            LIMIT $2 OFFSET $3
-            "#
-        )
+    // Optional filters collapse to TRUE when the parameter is NULL
+    let filter = r#"
+        WHERE stablecoin_id = $1
+          AND ($2::text IS NULL OR action = $2)
+          AND ($3::timestamptz IS NULL OR created_at >= $3)
+          AND ($4::timestamptz IS NULL OR created_at < $4)
+    "#;
+    
+    let total: i64 = query_scalar(&format!("SELECT COUNT(*) FROM audit_log {}", filter))
         .bind(id)
-        .bind(limit)
-        .bind(offset)
-        .fetch_all(state.db.pool())
+        .bind(&query.action)
+        .bind(query.from)
+        .bind(query.to)
+        .fetch_one(state.db.pool())
         .await
-        .map_err(|e| ApiError::Database(e.to_string()))?
-    };
+        .map_err(|e| ApiError::Database(e.to_string()))?;
     
-    Ok(Json(logs))
+    let logs: Vec<AuditLogEntry> = query_as(&format!(
+        "SELECT {} FROM audit_log {} ORDER BY created_at DESC LIMIT $5 OFFSET $6",
+        AUDIT_COLUMNS, filter
+    ))
+    .bind(id)
+    .bind(&query.action)
+    .bind(query.from)
+    .bind(query.to)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(state.db.pool())
+    .await
+    .map_err(|e| ApiError::Database(e.to_string()))?;
+    
+    Ok(Json(PaginatedResponse {
+        data: logs,
+        pagination: Pagination { total, limit, offset },
+    }))
 }
 
 /// Get a specific audit log entry by transaction signature
@@ -87,9 +120,10 @@ pub async fn get(
     AuthUser(user): AuthUser,
     Path(tx_signature): Path<String>,
 ) -> ApiResult<impl IntoResponse> {
-    let log: AuditLogEntry = query_as(
-        "SELECT * FROM audit_log WHERE tx_signature = $1"
-    )
+    let log: AuditLogEntry = query_as(&format!(
+        "SELECT {} FROM audit_log WHERE tx_signature = $1",
+        AUDIT_COLUMNS
+    ))
     .bind(&tx_signature)
     .fetch_optional(state.db.pool())
     .await
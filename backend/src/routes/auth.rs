@@ -11,28 +11,48 @@ use validator::Validate;
 
 use crate::{
     error::{ApiError, ApiResult},
-    models::{AuthResponse, LoginRequest, RegisterRequest, User, UserPublic},
+    models::{
+        AuthResponse, LoginRequest, RegisterRequest, User, UserPublic, WalletChallengeRequest,
+        WalletChallengeResponse, WalletVerifyRequest,
+    },
     AppState,
 };
-use crate::utils::{generate_tokens, hash_password, verify_password};
+use crate::app_middleware::auth::verify_refresh_token;
+use crate::app_middleware::request_id::RequestId;
+use crate::routes::users::ensure_wallet_unclaimed;
+use crate::utils::{
+    generate_tokens, generate_tokens_in_family, hash_password, verify_password, verify_wallet_signature,
+    wallet_challenge_message,
+};
+
+/// How long a wallet sign-in challenge can be answered
+pub const WALLET_CHALLENGE_TTL_SECS: i64 = 300;
+
+/// Domain of the placeholder emails of wallet sign-in users
+pub const WALLET_EMAIL_SUFFIX: &str = "@wallet.invalid";
+
+/// Placeholder email for users created by wallet sign-in, unique per wallet.
+/// `.invalid` is reserved, so it can never collide with a real address,
+/// and `register` refuses it.
+pub fn wallet_user_email(pubkey: &str) -> String {
+    format!("{}{}", pubkey, WALLET_EMAIL_SUFFIX)
+}
 
-/// Register a new user
+/// Register a new user. A `solana_pubkey` given here is only a claim; it
+/// authorizes nothing until verified with `POST /users/me/wallet`.
 pub async fn register(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     Json(req): Json<RegisterRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Validate input using validator crate
-    req.validate().map_err(|e| {
-        let error_messages: Vec<String> = e.field_errors()
-            .into_iter()
-            .flat_map(|(field, errors)| {
-                errors.iter().map(move |err| {
-                    format!("{}: {}", field, err.message.as_ref().map(|m| m.as_ref()).unwrap_or("invalid"))
-                })
-            })
-            .collect();
-        ApiError::Validation(error_messages.join("; "))
-    })?;
+    req.validate()?;
+    if req.email.to_ascii_lowercase().ends_with(WALLET_EMAIL_SUFFIX) {
+        return Err(ApiError::Validation("Email domain is reserved for wallet sign-in".to_string()));
+    }
+    if let Some(pubkey) = &req.solana_pubkey {
+        ensure_wallet_unclaimed(&state, pubkey, None).await?;
+    }
     
     // Check if user already exists
     let existing: Option<User> = query_as(
@@ -75,6 +95,14 @@ pub async fn register(
         state.config.jwt_expiry,
     ).map_err(|e| ApiError::Internal(e.to_string()))?;
     
+    // Track refresh token for rotation
+    state.db.create_refresh_session(
+        tokens.refresh_jti,
+        tokens.family_id,
+        user.id,
+        tokens.refresh_expires_at,
+    ).await.map_err(|e| ApiError::Database(e.to_string()))?;
+    
     // Log audit
     let _ = state.db.log_audit(
         None,
@@ -83,6 +111,7 @@ pub async fn register(
         None,
         Some(json!({"email": user.email})),
         None,
+        Some(&request_id),
     ).await;
     
     Ok((
@@ -100,20 +129,11 @@ pub async fn register(
 /// Login with email and password
 pub async fn login(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     Json(req): Json<LoginRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Validate input using validator crate
-    req.validate().map_err(|e| {
-        let error_messages: Vec<String> = e.field_errors()
-            .into_iter()
-            .flat_map(|(field, errors)| {
-                errors.iter().map(move |err| {
-                    format!("{}: {}", field, err.message.as_ref().map(|m| m.as_ref()).unwrap_or("invalid"))
-                })
-            })
-            .collect();
-        ApiError::Validation(error_messages.join("; "))
-    })?;
+    req.validate()?;
     
     // Find user
     let user: Option<User> = query_as(
@@ -143,6 +163,14 @@ pub async fn login(
         state.config.jwt_expiry,
     ).map_err(|e| ApiError::Internal(e.to_string()))?;
     
+    // Track refresh token for rotation
+    state.db.create_refresh_session(
+        tokens.refresh_jti,
+        tokens.family_id,
+        user.id,
+        tokens.refresh_expires_at,
+    ).await.map_err(|e| ApiError::Database(e.to_string()))?;
+    
     // Log audit
     let _ = state.db.log_audit(
         None,
@@ -151,6 +179,7 @@ pub async fn login(
         None,
         None,
         None,
+        Some(&request_id),
     ).await;
     
     Ok(Json(AuthResponse {
@@ -167,11 +196,8 @@ pub async fn refresh(
     State(state): State<AppState>,
     Json(req): Json<RefreshRequest>,
 ) -> ApiResult<impl IntoResponse> {
-    use crate::utils::validate_token;
-    
-    // Validate refresh token
-    let claims = validate_token(&req.refresh_token, &state.config.jwt_secret)
-        .map_err(|_| ApiError::Unauthorized("Invalid refresh token".to_string()))?;
+    // Validate refresh token and consume it (rotation with reuse detection)
+    let claims = verify_refresh_token(&state, &req.refresh_token).await?;
     
     // Get user
     let user: User = query_as(
@@ -183,7 +209,118 @@ pub async fn refresh(
     .map_err(|e| ApiError::Database(e.to_string()))?
     .ok_or_else(|| ApiError::Unauthorized("User not found".to_string()))?;
     
-    // Generate new tokens
+    // Generate new tokens in the same family
+    let tokens = generate_tokens_in_family(
+        user.id,
+        &user.email,
+        &user.role,
+        &state.config.jwt_secret,
+        state.config.jwt_expiry,
+        claims.family_id.unwrap_or_else(Uuid::new_v4),
+    ).map_err(|e| ApiError::Internal(e.to_string()))?;
+    
+    // Track refresh token for rotation
+    state.db.create_refresh_session(
+        tokens.refresh_jti,
+        tokens.family_id,
+        user.id,
+        tokens.refresh_expires_at,
+    ).await.map_err(|e| ApiError::Database(e.to_string()))?;
+    
+    Ok(Json(AuthResponse {
+        access_token: tokens.access_token,
+        refresh_token: tokens.refresh_token,
+        token_type: tokens.token_type,
+        expires_in: tokens.expires_in,
+        user: UserPublic::from(user),
+    }))
+}
+
+/// Issue a single-use nonce for a wallet to sign
+pub async fn wallet_challenge(
+    State(state): State<AppState>,
+    Json(req): Json<WalletChallengeRequest>,
+) -> ApiResult<impl IntoResponse> {
+    req.validate()?;
+    
+    let nonce = hex::encode(rand::random::<[u8; 32]>());
+    let expires_at = chrono::Utc::now() + chrono::Duration::seconds(WALLET_CHALLENGE_TTL_SECS);
+    state.db.create_wallet_challenge(&nonce, &req.pubkey, expires_at).await
+        .map_err(|e| ApiError::Database(e.to_string()))?;
+    
+    Ok(Json(WalletChallengeResponse {
+        message: wallet_challenge_message(&nonce),
+        nonce,
+        expires_at,
+    }))
+}
+
+/// Sign in with a wallet's signature of its challenge. The user who verified
+/// the wallet is signed in, or a new one is created for it with the wallet
+/// already verified. Unverified claims to the wallet are dropped either way,
+/// so signing in never lands in an account that only claimed it.
+pub async fn wallet_verify(
+    State(state): State<AppState>,
+    RequestId(request_id): RequestId,
+    Json(req): Json<WalletVerifyRequest>,
+) -> ApiResult<impl IntoResponse> {
+    req.validate()?;
+    
+    let invalid = || ApiError::Unauthorized("Invalid or expired wallet challenge".to_string());
+    // Check the signature first, so a bad one doesn't use up the challenge
+    if !verify_wallet_signature(&req.pubkey, &wallet_challenge_message(&req.nonce), &req.signature) {
+        return Err(invalid());
+    }
+    let consumed = state.db.consume_wallet_challenge(&req.nonce, &req.pubkey).await
+        .map_err(|e| ApiError::Database(e.to_string()))?;
+    if !consumed {
+        return Err(invalid());
+    }
+    
+    state.db.clear_unverified_wallet_claims(&req.pubkey).await
+        .map_err(|e| ApiError::Database(e.to_string()))?;
+    
+    // Verified wallets are unique, so this is the wallet's only owner
+    let existing: Option<User> = query_as(
+        "SELECT * FROM users WHERE solana_pubkey = $1 AND wallet_verified_at IS NOT NULL"
+    )
+    .bind(&req.pubkey)
+    .fetch_optional(state.db.pool())
+    .await
+    .map_err(|e| ApiError::Database(e.to_string()))?;
+    
+    let (user, action) = match existing {
+        Some(user) if !user.is_active => {
+            return Err(ApiError::Unauthorized("Account is disabled".to_string()));
+        }
+        Some(user) => (user, "user.login"),
+        None => {
+            // Wallet users sign in by signature only; nobody knows this password
+            let password_hash = hash_password(&hex::encode(rand::random::<[u8; 32]>()))
+                .map_err(|e| ApiError::Internal(e.to_string()))?;
+            let user: User = query_as(
+                r#"
+                INSERT INTO users (email, password_hash, solana_pubkey, role, wallet_verified_at)
+                VALUES ($1, $2, $3, 'user', NOW())
+                ON CONFLICT (email) DO NOTHING
+                RETURNING *
+                "#
+            )
+            .bind(wallet_user_email(&req.pubkey))
+            .bind(&password_hash)
+            .bind(&req.pubkey)
+            .fetch_optional(state.db.pool())
+            .await
+            .map_err(|e| ApiError::Database(e.to_string()))?
+            // Left by a password registration from before the domain was reserved
+            .ok_or_else(|| ApiError::Conflict(
+                "This wallet's sign-in email belongs to another account".to_string(),
+            ))?;
+            (user, "user.register")
+        }
+    };
+    
+    // Generate tokens
     let tokens = generate_tokens(
         user.id,
         &user.email,
@@ -192,6 +329,25 @@ pub async fn refresh(
         state.config.jwt_expiry,
     ).map_err(|e| ApiError::Internal(e.to_string()))?;
     
+    // Track refresh token for rotation
+    state.db.create_refresh_session(
+        tokens.refresh_jti,
+        tokens.family_id,
+        user.id,
+        tokens.refresh_expires_at,
+    ).await.map_err(|e| ApiError::Database(e.to_string()))?;
+    
+    // Log audit
+    let _ = state.db.log_audit(
+        None,
+        Some(user.id),
+        action,
+        None,
+        Some(json!({"method": "wallet", "pubkey": req.pubkey})),
+        None,
+        Some(&request_id),
+    ).await;
+    
     Ok(Json(AuthResponse {
         access_token: tokens.access_token,
         refresh_token: tokens.refresh_token,
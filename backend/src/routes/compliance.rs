@@ -12,34 +12,25 @@ use validator::Validate;
 
 use crate::{
     error::{ApiError, ApiResult},
-    models::{BlacklistAddRequest, BlacklistEntry, ScreeningResult, User},
-    app_middleware::auth::AuthUser,
+    models::{
+        validate_solana_pubkey, BatchScreenRequest, BlacklistAddRequest, BlacklistEntry,
+        ScreeningResult, User, MAX_BATCH_SCREEN_ADDRESSES,
+    },
+    app_middleware::{auth::AuthUser, request_id::RequestId},
     utils::audit,
     AppState,
 };
 
-/// Helper function to convert validation errors to API error
-fn validation_error_to_api_error(e: validator::ValidationErrors) -> ApiError {
-    let error_messages: Vec<String> = e.field_errors()
-        .into_iter()
-        .flat_map(|(field, errors)| {
-            errors.iter().map(move |err| {
-                format!("{}: {}", field, err.message.as_ref().map(|m| m.as_ref()).unwrap_or("invalid"))
-            })
-        })
-        .collect();
-    ApiError::Validation(error_messages.join("; "))
-}
-
 /// Add an account to the blacklist
 pub async fn blacklist_add(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Path(id): Path<Uuid>,
     Json(req): Json<BlacklistAddRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Validate input using validator crate
-    req.validate().map_err(validation_error_to_api_error)?;
+    req.validate()?;
     
     // Parse and validate account pubkey (additional validation)
     let account_pubkey: Pubkey = req.account.parse()
@@ -82,6 +73,7 @@ pub async fn blacklist_add(
         None,
         Some(json!({"account": req.account, "reason": req.reason, "pda": blacklist_pda.to_string()})),
         None,
+        Some(&request_id),
     ).await;
     
     Ok((StatusCode::CREATED, Json(entry)))
@@ -90,6 +82,7 @@ pub async fn blacklist_add(
 /// Remove an account from the blacklist
 pub async fn blacklist_remove(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Path((id, account)): Path<(Uuid, String)>,
 ) -> ApiResult<impl IntoResponse> {
@@ -119,6 +112,7 @@ pub async fn blacklist_remove(
         None,
         Some(json!({"account": account})),
         None,
+        Some(&request_id),
     ).await;
     
     Ok(StatusCode::NO_CONTENT)
@@ -144,6 +138,25 @@ pub async fn blacklist_list(
     Ok(Json(entries))
 }
 
+/// On-chain allowlist status of a token account
+pub async fn allowlist_status(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path((id, account)): Path<(Uuid, String)>,
+) -> ApiResult<impl IntoResponse> {
+    let account_pubkey: Pubkey = account.parse()
+        .map_err(|_| ApiError::Validation("Invalid account pubkey".to_string()))?;
+    
+    let stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
+    let stablecoin_pda: Pubkey = stablecoin.stablecoin_pda.parse()
+        .map_err(|_| ApiError::Internal("Invalid stablecoin PDA".to_string()))?;
+    
+    let status = state.compliance.get_allowlist_status(&stablecoin_pda, &account_pubkey).await
+        .map_err(ApiError::solana)?;
+    
+    Ok(Json(status))
+}
+
 /// Screen an address for compliance
 pub async fn screen(
     State(state): State<AppState>,
@@ -178,6 +191,62 @@ pub async fn screen(
     Ok(Json(result))
 }
 
+/// Screen many addresses against the on-chain blacklist in one RPC round-trip
+pub async fn screen_batch(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<BatchScreenRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let accounts = parse_batch_addresses(&req.addresses)?;
+    
+    let stablecoin: crate::models::Stablecoin = query_as(
+        "SELECT * FROM stablecoins WHERE id = $1 AND is_active = true"
+    )
+    .bind(id)
+    .fetch_optional(state.db.pool())
+    .await
+    .map_err(|e| ApiError::Database(e.to_string()))?
+    .ok_or(ApiError::StablecoinNotFound)?;
+    let stablecoin_pda: Pubkey = stablecoin.stablecoin_pda.parse()
+        .map_err(|_| ApiError::Internal("Invalid stablecoin PDA".to_string()))?;
+    
+    let blacklisted = state.solana.batch_blacklist_status(&stablecoin_pda, &accounts).await
+        .map_err(ApiError::solana)?;
+    
+    let results: Vec<ScreeningResult> = req.addresses.into_iter()
+        .zip(blacklisted)
+        .map(|(address, is_blacklisted)| ScreeningResult {
+            address,
+            risk_score: if is_blacklisted { 100 } else { 0 },
+            is_sanctioned: false, // Would call external API in production
+            is_blacklisted,
+            recommendation: if is_blacklisted { "block".to_string() } else { "allow".to_string() },
+        })
+        .collect();
+    
+    Ok(Json(results))
+}
+
+/// Parse a batch of addresses, rejecting batches over `MAX_BATCH_SCREEN_ADDRESSES`
+pub fn parse_batch_addresses(addresses: &[String]) -> ApiResult<Vec<Pubkey>> {
+    if addresses.len() > MAX_BATCH_SCREEN_ADDRESSES {
+        return Err(ApiError::BadRequest(format!(
+            "At most {} addresses can be screened per request, got {}",
+            MAX_BATCH_SCREEN_ADDRESSES,
+            addresses.len(),
+        )));
+    }
+    
+    addresses.iter()
+        .map(|address| {
+            validate_solana_pubkey(address)
+                .ok()
+                .and_then(|_| address.parse().ok())
+                .ok_or_else(|| ApiError::Validation(format!("Invalid address pubkey: {}", address)))
+        })
+        .collect()
+}
+
 // Helper function
 async fn get_stablecoin_for_admin(
     state: &AppState, 
@@ -191,7 +260,7 @@ async fn get_stablecoin_for_admin(
     .fetch_optional(state.db.pool())
     .await
     .map_err(|e| ApiError::Database(e.to_string()))?
-    .ok_or_else(|| ApiError::NotFound("Stablecoin not found".to_string()))?;
+    .ok_or(ApiError::StablecoinNotFound)?;
     
     // Check ownership or admin role
     if stablecoin.owner_id != user.id && user.role != "admin" {
@@ -0,0 +1,41 @@
+use axum::{
+    extract::State,
+    http::header,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+
+use crate::{
+    app_middleware::csrf::{generate_csrf_token, CSRF_COOKIE, CSRF_HEADER},
+    AppState,
+};
+
+/// Token a browser client echoes in the `X-CSRF-Token` header on
+/// state-changing requests that carry no Origin/Referer
+#[derive(Debug, Serialize)]
+pub struct CsrfTokenResponse {
+    pub csrf_token: String,
+    /// Header the token must be sent in
+    pub header: &'static str,
+}
+
+/// Issue a CSRF token (no auth required)
+pub async fn token(State(state): State<AppState>) -> impl IntoResponse {
+    issue_token(&state.config.csrf_secret, !state.config.environment.is_development())
+}
+
+/// Token response with the same token set as the double-submit cookie.
+/// `secure` marks the cookie HTTPS-only.
+pub fn issue_token(secret: &str, secure: bool) -> Response {
+    let token = generate_csrf_token(secret);
+    let mut cookie = format!("{}={}; Path=/; SameSite=Strict", CSRF_COOKIE, token);
+    if secure {
+        cookie.push_str("; Secure");
+    }
+    (
+        [(header::SET_COOKIE, cookie)],
+        Json(CsrfTokenResponse { csrf_token: token, header: CSRF_HEADER }),
+    )
+        .into_response()
+}
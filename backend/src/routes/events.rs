@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use axum::{
+    extract::{
+        ws::{close_code, CloseFrame, Message, WebSocket, WebSocketUpgrade},
+        Path, Query, State,
+    },
+    http::HeaderMap,
+    response::Response,
+};
+use serde::Deserialize;
+use sqlx::query_as;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::time::{sleep, Duration};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    models::{EventStreamMessage, EventStreamRequest, IndexedEvent, Stablecoin},
+    app_middleware::auth::{authenticate_token, bearer_token},
+    AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct EventStreamQuery {
+    /// Access token for clients that cannot set headers on the upgrade (browsers)
+    pub token: Option<String>,
+}
+
+/// Stream indexer events for a stablecoin over a WebSocket
+///
+/// The upgrade is authenticated with the access token from the
+/// `Authorization` header or the `?token=` query parameter. The socket is
+/// closed once that token expires; clients reconnect with a fresh token.
+pub async fn ws(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<EventStreamQuery>,
+    headers: HeaderMap,
+    upgrade: WebSocketUpgrade,
+) -> ApiResult<Response> {
+    let token = match query.token.as_deref() {
+        Some(token) => token,
+        None => bearer_token(&headers)?,
+    };
+    let (user, claims) = authenticate_token(&state, token).await?;
+
+    let stablecoin: Stablecoin = query_as(
+        "SELECT * FROM stablecoins WHERE id = $1"
+    )
+    .bind(id)
+    .fetch_optional(state.db.pool())
+    .await
+    .map_err(|e| ApiError::Database(e.to_string()))?
+    .ok_or(ApiError::StablecoinNotFound)?;
+
+    if stablecoin.owner_id != user.id && user.role != "admin" {
+        return Err(ApiError::Forbidden("Not authorized to stream events".to_string()));
+    }
+
+    // Subscribe before upgrading so no event is missed in between
+    let events = state.events.subscribe();
+    let expires_in = token_lifetime(claims.exp);
+
+    tracing::info!("User {} opened event stream for stablecoin {}", user.id, id);
+    Ok(upgrade.on_upgrade(move |socket| {
+        stream_events(socket, events, stablecoin.stablecoin_pda, expires_in)
+    }))
+}
+
+/// Time left until a token with the given `exp` (seconds since epoch) expires
+fn token_lifetime(exp: usize) -> Duration {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    Duration::from_secs((exp as u64).saturating_sub(now))
+}
+
+/// Whether an event belongs to this stablecoin and passes the subscription filter.
+/// An empty filter forwards every event type.
+pub fn event_matches(event: &IndexedEvent, stablecoin_pda: &str, event_types: &HashSet<String>) -> bool {
+    event.stablecoin == stablecoin_pda
+        && (event_types.is_empty() || event_types.contains(&event.event_type))
+}
+
+/// Apply a client message to the subscription filter and build the reply
+pub fn handle_client_message(text: &str, event_types: &mut HashSet<String>) -> EventStreamMessage {
+    match serde_json::from_str::<EventStreamRequest>(text) {
+        Ok(EventStreamRequest::Subscribe { events }) => {
+            *event_types = events.iter().cloned().collect();
+            EventStreamMessage::Subscribed { events }
+        }
+        Err(e) => EventStreamMessage::Error {
+            message: format!("Invalid message: {}", e),
+        },
+    }
+}
+
+async fn send_message(socket: &mut WebSocket, message: &EventStreamMessage) -> bool {
+    match serde_json::to_string(message) {
+        Ok(text) => socket.send(Message::Text(text)).await.is_ok(),
+        Err(e) => {
+            tracing::warn!("Failed to serialize event stream message: {}", e);
+            true
+        }
+    }
+}
+
+async fn stream_events(
+    mut socket: WebSocket,
+    mut events: broadcast::Receiver<IndexedEvent>,
+    stablecoin_pda: String,
+    expires_in: Duration,
+) {
+    let mut event_types = HashSet::new();
+    let expiry = sleep(expires_in);
+    tokio::pin!(expiry);
+
+    loop {
+        tokio::select! {
+            _ = &mut expiry => {
+                let _ = socket.send(Message::Close(Some(CloseFrame {
+                    code: close_code::POLICY,
+                    reason: "Token expired".into(),
+                }))).await;
+                break;
+            }
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Text(text))) => {
+                    let reply = handle_client_message(&text, &mut event_types);
+                    if !send_message(&mut socket, &reply).await {
+                        break;
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // Pings are answered by axum; binary frames are ignored
+                Some(Ok(_)) => {}
+            },
+            received = events.recv() => match received {
+                Ok(event) => {
+                    if event_matches(&event, &stablecoin_pda, &event_types)
+                        && !send_message(&mut socket, &EventStreamMessage::Event(event)).await
+                    {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    if !send_message(&mut socket, &EventStreamMessage::Lagged { skipped }).await {
+                        break;
+                    }
+                }
+                Err(RecvError::Closed) => break,
+            },
+        }
+    }
+
+    tracing::debug!("Event stream for {} closed", stablecoin_pda);
+}
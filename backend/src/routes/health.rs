@@ -84,7 +84,7 @@ pub struct MemoryHealth {
 pub struct ReadinessResponse {
     pub ready: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub checks: Option<std::collections::HashMap<String, bool>>,
+    pub checks: Option<std::collections::HashMap<String, ComponentHealth>>,
 }
 
 /// Liveness check response (for Kubernetes)
@@ -164,26 +164,18 @@ pub async fn detailed_handler(
 
 /// Readiness probe handler for Kubernetes
 /// Returns 200 OK only if the service is ready to accept traffic
-/// Checks: database connectivity, Solana RPC availability
+/// Checks: database connectivity, Solana RPC availability, authority SOL balance
 pub async fn readiness_handler(
     State(state): State<AppState>,
 ) -> (StatusCode, Json<ReadinessResponse>) {
     let mut checks = std::collections::HashMap::new();
-    let mut all_ready = true;
 
-    // Check database
-    let db_ready = state.db.health_check().await.is_ok();
-    checks.insert("database".to_string(), db_ready);
-    if !db_ready {
-        all_ready = false;
-    }
+    checks.insert("database".to_string(), check_database(&state).await);
+    checks.insert("solana_rpc".to_string(), check_solana_rpc(&state).await);
+    checks.insert("authority_balance".to_string(), check_authority_balance(&state).await);
 
-    // Check Solana RPC
-    let rpc_ready = state.solana.health_check().await.unwrap_or(false);
-    checks.insert("solana_rpc".to_string(), rpc_ready);
-    if !rpc_ready {
-        all_ready = false;
-    }
+    // Slow responses are degraded but still ready; only hard failures are critical
+    let all_ready = checks.values().all(|c| c.status != "unhealthy");
 
     let status_code = if all_ready {
         StatusCode::OK
@@ -250,6 +242,30 @@ async fn check_solana_rpc(state: &AppState) -> ComponentHealth {
     }
 }
 
+/// Check that the authority keypair can still pay transaction fees
+async fn check_authority_balance(state: &AppState) -> ComponentHealth {
+    let Some(authority) = state.solana.authority_pubkey().await else {
+        return ComponentHealth::degraded("No authority keypair configured");
+    };
+
+    match state.solana.get_balance(&authority).await {
+        Ok(balance) => authority_balance_health(balance, state.config.min_authority_balance),
+        Err(e) => ComponentHealth::unhealthy(format!("Failed to fetch authority balance: {}", e)),
+    }
+}
+
+/// Classify an authority balance (lamports) against the configured minimum
+pub fn authority_balance_health(balance: u64, min_balance: u64) -> ComponentHealth {
+    if balance < min_balance {
+        ComponentHealth::unhealthy(format!(
+            "Authority balance {} lamports is below minimum {}",
+            balance, min_balance
+        ))
+    } else {
+        ComponentHealth::healthy()
+    }
+}
+
 /// Get memory usage statistics
 fn get_memory_stats() -> MemoryHealth {
     // Use a simple approach that works cross-platform
@@ -1,12 +1,54 @@
 use axum::{extract::State, http::StatusCode, response::IntoResponse};
-use prometheus::{Encoder, TextEncoder};
+use once_cell::sync::Lazy;
+use prometheus::{
+    register_histogram_vec, register_int_counter_vec, Encoder, HistogramVec, IntCounterVec,
+    TextEncoder,
+};
+use std::time::Duration;
 use crate::AppState;
 
+/// Transactions sent through `SolanaService`, by operation and result
+static TX_TOTAL: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "sss_tx_total",
+        "Transactions sent and confirmed, by operation and result",
+        &["operation", "result"]
+    )
+    .expect("sss_tx_total registers once")
+});
+
+/// Send-to-confirmation latency of successful transactions, by operation
+static TX_CONFIRMATION_SECONDS: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "sss_tx_confirmation_seconds",
+        "Time from send to confirmation of successful transactions",
+        &["operation"],
+        vec![0.25, 0.5, 1.0, 2.0, 5.0, 10.0, 20.0, 30.0, 60.0, 120.0]
+    )
+    .expect("sss_tx_confirmation_seconds registers once")
+});
+
+/// Record one send-and-confirm attempt. Latency is only observed for
+/// confirmed transactions, so timeouts don't skew the histogram.
+pub fn record_transaction(operation: &str, success: bool, elapsed: Duration) {
+    let result = if success { "success" } else { "failure" };
+    TX_TOTAL.with_label_values(&[operation, result]).inc();
+    if success {
+        TX_CONFIRMATION_SECONDS
+            .with_label_values(&[operation])
+            .observe(elapsed.as_secs_f64());
+    }
+}
+
 /// Prometheus metrics endpoint
 pub async fn handler(State(_state): State<AppState>) -> impl IntoResponse {
     let mut buffer = Vec::new();
     let encoder = TextEncoder::new();
     
+    // Register the transaction metrics even before the first transaction
+    Lazy::force(&TX_TOTAL);
+    Lazy::force(&TX_CONFIRMATION_SECONDS);
+    
     // Gather all registered metrics
     let metric_families = prometheus::gather();
     
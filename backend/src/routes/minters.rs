@@ -13,33 +13,21 @@ use validator::Validate;
 use crate::{
     error::{ApiError, ApiResult},
     models::{AddMinterRequest, MinterQuota, SetQuotaRequest, User},
-    app_middleware::auth::AuthUser,
+    app_middleware::{auth::AuthUser, request_id::RequestId},
     utils::audit,
     AppState,
 };
 
-/// Helper function to convert validation errors to API error
-fn validation_error_to_api_error(e: validator::ValidationErrors) -> ApiError {
-    let error_messages: Vec<String> = e.field_errors()
-        .into_iter()
-        .flat_map(|(field, errors)| {
-            errors.iter().map(move |err| {
-                format!("{}: {}", field, err.message.as_ref().map(|m| m.as_ref()).unwrap_or("invalid"))
-            })
-        })
-        .collect();
-    ApiError::Validation(error_messages.join("; "))
-}
-
 /// Add a minter with optional quota
 pub async fn add(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Path(id): Path<Uuid>,
     Json(req): Json<AddMinterRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Validate input using validator crate
-    req.validate().map_err(validation_error_to_api_error)?;
+    req.validate()?;
     
     // Parse and validate minter pubkey (additional validation)
     let minter_pubkey: Pubkey = req.account.parse()
@@ -82,6 +70,7 @@ pub async fn add(
         None,
         Some(json!({"minter": req.account, "quota": quota, "pda": minter_pda.to_string()})),
         None,
+        Some(&request_id),
     ).await;
     
     Ok((StatusCode::CREATED, Json(minter)))
@@ -90,6 +79,7 @@ pub async fn add(
 /// Remove a minter
 pub async fn remove(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Path((id, account)): Path<(Uuid, String)>,
 ) -> ApiResult<impl IntoResponse> {
@@ -119,6 +109,7 @@ pub async fn remove(
         None,
         Some(json!({"minter": account})),
         None,
+        Some(&request_id),
     ).await;
     
     Ok(StatusCode::NO_CONTENT)
@@ -147,12 +138,13 @@ pub async fn list(
 /// Set or update minter quota
 pub async fn set_quota(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Path((id, account)): Path<(Uuid, String)>,
     Json(req): Json<SetQuotaRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Validate input using validator crate
-    req.validate().map_err(validation_error_to_api_error)?;
+    req.validate()?;
     
     // Get stablecoin and check ownership
     let _stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
@@ -183,6 +175,7 @@ pub async fn set_quota(
         None,
         Some(json!({"minter": account, "quota": req.quota})),
         None,
+        Some(&request_id),
     ).await;
     
     Ok(Json(minter))
@@ -201,7 +194,7 @@ async fn get_stablecoin_for_admin(
     .fetch_optional(state.db.pool())
     .await
     .map_err(|e| ApiError::Database(e.to_string()))?
-    .ok_or_else(|| ApiError::NotFound("Stablecoin not found".to_string()))?;
+    .ok_or(ApiError::StablecoinNotFound)?;
     
     // Check ownership or admin role
     if stablecoin.owner_id != user.id && user.role != "admin" {
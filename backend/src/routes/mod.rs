@@ -11,6 +11,10 @@ pub mod users;
 pub mod compliance;
 pub mod webhooks;
 pub mod proofs;
+pub mod events;
+pub mod api_keys;
+pub mod transactions;
+pub mod csrf;
 
 // Re-export health handlers for convenience
 pub use health::{handler as health_handler, detailed_handler as health_detail_handler};
@@ -1,138 +1,206 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
 };
+use serde::Deserialize;
 use serde_json::json;
-use solana_sdk::pubkey::Pubkey;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::Signature};
 use sqlx::query_as;
+use std::time::Instant;
 use uuid::Uuid;
 use validator::Validate;
 
 use crate::{
     error::{ApiError, ApiResult},
-    models::{BurnRequest, MintRequest, TransactionResponse, TransferRequest, User},
-    app_middleware::auth::AuthUser,
-    solana::explorer_url,
+    models::{
+        BurnRequest, EstimateRequest, FeeEstimateResponse, MintRequest, TransactionResponse,
+        TransferRequest,
+    },
+    app_middleware::{auth::AuthUser, request_id::RequestId},
+    services::mint_burn,
+    solana::{build_transfer_instructions, explorer_url, Role, TxStatus},
     AppState,
 };
 
-/// Helper function to convert validation errors to API error
-fn validation_error_to_api_error(e: validator::ValidationErrors) -> ApiError {
-    let error_messages: Vec<String> = e.field_errors()
-        .into_iter()
-        .flat_map(|(field, errors)| {
-            errors.iter().map(move |err| {
-                format!("{}: {}", field, err.message.as_ref().map(|m| m.as_ref()).unwrap_or("invalid"))
-            })
-        })
-        .collect();
-    ApiError::Validation(error_messages.join("; "))
+/// `?wait=true` blocks mint and burn until the transaction is confirmed
+#[derive(Debug, Default, Deserialize)]
+pub struct WaitQuery {
+    #[serde(default)]
+    pub wait: bool,
 }
 
 /// Mint tokens to a recipient
 pub async fn mint(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Path(id): Path<Uuid>,
+    Query(query): Query<WaitQuery>,
     Json(req): Json<MintRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Validate input using validator crate
-    req.validate().map_err(validation_error_to_api_error)?;
+    req.validate()?;
     
     // Parse and validate recipient pubkey (additional validation)
     let recipient: Pubkey = req.recipient.parse()
         .map_err(|_| ApiError::Validation("Invalid recipient pubkey".to_string()))?;
     
+    // A wallet passed as a token account would fail on-chain
+    if req.recipient_is_token_account {
+        require_token_account(&state, &recipient, "recipient").await?;
+    }
+    
     // Get stablecoin
     let stablecoin = get_stablecoin(&state, id).await?;
     
-    // Check if user has minter role
-    let has_role = check_role(&state, id, &user).await?;
-    if !has_role && user.role != "admin" {
-        return Err(ApiError::Forbidden("Not authorized to mint".to_string()));
-    }
-    
     // Parse stablecoin PDA
     let stablecoin_pda: Pubkey = stablecoin.stablecoin_pda.parse()
         .map_err(|_| ApiError::Internal("Invalid stablecoin PDA".to_string()))?;
     
-    // Build mint transaction
-    // In production, this would use Anchor client to build and send the transaction
-    let tx_signature = format!("mint_{}_{}_{}", id, recipient, req.amount);
+    // Check the caller holds the Minter role on-chain
+    state.roles.authorize_user(&stablecoin_pda, &user, Role::Minter).await?;
+    
+    // Screen the recipient for SSS-2 stablecoins
+    if stablecoin.requires_mint_screening() {
+        screen_mint_recipient(&state, id, &stablecoin_pda, &req.recipient, user.id, &request_id).await?;
+    }
+    
+    // Resolve the wallet to its associated token account (created on send if missing)
+    let asset_mint: Pubkey = stablecoin.asset_mint.parse()
+        .map_err(|_| ApiError::Internal("Invalid asset mint".to_string()))?;
+    let recipient_token_account = state.mint_burn.recipient_token_account(
+        &recipient,
+        &asset_mint,
+        req.recipient_is_token_account,
+    );
+    
+    // Send the mint transaction
+    let sent_at = Instant::now();
+    let result = state.mint_burn.process_mint_request(
+        &stablecoin_pda,
+        mint_burn::MintRequest {
+            recipient: req.recipient.clone(),
+            amount: req.amount,
+            fiat_proof: None,
+            recipient_is_token_account: req.recipient_is_token_account,
+        },
+        query.wait,
+    ).await.map_err(ApiError::solana)?;
     
     // Log audit
     let _ = state.db.log_audit(
         Some(id),
         Some(user.id),
         "stablecoin.mint",
-        Some(&tx_signature),
-        Some(json!({"recipient": req.recipient, "amount": req.amount})),
+        Some(&result.signature),
+        Some(json!({
+            "recipient": req.recipient,
+            "recipient_token_account": recipient_token_account.to_string(),
+            "amount": req.amount,
+            "status": result.status.as_str(),
+        })),
         None,
+        Some(&request_id),
     ).await;
     
+    if result.status == TxStatus::Pending {
+        spawn_confirmation(&state, "mint", &result.signature, sent_at);
+    }
+    
     Ok(Json(TransactionResponse {
-        tx_signature: tx_signature.clone(),
-        status: "pending".to_string(),
-        explorer_url: explorer_url(&tx_signature, "devnet"),
+        tx_signature: result.signature,
+        status: result.status.as_str().to_string(),
+        explorer_url: result.explorer_url,
     }))
 }
 
 /// Burn tokens from an account
 pub async fn burn(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Path(id): Path<Uuid>,
+    Query(query): Query<WaitQuery>,
     Json(req): Json<BurnRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Validate input using validator crate
-    req.validate().map_err(validation_error_to_api_error)?;
+    req.validate()?;
+    
+    if let Some(from_account) = &req.from_account {
+        let from_account: Pubkey = from_account.parse()
+            .map_err(|_| ApiError::Validation("Invalid from_account pubkey".to_string()))?;
+        require_token_account(&state, &from_account, "from_account").await?;
+    }
     
     // Get stablecoin
-    let _stablecoin = get_stablecoin(&state, id).await?;
+    let stablecoin = get_stablecoin(&state, id).await?;
+    
+    // Parse stablecoin PDA
+    let stablecoin_pda: Pubkey = stablecoin.stablecoin_pda.parse()
+        .map_err(|_| ApiError::Internal("Invalid stablecoin PDA".to_string()))?;
+    
+    // Check the caller holds the Burner role on-chain
+    state.roles.authorize_user(&stablecoin_pda, &user, Role::Burner).await?;
     
-    // Build burn transaction
-    let tx_signature = format!("burn_{}_{}", id, req.amount);
+    // Send the burn transaction
+    let sent_at = Instant::now();
+    let result = state.mint_burn.process_burn_request(
+        &stablecoin_pda,
+        mint_burn::BurnRequest {
+            amount: req.amount,
+            bank_account: None,
+            from_token_account: req.from_account.clone(),
+        },
+        query.wait,
+    ).await.map_err(ApiError::solana)?;
     
     // Log audit
     let _ = state.db.log_audit(
         Some(id),
         Some(user.id),
         "stablecoin.burn",
-        Some(&tx_signature),
-        Some(json!({"amount": req.amount, "from_account": req.from_account})),
+        Some(&result.signature),
+        Some(json!({
+            "amount": req.amount,
+            "from_account": req.from_account,
+            "status": result.status.as_str(),
+        })),
         None,
+        Some(&request_id),
     ).await;
     
+    if result.status == TxStatus::Pending {
+        spawn_confirmation(&state, "burn", &result.signature, sent_at);
+    }
+    
     Ok(Json(TransactionResponse {
-        tx_signature: tx_signature.clone(),
-        status: "pending".to_string(),
-        explorer_url: explorer_url(&tx_signature, "devnet"),
+        tx_signature: result.signature,
+        status: result.status.as_str().to_string(),
+        explorer_url: result.explorer_url,
     }))
 }
 
 /// Transfer tokens between accounts
 pub async fn transfer(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Path(id): Path<Uuid>,
     Json(req): Json<TransferRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Validate input using validator crate
-    req.validate().map_err(validation_error_to_api_error)?;
-    
-    // Parse and validate pubkeys (additional validation)
-    let _from: Pubkey = req.from.parse()
-        .map_err(|_| ApiError::Validation("Invalid from pubkey".to_string()))?;
-    let _to: Pubkey = req.to.parse()
-        .map_err(|_| ApiError::Validation("Invalid to pubkey".to_string()))?;
+    req.validate()?;
     
     // Get stablecoin
-    let _stablecoin = get_stablecoin(&state, id).await?;
+    let stablecoin = get_stablecoin(&state, id).await?;
     
-    // Build transfer transaction
-    let tx_signature = format!("transfer_{}_{}_{}", id, &req.from[..8], &req.to[..8]);
+    let instructions = transfer_instructions(&state, &stablecoin, &req).await?;
+    let sent_at = Instant::now();
+    let tx_signature = state.solana.build_and_send_instruction_unconfirmed(instructions, &[]).await
+        .map_err(ApiError::solana)?
+        .to_string();
     
     // Log audit
     let _ = state.db.log_audit(
@@ -140,18 +208,181 @@ pub async fn transfer(
         Some(user.id),
         "stablecoin.transfer",
         Some(&tx_signature),
-        Some(json!({"from": req.from, "to": req.to, "amount": req.amount})),
+        Some(json!({"from": req.from, "to": req.to, "amount": req.amount, "memo": req.memo})),
         None,
+        Some(&request_id),
     ).await;
     
+    spawn_confirmation(&state, "transfer", &tx_signature, sent_at);
+    
     Ok(Json(TransactionResponse {
         tx_signature: tx_signature.clone(),
-        status: "pending".to_string(),
+        status: TxStatus::Pending.as_str().to_string(),
         explorer_url: explorer_url(&tx_signature, "devnet"),
     }))
 }
 
+/// Estimate the SOL cost of a mint, burn or transfer without sending it.
+/// Checks the parameters and the caller's role the way the operation's own
+/// endpoint does, then simulates the transaction it would send.
+pub async fn estimate(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+    Json(req): Json<EstimateRequest>,
+) -> ApiResult<impl IntoResponse> {
+    req.validate()?;
+    
+    let stablecoin = get_stablecoin(&state, id).await?;
+    let stablecoin_pda: Pubkey = stablecoin.stablecoin_pda.parse()
+        .map_err(|_| ApiError::Internal("Invalid stablecoin PDA".to_string()))?;
+    
+    let instructions = match &req {
+        EstimateRequest::Mint(mint) => {
+            let recipient: Pubkey = mint.recipient.parse()
+                .map_err(|_| ApiError::Validation("Invalid recipient pubkey".to_string()))?;
+            if mint.recipient_is_token_account {
+                require_token_account(&state, &recipient, "recipient").await?;
+            }
+            state.roles.authorize_user(&stablecoin_pda, &user, Role::Minter).await?;
+            state.mint_burn.build_mint_instructions(
+                &stablecoin_pda,
+                &mint_burn::MintRequest {
+                    recipient: mint.recipient.clone(),
+                    amount: mint.amount,
+                    fiat_proof: None,
+                    recipient_is_token_account: mint.recipient_is_token_account,
+                },
+            ).await.map_err(ApiError::solana)?
+        }
+        EstimateRequest::Burn(burn) => {
+            if let Some(from_account) = &burn.from_account {
+                let from_account: Pubkey = from_account.parse()
+                    .map_err(|_| ApiError::Validation("Invalid from_account pubkey".to_string()))?;
+                require_token_account(&state, &from_account, "from_account").await?;
+            }
+            state.roles.authorize_user(&stablecoin_pda, &user, Role::Burner).await?;
+            vec![state.mint_burn.build_burn_instruction(
+                &stablecoin_pda,
+                &mint_burn::BurnRequest {
+                    amount: burn.amount,
+                    bank_account: None,
+                    from_token_account: burn.from_account.clone(),
+                },
+            ).await.map_err(ApiError::solana)?]
+        }
+        EstimateRequest::Transfer(transfer) => transfer_instructions(&state, &stablecoin, transfer).await?,
+    };
+    
+    let estimate = state.solana.estimate_fee(instructions).await
+        .map_err(ApiError::solana)?;
+    
+    Ok(Json(FeeEstimateResponse {
+        operation: req.name(),
+        estimate,
+    }))
+}
+
 // Helper functions
+/// Check a transfer against the source balance and build its instructions,
+/// with the memo instruction ahead of the transfer
+async fn transfer_instructions(
+    state: &AppState,
+    stablecoin: &crate::models::Stablecoin,
+    req: &TransferRequest,
+) -> ApiResult<Vec<Instruction>> {
+    // Parse and validate pubkeys (additional validation)
+    let from: Pubkey = req.from.parse()
+        .map_err(|_| ApiError::Validation("Invalid from pubkey".to_string()))?;
+    let to: Pubkey = req.to.parse()
+        .map_err(|_| ApiError::Validation("Invalid to pubkey".to_string()))?;
+    let asset_mint: Pubkey = stablecoin.asset_mint.parse()
+        .map_err(|_| ApiError::Internal("Invalid asset mint".to_string()))?;
+    
+    // The backend authority owns and signs for the source account
+    let owner = state.solana.authority_pubkey().await
+        .ok_or_else(|| ApiError::Solana("No authority keypair set".to_string()))?;
+    state.solana.require_token_balance(&from, req.amount).await
+        .map_err(ApiError::solana)?;
+    let token_program = state.solana.rpc_client()
+        .get_account(&asset_mint)
+        .map_err(|e| ApiError::Solana(e.to_string()))?
+        .owner;
+    
+    Ok(build_transfer_instructions(
+        &from,
+        &to,
+        &asset_mint,
+        &owner,
+        req.amount,
+        stablecoin.decimals as u8,
+        &token_program,
+        req.memo.as_deref(),
+    ))
+}
+
+/// Confirm a pending transaction in the background and record the outcome
+/// on its audit rows
+fn spawn_confirmation(state: &AppState, operation: &'static str, signature: &str, sent_at: Instant) {
+    let Ok(parsed) = signature.parse::<Signature>() else {
+        return;
+    };
+    let solana = state.solana.clone();
+    let db = state.db.clone();
+    let signature = signature.to_string();
+    tokio::spawn(async move {
+        let status = solana.await_confirmation(operation, &parsed, sent_at).await;
+        if let Err(e) = db.update_audit_tx_status(&signature, status.as_str()).await {
+            tracing::warn!("Failed to record status of {}: {}", signature, e);
+        }
+    });
+}
+
+/// Screen a mint recipient, rejecting blocked addresses and flagging ones that need review
+async fn screen_mint_recipient(
+    state: &AppState,
+    id: Uuid,
+    stablecoin_pda: &Pubkey,
+    recipient: &str,
+    user_id: Uuid,
+    request_id: &str,
+) -> ApiResult<()> {
+    let result = state.compliance.screen_address(recipient, stablecoin_pda).await
+        .map_err(|e| ApiError::Solana(format!("Compliance screening failed: {}", e)))?;
+    
+    let recommendation = result.recommendation.as_str();
+    if recommendation == "block" || recommendation == "review" {
+        let _ = state.db.log_audit(
+            Some(id),
+            Some(user_id),
+            "compliance_alert",
+            None,
+            Some(json!({
+                "operation": "mint",
+                "recipient": recipient,
+                "risk_score": result.risk_score,
+                "recommendation": recommendation,
+            })),
+            None,
+            Some(request_id),
+        ).await;
+    }
+    
+    if recommendation == "block" {
+        return Err(ApiError::Forbidden("Mint recipient failed compliance screening".to_string()));
+    }
+    
+    Ok(())
+}
+
+/// Reject `account` unless it is owned by a token program
+async fn require_token_account(state: &AppState, account: &Pubkey, field: &str) -> ApiResult<()> {
+    if !state.solana.is_token_account(account).await.map_err(ApiError::solana)? {
+        return Err(ApiError::Validation(format!("{} is not a token account", field)));
+    }
+    Ok(())
+}
+
 async fn get_stablecoin(state: &AppState, id: Uuid) -> ApiResult<crate::models::Stablecoin> {
     query_as(
         "SELECT * FROM stablecoins WHERE id = $1 AND is_active = true"
@@ -160,23 +391,5 @@ async fn get_stablecoin(state: &AppState, id: Uuid) -> ApiResult<crate::models::
     .fetch_optional(state.db.pool())
     .await
     .map_err(|e| ApiError::Database(e.to_string()))?
-    .ok_or_else(|| ApiError::NotFound("Stablecoin not found".to_string()))
-}
-
-async fn check_role(state: &AppState, stablecoin_id: Uuid, user: &User) -> ApiResult<bool> {
-    let pubkey = match user.solana_pubkey.as_deref() {
-        Some(pk) => pk,
-        None => return Ok(false),
-    };
-    
-    let role: Option<crate::models::RoleAssignment> = query_as(
-        "SELECT * FROM role_assignments WHERE stablecoin_id = $1 AND account_pubkey = $2 AND role = 'minter'"
-    )
-    .bind(stablecoin_id)
-    .bind(pubkey)
-    .fetch_optional(state.db.pool())
-    .await
-    .map_err(|e| ApiError::Database(e.to_string()))?;
-    
-    Ok(role.is_some())
+    .ok_or(ApiError::StablecoinNotFound)
 }
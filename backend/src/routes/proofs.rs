@@ -1,6 +1,91 @@
-use axum::{Json, http::StatusCode};
-use serde_json::json;
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use sqlx::query_as;
+use uuid::Uuid;
 
-pub async fn handler() -> (StatusCode, Json<serde_json::Value>) {
-    (StatusCode::OK, Json(json!({"proofs": []})))
+use crate::{
+    error::{ApiError, ApiResult},
+    models::{ReserveProof, Stablecoin},
+    routes::stablecoin::fetch_onchain_state,
+    AppState,
+};
+
+/// Domain prefix of the signed reserve message, so the signature can't be
+/// replayed as any other message the backend key signs
+pub const RESERVE_PROOF_DOMAIN: &str = "sss-proof-of-reserves:v1";
+
+/// Collateral / supply in basis points, rounded down; `None` while supply is zero
+pub fn reserve_ratio_bps(collateral: u64, supply: u64) -> Option<u64> {
+    if supply == 0 {
+        return None;
+    }
+    let ratio = collateral as u128 * 10_000 / supply as u128;
+    Some(ratio.min(u64::MAX as u128) as u64)
+}
+
+/// Message the backend signs:
+/// `<domain>|<stablecoin_pda>|<collateral>|<supply>|<attested_at>|<timestamp>`,
+/// timestamps as Unix seconds
+pub fn reserve_proof_message(
+    stablecoin_pda: &str,
+    collateral: u64,
+    supply: u64,
+    attested_at: DateTime<Utc>,
+    timestamp: DateTime<Utc>,
+) -> String {
+    format!(
+        "{}|{}|{}|{}|{}|{}",
+        RESERVE_PROOF_DOMAIN,
+        stablecoin_pda,
+        collateral,
+        supply,
+        attested_at.timestamp(),
+        timestamp.timestamp(),
+    )
+}
+
+/// Proof of reserves: the attested collateral against the on-chain supply,
+/// signed by the backend authority key
+pub async fn reserves(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<impl IntoResponse> {
+    let stablecoin: Stablecoin = query_as(
+        "SELECT * FROM stablecoins WHERE id = $1 AND is_active = true"
+    )
+    .bind(id)
+    .fetch_optional(state.db.pool())
+    .await
+    .map_err(|e| ApiError::Database(e.to_string()))?
+    .ok_or(ApiError::StablecoinNotFound)?;
+    
+    let (Some(collateral), Some(attested_at)) = (stablecoin.reserve_amount, stablecoin.reserve_attested_at) else {
+        return Err(ApiError::NotFound("No reserve attestation for this stablecoin".to_string()));
+    };
+    let collateral = collateral as u64;
+    
+    let supply = fetch_onchain_state(&state, &stablecoin)
+        .ok_or_else(|| ApiError::Solana("Failed to read on-chain stablecoin state".to_string()))?
+        .total_supply;
+    
+    let timestamp = Utc::now();
+    let message = reserve_proof_message(&stablecoin.stablecoin_pda, collateral, supply, attested_at, timestamp);
+    let (signer, signature) = state.solana.sign_message(message.as_bytes()).await
+        .ok_or_else(|| ApiError::ServiceUnavailable("No signing key configured".to_string()))?;
+    
+    Ok(Json(ReserveProof {
+        stablecoin_pda: stablecoin.stablecoin_pda,
+        collateral,
+        supply,
+        ratio_bps: reserve_ratio_bps(collateral, supply),
+        attested_at,
+        timestamp,
+        message,
+        signer: signer.to_string(),
+        signature: signature.to_string(),
+    }))
 }
@@ -13,33 +13,21 @@ use validator::Validate;
 use crate::{
     error::{ApiError, ApiResult},
     models::{AssignRoleRequest, RoleAssignment, User},
-    app_middleware::auth::AuthUser,
+    app_middleware::{auth::AuthUser, request_id::RequestId},
     utils::audit,
     AppState,
 };
 
-/// Helper function to convert validation errors to API error
-fn validation_error_to_api_error(e: validator::ValidationErrors) -> ApiError {
-    let error_messages: Vec<String> = e.field_errors()
-        .into_iter()
-        .flat_map(|(field, errors)| {
-            errors.iter().map(move |err| {
-                format!("{}: {}", field, err.message.as_ref().map(|m| m.as_ref()).unwrap_or("invalid"))
-            })
-        })
-        .collect();
-    ApiError::Validation(error_messages.join("; "))
-}
-
 /// Assign a role to an account
 pub async fn assign(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Path(id): Path<Uuid>,
     Json(req): Json<AssignRoleRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Validate input using validator crate (includes role and pubkey validation)
-    req.validate().map_err(validation_error_to_api_error)?;
+    req.validate()?;
     
     // Parse and validate account pubkey (additional validation)
     let account_pubkey: Pubkey = req.account.parse()
@@ -77,6 +65,9 @@ pub async fn assign(
     .await
     .map_err(|e| ApiError::Database(e.to_string()))?;
     
+    // Drop any cached on-chain role lookup for this account
+    state.roles.invalidate(&stablecoin_pda, &account_pubkey).await;
+    
     // Log audit
     audit(
         &state.db,
@@ -86,6 +77,7 @@ pub async fn assign(
         None,
         Some(json!({"account": req.account, "role": req.role, "pda": role_pda.to_string()})),
         None,
+        Some(&request_id),
     ).await;
     
     Ok((StatusCode::CREATED, Json(role_assignment)))
@@ -94,11 +86,12 @@ pub async fn assign(
 /// Revoke a role from an account
 pub async fn revoke(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Path((id, account)): Path<(Uuid, String)>,
 ) -> ApiResult<impl IntoResponse> {
     // Get stablecoin and check ownership
-    let _stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
+    let stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
     
     // Delete role assignment
     let result = sqlx::query(
@@ -114,6 +107,13 @@ pub async fn revoke(
         return Err(ApiError::NotFound("Role assignment not found".to_string()));
     }
     
+    // Drop any cached on-chain role lookup for this account
+    if let (Ok(stablecoin_pda), Ok(account_pubkey)) =
+        (stablecoin.stablecoin_pda.parse::<Pubkey>(), account.parse::<Pubkey>())
+    {
+        state.roles.invalidate(&stablecoin_pda, &account_pubkey).await;
+    }
+    
     // Log audit
     audit(
         &state.db,
@@ -123,27 +123,27 @@ pub async fn revoke(
         None,
         Some(json!({"account": account})),
         None,
+        Some(&request_id),
     ).await;
     
     Ok(StatusCode::NO_CONTENT)
 }
 
-/// List all role assignments for a stablecoin
+/// List all on-chain role assignments for a stablecoin
 pub async fn list(
     State(state): State<AppState>,
     AuthUser(user): AuthUser,
     Path(id): Path<Uuid>,
 ) -> ApiResult<impl IntoResponse> {
     // Get stablecoin and check ownership
-    let _stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
+    let stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
     
-    let roles: Vec<RoleAssignment> = query_as(
-        "SELECT * FROM role_assignments WHERE stablecoin_id = $1 ORDER BY created_at DESC"
-    )
-    .bind(id)
-    .fetch_all(state.db.pool())
-    .await
-    .map_err(|e| ApiError::Database(e.to_string()))?;
+    let stablecoin_pda: Pubkey = stablecoin.stablecoin_pda.parse()
+        .map_err(|_| ApiError::Internal("Invalid stablecoin PDA".to_string()))?;
+    
+    // Read the indexed snapshot; only the first listing scans the program
+    let roles = state.events.role_assignments(&state.db, &state.solana, id, &stablecoin_pda).await
+        .map_err(ApiError::solana)?;
     
     Ok(Json(roles))
 }
@@ -161,7 +161,7 @@ async fn get_stablecoin_for_admin(
     .fetch_optional(state.db.pool())
     .await
     .map_err(|e| ApiError::Database(e.to_string()))?
-    .ok_or_else(|| ApiError::NotFound("Stablecoin not found".to_string()))?;
+    .ok_or(ApiError::StablecoinNotFound)?;
     
     // Check ownership or admin role
     if stablecoin.owner_id != user.id && user.role != "admin" {
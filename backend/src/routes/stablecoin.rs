@@ -1,48 +1,110 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
 };
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
 use serde_json::json;
+use anchor_lang::AnchorDeserialize;
 use solana_sdk::{
     pubkey::Pubkey,
     signature::{Keypair, Signer},
 };
-use sqlx::query_as;
+use sqlx::{query_as, query_scalar};
 use uuid::Uuid;
 use validator::Validate;
 
 use crate::{
     error::{ApiError, ApiResult},
     models::{
-        CreateStablecoinRequest, Stablecoin, StablecoinStatus, UpdateStablecoinRequest,
+        CreateStablecoinRequest, PaginatedResponse, Pagination, PauseDetails,
+        ReconciliationReport, Stablecoin, StablecoinSnapshot, StablecoinStatus,
+        SupplyHistory, SupplyHistoryPoint, SupplyInterval, UpdateStablecoinRequest,
     },
-    app_middleware::auth::AuthUser,
+    solana::StablecoinStateAccount,
+    app_middleware::{auth::AuthUser, request_id::RequestId},
     AppState,
 };
+use sss_token::math::format_token_amount;
 
-/// Helper function to convert validation errors to API error
-fn validation_error_to_api_error(e: validator::ValidationErrors) -> ApiError {
-    let error_messages: Vec<String> = e.field_errors()
-        .into_iter()
-        .flat_map(|(field, errors)| {
-            errors.iter().map(move |err| {
-                format!("{}: {}", field, err.message.as_ref().map(|m| m.as_ref()).unwrap_or("invalid"))
-            })
-        })
-        .collect();
-    ApiError::Validation(error_messages.join("; "))
+/// Default page size for stablecoin listings
+pub const DEFAULT_STABLECOIN_PAGE_SIZE: i64 = 20;
+
+#[derive(Debug, Default, Deserialize, Validate)]
+pub struct ListStablecoinsQuery {
+    #[validate(range(min = 1, max = 100, message = "limit must be between 1 and 100"))]
+    pub limit: Option<i64>,
+    #[validate(range(min = 0, message = "offset must not be negative"))]
+    pub offset: Option<i64>,
+    pub sort: Option<StablecoinSort>,
+}
+
+/// Sort order for stablecoin listings; a leading `-` sorts descending
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum StablecoinSort {
+    #[serde(rename = "created_at")]
+    CreatedAt,
+    #[default]
+    #[serde(rename = "-created_at")]
+    CreatedAtDesc,
+    #[serde(rename = "name")]
+    Name,
+    #[serde(rename = "-name")]
+    NameDesc,
+    #[serde(rename = "symbol")]
+    Symbol,
+    #[serde(rename = "-symbol")]
+    SymbolDesc,
+}
+
+impl StablecoinSort {
+    /// `ORDER BY` clause, with `id` as a tie-breaker so pages don't overlap
+    pub fn order_by(self) -> &'static str {
+        match self {
+            StablecoinSort::CreatedAt => "created_at ASC, id ASC",
+            StablecoinSort::CreatedAtDesc => "created_at DESC, id DESC",
+            StablecoinSort::Name => "name ASC, id ASC",
+            StablecoinSort::NameDesc => "name DESC, id DESC",
+            StablecoinSort::Symbol => "symbol ASC, id ASC",
+            StablecoinSort::SymbolDesc => "symbol DESC, id DESC",
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct SupplyHistoryQuery {
+    /// Inclusive lower bound on the bucket start
+    pub from: Option<DateTime<Utc>>,
+    /// Exclusive upper bound on `created_at`
+    pub to: Option<DateTime<Utc>>,
+    /// `hour`, `day` or `week` (default `day`)
+    #[serde(default)]
+    pub interval: SupplyInterval,
+}
+
+impl SupplyHistoryQuery {
+    /// Validate the `from`/`to` ordering
+    pub fn check(&self) -> ApiResult<()> {
+        if let (Some(from), Some(to)) = (self.from, self.to) {
+            if from > to {
+                return Err(ApiError::Validation("from must be before to".to_string()));
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Create a new stablecoin
 pub async fn create(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Json(req): Json<CreateStablecoinRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Validate input using validator crate
-    req.validate().map_err(validation_error_to_api_error)?;
+    req.validate()?;
     
     // Parse and validate asset mint (additional validation beyond base58 format)
     let asset_mint: Pubkey = req.asset_mint.parse()
@@ -73,9 +135,9 @@ pub async fn create(
         r#"
         INSERT INTO stablecoins (
             owner_id, name, symbol, decimals, preset, asset_mint, 
-            stablecoin_pda, authority_pubkey
+            stablecoin_pda, authority_pubkey, max_supply
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
         RETURNING *
         "#
     )
@@ -87,6 +149,7 @@ pub async fn create(
     .bind(&req.asset_mint)
     .bind(stablecoin_pda.to_string())
     .bind(&authority_pubkey)
+    .bind(req.max_supply.unwrap_or(0) as i64)
     .fetch_one(state.db.pool())
     .await
     .map_err(|e| ApiError::Database(e.to_string()))?;
@@ -97,8 +160,9 @@ pub async fn create(
         Some(user.id),
         "stablecoin.create",
         None,
-        Some(json!({"name": req.name, "symbol": req.symbol, "preset": req.preset})),
+        Some(json!({"name": req.name, "symbol": req.symbol, "preset": req.preset, "max_supply": req.max_supply})),
         None,
+        Some(&request_id),
     ).await;
     
     Ok((StatusCode::CREATED, Json(stablecoin)))
@@ -116,7 +180,7 @@ pub async fn get(
     .fetch_optional(state.db.pool())
     .await
     .map_err(|e| ApiError::Database(e.to_string()))?
-    .ok_or_else(|| ApiError::NotFound("Stablecoin not found".to_string()))?;
+    .ok_or(ApiError::StablecoinNotFound)?;
     
     Ok(Json(stablecoin))
 }
@@ -129,7 +193,7 @@ pub async fn update(
     Json(req): Json<UpdateStablecoinRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Validate input using validator crate
-    req.validate().map_err(validation_error_to_api_error)?;
+    req.validate()?;
     
     // Check ownership
     let existing: Stablecoin = query_as(
@@ -139,25 +203,45 @@ pub async fn update(
     .fetch_optional(state.db.pool())
     .await
     .map_err(|e| ApiError::Database(e.to_string()))?
-    .ok_or_else(|| ApiError::NotFound("Stablecoin not found".to_string()))?;
+    .ok_or(ApiError::StablecoinNotFound)?;
     
     if existing.owner_id != user.id && user.role != "admin" {
         return Err(ApiError::Forbidden("Not authorized to update this stablecoin".to_string()));
     }
     
+    // A cap below the outstanding supply would be rejected on-chain
+    if let Some(max_supply) = req.max_supply.filter(|max_supply| *max_supply > 0) {
+        let total_supply = fetch_onchain_state(&state, &existing)
+            .map(|onchain| onchain.total_supply)
+            .unwrap_or(0);
+        if max_supply < total_supply {
+            return Err(ApiError::BadRequest(format!(
+                "max_supply {} is below the current total supply {}",
+                max_supply, total_supply
+            )));
+        }
+    }
+    
     // Update
     let stablecoin: Stablecoin = query_as(
         r#"
         UPDATE stablecoins
         SET name = COALESCE($1, name),
             is_active = COALESCE($2, is_active),
+            screen_on_mint = COALESCE($3, screen_on_mint),
+            max_supply = COALESCE($4, max_supply),
+            reserve_amount = COALESCE($5, reserve_amount),
+            reserve_attested_at = CASE WHEN $5 IS NULL THEN reserve_attested_at ELSE NOW() END,
             updated_at = NOW()
-        WHERE id = $3
+        WHERE id = $6
         RETURNING *
         "#
     )
     .bind(&req.name)
     .bind(req.is_active)
+    .bind(req.screen_on_mint)
+    .bind(req.max_supply.map(|max_supply| max_supply as i64))
+    .bind(req.reserve_amount.map(|reserve_amount| reserve_amount as i64))
     .bind(id)
     .fetch_one(state.db.pool())
     .await
@@ -166,25 +250,46 @@ pub async fn update(
     Ok(Json(stablecoin))
 }
 
-/// List all stablecoins for user
+/// List stablecoins for user
+///
+/// Supports `?limit=&offset=&sort=` and returns a paginated envelope.
 pub async fn list(
     State(state): State<AppState>,
     AuthUser(user): AuthUser,
+    Query(query): Query<ListStablecoinsQuery>,
 ) -> ApiResult<impl IntoResponse> {
-    let stablecoins: Vec<Stablecoin> = if user.role == "admin" {
-        query_as("SELECT * FROM stablecoins WHERE is_active = true ORDER BY created_at DESC")
-            .fetch_all(state.db.pool())
-            .await
-            .map_err(|e| ApiError::Database(e.to_string()))?
-    } else {
-        query_as("SELECT * FROM stablecoins WHERE owner_id = $1 AND is_active = true ORDER BY created_at DESC")
-            .bind(user.id)
-            .fetch_all(state.db.pool())
-            .await
-            .map_err(|e| ApiError::Database(e.to_string()))?
-    };
-    
-    Ok(Json(stablecoins))
+    query.validate()?;
+
+    let limit = query.limit.unwrap_or(DEFAULT_STABLECOIN_PAGE_SIZE);
+    let offset = query.offset.unwrap_or(0);
+    let sort = query.sort.unwrap_or_default();
+
+    // Admins see every stablecoin; the owner filter collapses to TRUE for them
+    let owner = (user.role != "admin").then_some(user.id);
+    let filter = "WHERE is_active = true AND ($1::uuid IS NULL OR owner_id = $1)";
+
+    let total: i64 = query_scalar(&format!("SELECT COUNT(*) FROM stablecoins {}", filter))
+        .bind(owner)
+        .fetch_one(state.db.pool())
+        .await
+        .map_err(|e| ApiError::Database(e.to_string()))?;
+
+    let stablecoins: Vec<Stablecoin> = query_as(&format!(
+        "SELECT * FROM stablecoins {} ORDER BY {} LIMIT $2 OFFSET $3",
+        filter,
+        sort.order_by()
+    ))
+    .bind(owner)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(state.db.pool())
+    .await
+    .map_err(|e| ApiError::Database(e.to_string()))?;
+
+    Ok(Json(PaginatedResponse {
+        data: stablecoins,
+        pagination: Pagination { total, limit, offset },
+    }))
 }
 
 /// Get stablecoin status (with on-chain data)
@@ -199,38 +304,224 @@ pub async fn status(
     .fetch_optional(state.db.pool())
     .await
     .map_err(|e| ApiError::Database(e.to_string()))?
-    .ok_or_else(|| ApiError::NotFound("Stablecoin not found".to_string()))?;
-    
-    // Parse stablecoin PDA
-    let stablecoin_pda: Pubkey = stablecoin.stablecoin_pda.parse()
-        .map_err(|_| ApiError::Internal("Invalid stablecoin PDA".to_string()))?;
+    .ok_or(ApiError::StablecoinNotFound)?;
     
     // Fetch on-chain state
-    let account_info = state.solana.rpc_client()
-        .get_account(&stablecoin_pda)
-        .ok();
-    
-    let (total_supply, paused, holder_count) = if let Some(account) = account_info {
-        // Parse on-chain state (simplified)
-        let data = &account.data;
-        let paused = data.len() > 0 && data[0] == 1;
-        let total_supply = if data.len() >= 12 {
-            u64::from_le_bytes(data[4..12].try_into().unwrap_or([0u8; 8]))
-        } else {
+    let onchain_state = fetch_onchain_state(&state, &stablecoin);
+    
+    let decimals = u8::try_from(stablecoin.decimals).unwrap_or(0);
+    let now = Utc::now().timestamp();
+    let holder_count = match (&onchain_state, stablecoin.asset_mint.parse::<Pubkey>()) {
+        (Some(_), Ok(mint)) => state.solana.count_token_holders(&mint).await.unwrap_or_else(|e| {
+            tracing::warn!("Failed to count holders of {}: {}", mint, e);
             0
-        };
-        (total_supply, paused, 0)
-    } else {
-        (0, false, 0)
+        }),
+        _ => 0,
     };
-    
-    let status = StablecoinStatus {
-        stablecoin,
-        total_supply,
-        paused,
-        compliance_enabled: false,
-        holder_count,
+    let status = match onchain_state {
+        Some(onchain) => StablecoinStatus {
+            total_supply_display: format_token_amount(onchain.total_supply, decimals),
+            stablecoin,
+            total_supply: onchain.total_supply,
+            paused: onchain.is_paused(now),
+            compliance_enabled: onchain.compliance_enabled,
+            holder_count,
+            blacklist_count: onchain.blacklist_count,
+            max_supply: onchain.max_supply,
+            min_mint_amount: onchain.min_mint_amount,
+            min_burn_amount: onchain.min_burn_amount,
+            pause: onchain.is_paused(now).then(|| PauseDetails {
+                paused_by: onchain.paused_by.to_string(),
+                paused_at: DateTime::from_timestamp(onchain.paused_at, 0),
+                reason: onchain.pause_reason(),
+                until: (onchain.pause_until != 0)
+                    .then(|| DateTime::from_timestamp(onchain.pause_until, 0))
+                    .flatten(),
+            }),
+        },
+        None => StablecoinStatus {
+            total_supply_display: format_token_amount(0, decimals),
+            stablecoin,
+            total_supply: 0,
+            paused: false,
+            compliance_enabled: false,
+            holder_count: 0,
+            blacklist_count: 0,
+            max_supply: 0,
+            min_mint_amount: 0,
+            min_burn_amount: 0,
+            pause: None,
+        },
     };
     
     Ok(Json(status))
 }
+
+/// Fetch and decode the on-chain state, or `None` if it is missing or unreadable
+pub(crate) fn fetch_onchain_state(state: &AppState, stablecoin: &Stablecoin) -> Option<StablecoinStateAccount> {
+    let stablecoin_pda: Pubkey = stablecoin.stablecoin_pda.parse().ok()?;
+    let account = state.solana.rpc_client()
+        .get_account(&stablecoin_pda)
+        .ok()?;
+    
+    // Skip the 8-byte Anchor discriminator before deserializing
+    if account.data.len() <= 8 {
+        return None;
+    }
+    StablecoinStateAccount::deserialize(&mut &account.data[8..]).ok()
+}
+
+/// Compare the database record with on-chain state (admin only, read-only).
+/// Supply and pause status are derived from the audit log, which is the
+/// only off-chain record of mints, burns and pauses.
+pub async fn reconcile(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> ApiResult<impl IntoResponse> {
+    if user.role != "admin" {
+        return Err(ApiError::Forbidden("Admin role required".to_string()));
+    }
+    
+    let stablecoin: Stablecoin = query_as(
+        "SELECT * FROM stablecoins WHERE id = $1"
+    )
+    .bind(id)
+    .fetch_optional(state.db.pool())
+    .await
+    .map_err(|e| ApiError::Database(e.to_string()))?
+    .ok_or(ApiError::StablecoinNotFound)?;
+    
+    let net_supply: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COALESCE(SUM(
+            CASE WHEN action = 'stablecoin.mint' THEN (details->>'amount')::BIGINT
+                 ELSE -(details->>'amount')::BIGINT END
+        ), 0)::BIGINT
+        FROM audit_log
+        WHERE stablecoin_id = $1 AND action IN ('stablecoin.mint', 'stablecoin.burn')
+        "#
+    )
+    .bind(id)
+    .fetch_one(state.db.pool())
+    .await
+    .map_err(|e| ApiError::Database(e.to_string()))?;
+    
+    let last_pause_action: Option<String> = sqlx::query_scalar(
+        "SELECT action FROM audit_log WHERE stablecoin_id = $1 AND action IN ('stablecoin.pause', 'stablecoin.unpause') ORDER BY created_at DESC LIMIT 1"
+    )
+    .bind(id)
+    .fetch_optional(state.db.pool())
+    .await
+    .map_err(|e| ApiError::Database(e.to_string()))?;
+    
+    let database = StablecoinSnapshot {
+        total_supply: u64::try_from(net_supply).unwrap_or(0),
+        paused: last_pause_action.as_deref() == Some("stablecoin.pause"),
+        preset: stablecoin.preset as u8,
+        compliance_enabled: stablecoin.preset >= 2,
+        authority: stablecoin.authority_pubkey.clone(),
+    };
+    
+    // Fetch on-chain state
+    let stablecoin_pda: Pubkey = stablecoin.stablecoin_pda.parse()
+        .map_err(|_| ApiError::Internal("Invalid stablecoin PDA".to_string()))?;
+    let data = state.solana.get_account_data(&stablecoin_pda).await
+        .map_err(ApiError::solana)?;
+    if data.len() < 8 {
+        return Err(ApiError::Solana("Stablecoin state account data too short".to_string()));
+    }
+    let onchain_state = StablecoinStateAccount::deserialize(&mut &data[8..])
+        .map_err(|e| ApiError::Solana(format!("Failed to deserialize stablecoin state: {}", e)))?;
+    
+    let onchain = StablecoinSnapshot {
+        total_supply: onchain_state.total_supply,
+        paused: onchain_state.paused,
+        preset: onchain_state.preset,
+        compliance_enabled: onchain_state.compliance_enabled,
+        authority: onchain_state.authority.to_string(),
+    };
+    
+    let mismatches = database.diff(&onchain);
+    if !mismatches.is_empty() {
+        tracing::warn!(
+            "Stablecoin {} drifted from on-chain state: {:?}",
+            id,
+            mismatches.iter().map(|m| m.field.as_str()).collect::<Vec<_>>()
+        );
+    }
+    
+    Ok(Json(ReconciliationReport {
+        stablecoin_id: id,
+        in_sync: mismatches.is_empty(),
+        database,
+        onchain,
+        mismatches,
+        checked_at: chrono::Utc::now(),
+    }))
+}
+
+/// Net supply change per time bucket, from the mints and burns in the audit log.
+///
+/// `supply` is a running total over all recorded history, so the series can be
+/// charted directly even when `from` cuts off earlier buckets.
+pub async fn supply_history(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+    Query(query): Query<SupplyHistoryQuery>,
+) -> ApiResult<impl IntoResponse> {
+    query.check()?;
+    
+    let stablecoin: Stablecoin = query_as(
+        "SELECT * FROM stablecoins WHERE id = $1"
+    )
+    .bind(id)
+    .fetch_optional(state.db.pool())
+    .await
+    .map_err(|e| ApiError::Database(e.to_string()))?
+    .ok_or(ApiError::StablecoinNotFound)?;
+    
+    if stablecoin.owner_id != user.id && user.role != "admin" {
+        return Err(ApiError::Forbidden("Not authorized to view supply history".to_string()));
+    }
+    
+    // The running total is computed before `from` is applied
+    let series: Vec<SupplyHistoryPoint> = query_as(
+        r#"
+        SELECT bucket, minted, burned, net_change, supply FROM (
+            SELECT bucket, minted, burned, minted - burned AS net_change,
+                   SUM(minted - burned) OVER (ORDER BY bucket)::BIGINT AS supply
+            FROM (
+                SELECT date_trunc($2, created_at) AS bucket,
+                       COALESCE(SUM((details->>'amount')::BIGINT)
+                           FILTER (WHERE action = 'stablecoin.mint'), 0)::BIGINT AS minted,
+                       COALESCE(SUM((details->>'amount')::BIGINT)
+                           FILTER (WHERE action = 'stablecoin.burn'), 0)::BIGINT AS burned
+                FROM audit_log
+                WHERE stablecoin_id = $1
+                  AND action IN ('stablecoin.mint', 'stablecoin.burn')
+                  AND ($4::timestamptz IS NULL OR created_at < $4)
+                GROUP BY 1
+            ) buckets
+        ) history
+        WHERE $3::timestamptz IS NULL OR bucket >= date_trunc($2, $3::timestamptz)
+        ORDER BY bucket
+        "#
+    )
+    .bind(id)
+    .bind(query.interval.as_str())
+    .bind(query.from)
+    .bind(query.to)
+    .fetch_all(state.db.pool())
+    .await
+    .map_err(|e| ApiError::Database(e.to_string()))?;
+    
+    Ok(Json(SupplyHistory {
+        stablecoin_id: id,
+        interval: query.interval,
+        from: query.from,
+        to: query.to,
+        series,
+    }))
+}
@@ -0,0 +1,268 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Json,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::json;
+use solana_sdk::{pubkey::Pubkey, signature::Signature, transaction::Transaction};
+use sqlx::query_as;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    models::{
+        BuildTransactionRequest, BuildTransactionResponse, OfflineAction, Stablecoin,
+        SubmitTransactionRequest, TransactionResponse, User,
+    },
+    app_middleware::{auth::AuthUser, request_id::RequestId},
+    routes::admin::CommitmentQuery,
+    solana::{explorer_url, required_signers, validate_offline_transaction, Role},
+    utils::audit,
+    AppState,
+};
+
+/// Build an unsigned transaction for an offline (cold wallet) authority.
+///
+/// The backend never holds `authority`'s key: it returns the serialized
+/// message and the signers it needs, and the signed transaction comes back
+/// through `submit`.
+pub async fn build(
+    State(state): State<AppState>,
+    RequestId(request_id): RequestId,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+    Json(req): Json<BuildTransactionRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Validate input using validator crate (includes pubkey and amount validation)
+    req.validate()?;
+
+    let authority = parse_pubkey(&req.authority, "authority")?;
+    let fee_payer = match req.fee_payer.as_deref() {
+        Some(fee_payer) => parse_pubkey(fee_payer, "fee_payer")?,
+        None => authority,
+    };
+    let nonce_account = req.nonce_account.as_deref()
+        .map(|nonce| parse_pubkey(nonce, "nonce_account"))
+        .transpose()?;
+
+    // Get stablecoin and check ownership
+    let stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
+    let stablecoin_pda: Pubkey = stablecoin.stablecoin_pda.parse()
+        .map_err(|_| ApiError::Internal("Invalid stablecoin PDA".to_string()))?;
+
+    // Refuse to build transactions the program would reject for this signer
+    let roles = state.roles.get_onchain_roles(&stablecoin_pda, &authority).await
+        .map_err(ApiError::solana)?;
+    let permitted = match req.action {
+        OfflineAction::Seize { .. } => roles.satisfies(Role::Seizer),
+        // Admin instructions check `has_one = authority`, so a Master role is not enough
+        _ => roles.is_authority,
+    };
+    if !permitted {
+        return Err(ApiError::Forbidden(format!(
+            "{} cannot sign {} for this stablecoin",
+            authority,
+            req.action.name(),
+        )));
+    }
+
+    let instruction = match &req.action {
+        OfflineAction::Pause { reason, until } => {
+            state.solana.build_pause_instruction(&stablecoin_pda, &authority, reason.clone(), *until)
+        }
+        OfflineAction::Unpause => {
+            state.solana.build_unpause_instruction(&stablecoin_pda, &authority)
+        }
+        OfflineAction::TransferAuthority { new_authority } => {
+            let new_authority = parse_pubkey(new_authority, "new_authority")?;
+            state.solana.build_transfer_authority_instruction(&stablecoin_pda, &authority, &new_authority)
+        }
+        OfflineAction::SetMaxTransfer { amount } => {
+            state.solana.build_set_max_transfer_instruction(&stablecoin_pda, &authority, *amount)
+        }
+        OfflineAction::SetMaxSupply { max_supply } => {
+            state.solana.build_set_max_supply_instruction(&stablecoin_pda, &authority, *max_supply)
+        }
+        OfflineAction::SetCompliance { enabled, upgrade_preset } => {
+            state.solana.build_set_compliance_instruction(&stablecoin_pda, &authority, *enabled, *upgrade_preset)
+        }
+        OfflineAction::AddToAllowlist { account } => {
+            let account = parse_pubkey(account, "account")?;
+            state.solana.build_add_allowlist_instruction(&stablecoin_pda, &authority, &account)
+        }
+        OfflineAction::RemoveFromAllowlist { account } => {
+            let account = parse_pubkey(account, "account")?;
+            state.solana.build_remove_allowlist_instruction(&stablecoin_pda, &authority, &account)
+        }
+        OfflineAction::SetAllowlistMode { enabled } => {
+            state.solana.build_set_allowlist_mode_instruction(&stablecoin_pda, &authority, *enabled)
+        }
+        OfflineAction::Seize { from_account, to_account, amount } => {
+            let from = parse_pubkey(from_account, "from_account")?;
+            let to = parse_pubkey(to_account, "to_account")?;
+            let asset_mint = parse_pubkey(&stablecoin.asset_mint, "asset_mint")?;
+
+            // The owner's blacklist entry, or else the token account's own;
+            // the program and the transfer hook accept either
+            let from_data = state.solana.get_account_data(&from).await
+                .map_err(ApiError::solana)?;
+            let from_owner = from_data.get(32..64)
+                .and_then(|owner| Pubkey::try_from(owner).ok())
+                .ok_or_else(|| ApiError::BadRequest("from_account is not a token account".to_string()))?;
+            let (owner_entry, _) = state.solana.find_blacklist_pda(&stablecoin_pda, &from_owner);
+            let blacklist_entry = if state.solana.account_exists(&owner_entry).await {
+                owner_entry
+            } else {
+                state.solana.find_blacklist_pda(&stablecoin_pda, &from).0
+            };
+
+            let token_program = state.solana.rpc_client()
+                .get_account(&asset_mint)
+                .map_err(|e| ApiError::Solana(e.to_string()))?
+                .owner;
+
+            let role_assignment = roles.acting_role(Role::Seizer)
+                .filter(|_| !roles.is_authority)
+                .map(|role| state.roles.find_role_assignment_pda(&stablecoin_pda, &authority, role).0);
+
+            state.solana.build_seize_instruction(
+                &stablecoin_pda,
+                &asset_mint,
+                &authority,
+                role_assignment.as_ref(),
+                &from,
+                &to,
+                &blacklist_entry,
+                *amount,
+                &token_program,
+            )
+        }
+    };
+
+    let message = state.solana
+        .build_unsigned_message(vec![instruction], &fee_payer, nonce_account.as_ref())
+        .await
+        .map_err(ApiError::solana)?;
+
+    let signers: Vec<String> = required_signers(&message).iter().map(|s| s.to_string()).collect();
+
+    // Log audit
+    audit(
+        &state.db,
+        Some(id),
+        Some(user.id),
+        "transaction.build",
+        None,
+        Some(json!({
+            "action": req.action.name(),
+            "authority": req.authority,
+            "nonce_account": req.nonce_account,
+            "required_signers": &signers,
+        })),
+        None,
+        Some(&request_id),
+    ).await;
+
+    Ok(Json(BuildTransactionResponse {
+        message: STANDARD.encode(message.serialize()),
+        required_signers: signers,
+        blockhash: message.recent_blockhash.to_string(),
+        nonce_account: req.nonce_account,
+    }))
+}
+
+/// Submit a transaction signed offline and wait for confirmation
+pub async fn submit(
+    State(state): State<AppState>,
+    RequestId(request_id): RequestId,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+    Query(query): Query<CommitmentQuery>,
+    Json(req): Json<SubmitTransactionRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let commitment = query.resolve(state.solana.commitment())?;
+
+    let bytes = STANDARD.decode(req.transaction.trim())
+        .map_err(|_| ApiError::Validation("transaction: must be base64".to_string()))?;
+    let transaction: Transaction = bincode::deserialize(&bytes)
+        .map_err(|_| ApiError::Validation("transaction: not a serialized transaction".to_string()))?;
+
+    // Get stablecoin and check ownership
+    let stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
+    let stablecoin_pda: Pubkey = stablecoin.stablecoin_pda.parse()
+        .map_err(|_| ApiError::Internal("Invalid stablecoin PDA".to_string()))?;
+
+    validate_offline_transaction(&transaction, state.solana.program_id(), &stablecoin_pda)
+        .map_err(ApiError::BadRequest)?;
+
+    let signature = state.solana
+        .send_and_confirm_transaction("offline_submit", transaction, Some(commitment))
+        .await
+        .map_err(ApiError::solana)?;
+    let tx_signature = signature.to_string();
+
+    // Log audit
+    audit(
+        &state.db,
+        Some(id),
+        Some(user.id),
+        "transaction.submit",
+        Some(&tx_signature),
+        Some(json!({"commitment": commitment.commitment.to_string()})),
+        None,
+        Some(&request_id),
+    ).await;
+
+    Ok(Json(TransactionResponse {
+        tx_signature: tx_signature.clone(),
+        status: "confirmed".to_string(),
+        explorer_url: explorer_url(&tx_signature, "devnet"),
+    }))
+}
+
+/// Report whether a transaction is `pending`, `confirmed`, `finalized` or `failed`
+pub async fn status(
+    State(state): State<AppState>,
+    Path(signature): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let parsed: Signature = signature.parse()
+        .map_err(|_| ApiError::Validation("Invalid transaction signature".to_string()))?;
+    
+    let status = state.solana.get_transaction_status(&parsed).await
+        .map_err(ApiError::solana)?;
+    
+    Ok(Json(json!({
+        "signature": signature,
+        "status": status,
+    })))
+}
+
+// Helper functions
+fn parse_pubkey(value: &str, field: &str) -> ApiResult<Pubkey> {
+    value.parse()
+        .map_err(|_| ApiError::Validation(format!("Invalid {} pubkey", field)))
+}
+
+async fn get_stablecoin_for_admin(
+    state: &AppState,
+    id: Uuid,
+    user: &User
+) -> ApiResult<Stablecoin> {
+    let stablecoin: Stablecoin = query_as(
+        "SELECT * FROM stablecoins WHERE id = $1 AND is_active = true"
+    )
+    .bind(id)
+    .fetch_optional(state.db.pool())
+    .await
+    .map_err(|e| ApiError::Database(e.to_string()))?
+    .ok_or(ApiError::StablecoinNotFound)?;
+
+    // Check ownership or admin role
+    if stablecoin.owner_id != user.id && user.role != "admin" {
+        return Err(ApiError::Forbidden("Not authorized for admin operations".to_string()));
+    }
+
+    Ok(stablecoin)
+}
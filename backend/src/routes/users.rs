@@ -4,13 +4,18 @@ use axum::{
     Json,
 };
 use serde::Deserialize;
+use serde_json::json;
 use solana_sdk::pubkey::Pubkey;
 use sqlx::query_as;
+use uuid::Uuid;
+use validator::Validate;
 
 use crate::{
     error::{ApiError, ApiResult},
-    models::{User, UserPublic},
+    models::{User, UserPublic, WalletVerifyRequest},
     app_middleware::auth::AuthUser,
+    app_middleware::request_id::RequestId,
+    utils::{verify_wallet_signature, wallet_challenge_message},
     AppState,
 };
 
@@ -19,6 +24,19 @@ pub struct UpdateUserRequest {
     pub solana_pubkey: Option<String>,
 }
 
+/// Fail with `Conflict` if a user other than `user_id` has verified `pubkey`
+/// as their wallet, so nobody can claim a wallet someone else has signed for
+pub async fn ensure_wallet_unclaimed(state: &AppState, pubkey: &str, user_id: Option<Uuid>) -> ApiResult<()> {
+    let owner = state.db.verified_wallet_owner(pubkey).await
+        .map_err(|e| ApiError::Database(e.to_string()))?;
+    match owner {
+        Some(owner) if Some(owner) != user_id => Err(ApiError::Conflict(
+            "Wallet is already linked to another account".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
 /// Get current user profile
 pub async fn me(
     State(state): State<AppState>,
@@ -36,7 +54,8 @@ pub async fn me(
     Ok(Json(UserPublic::from(user)))
 }
 
-/// Update current user profile
+/// Update current user profile. Changing `solana_pubkey` drops its
+/// verification; link the new wallet with `POST /users/me/wallet`.
 pub async fn update(
     State(state): State<AppState>,
     AuthUser(user): AuthUser,
@@ -47,13 +66,18 @@ pub async fn update(
         if !pubkey.is_empty() {
             pubkey.parse::<Pubkey>()
                 .map_err(|_| ApiError::Validation("Invalid Solana pubkey".to_string()))?;
+            ensure_wallet_unclaimed(&state, pubkey, Some(user.id)).await?;
         }
     }
     
     let user: User = query_as(
         r#"
         UPDATE users
-        SET solana_pubkey = $1, updated_at = NOW()
+        SET wallet_verified_at = CASE
+                WHEN solana_pubkey IS NOT DISTINCT FROM $1 THEN wallet_verified_at
+            END,
+            solana_pubkey = $1,
+            updated_at = NOW()
         WHERE id = $2 AND is_active = true
         RETURNING *
         "#
@@ -67,3 +91,58 @@ pub async fn update(
     
     Ok(Json(UserPublic::from(user)))
 }
+
+/// Link a wallet to the current user by its signature of a challenge from
+/// `POST /auth/wallet/challenge`. Only a linked wallet is used for on-chain
+/// role checks. Fails if another user already linked it; other users'
+/// unverified claims to it are dropped.
+pub async fn link_wallet(
+    State(state): State<AppState>,
+    AuthUser(user): AuthUser,
+    RequestId(request_id): RequestId,
+    Json(req): Json<WalletVerifyRequest>,
+) -> ApiResult<impl IntoResponse> {
+    req.validate()?;
+    
+    let invalid = || ApiError::Unauthorized("Invalid or expired wallet challenge".to_string());
+    // Check the signature first, so a bad one doesn't use up the challenge
+    if !verify_wallet_signature(&req.pubkey, &wallet_challenge_message(&req.nonce), &req.signature) {
+        return Err(invalid());
+    }
+    let consumed = state.db.consume_wallet_challenge(&req.nonce, &req.pubkey).await
+        .map_err(|e| ApiError::Database(e.to_string()))?;
+    if !consumed {
+        return Err(invalid());
+    }
+    
+    ensure_wallet_unclaimed(&state, &req.pubkey, Some(user.id)).await?;
+    state.db.clear_unverified_wallet_claims(&req.pubkey).await
+        .map_err(|e| ApiError::Database(e.to_string()))?;
+    
+    let user: User = query_as(
+        r#"
+        UPDATE users
+        SET solana_pubkey = $1, wallet_verified_at = NOW(), updated_at = NOW()
+        WHERE id = $2 AND is_active = true
+        RETURNING *
+        "#
+    )
+    .bind(&req.pubkey)
+    .bind(user.id)
+    .fetch_optional(state.db.pool())
+    .await
+    .map_err(|e| ApiError::Database(e.to_string()))?
+    .ok_or_else(|| ApiError::NotFound("User not found".to_string()))?;
+    
+    let _ = state.db.log_audit(
+        None,
+        Some(user.id),
+        "user.wallet_link",
+        None,
+        Some(json!({"pubkey": req.pubkey})),
+        None,
+        Some(&request_id),
+    ).await;
+    
+    Ok(Json(UserPublic::from(user)))
+}
@@ -12,23 +12,10 @@ use validator::Validate;
 use crate::{
     error::{ApiError, ApiResult},
     models::{CreateWebhookRequest, User, Webhook},
-    app_middleware::auth::AuthUser,
+    app_middleware::{auth::AuthUser, request_id::RequestId},
     AppState,
 };
 
-/// Helper function to convert validation errors to API error
-fn validation_error_to_api_error(e: validator::ValidationErrors) -> ApiError {
-    let error_messages: Vec<String> = e.field_errors()
-        .into_iter()
-        .flat_map(|(field, errors)| {
-            errors.iter().map(move |err| {
-                format!("{}: {}", field, err.message.as_ref().map(|m| m.as_ref()).unwrap_or("invalid"))
-            })
-        })
-        .collect();
-    ApiError::Validation(error_messages.join("; "))
-}
-
 /// Handle incoming webhook events (from external services)
 pub async fn handler(
     State(_state): State<AppState>,
@@ -46,12 +33,13 @@ pub async fn handler(
 /// Create a new webhook subscription
 pub async fn create(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Path(id): Path<Uuid>,
     Json(req): Json<CreateWebhookRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Validate input using validator crate
-    req.validate().map_err(validation_error_to_api_error)?;
+    req.validate()?;
     
     // Get stablecoin and check ownership
     let _stablecoin = get_stablecoin_for_admin(&state, id, &user).await?;
@@ -80,6 +68,7 @@ pub async fn create(
         None,
         Some(json!({"url": req.url})),
         None,
+        Some(&request_id),
     ).await;
     
     Ok((StatusCode::CREATED, Json(webhook)))
@@ -108,6 +97,7 @@ pub async fn list(
 /// Delete a webhook
 pub async fn delete(
     State(state): State<AppState>,
+    RequestId(request_id): RequestId,
     AuthUser(user): AuthUser,
     Path((id, webhook_id)): Path<(Uuid, Uuid)>,
 ) -> ApiResult<impl IntoResponse> {
@@ -136,6 +126,7 @@ pub async fn delete(
         None,
         Some(json!({"webhook_id": webhook_id})),
         None,
+        Some(&request_id),
     ).await;
     
     Ok(StatusCode::NO_CONTENT)
@@ -154,7 +145,7 @@ async fn get_stablecoin_for_admin(
     .fetch_optional(state.db.pool())
     .await
     .map_err(|e| ApiError::Database(e.to_string()))?
-    .ok_or_else(|| ApiError::NotFound("Stablecoin not found".to_string()))?;
+    .ok_or(ApiError::StablecoinNotFound)?;
     
     // Check ownership or admin role
     if stablecoin.owner_id != user.id && user.role != "admin" {
@@ -8,7 +8,7 @@ use solana_sdk::{
 use tracing::{info, warn};
 
 use crate::solana::{
-    SolanaService, StablecoinStateAccount, BlacklistEntryAccount, Role,
+    SolanaService, StablecoinStateAccount, BlacklistEntryAccount, AllowlistEntryAccount, Role,
 };
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -37,6 +37,17 @@ pub struct BlacklistEntry {
     pub blacklisted_at: i64,
 }
 
+/// Whether the transfer hook lets an account send and receive tokens
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AllowlistStatus {
+    pub address: String,
+    /// Allowlist mode on the stablecoin; when off every account may transfer
+    pub allowlist_mode: bool,
+    pub allowlisted: bool,
+    pub added_by: Option<String>,
+    pub added_at: Option<i64>,
+}
+
 pub struct ComplianceService {
     provider_api_key: String,
     solana: Arc<SolanaService>,
@@ -153,6 +164,28 @@ impl ComplianceService {
         }))
     }
     
+    /// Get the on-chain allowlist entry of a token account and the stablecoin's allowlist mode
+    pub async fn get_allowlist_status(&self, stablecoin: &Pubkey, address: &Pubkey) -> Result<AllowlistStatus> {
+        let state_data = self.solana.get_account_data(stablecoin).await?;
+        let state = self.deserialize_stablecoin_state(&state_data)?;
+        
+        let allowlist_pda = self.solana.find_allowlist_pda(stablecoin, address).0;
+        let entry = if self.solana.account_exists(&allowlist_pda).await {
+            let data = self.solana.get_account_data(&allowlist_pda).await?;
+            Some(self.deserialize_allowlist_entry(&data)?)
+        } else {
+            None
+        };
+        
+        Ok(AllowlistStatus {
+            address: address.to_string(),
+            allowlist_mode: state.allowlist_mode,
+            allowlisted: entry.is_some(),
+            added_by: entry.as_ref().map(|entry| entry.added_by.to_string()),
+            added_at: entry.map(|entry| entry.added_at),
+        })
+    }
+    
     /// Adds an address to the on-chain SSS-2 Blacklist
     pub async fn enforce_blacklist(
         &self,
@@ -216,7 +249,7 @@ impl ComplianceService {
         );
         
         // Send transaction
-        match self.solana.build_and_send_instruction(vec![instruction], &[]).await {
+        match self.solana.build_and_send_instruction("blacklist_add", vec![instruction], &[]).await {
             Ok(signature) => {
                 info!(
                     "Blacklist transaction successful: signature={}, address={}, reason={}",
@@ -305,7 +338,7 @@ impl ComplianceService {
         );
         
         // Send transaction
-        match self.solana.build_and_send_instruction(vec![instruction], &[]).await {
+        match self.solana.build_and_send_instruction("blacklist_remove", vec![instruction], &[]).await {
             Ok(signature) => {
                 info!(
                     "Unblacklist transaction successful: signature={}, address={}",
@@ -384,6 +417,17 @@ impl ComplianceService {
             .context("Failed to deserialize blacklist entry")
     }
     
+    /// Deserialize allowlist entry from account data
+    fn deserialize_allowlist_entry(&self, data: &[u8]) -> Result<AllowlistEntryAccount> {
+        if data.len() < 8 {
+            anyhow::bail!("Invalid allowlist entry data length");
+        }
+        
+        let mut slice = &data[8..];
+        AllowlistEntryAccount::deserialize(&mut slice)
+            .context("Failed to deserialize allowlist entry")
+    }
+    
     /// Verify compliance authority has required role
     pub async fn verify_blacklister_role(&self, stablecoin: &Pubkey, authority: &Pubkey) -> Result<bool> {
         // Check if authority is the master authority
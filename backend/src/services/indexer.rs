@@ -1,20 +1,213 @@
+use chrono::Utc;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::time::{sleep, Duration};
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use sss_token::events::decode_event_json;
+
+use crate::db::Database;
+use crate::models::{AccountChange, DiscoveredStablecoin, IndexedEvent, OnchainRoleAssignment};
+use crate::solana::SolanaService;
+
+/// Buffered events per subscriber; slower subscribers skip ahead once they lag this far
+pub const EVENT_CHANNEL_CAPACITY: usize = 1024;
+
+/// Prefix the runtime puts on `emit!` output in transaction logs
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+/// Parse the `Program data:` lines of a transaction's logs into indexed events.
+/// Lines that aren't a known program event are skipped.
+pub fn parse_log_events(logs: &[String], signature: Option<&str>, slot: u64) -> Vec<IndexedEvent> {
+    logs.iter()
+        .filter_map(|line| STANDARD.decode(line.strip_prefix(PROGRAM_DATA_PREFIX)?).ok())
+        .filter_map(|payload| decode_event_json(&payload))
+        .filter_map(|json| serde_json::from_str::<serde_json::Value>(&json).ok())
+        .filter_map(|data| {
+            let event_type = data.get("event")?.as_str()?.to_string();
+            let stablecoin = data.get("stablecoin")?.as_str()?.to_string();
+            Some(IndexedEvent {
+                stablecoin,
+                event_type,
+                signature: signature.map(str::to_string),
+                slot,
+                data,
+                timestamp: Utc::now(),
+            })
+        })
+        .collect()
+}
+
+/// Audit log action for governance events, which create the stablecoin or
+/// change who controls it
+pub fn governance_audit_action(event_type: &str) -> Option<&'static str> {
+    match event_type {
+        "StablecoinInitialized" => Some("stablecoin.create"),
+        "RoleAssigned" => Some("role.assign"),
+        "RoleRevoked" => Some("role.revoke"),
+        "AuthorityTransferred" => Some("authority.transfer"),
+        _ => None,
+    }
+}
+
+/// The new stablecoin a `StablecoinInitialized` event announces, if that's what `event` is
+pub fn discovered_stablecoin(event: &IndexedEvent) -> Option<DiscoveredStablecoin> {
+    if event.event_type != "StablecoinInitialized" {
+        return None;
+    }
+    let data = &event.data;
+    let text = |field: &str| -> Option<String> { data.get(field)?.as_str().map(str::to_string) };
+    let small = |field: &str| -> Option<i16> { data.get(field)?.as_u64()?.try_into().ok() };
+    Some(DiscoveredStablecoin {
+        stablecoin_pda: event.stablecoin.clone(),
+        authority: text("authority")?,
+        asset_mint: text("asset_mint")?,
+        preset: small("preset")?,
+        decimals: small("decimals")?,
+        name: text("name")?,
+        symbol: text("symbol")?,
+    })
+}
+
+/// Register the stablecoin behind a `StablecoinInitialized` event, so deployments
+/// made outside the API show up for the user holding their authority key.
+/// Returns the new stablecoin's id, or `None` for other events, stablecoins
+/// already registered and authorities no user has verified as their wallet.
+pub async fn auto_register(db: &Database, event: &IndexedEvent) -> anyhow::Result<Option<uuid::Uuid>> {
+    let Some(discovered) = discovered_stablecoin(event) else {
+        return Ok(None);
+    };
+    let id = db.register_discovered_stablecoin(&discovered).await?;
+    match id {
+        Some(id) => tracing::info!("Registered stablecoin {} discovered on chain as {}", discovered.stablecoin_pda, id),
+        None => tracing::debug!("Not registering stablecoin {}: already known or no user verified {}",
+            discovered.stablecoin_pda, discovered.authority),
+    }
+    Ok(id)
+}
+
+/// The minter, role or blacklist change an event makes, if any.
+/// Role names are lowercased to match the API; a role's `assigned_at` is the
+/// time the event was indexed, since the event doesn't carry one.
+pub fn account_change(event: &IndexedEvent) -> Option<AccountChange> {
+    let data = &event.data;
+    let text = |field: &str| -> Option<String> { data.get(field)?.as_str().map(str::to_string) };
+    let amount = |field: &str| -> Option<u64> { data.get(field)?.as_u64() };
+    let change = match event.event_type.as_str() {
+        "RoleAssigned" => AccountChange::RoleAssigned(OnchainRoleAssignment {
+            role: text("role")?.to_lowercase(),
+            account: text("account")?,
+            assigned_by: text("assigned_by")?,
+            assigned_at: event.timestamp.timestamp(),
+            expires_at: data.get("expires_at").and_then(|v| v.as_i64()),
+        }),
+        "RoleRevoked" | "ExpiredRoleCleaned" => AccountChange::RoleRemoved {
+            role: text("role")?.to_lowercase(),
+            account: text("account")?,
+        },
+        "MinterAdded" => AccountChange::MinterQuota { minter: text("minter")?, quota: amount("quota")? },
+        "QuotaUpdated" => AccountChange::MinterQuota { minter: text("minter")?, quota: amount("new_quota")? },
+        "MinterRemoved" => AccountChange::MinterRemoved { minter: text("minter")? },
+        "MinterRotated" => AccountChange::MinterRotated {
+            old_minter: text("old_minter")?,
+            new_minter: text("new_minter")?,
+            quota: amount("quota")?,
+            minted_amount: amount("minted_amount")?,
+        },
+        "BlacklistAdded" => AccountChange::Blacklisted { account: text("account")?, reason: text("reason")? },
+        "BlacklistRemoved" => AccountChange::Unblacklisted { account: text("account")? },
+        _ => return None,
+    };
+    Some(change)
+}
 
 pub struct EventIndexer {
     pub rpc_url: String,
     pub program_id: String,
     running: Arc<RwLock<bool>>,
+    events: broadcast::Sender<IndexedEvent>,
+    /// Role snapshots loaded so far, by stablecoin PDA
+    roles: RwLock<HashMap<String, Vec<OnchainRoleAssignment>>>,
 }
 
 impl EventIndexer {
     pub fn new(rpc_url: &str, program_id: String) -> Self {
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
         Self {
             rpc_url: rpc_url.to_string(),
             program_id,
             running: Arc::new(RwLock::new(false)),
+            events,
+            roles: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Receive every event indexed from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<IndexedEvent> {
+        self.events.subscribe()
+    }
+
+    /// Forward an indexed event to live subscribers.
+    /// Returns the number of subscribers it was delivered to.
+    pub fn publish(&self, event: IndexedEvent) -> usize {
+        // Sending only fails when nobody is listening, which is fine
+        self.events.send(event).unwrap_or(0)
+    }
+
+    /// On-chain role assignments of a stablecoin, newest first.
+    /// Served from memory, then from the database snapshot; only a stablecoin
+    /// never listed before costs a program-wide `getProgramAccounts` scan.
+    pub async fn role_assignments(
+        &self,
+        db: &Database,
+        solana: &SolanaService,
+        stablecoin_id: uuid::Uuid,
+        stablecoin_pda: &Pubkey,
+    ) -> anyhow::Result<Vec<OnchainRoleAssignment>> {
+        let key = stablecoin_pda.to_string();
+        if let Some(roles) = self.roles.read().await.get(&key) {
+            return Ok(roles.clone());
+        }
+
+        let mut roles = match db.load_role_snapshot(stablecoin_id).await? {
+            Some(roles) => roles,
+            None => {
+                tracing::info!("Seeding role snapshot for {} from a full scan", stablecoin_pda);
+                let roles: Vec<OnchainRoleAssignment> = solana.get_roles_for_stablecoin(stablecoin_pda).await?
+                    .into_iter()
+                    .map(|assignment| OnchainRoleAssignment {
+                        role: assignment.role.as_str().to_string(),
+                        account: assignment.account.to_string(),
+                        assigned_by: assignment.assigned_by.to_string(),
+                        assigned_at: assignment.assigned_at,
+                        expires_at: assignment.expires_at,
+                    })
+                    .collect();
+                db.replace_role_snapshot(stablecoin_id, &roles).await?;
+                roles
+            }
+        };
+        roles.sort_by_key(|role| std::cmp::Reverse(role.assigned_at));
+
+        self.roles.write().await.insert(key, roles.clone());
+        Ok(roles)
+    }
+
+    /// Keep the account snapshots current with an indexed event: the role
+    /// snapshot, minter quotas and blacklist entries. Returns whether the
+    /// event changed any of them.
+    pub async fn apply_account_change(&self, db: &Database, event: &IndexedEvent) -> anyhow::Result<bool> {
+        let Some(change) = account_change(event) else {
+            return Ok(false);
+        };
+        db.apply_account_change(&event.stablecoin, &change).await?;
+
+        if let Some(roles) = self.roles.write().await.get_mut(&event.stablecoin) {
+            change.apply_to_roles(roles);
+            roles.sort_by_key(|role| std::cmp::Reverse(role.assigned_at));
         }
+        Ok(true)
     }
 
     /// Background task to poll signatures and parse Anchor events
@@ -34,8 +227,11 @@ impl EventIndexer {
             // In production: 
             // 1. Fetch recent signatures for self.program_id
             // 2. GetTransaction for new signatures
-            // 3. Parse inner instructions and log messages for Anchor Events
-            // 4. Push to database or emit webhooks
+            // 3. `parse_log_events` on each transaction's log messages
+            // 4. `auto_register` new stablecoins, `apply_account_change` to keep
+            //    the minter/role/blacklist snapshots current, audit governance
+            //    events under `governance_audit_action`, emit webhooks, and
+            //    `publish` to live subscribers
             
             sleep(Duration::from_secs(10)).await;
         }
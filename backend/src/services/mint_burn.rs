@@ -2,18 +2,26 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use anyhow::{Context, Result};
 use solana_sdk::{
+    instruction::Instruction,
     pubkey::Pubkey,
-    signature::{Keypair, Signer},
+    signature::{Keypair, Signature, Signer},
 };
-use tracing::{info, warn};
+use tracing::info;
 
-use crate::solana::{SolanaService, StablecoinStateAccount};
+use crate::solana::{
+    build_create_associated_token_account_idempotent, find_associated_token_address,
+    Role, SolanaService, StablecoinStateAccount, TxStatus,
+};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MintRequest {
+    /// Recipient wallet; tokens go to its associated token account
     pub recipient: String,
     pub amount: u64,
     pub fiat_proof: Option<String>,
+    /// Treat `recipient` as an existing token account instead of a wallet
+    #[serde(default)]
+    pub recipient_is_token_account: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -28,6 +36,8 @@ pub struct TransactionResult {
     pub signature: String,
     pub explorer_url: String,
     pub slot: Option<u64>,
+    /// `pending` unless the request waited for confirmation
+    pub status: TxStatus,
 }
 
 pub struct MintBurnService {
@@ -74,33 +84,64 @@ impl MintBurnService {
             .with_context(|| format!("Invalid recipient pubkey: {}", recipient))
     }
     
-    /// Get or derive the token account for a recipient
-    async fn get_or_derive_token_account(&self, owner: &Pubkey, asset_mint: &Pubkey) -> Result<Pubkey> {
-        // Try to find associated token account
-        let associated_token = self.find_associated_token_account(owner, asset_mint);
-        
-        // Check if it exists
-        if self.solana.account_exists(&associated_token).await {
-            return Ok(associated_token);
+    /// Token account a mint to `recipient` lands in: the recipient itself when it
+    /// is already a token account, otherwise the wallet's associated token account
+    pub fn recipient_token_account(
+        &self,
+        recipient: &Pubkey,
+        asset_mint: &Pubkey,
+        recipient_is_token_account: bool,
+    ) -> Pubkey {
+        if recipient_is_token_account {
+            *recipient
+        } else {
+            find_associated_token_address(recipient, asset_mint, &self.token_program)
         }
-        
-        // If not, return the owner's pubkey (caller should ensure account exists)
-        warn!("Associated token account {} does not exist for owner {}", associated_token, owner);
-        Err(anyhow::anyhow!("Recipient token account does not exist. Please create it first."))
     }
     
-    /// Find associated token account address
-    fn find_associated_token_account(&self, owner: &Pubkey, mint: &Pubkey) -> Pubkey {
-        // For Token-2022, we use the same derivation as Token program
-        let seeds = &[
-            owner.as_ref(),
-            self.token_program.as_ref(),
-            mint.as_ref(),
-        ];
+    /// Resolve the token account to mint into.
+    ///
+    /// When a wallet's associated token account does not exist yet, an
+    /// idempotent create instruction paid by `payer` is returned to prepend
+    /// to the mint.
+    async fn resolve_recipient_token_account(
+        &self,
+        payer: &Pubkey,
+        recipient: &Pubkey,
+        asset_mint: &Pubkey,
+        recipient_is_token_account: bool,
+    ) -> (Pubkey, Option<Instruction>) {
+        let associated_token = self.recipient_token_account(recipient, asset_mint, recipient_is_token_account);
+        if recipient_is_token_account || self.solana.account_exists(&associated_token).await {
+            return (associated_token, None);
+        }
         
-        // Use spl-associated-token-account derivation
-        // This is a simplified version - in production use spl_associated_token_account crate
-        Pubkey::find_program_address(seeds, &self.token_program).0
+        info!("Creating associated token account {} for {}", associated_token, recipient);
+        let create = build_create_associated_token_account_idempotent(
+            payer,
+            recipient,
+            asset_mint,
+            &self.token_program,
+        );
+        (associated_token, Some(create))
+    }
+    
+    /// Send `instructions`, confirming them first when `wait` is set.
+    /// Unconfirmed sends return `Pending`; follow up with
+    /// `SolanaService::await_confirmation`.
+    async fn send(
+        &self,
+        operation: &str,
+        instructions: Vec<Instruction>,
+        wait: bool,
+    ) -> Result<(Signature, TxStatus)> {
+        if wait {
+            let signature = self.solana.build_and_send_instruction(operation, instructions, &[]).await?;
+            Ok((signature, TxStatus::confirmed_at(self.solana.commitment())))
+        } else {
+            let signature = self.solana.build_and_send_instruction_unconfirmed(instructions, &[]).await?;
+            Ok((signature, TxStatus::Pending))
+        }
     }
     
     /// Validates fiat deposit and creates a mint transaction
@@ -108,6 +149,7 @@ impl MintBurnService {
         &self,
         stablecoin_pubkey: &Pubkey,
         req: MintRequest,
+        wait: bool,
     ) -> Result<TransactionResult> {
         // Validate fiat proof if required
         if let Some(proof) = &req.fiat_proof {
@@ -116,6 +158,33 @@ impl MintBurnService {
             self.validate_fiat_proof(proof).await?;
         }
         
+        let instructions = self.build_mint_instructions(stablecoin_pubkey, &req).await?;
+        
+        // Send transaction
+        let (signature, status) = self.send("mint", instructions, wait).await?;
+        
+        let slot = self.solana.get_slot().await.ok();
+        
+        info!(
+            "Mint transaction {}: signature={}, recipient={}, amount={}",
+            status.as_str(), signature, req.recipient, req.amount
+        );
+        
+        Ok(TransactionResult {
+            signature: signature.to_string(),
+            explorer_url: crate::solana::explorer_url(&signature.to_string(), &self.cluster),
+            slot,
+            status,
+        })
+    }
+    
+    /// Check `req` against the on-chain state and build the mint instructions,
+    /// with an associated token account creation ahead of the mint when needed
+    pub async fn build_mint_instructions(
+        &self,
+        stablecoin_pubkey: &Pubkey,
+        req: &MintRequest,
+    ) -> Result<Vec<Instruction>> {
         // Parse recipient
         let recipient = self.parse_recipient(&req.recipient)?;
         
@@ -124,33 +193,44 @@ impl MintBurnService {
         let state = self.deserialize_stablecoin_state(&state_data)?;
         
         // Check if paused
-        if state.paused {
+        if state.is_paused(chrono::Utc::now().timestamp()) {
             anyhow::bail!("Stablecoin is currently paused");
         }
-        
-        // Get recipient token account
-        let recipient_token_account = self.get_or_derive_token_account(&recipient, &state.asset_mint).await?;
+        if state.min_mint_amount > 0 && req.amount < state.min_mint_amount {
+            anyhow::bail!("Amount {} is below the minimum mint amount {}", req.amount, state.min_mint_amount);
+        }
         
         // Get authority keypair
         let authority = self.authority_keypair.as_ref()
             .context("Authority keypair not set")?;
         
+        // Resolve the recipient token account, creating the ATA if needed
+        let (recipient_token_account, create_ata) = self.resolve_recipient_token_account(
+            &authority.pubkey(),
+            &recipient,
+            &state.asset_mint,
+            req.recipient_is_token_account,
+        ).await;
+        
         // Find role assignment PDA if authority has a role
-        let role_pda = self.find_role_assignment(stablecoin_pubkey, &authority.pubkey());
-        let role_account = if self.solana.account_exists(&role_pda).await {
-            Some((role_pda, 0)) // Bump would need to be fetched
-        } else {
-            None
-        };
+        let role_account = self.find_role_assignment(stablecoin_pubkey, &authority.pubkey(), Role::Minter).await
+            .map(|pda| (pda, 0)); // Bump would need to be fetched
         
         // Find minter info PDA
         let minter_pda = self.solana.find_minter_pda(stablecoin_pubkey, &authority.pubkey()).0;
         let minter_info = if self.solana.account_exists(&minter_pda).await {
             Some((minter_pda, 0))
-        } else {
+        } else if authority.pubkey() == state.authority {
             None
+        } else {
+            // The program rejects a non-master mint without it (MinterInfoRequired)
+            anyhow::bail!("Authority {} is not a registered minter of this stablecoin", authority.pubkey());
         };
         
+        // Reserve-backed mints are checked against the latest attestation
+        let reserve_attestation = state.reserve_backed
+            .then(|| self.solana.find_reserve_attestation_pda(stablecoin_pubkey).0);
+        
         // Build mint instruction
         let instruction = self.solana.build_mint_instruction(
             stablecoin_pubkey,
@@ -162,42 +242,63 @@ impl MintBurnService {
             role_account.as_ref().map(|(p, b)| (*p, *b)),
             minter_info.as_ref().map(|(p, b)| (*p, *b)),
             &self.token_program,
+            (state.fee_bps > 0).then_some(&state.fee_treasury),
+            reserve_attestation.as_ref(),
         );
         
+        Ok(create_ata.into_iter().chain([instruction]).collect())
+    }
+    
+    /// Processes burn requests and coordinates fiat wire transfers
+    pub async fn process_burn_request(
+        &self,
+        stablecoin_pubkey: &Pubkey,
+        req: BurnRequest,
+        wait: bool,
+    ) -> Result<TransactionResult> {
+        let instruction = self.build_burn_instruction(stablecoin_pubkey, &req).await?;
+        
         // Send transaction
-        let signature = self.solana.build_and_send_instruction(
-            vec![instruction],
-            &[],
-        ).await?;
+        let (signature, status) = self.send("burn", vec![instruction], wait).await?;
         
         let slot = self.solana.get_slot().await.ok();
         
+        // In production: Initiate fiat wire transfer to bank_account
+        if let Some(bank_account) = &req.bank_account {
+            tracing::debug!("Initiating wire transfer to bank account: {}", bank_account);
+            // This would integrate with a banking API
+        }
+        
         info!(
-            "Mint transaction successful: signature={}, recipient={}, amount={}",
-            signature, req.recipient, req.amount
+            "Burn transaction {}: signature={}, amount={}",
+            status.as_str(), signature, req.amount
         );
         
         Ok(TransactionResult {
             signature: signature.to_string(),
             explorer_url: crate::solana::explorer_url(&signature.to_string(), &self.cluster),
             slot,
+            status,
         })
     }
     
-    /// Processes burn requests and coordinates fiat wire transfers
-    pub async fn process_burn_request(
+    /// Check `req` against the on-chain state and the source balance, and build the burn instruction
+    pub async fn build_burn_instruction(
         &self,
         stablecoin_pubkey: &Pubkey,
-        req: BurnRequest,
-    ) -> Result<TransactionResult> {
+        req: &BurnRequest,
+    ) -> Result<Instruction> {
         // Get stablecoin state
         let state_data = self.solana.get_account_data(stablecoin_pubkey).await?;
         let state = self.deserialize_stablecoin_state(&state_data)?;
         
         // Check if paused
-        if state.paused {
+        if state.is_paused(chrono::Utc::now().timestamp()) {
             anyhow::bail!("Stablecoin is currently paused");
         }
+        if state.min_burn_amount > 0 && req.amount < state.min_burn_amount {
+            anyhow::bail!("Amount {} is below the minimum burn amount {}", req.amount, state.min_burn_amount);
+        }
         
         // Get authority keypair
         let authority = self.authority_keypair.as_ref()
@@ -208,25 +309,18 @@ impl MintBurnService {
             acc.parse::<Pubkey>()
                 .with_context(|| format!("Invalid token account: {}", acc))?
         } else {
-            self.find_associated_token_account(&authority.pubkey(), &state.asset_mint)
+            find_associated_token_address(&authority.pubkey(), &state.asset_mint, &self.token_program)
         };
         
         // Check balance
-        let balance = self.solana.get_token_account_balance(&from_token_account).await?;
-        if balance < req.amount {
-            anyhow::bail!("Insufficient balance. Available: {}, Required: {}", balance, req.amount);
-        }
+        self.solana.require_token_balance(&from_token_account, req.amount).await?;
         
         // Find role assignment PDA if authority has a role
-        let role_pda = self.find_role_assignment(stablecoin_pubkey, &authority.pubkey());
-        let role_account = if self.solana.account_exists(&role_pda).await {
-            Some((role_pda, 0))
-        } else {
-            None
-        };
+        let role_account = self.find_role_assignment(stablecoin_pubkey, &authority.pubkey(), Role::Burner).await
+            .map(|pda| (pda, 0));
         
         // Build burn instruction
-        let instruction = self.solana.build_burn_instruction(
+        Ok(self.solana.build_burn_instruction(
             stablecoin_pubkey,
             &state.asset_mint,
             &authority.pubkey(),
@@ -234,32 +328,8 @@ impl MintBurnService {
             req.amount,
             role_account.as_ref().map(|(p, b)| (*p, *b)),
             &self.token_program,
-        );
-        
-        // Send transaction
-        let signature = self.solana.build_and_send_instruction(
-            vec![instruction],
-            &[],
-        ).await?;
-        
-        let slot = self.solana.get_slot().await.ok();
-        
-        // In production: Initiate fiat wire transfer to bank_account
-        if let Some(bank_account) = &req.bank_account {
-            tracing::debug!("Initiating wire transfer to bank account: {}", bank_account);
-            // This would integrate with a banking API
-        }
-        
-        info!(
-            "Burn transaction successful: signature={}, amount={}",
-            signature, req.amount
-        );
-        
-        Ok(TransactionResult {
-            signature: signature.to_string(),
-            explorer_url: crate::solana::explorer_url(&signature.to_string(), &self.cluster),
-            slot,
-        })
+            (state.fee_bps > 0).then_some(&state.fee_treasury),
+        ))
     }
     
     /// Validate fiat proof with banking API (placeholder)
@@ -272,11 +342,16 @@ impl MintBurnService {
         Ok(())
     }
     
-    /// Find role assignment PDA
-    fn find_role_assignment(&self, stablecoin: &Pubkey, account: &Pubkey) -> Pubkey {
-        // Try to find any role assignment for this account
-        // In a real implementation, we'd check all role types
-        self.solana.find_role_pda(stablecoin, account, b"minter").0
+    /// Find the role assignment PDA `account` would act with: its `role`
+    /// assignment if one exists, otherwise its Master assignment
+    async fn find_role_assignment(&self, stablecoin: &Pubkey, account: &Pubkey, role: Role) -> Option<Pubkey> {
+        for role in [role, Role::Master] {
+            let role_pda = self.solana.find_role_pda(stablecoin, account, role.to_seed()).0;
+            if self.solana.account_exists(&role_pda).await {
+                return Some(role_pda);
+            }
+        }
+        None
     }
     
     /// Deserialize stablecoin state from account data
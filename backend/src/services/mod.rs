@@ -1,13 +1,15 @@
 pub mod mint_burn;
 pub mod indexer;
 pub mod compliance;
+pub mod roles;
 
 pub use mint_burn::{MintBurnService, MintRequest, BurnRequest, TransactionResult};
 pub use indexer::EventIndexer;
 pub use compliance::{ComplianceService, ScreeningResult, BlacklistResult, BlacklistEntry};
+pub use roles::{RoleService, OnchainRoles};
 
 // Re-export SolanaService and types from parent module
 pub use crate::solana::{
-    SolanaService, Role, StablecoinStateAccount, BlacklistEntryAccount, 
+    SolanaService, ComputeBudgetConfig, ConfirmationConfig, RpcRetryConfig, Role, StablecoinStateAccount, BlacklistEntryAccount, 
     MinterInfoAccount, RoleAssignmentAccount,
 };
@@ -0,0 +1,166 @@
+use anchor_lang::AnchorDeserialize;
+use anyhow::{Context, Result};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+use tracing::debug;
+
+use crate::error::{ApiError, ApiResult};
+use crate::models::User;
+use crate::solana::{RoleAssignmentAccount, SolanaService, StablecoinStateAccount};
+use crate::solana::Role;
+
+/// How long an on-chain role lookup is reused before hitting RPC again
+pub const DEFAULT_ROLE_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// On-chain privileges held by a pubkey for one stablecoin
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OnchainRoles {
+    /// Pubkey is the stablecoin's master authority
+    pub is_authority: bool,
+    /// Roles the pubkey holds a RoleAssignment PDA for
+    pub roles: Vec<Role>,
+}
+
+impl OnchainRoles {
+    /// Mirrors the program's RBAC: the authority and Master role holders
+    /// pass every check, otherwise the required role must be assigned.
+    pub fn satisfies(&self, required: Role) -> bool {
+        self.is_authority || self.acting_role(required).is_some()
+    }
+
+    /// Role whose assignment PDA the pubkey signs `required` operations
+    /// with: `required` itself if assigned, otherwise Master
+    pub fn acting_role(&self, required: Role) -> Option<Role> {
+        [required, Role::Master].into_iter().find(|role| self.roles.contains(role))
+    }
+}
+
+struct CachedRoles {
+    roles: OnchainRoles,
+    fetched_at: Instant,
+}
+
+/// Verifies that callers hold the on-chain role an operation requires
+pub struct RoleService {
+    solana: Arc<SolanaService>,
+    cache: RwLock<HashMap<(Pubkey, Pubkey), CachedRoles>>,
+    ttl: Duration,
+}
+
+impl RoleService {
+    pub fn new(solana: Arc<SolanaService>) -> Self {
+        Self::with_ttl(solana, DEFAULT_ROLE_CACHE_TTL)
+    }
+
+    pub fn with_ttl(solana: Arc<SolanaService>, ttl: Duration) -> Self {
+        Self {
+            solana,
+            cache: RwLock::new(HashMap::new()),
+            ttl,
+        }
+    }
+
+    /// Find the RoleAssignment PDA (one assignment per role per account per stablecoin)
+    pub fn find_role_assignment_pda(&self, stablecoin: &Pubkey, account: &Pubkey, role: Role) -> (Pubkey, u8) {
+        self.solana.find_role_pda(stablecoin, account, role.to_seed())
+    }
+
+    /// Look up the on-chain privileges of `account`, using the cache when fresh
+    pub async fn get_onchain_roles(&self, stablecoin: &Pubkey, account: &Pubkey) -> Result<OnchainRoles> {
+        let key = (*stablecoin, *account);
+
+        if let Some(cached) = self.cache.read().await.get(&key) {
+            if cached.fetched_at.elapsed() < self.ttl {
+                return Ok(cached.roles.clone());
+            }
+        }
+
+        let roles = self.fetch_onchain_roles(stablecoin, account).await?;
+        self.cache.write().await.insert(key, CachedRoles {
+            roles: roles.clone(),
+            fetched_at: Instant::now(),
+        });
+
+        Ok(roles)
+    }
+
+    /// Drop a cached lookup (call after assigning or revoking a role)
+    pub async fn invalidate(&self, stablecoin: &Pubkey, account: &Pubkey) {
+        self.cache.write().await.remove(&(*stablecoin, *account));
+    }
+
+    /// Fail with `Forbidden` unless `account` holds `role` on-chain
+    pub async fn require_onchain_role(
+        &self,
+        stablecoin: &Pubkey,
+        account: &Pubkey,
+        role: Role,
+    ) -> ApiResult<()> {
+        let roles = self.get_onchain_roles(stablecoin, account).await
+            .map_err(ApiError::solana)?;
+
+        if !roles.satisfies(role) {
+            return Err(ApiError::Forbidden(format!(
+                "Account {} does not hold the on-chain {:?} role",
+                account, role
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Authorize an API user for an operation requiring `role`.
+    /// Platform admins are exempt; everyone else must have a wallet, verified
+    /// by signature, that holds the role on-chain. A `solana_pubkey` that was
+    /// only claimed at registration or in a profile update is not enough.
+    pub async fn authorize_user(&self, stablecoin: &Pubkey, user: &User, role: Role) -> ApiResult<()> {
+        if user.role == "admin" {
+            return Ok(());
+        }
+
+        let pubkey: Pubkey = user.verified_wallet()
+            .ok_or_else(|| ApiError::Forbidden("No verified Solana wallet linked to this account".to_string()))?
+            .parse()
+            .map_err(|_| ApiError::Forbidden("Linked Solana pubkey is invalid".to_string()))?;
+
+        self.require_onchain_role(stablecoin, &pubkey, role).await
+    }
+
+    async fn fetch_onchain_roles(&self, stablecoin: &Pubkey, account: &Pubkey) -> Result<OnchainRoles> {
+        debug!("Fetching on-chain roles for {} on {}", account, stablecoin);
+
+        let mut keys = vec![*stablecoin];
+        keys.extend(Role::ALL.iter().map(|role| self.find_role_assignment_pda(stablecoin, account, *role).0));
+        let mut accounts = self.solana.get_multiple_accounts(&keys).await?.into_iter();
+
+        let state_data = accounts.next().flatten()
+            .context("Stablecoin state account not found")?;
+        let state = deserialize_account::<StablecoinStateAccount>(&state_data)?;
+
+        let now = chrono::Utc::now().timestamp();
+        let mut roles = Vec::new();
+        for data in accounts.flatten() {
+            let assignment = deserialize_account::<RoleAssignmentAccount>(&data)?;
+            if !assignment.is_expired(now) {
+                roles.push(assignment.role);
+            }
+        }
+
+        Ok(OnchainRoles {
+            is_authority: state.authority == *account,
+            roles,
+        })
+    }
+}
+
+/// Skip the 8-byte Anchor discriminator and deserialize the account body
+fn deserialize_account<T: AnchorDeserialize>(data: &[u8]) -> Result<T> {
+    if data.len() < 8 {
+        anyhow::bail!("Account data too short");
+    }
+    let mut slice = &data[8..];
+    T::deserialize(&mut slice).context("Failed to deserialize account")
+}
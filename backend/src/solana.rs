@@ -1,10 +1,14 @@
 use anyhow::{Context, Result};
 use anchor_client::{
     solana_client::{
+        client_error::{ClientError, ClientErrorKind},
+        nonce_utils,
         rpc_client::RpcClient,
-        rpc_config::RpcSendTransactionConfig,
+        rpc_config::{RpcProgramAccountsConfig, RpcSendTransactionConfig},
+        rpc_filter::{Memcmp, RpcFilterType},
     },
     solana_sdk::{
+        compute_budget::{self, ComputeBudgetInstruction},
         instruction::{AccountMeta, Instruction},
         message::Message,
         pubkey::Pubkey,
@@ -16,21 +20,263 @@ use anchor_client::{
     },
 };
 use anchor_lang::{AnchorDeserialize, AnchorSerialize, InstructionData};
-use std::sync::Arc;
+use rand::Rng;
+use std::{sync::Arc, time::{Duration, Instant}};
 use tokio::sync::RwLock;
+use sss_token::constants;
+use crate::routes::metrics::record_transaction;
 use tracing::{info, warn};
 
-/// Seed constants matching the Solana program
-pub const VAULT_SEED: &[u8] = b"stablecoin";
-pub const ROLE_SEED: &[u8] = b"role";
-pub const BLACKLIST_SEED: &[u8] = b"blacklist";
-pub const MINTER_SEED: &[u8] = b"minter";
+/// Seed of role assignment PDAs, from the Solana program
+pub use sss_token::constants::ROLE_SEED;
+
+/// Associated Token Account program
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// SPL Token and Token-2022, the programs that own token accounts
+pub const TOKEN_PROGRAM_IDS: [Pubkey; 2] = [
+    Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+    Pubkey::from_str_const("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"),
+];
+
+/// Base token account size; Token-2022 accounts with extensions are larger
+const TOKEN_ACCOUNT_BASE_LEN: usize = 165;
+
+/// Token-2022 `AccountType::Account` marker stored right after the base layout
+const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+
+/// Offset of the `u64` amount in a token account: mint (32) | owner (32) | amount
+const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+
+/// SPL Memo program (v2)
+pub const MEMO_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// Token program `TransferChecked` instruction tag, shared by SPL Token and Token-2022
+const TRANSFER_CHECKED_TAG: u8 = 12;
+
+/// Commitment levels accepted from configuration and API callers
+pub const ALLOWED_COMMITMENTS: &[&str] = &["processed", "confirmed", "finalized"];
+
+/// How often a background confirmation polls the signature status
+const CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Give up on confirmation after this long; the blockhash has expired by
+/// then, so the transaction can no longer land
+const CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// Where a sent transaction stands on chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TxStatus {
+    /// Not yet seen by the cluster, or only processed
+    Pending,
+    Confirmed,
+    Finalized,
+    /// Executed with an error, or expired before landing
+    Failed,
+}
+
+impl TxStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TxStatus::Pending => "pending",
+            TxStatus::Confirmed => "confirmed",
+            TxStatus::Finalized => "finalized",
+            TxStatus::Failed => "failed",
+        }
+    }
+
+    /// Status right after a synchronous send confirmed at `commitment`
+    pub fn confirmed_at(commitment: CommitmentConfig) -> Self {
+        if commitment.is_finalized() {
+            TxStatus::Finalized
+        } else {
+            TxStatus::Confirmed
+        }
+    }
+
+    /// Whether a transaction in this status has reached `commitment` or
+    /// will never land, so there is nothing left to wait for
+    pub fn is_settled(&self, commitment: CommitmentConfig) -> bool {
+        match self {
+            TxStatus::Pending => false,
+            TxStatus::Confirmed => !commitment.is_finalized(),
+            TxStatus::Finalized | TxStatus::Failed => true,
+        }
+    }
+}
+
+/// Parse a commitment level, accepting only the values in `ALLOWED_COMMITMENTS`
+pub fn parse_commitment(value: &str) -> Option<CommitmentConfig> {
+    match value {
+        "processed" => Some(CommitmentConfig::processed()),
+        "confirmed" => Some(CommitmentConfig::confirmed()),
+        "finalized" => Some(CommitmentConfig::finalized()),
+        _ => None,
+    }
+}
+
+/// Optional compute-budget instructions prepended to every transaction
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ComputeBudgetConfig {
+    pub unit_limit: Option<u32>,
+    pub unit_price_microlamports: Option<u64>,
+}
+
+impl ComputeBudgetConfig {
+    /// Compute-budget instructions to prepend (empty when nothing is set)
+    pub fn instructions(&self) -> Vec<Instruction> {
+        let mut instructions = Vec::new();
+        if let Some(units) = self.unit_limit {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(units));
+        }
+        if let Some(price) = self.unit_price_microlamports {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_price(price));
+        }
+        instructions
+    }
+    
+    /// Fee for a transaction with `signatures` signatures that used `compute_units`.
+    /// The priority fee is charged on the requested unit limit when one is set,
+    /// so the limit takes the place of the simulated units then.
+    pub fn estimate_fee(&self, compute_units: u64, signatures: u64) -> FeeEstimate {
+        let billed_units = self.unit_limit.map(u64::from).unwrap_or(compute_units);
+        let price = u128::from(self.unit_price_microlamports.unwrap_or(0));
+        let priority_fee_lamports =
+            u64::try_from((price * u128::from(billed_units)).div_ceil(MICRO_LAMPORTS_PER_LAMPORT))
+                .unwrap_or(u64::MAX);
+        let base_fee_lamports = signatures.saturating_mul(LAMPORTS_PER_SIGNATURE);
+        FeeEstimate {
+            compute_units,
+            signatures,
+            base_fee_lamports,
+            priority_fee_lamports,
+            total_lamports: base_fee_lamports.saturating_add(priority_fee_lamports),
+        }
+    }
+}
+
+/// Base fee the runtime charges per transaction signature
+pub const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+
+/// Compute-unit prices are quoted in micro-lamports
+const MICRO_LAMPORTS_PER_LAMPORT: u128 = 1_000_000;
+
+/// Estimated cost of sending a transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct FeeEstimate {
+    /// Compute units the simulation consumed
+    pub compute_units: u64,
+    pub signatures: u64,
+    pub base_fee_lamports: u64,
+    pub priority_fee_lamports: u64,
+    pub total_lamports: u64,
+}
+
+/// Retry policy for transient RPC failures (HTTP 429s and timeouts)
+#[derive(Debug, Clone, Copy)]
+pub struct RpcRetryConfig {
+    /// Retries after the first attempt (0 disables retrying)
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for each one after it
+    pub base_delay: Duration,
+    /// Cap on a single delay
+    pub max_delay: Duration,
+}
+
+impl Default for RpcRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RpcRetryConfig {
+    /// Backoff before retry number `retry` (0-based), jittered to between
+    /// half and all of the exponential delay
+    pub fn delay(&self, retry: u32) -> Duration {
+        let backoff = self.base_delay
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay);
+        backoff.mul_f64(rand::thread_rng().gen_range(0.5..=1.0))
+    }
+}
+
+/// How long `send_and_confirm_transaction` waits for a confirmation, and how
+/// often it resubmits with a fresh blockhash when none arrives
+#[derive(Debug, Clone, Copy)]
+pub struct ConfirmationConfig {
+    /// Wait per submission before treating it as stuck
+    pub timeout: Duration,
+    /// Resubmissions after the first send (0 = surface the timeout immediately)
+    pub max_resubmits: u32,
+}
+
+impl Default for ConfirmationConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(60),
+            max_resubmits: 2,
+        }
+    }
+}
+
+/// A transaction that was never confirmed, even after resubmitting
+#[derive(Debug, thiserror::Error)]
+#[error("Transaction {signature} not confirmed after {attempts} attempts of {timeout:?} each")]
+pub struct ConfirmationTimeout {
+    /// Signature of the last submission
+    pub signature: Signature,
+    pub attempts: u32,
+    pub timeout: Duration,
+}
+
+/// An RPC call still rate limited or timing out once retries ran out
+#[derive(Debug, thiserror::Error)]
+#[error("RPC rate limited: {operation} failed after {attempts} attempts: {last_error}")]
+pub struct RpcRateLimited {
+    pub operation: &'static str,
+    pub attempts: u32,
+    pub last_error: String,
+}
+
+/// A burn or seize asking for more than the source token account holds
+#[derive(Debug, thiserror::Error)]
+#[error("Insufficient balance in {account}: {available} available, {requested} requested")]
+pub struct InsufficientBalance {
+    pub account: Pubkey,
+    pub available: u64,
+    pub requested: u64,
+}
+
+/// Whether an RPC error is worth retrying: HTTP 429s, timeouts and dropped connections
+pub fn is_transient_rpc_error(err: &ClientError) -> bool {
+    match err.kind() {
+        ClientErrorKind::Reqwest(e) => {
+            e.is_timeout() || e.is_connect() || e.status().map(|s| s.as_u16()) == Some(429)
+        }
+        ClientErrorKind::Io(_) => true,
+        _ => {
+            let msg = err.to_string().to_lowercase();
+            msg.contains("429") || msg.contains("too many requests") || msg.contains("timed out")
+        }
+    }
+}
 
 /// Solana service for interacting with the SSS token program
 pub struct SolanaService {
     rpc_client: Arc<RpcClient>,
     program_id: Pubkey,
     keypair: Arc<RwLock<Option<Keypair>>>,
+    compute_budget: ComputeBudgetConfig,
+    commitment: CommitmentConfig,
+    retry: RpcRetryConfig,
+    confirmation: ConfirmationConfig,
 }
 
 impl SolanaService {
@@ -48,15 +294,91 @@ impl SolanaService {
             rpc_client,
             program_id,
             keypair: Arc::new(RwLock::new(None)),
+            compute_budget: ComputeBudgetConfig::default(),
+            commitment,
+            retry: RpcRetryConfig::default(),
+            confirmation: ConfirmationConfig::default(),
         })
     }
     
+    /// Use a different default commitment for reads, sends and confirmations
+    pub fn with_commitment(mut self, commitment: CommitmentConfig) -> Self {
+        self.rpc_client = Arc::new(RpcClient::new_with_commitment(
+            self.rpc_client.url(),
+            commitment,
+        ));
+        self.commitment = commitment;
+        self
+    }
+    
+    /// Get the default commitment used when a call does not specify one
+    pub fn commitment(&self) -> CommitmentConfig {
+        self.commitment
+    }
+    
+    /// Prepend compute-budget instructions to transactions sent by this service
+    pub fn with_compute_budget(mut self, compute_budget: ComputeBudgetConfig) -> Self {
+        self.compute_budget = compute_budget;
+        self
+    }
+    
+    /// Retry rate-limited and timed-out RPC calls with this policy
+    pub fn with_retry(mut self, retry: RpcRetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+    
+    /// Time out and resubmit unconfirmed transactions with this policy
+    pub fn with_confirmation(mut self, confirmation: ConfirmationConfig) -> Self {
+        self.confirmation = confirmation;
+        self
+    }
+    
+    /// Run an RPC call, retrying transient failures with exponential backoff.
+    /// Gives up with `RpcRateLimited` once `max_retries` is used up.
+    async fn with_rpc_retry<T>(
+        &self,
+        operation: &'static str,
+        mut call: impl FnMut(&RpcClient) -> std::result::Result<T, ClientError>,
+    ) -> Result<T> {
+        let mut retry = 0;
+        loop {
+            match call(&self.rpc_client) {
+                Ok(value) => return Ok(value),
+                Err(e) if is_transient_rpc_error(&e) => {
+                    if retry >= self.retry.max_retries {
+                        return Err(RpcRateLimited {
+                            operation,
+                            attempts: retry + 1,
+                            last_error: e.to_string(),
+                        }.into());
+                    }
+                    let delay = self.retry.delay(retry);
+                    warn!("Failed to {} ({}), retrying in {:?}", operation, e, delay);
+                    tokio::time::sleep(delay).await;
+                    retry += 1;
+                }
+                Err(e) => return Err(anyhow::Error::new(e).context(format!("Failed to {}", operation))),
+            }
+        }
+    }
+    
     /// Set the authority keypair for signing transactions
     pub async fn set_keypair(&self, keypair: Keypair) {
         let mut kp = self.keypair.write().await;
         *kp = Some(keypair);
     }
     
+    /// Get the authority pubkey, if a keypair has been set
+    pub async fn authority_pubkey(&self) -> Option<Pubkey> {
+        self.keypair.read().await.as_ref().map(|kp| kp.pubkey())
+    }
+    
+    /// Sign `message` with the authority keypair, if one has been set
+    pub async fn sign_message(&self, message: &[u8]) -> Option<(Pubkey, Signature)> {
+        self.keypair.read().await.as_ref().map(|kp| (kp.pubkey(), kp.sign_message(message)))
+    }
+    
     /// Get the current program ID
     pub fn program_id(&self) -> &Pubkey {
         &self.program_id
@@ -101,64 +423,65 @@ impl SolanaService {
     
     /// Get the latest blockhash
     pub async fn get_latest_blockhash(&self) -> Result<Hash> {
-        self.rpc_client
-            .get_latest_blockhash()
-            .context("Failed to get latest blockhash")
+        self.with_rpc_retry("get latest blockhash", |rpc| rpc.get_latest_blockhash()).await
     }
     
     /// Find the stablecoin PDA
     pub fn find_stablecoin_pda(&self, asset_mint: &Pubkey) -> (Pubkey, u8) {
-        Pubkey::find_program_address(
-            &[VAULT_SEED, asset_mint.as_ref()],
-            &self.program_id,
-        )
+        constants::stablecoin_pda(asset_mint, &self.program_id)
     }
     
     /// Find the role assignment PDA
     pub fn find_role_pda(&self, stablecoin: &Pubkey, account: &Pubkey, role: &[u8]) -> (Pubkey, u8) {
-        Pubkey::find_program_address(
-            &[ROLE_SEED, stablecoin.as_ref(), account.as_ref(), role],
-            &self.program_id,
-        )
+        constants::role_pda(stablecoin, account, role, &self.program_id)
     }
     
     /// Find the minter info PDA
     pub fn find_minter_pda(&self, stablecoin: &Pubkey, minter: &Pubkey) -> (Pubkey, u8) {
-        Pubkey::find_program_address(
-            &[MINTER_SEED, stablecoin.as_ref(), minter.as_ref()],
-            &self.program_id,
-        )
+        constants::minter_pda(stablecoin, minter, &self.program_id)
     }
     
     /// Find the blacklist entry PDA
     pub fn find_blacklist_pda(&self, stablecoin: &Pubkey, account: &Pubkey) -> (Pubkey, u8) {
-        Pubkey::find_program_address(
-            &[BLACKLIST_SEED, stablecoin.as_ref(), account.as_ref()],
-            &self.program_id,
-        )
+        constants::blacklist_pda(stablecoin, account, &self.program_id)
+    }
+    
+    /// Find the allowlist entry PDA
+    pub fn find_allowlist_pda(&self, stablecoin: &Pubkey, account: &Pubkey) -> (Pubkey, u8) {
+        constants::allowlist_pda(stablecoin, account, &self.program_id)
+    }
+    
+    /// Find the reserve attestation PDA
+    pub fn find_reserve_attestation_pda(&self, stablecoin: &Pubkey) -> (Pubkey, u8) {
+        constants::reserve_attestation_pda(stablecoin, &self.program_id)
     }
     
     /// Find the freeze account PDA
     pub fn find_freeze_pda(&self, stablecoin: &Pubkey, account: &Pubkey) -> (Pubkey, u8) {
-        Pubkey::find_program_address(
-            &[b"freeze", stablecoin.as_ref(), account.as_ref()],
-            &self.program_id,
-        )
+        constants::freeze_pda(stablecoin, account, &self.program_id)
     }
     
-    /// Validate a Solana pubkey format (base58, 32-44 chars)
+    /// Validate a Solana pubkey: base58, 32 bytes, not the all-zeros default
     pub fn validate_pubkey(pubkey: &str) -> bool {
         if pubkey.len() < 32 || pubkey.len() > 44 {
             return false;
         }
-        pubkey.parse::<Pubkey>().is_ok()
+        pubkey.parse::<Pubkey>().is_ok_and(|key| key != Pubkey::default())
+    }
+    
+    /// Whether `pubkey` is an existing account owned by SPL Token or Token-2022.
+    /// Wallets (system-owned) and missing accounts are not token accounts.
+    pub async fn is_token_account(&self, pubkey: &Pubkey) -> Result<bool> {
+        let account = self
+            .with_rpc_retry("get account", |rpc| rpc.get_account_with_commitment(pubkey, self.commitment))
+            .await?
+            .value;
+        Ok(account.is_some_and(|account| TOKEN_PROGRAM_IDS.contains(&account.owner)))
     }
     
     /// Get account data as raw bytes
     pub async fn get_account_data(&self, pubkey: &Pubkey) -> Result<Vec<u8>> {
-        self.rpc_client
-            .get_account_data(pubkey)
-            .context("Failed to get account data")
+        self.with_rpc_retry("get account data", |rpc| rpc.get_account_data(pubkey)).await
     }
     
     /// Check if an account exists
@@ -168,46 +491,310 @@ impl SolanaService {
     
     /// Get multiple accounts in a batch
     pub async fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> Result<Vec<Option<Vec<u8>>>> {
-        let accounts = self.rpc_client
-            .get_multiple_accounts(pubkeys)
-            .context("Failed to get multiple accounts")?;
+        let accounts = self
+            .with_rpc_retry("get multiple accounts", |rpc| rpc.get_multiple_accounts(pubkeys))
+            .await?;
         
         Ok(accounts.into_iter().map(|opt| opt.map(|acc| acc.data)).collect())
     }
     
-    /// Send a transaction and return the signature
-    pub async fn send_transaction(&self, transaction: Transaction) -> Result<Signature> {
-        let signature = self.rpc_client
-            .send_transaction_with_config(
-                &transaction,
-                RpcSendTransactionConfig {
-                    skip_preflight: false,
-                    preflight_commitment: Some(CommitmentConfig::confirmed().commitment),
+    /// Whether each of `accounts` has a blacklist entry, fetched in one
+    /// `getMultipleAccounts` round-trip (the RPC serves at most 100 per call)
+    pub async fn batch_blacklist_status(&self, stablecoin: &Pubkey, accounts: &[Pubkey]) -> Result<Vec<bool>> {
+        let pdas: Vec<Pubkey> = accounts
+            .iter()
+            .map(|account| self.find_blacklist_pda(stablecoin, account).0)
+            .collect();
+        let entries = self.get_multiple_accounts(&pdas).await?;
+        Ok(entries.iter().map(Option::is_some).collect())
+    }
+    
+    /// Fetch all role assignments for a stablecoin.
+    ///
+    /// `RoleAssignment` does not store its stablecoin, so accounts are filtered
+    /// by discriminator on the RPC side and matched to the stablecoin by PDA here.
+    pub async fn get_roles_for_stablecoin(&self, stablecoin: &Pubkey) -> Result<Vec<RoleAssignmentAccount>> {
+        let accounts = self.rpc_client
+            .get_program_accounts_with_config(
+                &self.program_id,
+                RpcProgramAccountsConfig {
+                    filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                        0,
+                        account_discriminator("RoleAssignment").to_vec(),
+                    ))]),
                     ..Default::default()
                 },
             )
-            .context("Failed to send transaction")?;
+            .context("Failed to fetch role assignments")?;
+        
+        Ok(decode_role_assignments(
+            &self.program_id,
+            stablecoin,
+            accounts.into_iter().map(|(pubkey, account)| (pubkey, account.data)),
+        ))
+    }
+    
+    /// Count token accounts of `mint` with a non-zero balance.
+    /// Scans with `getProgramAccounts` under whichever token program owns the mint.
+    pub async fn count_token_holders(&self, mint: &Pubkey) -> Result<u64> {
+        let token_program = self
+            .with_rpc_retry("get account", |rpc| rpc.get_account(mint))
+            .await?
+            .owner;
+        let scans = token_account_filters(&token_program, mint)
+            .with_context(|| format!("Mint {} is not owned by a token program", mint))?;
+        
+        let mut holders = 0;
+        for filters in scans {
+            let accounts = self
+                .with_rpc_retry("get token accounts", |rpc| {
+                    rpc.get_program_accounts_with_config(
+                        &token_program,
+                        RpcProgramAccountsConfig {
+                            filters: Some(filters.clone()),
+                            ..Default::default()
+                        },
+                    )
+                })
+                .await?;
+            holders += accounts
+                .iter()
+                .filter(|(_, account)| token_account_amount(&account.data).is_some_and(|amount| amount > 0))
+                .count() as u64;
+        }
+        Ok(holders)
+    }
+    
+    /// Send a transaction and return the signature.
+    /// Preflight runs at `commitment`, or the service default when `None`.
+    pub async fn send_transaction(
+        &self,
+        transaction: Transaction,
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<Signature> {
+        let commitment = commitment.unwrap_or(self.commitment);
+        // Resending the same signed transaction is safe: it has one signature
+        let signature = self
+            .with_rpc_retry("send transaction", |rpc| {
+                rpc.send_transaction_with_config(
+                    &transaction,
+                    RpcSendTransactionConfig {
+                        skip_preflight: false,
+                        preflight_commitment: Some(commitment.commitment),
+                        ..Default::default()
+                    },
+                )
+            })
+            .await?;
         
         info!("Transaction sent: {}", signature);
         Ok(signature)
     }
     
-    /// Send a transaction and wait for confirmation
-    pub async fn send_and_confirm_transaction(&self, transaction: Transaction) -> Result<Signature> {
-        let signature = self.rpc_client
-            .send_and_confirm_transaction_with_spinner(&transaction)
-            .context("Failed to send and confirm transaction")?;
+    /// Send a transaction and wait until it reaches `commitment`,
+    /// or the service default when `None`.
+    /// The outcome and confirmation latency are recorded under `operation`.
+    ///
+    /// An already-signed transaction cannot take a fresh blockhash, so it is
+    /// not resubmitted: it fails with `ConfirmationTimeout` once its blockhash
+    /// expires unconfirmed.
+    pub async fn send_and_confirm_transaction(
+        &self,
+        operation: &str,
+        transaction: Transaction,
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<Signature> {
+        self.confirm_recording(operation, transaction, None, commitment).await
+    }
+    
+    /// Run `send_and_confirm` and record its outcome and latency under `operation`
+    async fn confirm_recording(
+        &self,
+        operation: &str,
+        transaction: Transaction,
+        resign: Option<(&[Instruction], &[&Keypair])>,
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<Signature> {
+        let commitment = commitment.unwrap_or(self.commitment);
+        let started = Instant::now();
+        let result = self.send_and_confirm(transaction, resign, commitment).await;
+        record_transaction(operation, result.is_ok(), started.elapsed());
+        let signature = result?;
         
-        info!("Transaction confirmed: {}", signature);
+        info!("Transaction confirmed ({:?}): {}", commitment.commitment, signature);
         Ok(signature)
     }
     
+    /// Send `transaction` and poll it for up to the configured timeout. When it
+    /// stays unconfirmed and `resign` is given, re-sign those instructions
+    /// against a fresh blockhash and resubmit, up to `max_resubmits` times.
+    ///
+    /// A slow transaction can still land until its blockhash expires, so each
+    /// resubmission first waits that out; only one copy can ever execute.
+    async fn send_and_confirm(
+        &self,
+        mut transaction: Transaction,
+        resign: Option<(&[Instruction], &[&Keypair])>,
+        commitment: CommitmentConfig,
+    ) -> Result<Signature> {
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let signature = self.send_transaction(transaction.clone(), Some(commitment)).await?;
+            
+            let deadline = Instant::now() + self.confirmation.timeout;
+            while Instant::now() < deadline {
+                if self.signature_settled(&signature, commitment).await? == Some(true) {
+                    return Ok(signature);
+                }
+                tokio::time::sleep(CONFIRMATION_POLL_INTERVAL).await;
+            }
+            
+            warn!("Transaction {} not confirmed after {:?}, waiting for its blockhash to expire",
+                signature, self.confirmation.timeout);
+            if self.await_blockhash_expiry(&signature, &transaction.message.recent_blockhash, commitment).await? {
+                return Ok(signature);
+            }
+            
+            let Some((instructions, signers)) = resign.filter(|_| attempts <= self.confirmation.max_resubmits) else {
+                return Err(ConfirmationTimeout {
+                    signature,
+                    attempts,
+                    timeout: self.confirmation.timeout,
+                }.into());
+            };
+            warn!("Resubmitting expired transaction {} with a fresh blockhash", signature);
+            transaction = self.build_signed_transaction(instructions.to_vec(), signers).await?;
+        }
+    }
+    
+    /// Whether `signature` has reached `commitment`: `None` if the cluster has
+    /// not seen it, an error if it executed and failed
+    async fn signature_settled(&self, signature: &Signature, commitment: CommitmentConfig) -> Result<Option<bool>> {
+        let statuses = self
+            .with_rpc_retry("get signature status", |rpc| rpc.get_signature_statuses(&[*signature]))
+            .await?;
+        let Some(status) = statuses.value.into_iter().next().flatten() else {
+            return Ok(None);
+        };
+        if let Some(err) = &status.err {
+            anyhow::bail!("Transaction {} failed: {}", signature, err);
+        }
+        Ok(Some(status.satisfies_commitment(commitment)))
+    }
+    
+    /// Wait until `blockhash` can no longer land a transaction, returning
+    /// whether `signature` reached `commitment` in the meantime. A signature
+    /// seen but not yet at `commitment` is waited on, since it may still settle.
+    async fn await_blockhash_expiry(
+        &self,
+        signature: &Signature,
+        blockhash: &Hash,
+        commitment: CommitmentConfig,
+    ) -> Result<bool> {
+        loop {
+            // Check validity before the status, so a transaction processed just
+            // before expiry is still seen below
+            let valid = self
+                .with_rpc_retry("check blockhash", |rpc| {
+                    rpc.is_blockhash_valid(blockhash, CommitmentConfig::processed())
+                })
+                .await?;
+            match self.signature_settled(signature, commitment).await? {
+                Some(true) => return Ok(true),
+                None if !valid => return Ok(false),
+                _ => {}
+            }
+            tokio::time::sleep(CONFIRMATION_POLL_INTERVAL).await;
+        }
+    }
+    
+    /// Current status of `signature`, searching history so older
+    /// transactions are found too
+    pub async fn get_transaction_status(&self, signature: &Signature) -> Result<TxStatus> {
+        let statuses = self
+            .with_rpc_retry("get signature status", |rpc| {
+                rpc.get_signature_statuses_with_history(&[*signature])
+            })
+            .await?;
+        
+        Ok(match statuses.value.into_iter().next().flatten() {
+            None => TxStatus::Pending,
+            Some(status) if status.err.is_some() => TxStatus::Failed,
+            Some(status) if status.satisfies_commitment(CommitmentConfig::finalized()) => TxStatus::Finalized,
+            Some(status) if status.satisfies_commitment(CommitmentConfig::confirmed()) => TxStatus::Confirmed,
+            Some(_) => TxStatus::Pending,
+        })
+    }
+    
+    /// Poll a transaction sent at `sent_at` until it settles at the service
+    /// commitment, or mark it failed after `CONFIRMATION_TIMEOUT`.
+    /// The outcome and confirmation latency are recorded under `operation`.
+    pub async fn await_confirmation(
+        &self,
+        operation: &str,
+        signature: &Signature,
+        sent_at: Instant,
+    ) -> TxStatus {
+        let status = loop {
+            match self.get_transaction_status(signature).await {
+                Ok(status) if status.is_settled(self.commitment) => break status,
+                Ok(_) => {}
+                Err(e) => warn!("Failed to poll status of {}: {}", signature, e),
+            }
+            if sent_at.elapsed() >= CONFIRMATION_TIMEOUT {
+                warn!("Transaction {} not confirmed after {:?}", signature, CONFIRMATION_TIMEOUT);
+                break TxStatus::Failed;
+            }
+            tokio::time::sleep(CONFIRMATION_POLL_INTERVAL).await;
+        };
+        
+        record_transaction(operation, status != TxStatus::Failed, sent_at.elapsed());
+        info!("Transaction {} settled: {}", signature, status.as_str());
+        status
+    }
+    
     /// Build and send a transaction with instructions
     pub async fn build_and_send_instruction(
+        &self,
+        operation: &str,
+        instructions: Vec<Instruction>,
+        signers: &[&Keypair],
+    ) -> Result<Signature> {
+        self.build_and_send_instruction_with_commitment(operation, instructions, signers, None).await
+    }
+    
+    /// Build and send a transaction, confirming it at `commitment`
+    /// (the service default when `None`)
+    pub async fn build_and_send_instruction_with_commitment(
+        &self,
+        operation: &str,
+        instructions: Vec<Instruction>,
+        signers: &[&Keypair],
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<Signature> {
+        let transaction = self.build_signed_transaction(instructions.clone(), signers).await?;
+        self.confirm_recording(operation, transaction, Some((instructions.as_slice(), signers)), commitment).await
+    }
+    
+    /// Build and send a transaction without waiting for confirmation;
+    /// follow up with `await_confirmation`
+    pub async fn build_and_send_instruction_unconfirmed(
         &self,
         instructions: Vec<Instruction>,
         signers: &[&Keypair],
     ) -> Result<Signature> {
+        let transaction = self.build_signed_transaction(instructions, signers).await?;
+        self.send_transaction(transaction, None).await
+    }
+    
+    /// Sign `instructions` (after the compute-budget ones) with the authority
+    /// as fee payer plus `signers`, against the latest blockhash
+    async fn build_signed_transaction(
+        &self,
+        instructions: Vec<Instruction>,
+        signers: &[&Keypair],
+    ) -> Result<Transaction> {
         let keypair_guard = self.keypair.read().await;
         let authority = keypair_guard.as_ref()
             .context("No authority keypair set")?;
@@ -217,13 +804,14 @@ impl SolanaService {
         let mut all_signers: Vec<&Keypair> = vec![authority];
         all_signers.extend(signers);
         
-        let transaction = Transaction::new(
+        let mut all_instructions = self.compute_budget.instructions();
+        all_instructions.extend(instructions);
+        
+        Ok(Transaction::new(
             &all_signers,
-            Message::new_with_blockhash(&instructions, Some(&authority.pubkey()), &latest_blockhash),
+            Message::new_with_blockhash(&all_instructions, Some(&authority.pubkey()), &latest_blockhash),
             latest_blockhash,
-        );
-        
-        self.send_and_confirm_transaction(transaction).await
+        ))
     }
     
     /// Build a mint instruction for the SSS token program
@@ -238,11 +826,13 @@ impl SolanaService {
         role_assignment: Option<(&Pubkey, u8)>,
         minter_info: Option<(&Pubkey, u8)>,
         token_program: &Pubkey,
+        fee_treasury: Option<&Pubkey>,
+        reserve_attestation: Option<&Pubkey>,
     ) -> Instruction {
         let mut accounts = vec![
             AccountMeta::new(*authority, true),
             AccountMeta::new(*stablecoin, false),
-            AccountMeta::new_readonly(*asset_mint, false),
+            AccountMeta::new(*asset_mint, false),
             AccountMeta::new(*recipient_token_account, false),
             AccountMeta::new_readonly(*token_program, false),
         ];
@@ -252,7 +842,7 @@ impl SolanaService {
             accounts.insert(2, AccountMeta::new_readonly(*role_pda, false));
         } else {
             // Insert placeholder for optional account
-            accounts.insert(2, AccountMeta::new_readonly(system_program::ID, false));
+            accounts.insert(2, AccountMeta::new_readonly(self.program_id, false));
         }
         
         // Add minter info PDA if provided
@@ -260,7 +850,21 @@ impl SolanaService {
             accounts.insert(3, AccountMeta::new(*minter_pda, false));
         } else {
             // Insert placeholder for optional account
-            accounts.insert(3, AccountMeta::new_readonly(system_program::ID, false));
+            accounts.insert(3, AccountMeta::new_readonly(self.program_id, false));
+        }
+        
+        // Fee treasury, required when the stablecoin charges a mint fee
+        if let Some(fee_treasury) = fee_treasury {
+            accounts.push(AccountMeta::new(*fee_treasury, false));
+        }
+        
+        // Reserve attestation, required when mints are reserve-backed
+        if let Some(reserve_attestation) = reserve_attestation {
+            if fee_treasury.is_none() {
+                // Insert placeholder for optional account
+                accounts.push(AccountMeta::new_readonly(self.program_id, false));
+            }
+            accounts.push(AccountMeta::new_readonly(*reserve_attestation, false));
         }
         
         Instruction {
@@ -280,11 +884,12 @@ impl SolanaService {
         amount: u64,
         role_assignment: Option<(&Pubkey, u8)>,
         token_program: &Pubkey,
+        fee_treasury: Option<&Pubkey>,
     ) -> Instruction {
         let mut accounts = vec![
             AccountMeta::new(*authority, true),
             AccountMeta::new(*stablecoin, false),
-            AccountMeta::new_readonly(*asset_mint, false),
+            AccountMeta::new(*asset_mint, false),
             AccountMeta::new(*from_token_account, false),
             AccountMeta::new_readonly(*token_program, false),
         ];
@@ -294,7 +899,12 @@ impl SolanaService {
             accounts.insert(2, AccountMeta::new_readonly(*role_pda, false));
         } else {
             // Insert placeholder for optional account
-            accounts.insert(2, AccountMeta::new_readonly(system_program::ID, false));
+            accounts.insert(2, AccountMeta::new_readonly(self.program_id, false));
+        }
+        
+        // Fee treasury, required when the stablecoin charges a burn fee
+        if let Some(fee_treasury) = fee_treasury {
+            accounts.push(AccountMeta::new(*fee_treasury, false));
         }
         
         Instruction {
@@ -347,6 +957,231 @@ impl SolanaService {
         }
     }
     
+    /// Build an add to allowlist instruction (master authority only)
+    pub fn build_add_allowlist_instruction(
+        &self,
+        stablecoin: &Pubkey,
+        authority: &Pubkey,
+        account: &Pubkey,
+    ) -> Instruction {
+        let mut accounts = self.allowlist_accounts(stablecoin, authority, account);
+        accounts.push(AccountMeta::new_readonly(system_program::ID, false));
+        Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: AddAllowlistInstruction.data(),
+        }
+    }
+    
+    /// Build a remove from allowlist instruction (master authority only)
+    pub fn build_remove_allowlist_instruction(
+        &self,
+        stablecoin: &Pubkey,
+        authority: &Pubkey,
+        account: &Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: self.allowlist_accounts(stablecoin, authority, account),
+            data: RemoveAllowlistInstruction.data(),
+        }
+    }
+    
+    fn allowlist_accounts(&self, stablecoin: &Pubkey, authority: &Pubkey, account: &Pubkey) -> Vec<AccountMeta> {
+        let (entry, _bump) = self.find_allowlist_pda(stablecoin, account);
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*stablecoin, false),
+            AccountMeta::new(entry, false),
+            AccountMeta::new_readonly(*account, false),
+        ]
+    }
+    
+    /// Build a set_allowlist_mode instruction (master authority only)
+    pub fn build_set_allowlist_mode_instruction(
+        &self,
+        stablecoin: &Pubkey,
+        authority: &Pubkey,
+        enabled: bool,
+    ) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*stablecoin, false),
+            ],
+            data: SetAllowlistModeInstruction { enabled }.data(),
+        }
+    }
+    
+    /// Build a set_max_transfer instruction (amount 0 removes the cap)
+    pub fn build_set_max_transfer_instruction(
+        &self,
+        stablecoin: &Pubkey,
+        authority: &Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*stablecoin, false),
+            ],
+            data: SetMaxTransferInstruction { amount }.data(),
+        }
+    }
+    
+    /// Build a set_max_supply instruction (0 removes the cap)
+    pub fn build_set_max_supply_instruction(
+        &self,
+        stablecoin: &Pubkey,
+        authority: &Pubkey,
+        max_supply: u64,
+    ) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*stablecoin, false),
+            ],
+            data: SetMaxSupplyInstruction { max_supply }.data(),
+        }
+    }
+    
+    /// Build a set_compliance instruction (master authority only)
+    pub fn build_set_compliance_instruction(
+        &self,
+        stablecoin: &Pubkey,
+        authority: &Pubkey,
+        enabled: bool,
+        upgrade_preset: bool,
+    ) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*stablecoin, false),
+            ],
+            data: SetComplianceInstruction { enabled, upgrade_preset }.data(),
+        }
+    }
+    
+    /// Build a pause instruction (master authority only)
+    pub fn build_pause_instruction(
+        &self,
+        stablecoin: &Pubkey,
+        authority: &Pubkey,
+        reason: String,
+        until: i64,
+    ) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*stablecoin, false),
+            ],
+            data: PauseInstruction { reason, until }.data(),
+        }
+    }
+    
+    /// Build an unpause instruction (master authority only)
+    pub fn build_unpause_instruction(
+        &self,
+        stablecoin: &Pubkey,
+        authority: &Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*stablecoin, false),
+            ],
+            data: UnpauseInstruction.data(),
+        }
+    }
+    
+    /// Build a transfer_authority instruction (master authority only)
+    pub fn build_transfer_authority_instruction(
+        &self,
+        stablecoin: &Pubkey,
+        authority: &Pubkey,
+        new_authority: &Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*stablecoin, false),
+            ],
+            data: TransferAuthorityInstruction { new_authority: *new_authority }.data(),
+        }
+    }
+    
+    /// Build a seize instruction. `blacklist_entry` is the blacklist PDA of
+    /// `from_token_account` or of its owner.
+    #[allow(clippy::too_many_arguments)]
+    pub fn build_seize_instruction(
+        &self,
+        stablecoin: &Pubkey,
+        asset_mint: &Pubkey,
+        authority: &Pubkey,
+        role_assignment: Option<&Pubkey>,
+        from_token_account: &Pubkey,
+        to_token_account: &Pubkey,
+        blacklist_entry: &Pubkey,
+        amount: u64,
+        token_program: &Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*stablecoin, false),
+                // Anchor reads the program ID as `None` for optional accounts
+                AccountMeta::new_readonly(*role_assignment.unwrap_or(&self.program_id), false),
+                AccountMeta::new(*asset_mint, false),
+                AccountMeta::new(*from_token_account, false),
+                AccountMeta::new(*to_token_account, false),
+                AccountMeta::new_readonly(*blacklist_entry, false),
+                AccountMeta::new_readonly(*token_program, false),
+            ],
+            data: SeizeInstruction { amount }.data(),
+        }
+    }
+    
+    /// Build an unsigned message for offline signing.
+    ///
+    /// With a nonce account the message advances it first and uses its durable
+    /// nonce instead of a recent blockhash, so it stays valid until submitted.
+    pub async fn build_unsigned_message(
+        &self,
+        instructions: Vec<Instruction>,
+        fee_payer: &Pubkey,
+        nonce_account: Option<&Pubkey>,
+    ) -> Result<Message> {
+        let mut all_instructions = self.compute_budget.instructions();
+        all_instructions.extend(instructions);
+        
+        let Some(nonce_account) = nonce_account else {
+            let latest_blockhash = self.get_latest_blockhash().await?;
+            return Ok(Message::new_with_blockhash(&all_instructions, Some(fee_payer), &latest_blockhash));
+        };
+        
+        let account = nonce_utils::get_account_with_commitment(&self.rpc_client, nonce_account, self.commitment)
+            .context("Failed to fetch nonce account")?;
+        let nonce = nonce_utils::data_from_account(&account)
+            .context("Account is not an initialized nonce account")?;
+        
+        let mut message = Message::new_with_nonce(
+            all_instructions,
+            Some(fee_payer),
+            nonce_account,
+            &nonce.authority,
+        );
+        message.recent_blockhash = nonce.blockhash();
+        Ok(message)
+    }
+    
     /// Get token account balance (returns raw amount)
     pub async fn get_token_account_balance(&self, token_account: &Pubkey) -> Result<u64> {
         let balance = self.rpc_client
@@ -357,6 +1192,16 @@ impl SolanaService {
             .context("Failed to parse token balance")
     }
     
+    /// Fail with `InsufficientBalance` when `token_account` holds less than
+    /// `requested`, before a transaction is sent for the token program to reject
+    pub async fn require_token_balance(&self, token_account: &Pubkey, requested: u64) -> Result<()> {
+        let available = self.get_token_account_balance(token_account).await?;
+        if available < requested {
+            return Err(InsufficientBalance { account: *token_account, available, requested }.into());
+        }
+        Ok(())
+    }
+    
     /// Confirm a transaction by signature
     pub async fn confirm_transaction(&self, signature: &Signature) -> Result<bool> {
         let result = self.rpc_client
@@ -372,8 +1217,8 @@ impl SolanaService {
         }
     }
     
-    /// Simulate a transaction without sending it
-    pub async fn simulate_transaction(&self, transaction: &Transaction) -> Result<()> {
+    /// Simulate a transaction without sending it and return the compute units it consumed
+    pub async fn simulate_transaction(&self, transaction: &Transaction) -> Result<u64> {
         let result = self.rpc_client
             .simulate_transaction(transaction)
             .context("Failed to simulate transaction")?;
@@ -383,7 +1228,16 @@ impl SolanaService {
             anyhow::bail!("Simulation failed: {:?}, logs: {:?}", err, logs);
         }
         
-        Ok(())
+        result.value.units_consumed.context("Simulation did not report compute units")
+    }
+    
+    /// Estimate the fee of sending `instructions` the way `build_and_send_instruction`
+    /// would: signed by the authority, after the compute-budget instructions
+    pub async fn estimate_fee(&self, instructions: Vec<Instruction>) -> Result<FeeEstimate> {
+        let transaction = self.build_signed_transaction(instructions, &[]).await?;
+        let compute_units = self.simulate_transaction(&transaction).await?;
+        let signatures = u64::from(transaction.message.header.num_required_signatures);
+        Ok(self.compute_budget.estimate_fee(compute_units, signatures))
     }
 }
 
@@ -406,6 +1260,99 @@ struct AddBlacklistInstruction {
 #[derive(AnchorSerialize, AnchorDeserialize)]
 struct RemoveBlacklistInstruction;
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct AddAllowlistInstruction;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct RemoveAllowlistInstruction;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct SetAllowlistModeInstruction {
+    enabled: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct SetMaxTransferInstruction {
+    amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct SetMaxSupplyInstruction {
+    max_supply: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct SetComplianceInstruction {
+    enabled: bool,
+    upgrade_preset: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct PauseInstruction {
+    reason: String,
+    until: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct UnpauseInstruction;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct TransferAuthorityInstruction {
+    new_authority: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct SeizeInstruction {
+    amount: u64,
+}
+
+/// Anchor account discriminator: sha256("account:<Name>")[0..8]
+pub fn account_discriminator(name: &str) -> [u8; 8] {
+    use sha2::{Digest, Sha256};
+    let hash = Sha256::digest(format!("account:{}", name).as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash[..8]);
+    discriminator
+}
+
+/// Decode raw `RoleAssignment` accounts, keeping only those whose address is the
+/// role PDA for `stablecoin`. Accounts that fail to decode are logged and skipped.
+pub fn decode_role_assignments(
+    program_id: &Pubkey,
+    stablecoin: &Pubkey,
+    accounts: impl IntoIterator<Item = (Pubkey, Vec<u8>)>,
+) -> Vec<RoleAssignmentAccount> {
+    let discriminator = account_discriminator("RoleAssignment");
+    accounts
+        .into_iter()
+        .filter_map(|(pubkey, data)| {
+            if data.len() < 8 || data[..8] != discriminator {
+                warn!("Skipping {}: not a RoleAssignment account", pubkey);
+                return None;
+            }
+            let assignment = match RoleAssignmentAccount::deserialize(&mut &data[8..]) {
+                Ok(assignment) => assignment,
+                Err(e) => {
+                    warn!("Skipping role assignment {}: {}", pubkey, e);
+                    return None;
+                }
+            };
+            let expected = Pubkey::create_program_address(
+                &[
+                    ROLE_SEED,
+                    stablecoin.as_ref(),
+                    assignment.account.as_ref(),
+                    assignment.role.to_seed(),
+                    &[assignment.bump],
+                ],
+                program_id,
+            )
+            .ok()?;
+            (expected == pubkey).then_some(assignment)
+        })
+        .collect()
+}
+
 /// Helper to parse a Pubkey from string
 pub fn parse_pubkey(s: &str) -> Result<Pubkey> {
     s.parse::<Pubkey>()
@@ -421,6 +1368,150 @@ pub fn parse_keypair(s: &str) -> Result<Keypair> {
         .context("Invalid keypair bytes")
 }
 
+/// `getProgramAccounts` filter sets that together select every token account of `mint`,
+/// or `None` when `token_program` is neither SPL Token nor Token-2022.
+///
+/// SPL Token accounts are always 165 bytes. Token-2022 accounts are 165 bytes
+/// without extensions, or longer with the account type byte at offset 165, so
+/// they take two scans. The two sets never match the same account.
+pub fn token_account_filters(token_program: &Pubkey, mint: &Pubkey) -> Option<Vec<Vec<RpcFilterType>>> {
+    let mint_filter = RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, mint.to_bytes().to_vec()));
+    let base_size = RpcFilterType::DataSize(TOKEN_ACCOUNT_BASE_LEN as u64);
+    
+    if *token_program == TOKEN_PROGRAM_IDS[0] {
+        Some(vec![vec![mint_filter, base_size]])
+    } else if *token_program == TOKEN_PROGRAM_IDS[1] {
+        let extended = RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            TOKEN_ACCOUNT_BASE_LEN,
+            vec![ACCOUNT_TYPE_ACCOUNT],
+        ));
+        Some(vec![vec![mint_filter.clone(), base_size], vec![mint_filter, extended]])
+    } else {
+        None
+    }
+}
+
+/// Balance of a raw SPL Token or Token-2022 account
+fn token_account_amount(data: &[u8]) -> Option<u64> {
+    let bytes = data.get(TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_OFFSET + 8)?;
+    Some(u64::from_le_bytes(bytes.try_into().ok()?))
+}
+
+/// Derive the associated token account of `owner` for `mint` under `token_program`
+pub fn find_associated_token_address(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    ).0
+}
+
+/// Build an SPL Memo instruction. Every key in `signers` must also sign the transaction.
+pub fn build_memo_instruction(memo: &str, signers: &[Pubkey]) -> Instruction {
+    Instruction {
+        program_id: MEMO_PROGRAM_ID,
+        accounts: signers.iter().map(|signer| AccountMeta::new_readonly(*signer, true)).collect(),
+        data: memo.as_bytes().to_vec(),
+    }
+}
+
+/// Build a token transfer from `from` to `to` (both token accounts), signed by
+/// `owner`. A memo, when given, is prepended so it is recorded with the transfer.
+#[allow(clippy::too_many_arguments)]
+pub fn build_transfer_instructions(
+    from: &Pubkey,
+    to: &Pubkey,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+    decimals: u8,
+    token_program: &Pubkey,
+    memo: Option<&str>,
+) -> Vec<Instruction> {
+    let mut data = vec![TRANSFER_CHECKED_TAG];
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(decimals);
+    let transfer = Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(*from, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(*to, false),
+            AccountMeta::new_readonly(*owner, true),
+        ],
+        data,
+    };
+    memo.map(|memo| build_memo_instruction(memo, &[*owner]))
+        .into_iter()
+        .chain(std::iter::once(transfer))
+        .collect()
+}
+
+/// Build the Associated Token Account program's `CreateIdempotent` instruction,
+/// which succeeds without changes when the account already exists
+pub fn build_create_associated_token_account_idempotent(
+    payer: &Pubkey,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(find_associated_token_address(owner, mint, token_program), false),
+            AccountMeta::new_readonly(*owner, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        // AssociatedTokenAccountInstruction::CreateIdempotent
+        data: vec![1],
+    }
+}
+
+/// Pubkeys that must sign `message`, in signature order
+pub fn required_signers(message: &Message) -> Vec<Pubkey> {
+    let count = message.header.num_required_signatures as usize;
+    message.account_keys.iter().take(count).copied().collect()
+}
+
+/// Check an offline-signed transaction before relaying it.
+///
+/// Every signature must be present and valid, every instruction must target
+/// the SSS program, the compute budget program or the system program (nonce
+/// advance), and at least one SSS instruction must reference `stablecoin`.
+pub fn validate_offline_transaction(
+    transaction: &Transaction,
+    program_id: &Pubkey,
+    stablecoin: &Pubkey,
+) -> std::result::Result<(), String> {
+    if transaction.verify().is_err() {
+        return Err("Transaction is missing signatures or has an invalid signature".to_string());
+    }
+    
+    let keys = &transaction.message.account_keys;
+    let mut targets_stablecoin = false;
+    for instruction in &transaction.message.instructions {
+        let program = keys
+            .get(instruction.program_id_index as usize)
+            .ok_or("Instruction references a missing program account")?;
+        
+        if program == program_id {
+            targets_stablecoin |= instruction
+                .accounts
+                .iter()
+                .any(|&index| keys.get(index as usize) == Some(stablecoin));
+        } else if *program != compute_budget::id() && *program != system_program::ID {
+            return Err(format!("Instructions for program {} are not allowed", program));
+        }
+    }
+    
+    if !targets_stablecoin {
+        return Err("Transaction does not operate on this stablecoin".to_string());
+    }
+    Ok(())
+}
+
 /// Generate an explorer URL for a transaction
 pub fn explorer_url(signature: &str, cluster: &str) -> String {
     match cluster {
@@ -451,14 +1542,27 @@ pub enum Role {
 }
 
 impl Role {
+    pub const ALL: [Role; 6] = [
+        Role::Master,
+        Role::Minter,
+        Role::Burner,
+        Role::Blacklister,
+        Role::Pauser,
+        Role::Seizer,
+    ];
+
     pub fn to_seed(&self) -> &'static [u8] {
+        self.as_str().as_bytes()
+    }
+
+    pub fn as_str(&self) -> &'static str {
         match self {
-            Role::Master => b"master",
-            Role::Minter => b"minter",
-            Role::Burner => b"burner",
-            Role::Blacklister => b"blacklister",
-            Role::Pauser => b"pauser",
-            Role::Seizer => b"seizer",
+            Role::Master => "master",
+            Role::Minter => "minter",
+            Role::Burner => "burner",
+            Role::Blacklister => "blacklister",
+            Role::Pauser => "pauser",
+            Role::Seizer => "seizer",
         }
     }
 }
@@ -473,6 +1577,51 @@ pub struct StablecoinStateAccount {
     pub preset: u8,
     pub compliance_enabled: bool,
     pub bump: u8,
+    pub max_transfer_amount: u64,
+    pub fee_bps: u16,
+    pub fee_treasury: Pubkey,
+    pub minter_count: u32,
+    pub blacklist_count: u32,
+    pub max_supply: u64,
+    pub min_burn_amount: u64,
+    pub min_mint_amount: u64,
+    pub reserve_backed: bool,
+    pub allowlist_mode: bool,
+    pub paused_by: Pubkey,
+    pub paused_at: i64,
+    /// When the pause lifts on its own (0 = until unpaused)
+    pub pause_until: i64,
+    pub pause_reason_len: u8,
+    /// Zero-padded; read it with `pause_reason()`
+    pub pause_reason: [u8; constants::MAX_REASON_LENGTH],
+    pub role_count: u32,
+    pub minter_count_verified: bool,
+    pub role_count_verified: bool,
+    pub _reserved: [u8; 58],
+}
+
+impl StablecoinStateAccount {
+    /// Whether operations are paused at `now`, mirroring the program:
+    /// a scheduled pause stops applying at `pause_until`
+    pub fn is_paused(&self, now: i64) -> bool {
+        self.paused && (self.pause_until == 0 || now < self.pause_until)
+    }
+
+    /// The operator-supplied pause reason
+    pub fn pause_reason(&self) -> String {
+        let len = usize::from(self.pause_reason_len).min(self.pause_reason.len());
+        String::from_utf8_lossy(&self.pause_reason[..len]).into_owned()
+    }
+}
+
+/// On-chain FreezeRecord account structure
+#[derive(Debug, Clone, AnchorDeserialize)]
+pub struct FreezeRecordAccount {
+    pub account: Pubkey,
+    pub frozen_by: Pubkey,
+    pub frozen_at: i64,
+    pub reason: String,
+    pub bump: u8,
 }
 
 /// On-chain BlacklistEntry account structure
@@ -485,6 +1634,15 @@ pub struct BlacklistEntryAccount {
     pub bump: u8,
 }
 
+/// On-chain AllowlistEntry account structure
+#[derive(Debug, Clone, AnchorDeserialize)]
+pub struct AllowlistEntryAccount {
+    pub account: Pubkey,
+    pub added_by: Pubkey,
+    pub added_at: i64,
+    pub bump: u8,
+}
+
 /// On-chain MinterInfo account structure
 #[derive(Debug, Clone, AnchorDeserialize)]
 pub struct MinterInfoAccount {
@@ -495,11 +1653,20 @@ pub struct MinterInfoAccount {
 }
 
 /// On-chain RoleAssignment account structure
-#[derive(Debug, Clone, AnchorDeserialize)]
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct RoleAssignmentAccount {
     pub role: Role,
     pub account: Pubkey,
     pub assigned_by: Pubkey,
     pub assigned_at: i64,
     pub bump: u8,
+    /// When the assignment stops granting its role (`None` = never)
+    pub expires_at: Option<i64>,
+}
+
+impl RoleAssignmentAccount {
+    /// Mirrors the program: an assignment past `expires_at` grants nothing
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
 }
\ No newline at end of file
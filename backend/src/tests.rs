@@ -32,6 +32,7 @@ mod tests {
             is_active: true,
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            wallet_verified_at: None,
         }
     }
 
@@ -50,6 +51,10 @@ mod tests {
             is_active: true,
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            screen_on_mint: true,
+            max_supply: 0,
+            reserve_amount: None,
+            reserve_attested_at: None,
         }
     }
 
@@ -167,6 +172,53 @@ mod tests {
             assert!(!invalid);
         }
 
+        /// Test a wallet signature only verifies for its own key and challenge
+        #[test]
+        fn test_wallet_signature_verification() {
+            use crate::routes::auth::wallet_user_email;
+            use crate::utils::{verify_wallet_signature, wallet_challenge_message};
+            use solana_sdk::signature::{Keypair, Signer};
+
+            let wallet = Keypair::new();
+            let pubkey = wallet.pubkey().to_string();
+            let message = wallet_challenge_message("abc123");
+            assert!(message.contains("abc123"));
+            let signature = wallet.sign_message(message.as_bytes()).to_string();
+
+            assert!(verify_wallet_signature(&pubkey, &message, &signature));
+            assert!(!verify_wallet_signature(&pubkey, &wallet_challenge_message("other"), &signature));
+            assert!(!verify_wallet_signature(&Keypair::new().pubkey().to_string(), &message, &signature));
+            assert!(!verify_wallet_signature(&pubkey, &message, "not-a-signature"));
+            assert!(!verify_wallet_signature("not-a-pubkey", &message, &signature));
+
+            assert!(is_valid_email(&wallet_user_email(&pubkey)));
+        }
+
+        /// Test wallet sign-in emails all fall in the domain `register` refuses
+        #[test]
+        fn test_wallet_user_email_uses_reserved_domain() {
+            use crate::routes::auth::{wallet_user_email, WALLET_EMAIL_SUFFIX};
+
+            let email = wallet_user_email("7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU");
+            assert!(email.ends_with(WALLET_EMAIL_SUFFIX));
+            assert_eq!(email, "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU@wallet.invalid");
+        }
+
+        /// Test only a signature-verified wallet counts as the user's wallet
+        #[test]
+        fn test_verified_wallet_requires_signature() {
+            let mut user = create_mock_user(Uuid::new_v4(), "user@example.com", "user");
+            assert_eq!(user.verified_wallet(), None);
+            assert!(!crate::models::UserPublic::from(user.clone()).wallet_verified);
+
+            user.wallet_verified_at = Some(Utc::now());
+            assert_eq!(user.verified_wallet(), user.solana_pubkey.as_deref());
+            assert!(crate::models::UserPublic::from(user.clone()).wallet_verified);
+
+            user.solana_pubkey = None;
+            assert_eq!(user.verified_wallet(), None);
+        }
+
         /// Test email validation
         #[test]
         fn test_email_validation() {
@@ -294,6 +346,111 @@ mod tests {
             // Refresh token expiry should be greater than access token expiry
             assert!(refresh_claims.exp > access_claims.exp);
         }
+        /// Test refresh tokens carry a type and rotation family
+        #[test]
+        fn test_refresh_token_family_claims() {
+            use crate::utils::{generate_tokens_in_family, ACCESS_TOKEN_TYPE, REFRESH_TOKEN_TYPE};
+
+            let user_id = Uuid::new_v4();
+            let tokens = generate_tokens(user_id, "test@example.com", "user", "secret", 3600)
+                .expect("Failed to generate tokens");
+
+            let access_claims = validate_token(&tokens.access_token, "secret").unwrap();
+            let refresh_claims = validate_token(&tokens.refresh_token, "secret").unwrap();
+            assert_eq!(access_claims.token_type, ACCESS_TOKEN_TYPE);
+            assert_eq!(access_claims.family_id, None);
+            assert_eq!(refresh_claims.token_type, REFRESH_TOKEN_TYPE);
+            assert_eq!(refresh_claims.family_id, Some(tokens.family_id));
+            assert_eq!(refresh_claims.jti, tokens.refresh_jti);
+
+            // Rotation keeps the family but issues a fresh jti
+            let rotated = generate_tokens_in_family(
+                user_id, "test@example.com", "user", "secret", 3600, tokens.family_id,
+            ).expect("Failed to generate tokens");
+            let rotated_claims = validate_token(&rotated.refresh_token, "secret").unwrap();
+            assert_eq!(rotated_claims.family_id, Some(tokens.family_id));
+            assert_ne!(rotated_claims.jti, refresh_claims.jti);
+        }
+
+        /// Test legacy tokens without a token type still decode
+        #[test]
+        fn test_legacy_token_without_type() {
+            let secret = "test-secret-key";
+            let token = generate_test_token(Uuid::new_v4(), "test@example.com", "user", secret);
+
+            let claims = validate_token(&token, secret).expect("Failed to validate token");
+            assert!(claims.token_type.is_empty());
+            assert!(claims.family_id.is_none());
+        }
+
+        /// Test refresh session status transitions used for replay detection
+        #[test]
+        fn test_refresh_session_replay_detection() {
+            use crate::models::{RefreshSession, RefreshSessionStatus};
+
+            let now = Utc::now();
+            let mut session = RefreshSession {
+                jti: Uuid::new_v4(),
+                family_id: Uuid::new_v4(),
+                user_id: Uuid::new_v4(),
+                expires_at: now + chrono::Duration::days(30),
+                rotated_at: None,
+                revoked_at: None,
+                created_at: now,
+            };
+            assert_eq!(session.status(now), RefreshSessionStatus::Active);
+
+            // After the first exchange, presenting the token again is a replay
+            session.rotated_at = Some(now);
+            assert_eq!(session.status(now), RefreshSessionStatus::Reused);
+
+            // Once the family is revoked, every token in it is rejected
+            session.revoked_at = Some(now);
+            assert_eq!(session.status(now), RefreshSessionStatus::Revoked);
+
+            let expired = RefreshSession {
+                expires_at: now - chrono::Duration::seconds(1),
+                rotated_at: None,
+                revoked_at: None,
+                ..session
+            };
+            assert_eq!(expired.status(now), RefreshSessionStatus::Expired);
+        }
+
+        /// Test rotation and family revocation against a live database
+        #[tokio::test]
+        async fn test_refresh_rotation_database() {
+            // This test requires a running database
+            // Use DATABASE_URL environment variable
+            let Ok(url) = std::env::var("DATABASE_URL") else {
+                return;
+            };
+            let db = crate::db::Database::new(&url).await.expect("Failed to connect");
+            db.migrate().await.expect("Failed to migrate");
+
+            let user_id: Uuid = sqlx::query_scalar(
+                "INSERT INTO users (email, password_hash, role) VALUES ($1, 'x', 'user') RETURNING id"
+            )
+            .bind(format!("refresh-{}@example.com", Uuid::new_v4()))
+            .fetch_one(db.pool())
+            .await
+            .expect("Failed to create user");
+
+            let family_id = Uuid::new_v4();
+            let first = Uuid::new_v4();
+            let second = Uuid::new_v4();
+            let expires_at = Utc::now() + chrono::Duration::days(30);
+            db.create_refresh_session(first, family_id, user_id, expires_at).await.unwrap();
+
+            // First exchange succeeds, replay of the same token fails
+            assert!(db.rotate_refresh_session(first).await.unwrap().is_some());
+            assert!(db.rotate_refresh_session(first).await.unwrap().is_none());
+
+            // Replay revokes the whole family, including the newer token
+            db.create_refresh_session(second, family_id, user_id, expires_at).await.unwrap();
+            assert_eq!(db.revoke_refresh_family(family_id).await.unwrap(), 2);
+            assert!(db.rotate_refresh_session(second).await.unwrap().is_none());
+        }
     }
 
     // ============================================================================
@@ -314,6 +471,7 @@ mod tests {
                 preset: 1,
                 asset_mint: "So11111111111111111111111111111111111111112".to_string(),
                 authority_keypair: None,
+                max_supply: None,
             };
 
             // Validate input
@@ -326,6 +484,36 @@ mod tests {
             assert!(parse_result.is_ok());
         }
 
+        /// Test reconciliation diff reports only mismatched fields
+        #[test]
+        fn test_reconciliation_diff() {
+            use crate::models::StablecoinSnapshot;
+
+            let database = StablecoinSnapshot {
+                total_supply: 1_000_000,
+                paused: false,
+                preset: 2,
+                compliance_enabled: true,
+                authority: "9xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU".to_string(),
+            };
+
+            assert!(database.diff(&database.clone()).is_empty());
+
+            let onchain = StablecoinSnapshot {
+                total_supply: 1_500_000,
+                paused: true,
+                ..database.clone()
+            };
+            let mismatches = database.diff(&onchain);
+
+            assert_eq!(mismatches.len(), 2);
+            assert_eq!(mismatches[0].field, "total_supply");
+            assert_eq!(mismatches[0].database, json!(1_000_000));
+            assert_eq!(mismatches[0].onchain, json!(1_500_000));
+            assert_eq!(mismatches[1].field, "paused");
+            assert_eq!(mismatches[1].onchain, json!(true));
+        }
+
         /// Test stablecoin creation validation - invalid name (too long)
         #[test]
         fn test_create_stablecoin_invalid_name() {
@@ -375,12 +563,35 @@ mod tests {
             let update_req = UpdateStablecoinRequest {
                 name: Some("Updated Name".to_string()),
                 is_active: Some(false),
+                screen_on_mint: None,
+                max_supply: None,
+                reserve_amount: None,
             };
 
             assert!(update_req.name.is_some());
             assert!(update_req.is_active.is_some());
         }
 
+        /// Test the supply cap is bounded like other amounts and 0 means uncapped
+        #[test]
+        fn test_max_supply_validation() {
+            use crate::models::{validate_max_supply, MAX_OPERATION_AMOUNT};
+            use validator::Validate;
+
+            assert!(validate_max_supply(&0).is_ok());
+            assert!(validate_max_supply(&MAX_OPERATION_AMOUNT).is_ok());
+            assert!(validate_max_supply(&(MAX_OPERATION_AMOUNT + 1)).is_err());
+
+            let update_req = UpdateStablecoinRequest {
+                name: None,
+                is_active: None,
+                screen_on_mint: None,
+                max_supply: Some(u64::MAX),
+                reserve_amount: None,
+            };
+            assert!(update_req.validate().is_err());
+        }
+
         /// Test stablecoin model creation
         #[test]
         fn test_stablecoin_model() {
@@ -408,6 +619,50 @@ mod tests {
             // Non-owner should not match
             assert_ne!(stablecoin.owner_id, non_owner_id);
         }
+
+        /// Test stablecoin list query validation and sort parsing
+        #[test]
+        fn test_list_stablecoins_query() {
+            use crate::routes::stablecoin::{ListStablecoinsQuery, StablecoinSort};
+            use validator::Validate;
+
+            let query = |limit: Option<i64>, offset: Option<i64>| ListStablecoinsQuery {
+                limit,
+                offset,
+                sort: None,
+            };
+            assert!(query(None, None).validate().is_ok());
+            assert!(query(Some(100), Some(0)).validate().is_ok());
+            assert!(query(Some(101), None).validate().is_err());
+            assert!(query(Some(0), None).validate().is_err());
+            assert!(query(Some(10), Some(-1)).validate().is_err());
+
+            let sort: StablecoinSort = serde_json::from_value(serde_json::json!("-name")).unwrap();
+            assert_eq!(sort, StablecoinSort::NameDesc);
+            assert_eq!(StablecoinSort::default().order_by(), "created_at DESC, id DESC");
+            assert!(serde_json::from_value::<StablecoinSort>(serde_json::json!("owner_id")).is_err());
+        }
+
+        /// Test supply history query parsing and validation
+        #[test]
+        fn test_supply_history_query() {
+            use crate::models::SupplyInterval;
+            use crate::routes::stablecoin::SupplyHistoryQuery;
+
+            let query: SupplyHistoryQuery = serde_json::from_value(json!({})).unwrap();
+            assert_eq!(query.interval, SupplyInterval::Day);
+            let query: SupplyHistoryQuery = serde_json::from_value(json!({"interval": "week"})).unwrap();
+            assert_eq!(query.interval.as_str(), "week");
+            assert!(serde_json::from_value::<SupplyHistoryQuery>(json!({"interval": "minute"})).is_err());
+
+            let query = |from: &str, to: &str| SupplyHistoryQuery {
+                from: Some(from.parse().unwrap()),
+                to: Some(to.parse().unwrap()),
+                interval: SupplyInterval::Hour,
+            };
+            assert!(query("2024-01-01T00:00:00Z", "2024-02-01T00:00:00Z").check().is_ok());
+            assert!(query("2024-02-01T00:00:00Z", "2024-01-01T00:00:00Z").check().is_err());
+        }
     }
 
     // ============================================================================
@@ -418,12 +673,61 @@ mod tests {
         use super::*;
         use crate::models::{MintRequest, BurnRequest, TransferRequest};
 
+        /// Test mint and burn return immediately unless `?wait=true` is passed
+        #[test]
+        fn test_wait_query_defaults_to_async() {
+            use crate::routes::operations::WaitQuery;
+
+            let query: WaitQuery = serde_json::from_value(serde_json::json!({})).unwrap();
+            assert!(!query.wait);
+
+            let query: WaitQuery = serde_json::from_value(serde_json::json!({"wait": true})).unwrap();
+            assert!(query.wait);
+        }
+
+        /// Test estimate requests are tagged by operation and validated like the real request
+        #[test]
+        fn test_estimate_request() {
+            use crate::models::EstimateRequest;
+            use validator::Validate;
+
+            let mint: EstimateRequest = serde_json::from_value(serde_json::json!({
+                "operation": "mint",
+                "recipient": "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU",
+                "amount": 1_000_000,
+            })).unwrap();
+            assert_eq!(mint.name(), "mint");
+            assert!(mint.validate().is_ok());
+
+            let zero_burn: EstimateRequest = serde_json::from_value(serde_json::json!({
+                "operation": "burn",
+                "amount": 0,
+            })).unwrap();
+            assert_eq!(zero_burn.name(), "burn");
+            assert!(zero_burn.validate().is_err());
+
+            let bad_transfer: EstimateRequest = serde_json::from_value(serde_json::json!({
+                "operation": "transfer",
+                "from": "not-a-pubkey",
+                "to": "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU",
+                "amount": 5,
+            })).unwrap();
+            assert!(bad_transfer.validate().is_err());
+
+            let unknown = serde_json::from_value::<EstimateRequest>(serde_json::json!({
+                "operation": "seize",
+                "amount": 5,
+            }));
+            assert!(unknown.is_err());
+        }
+
         /// Test mint request validation - valid input
         #[test]
         fn test_mint_request_valid() {
             let req = MintRequest {
                 recipient: "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU".to_string(),
                 amount: 1000000, // 1 unit with 6 decimals
+                recipient_is_token_account: false,
             };
 
             // Validate recipient pubkey
@@ -440,24 +744,48 @@ mod tests {
             let req = MintRequest {
                 recipient: "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU".to_string(),
                 amount: 0,
+                recipient_is_token_account: false,
             };
 
             // Amount should be > 0
             assert_eq!(req.amount, 0);
         }
 
+        /// Test mint screening applies only to SSS-2 stablecoins that opt in
+        #[test]
+        fn test_requires_mint_screening() {
+            let mut stablecoin = create_mock_stablecoin(Uuid::new_v4(), Uuid::new_v4());
+            assert!(!stablecoin.requires_mint_screening());
+
+            stablecoin.preset = 2;
+            assert!(stablecoin.requires_mint_screening());
+
+            stablecoin.screen_on_mint = false;
+            assert!(!stablecoin.requires_mint_screening());
+        }
+
         /// Test mint request validation - invalid recipient
         #[test]
         fn test_mint_request_invalid_recipient() {
             let req = MintRequest {
                 recipient: "invalid-pubkey".to_string(),
                 amount: 1000000,
+                recipient_is_token_account: false,
             };
 
             let parse_result: Result<solana_sdk::pubkey::Pubkey, _> = req.recipient.parse();
             assert!(parse_result.is_err());
         }
 
+        /// Test the token account flag defaults to off so recipients are treated as wallets
+        #[test]
+        fn test_mint_request_recipient_defaults_to_wallet() {
+            let req: MintRequest = serde_json::from_str(
+                r#"{"recipient": "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU", "amount": 1}"#
+            ).unwrap();
+            assert!(!req.recipient_is_token_account);
+        }
+
         /// Test burn request validation
         #[test]
         fn test_burn_request_valid() {
@@ -492,6 +820,7 @@ mod tests {
                 from: "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU".to_string(),
                 to: "9xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU".to_string(),
                 amount: 1000000,
+                memo: None,
             };
 
             // Validate from pubkey
@@ -514,6 +843,7 @@ mod tests {
                 from: same_account.to_string(),
                 to: same_account.to_string(),
                 amount: 1000000,
+                memo: None,
             };
 
             // From and to should be different for valid transfer
@@ -527,12 +857,36 @@ mod tests {
                 from: "invalid".to_string(),
                 to: "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU".to_string(),
                 amount: 1000000,
+                memo: None,
             };
 
             let from_parse: Result<solana_sdk::pubkey::Pubkey, _> = req.from.parse();
             assert!(from_parse.is_err());
         }
 
+        /// Test transfer memos are limited to the memo program's size and reject control characters
+        #[test]
+        fn test_transfer_memo_validation() {
+            use crate::models::{validate_memo, MAX_MEMO_BYTES};
+            use validator::Validate;
+
+            assert!(validate_memo("Invoice 2024-0042").is_ok());
+            assert!(validate_memo(&"a".repeat(MAX_MEMO_BYTES)).is_ok());
+            assert!(validate_memo(&"a".repeat(MAX_MEMO_BYTES + 1)).is_err());
+            // Length is counted in bytes, not characters
+            assert!(validate_memo(&"é".repeat(MAX_MEMO_BYTES / 2 + 1)).is_err());
+            assert!(validate_memo("line\nbreak").is_err());
+            assert!(validate_memo("nul\0").is_err());
+
+            let req = TransferRequest {
+                from: "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU".to_string(),
+                to: "9xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU".to_string(),
+                amount: 1000000,
+                memo: Some("tab\there".to_string()),
+            };
+            assert!(req.validate().is_err());
+        }
+
         /// Test amount overflow protection
         #[test]
         fn test_large_amounts() {
@@ -575,6 +929,22 @@ mod tests {
             assert!(req.amount > 0);
         }
 
+        /// Test the ?commitment= override falls back to the default and rejects unknown levels
+        #[test]
+        fn test_commitment_query() {
+            use crate::routes::admin::CommitmentQuery;
+            use solana_sdk::commitment_config::CommitmentConfig;
+
+            let default = CommitmentConfig::confirmed();
+            assert_eq!(CommitmentQuery::default().resolve(default).unwrap(), default);
+
+            let finalized = CommitmentQuery { commitment: Some("finalized".to_string()) };
+            assert_eq!(finalized.resolve(default).unwrap(), CommitmentConfig::finalized());
+
+            let invalid = CommitmentQuery { commitment: Some("recent".to_string()) };
+            assert!(matches!(invalid.resolve(default), Err(crate::error::ApiError::Validation(_))));
+        }
+
         /// Test seize request validation - zero amount
         #[test]
         fn test_seize_request_zero_amount() {
@@ -637,6 +1007,21 @@ mod tests {
             let regular_has_access = regular_user.role == "admin" || stablecoin.owner_id == regular_user.id;
             assert!(!regular_has_access);
         }
+        /// Test max transfer request validation (0 disables the cap)
+        #[test]
+        fn test_set_max_transfer_request_validation() {
+            use crate::models::{SetMaxTransferRequest, MAX_OPERATION_AMOUNT};
+            use validator::Validate;
+
+            let unlimited = SetMaxTransferRequest { amount: 0 };
+            assert!(unlimited.validate().is_ok());
+
+            let capped = SetMaxTransferRequest { amount: 10_000_000_000 };
+            assert!(capped.validate().is_ok());
+
+            let too_large = SetMaxTransferRequest { amount: MAX_OPERATION_AMOUNT + 1 };
+            assert!(too_large.validate().is_err());
+        }
     }
 
     // ============================================================================
@@ -662,6 +1047,54 @@ mod tests {
             }
         }
 
+        /// Test decoding on-chain role assignments skips foreign and malformed accounts
+        #[test]
+        fn test_decode_role_assignments() {
+            use anchor_lang::AnchorSerialize;
+            use crate::solana::{
+                account_discriminator, decode_role_assignments, Role, RoleAssignmentAccount, ROLE_SEED,
+            };
+            use solana_sdk::pubkey::Pubkey;
+
+            let program_id = Pubkey::new_unique();
+            let stablecoin = Pubkey::new_unique();
+            let other_stablecoin = Pubkey::new_unique();
+            let account = Pubkey::new_unique();
+
+            let encode = |stablecoin: &Pubkey| {
+                let (pda, bump) = Pubkey::find_program_address(
+                    &[ROLE_SEED, stablecoin.as_ref(), account.as_ref(), Role::Minter.to_seed()],
+                    &program_id,
+                );
+                let mut data = account_discriminator("RoleAssignment").to_vec();
+                RoleAssignmentAccount {
+                    role: Role::Minter,
+                    account,
+                    assigned_by: Pubkey::new_unique(),
+                    assigned_at: 1_700_000_000,
+                    bump,
+                    expires_at: None,
+                }
+                .serialize(&mut data)
+                .expect("should serialize");
+                (pda, data)
+            };
+
+            let (pda, data) = encode(&stablecoin);
+            let mut truncated = data.clone();
+            truncated.truncate(20);
+
+            let decoded = decode_role_assignments(&program_id, &stablecoin, vec![
+                (pda, data),
+                encode(&other_stablecoin),
+                (Pubkey::new_unique(), truncated),
+            ]);
+
+            assert_eq!(decoded.len(), 1);
+            assert_eq!(decoded[0].account, account);
+            assert_eq!(decoded[0].role.as_str(), "minter");
+        }
+
         /// Test role assignment validation - invalid role
         #[test]
         fn test_invalid_role() {
@@ -744,6 +1177,40 @@ mod tests {
             let has_minter_role = burner_assignment.role == "minter";
             assert!(!has_minter_role);
         }
+
+        /// Test on-chain role checks mirror the program's RBAC
+        #[test]
+        fn test_onchain_roles_satisfies() {
+            use crate::services::OnchainRoles;
+            use crate::solana::Role;
+
+            let authority = OnchainRoles { is_authority: true, roles: vec![] };
+            assert!(authority.satisfies(Role::Minter));
+            assert!(authority.satisfies(Role::Seizer));
+
+            let master = OnchainRoles { is_authority: false, roles: vec![Role::Master] };
+            assert!(master.satisfies(Role::Burner));
+            assert!(master.satisfies(Role::Pauser));
+            assert_eq!(master.acting_role(Role::Seizer), Some(Role::Master));
+
+            let minter = OnchainRoles { is_authority: false, roles: vec![Role::Minter] };
+            assert!(minter.satisfies(Role::Minter));
+            assert!(!minter.satisfies(Role::Burner));
+            assert!(!minter.satisfies(Role::Pauser));
+
+            let minter_burner = OnchainRoles {
+                is_authority: false,
+                roles: vec![Role::Minter, Role::Burner],
+            };
+            assert!(minter_burner.satisfies(Role::Minter));
+            assert!(minter_burner.satisfies(Role::Burner));
+            assert!(!minter_burner.satisfies(Role::Seizer));
+            assert_eq!(minter_burner.acting_role(Role::Burner), Some(Role::Burner));
+
+            let nobody = OnchainRoles::default();
+            assert!(!nobody.satisfies(Role::Minter));
+            assert_eq!(nobody.acting_role(Role::Minter), None);
+        }
     }
 
     // ============================================================================
@@ -803,6 +1270,28 @@ mod tests {
             assert!(!result.is_sanctioned);
         }
 
+        /// Test batch screening caps the batch and rejects bad addresses
+        #[test]
+        fn test_parse_batch_addresses() {
+            use crate::error::ApiError;
+            use crate::models::MAX_BATCH_SCREEN_ADDRESSES;
+            use crate::routes::compliance::parse_batch_addresses;
+
+            let address = "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU".to_string();
+            let parsed = parse_batch_addresses(&[address.clone(), address.clone()]).unwrap();
+            assert_eq!(parsed.len(), 2);
+            assert_eq!(parsed[0].to_string(), address);
+
+            let full = vec![address.clone(); MAX_BATCH_SCREEN_ADDRESSES];
+            assert!(parse_batch_addresses(&full).is_ok());
+
+            let over = vec![address.clone(); MAX_BATCH_SCREEN_ADDRESSES + 1];
+            assert!(matches!(parse_batch_addresses(&over), Err(ApiError::BadRequest(_))));
+
+            let invalid = vec![address, "not-a-pubkey".to_string()];
+            assert!(matches!(parse_batch_addresses(&invalid), Err(ApiError::Validation(_))));
+        }
+
         /// Test risk score range
         #[test]
         fn test_risk_score_range() {
@@ -899,6 +1388,22 @@ mod tests {
     mod audit_tests {
         use super::*;
         use crate::models::AuditLogEntry;
+        use base64::{engine::general_purpose::STANDARD, Engine};
+        use solana_sdk::pubkey::Pubkey;
+        use sss_token::events;
+
+        const STABLECOIN: &str = "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU";
+        const ACCOUNT: &str = "11111111111111111111111111111112";
+        const SIGNER: &str = "11111111111111111111111111111113";
+
+        fn key(s: &str) -> Pubkey {
+            s.parse().unwrap()
+        }
+
+        /// The `Program data:` line `emit!` writes for `event`
+        fn event_log<E: anchor_lang::Event>(event: E) -> String {
+            format!("Program data: {}", STANDARD.encode(event.data()))
+        }
 
         /// Test audit log entry creation
         #[test]
@@ -911,6 +1416,7 @@ mod tests {
                 tx_signature: Some("5xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU".to_string()),
                 details: Some(json!({"amount": 1000000, "recipient": "7xKX..."})),
                 ip_address: Some("192.168.1.1".to_string()),
+                request_id: Some(Uuid::new_v4().to_string()),
                 created_at: Utc::now(),
             };
 
@@ -936,6 +1442,7 @@ mod tests {
                 "stablecoin.seize",
                 "role.assign",
                 "role.revoke",
+                "authority.transfer",
                 "compliance.blacklist",
                 "compliance.unblacklist",
             ];
@@ -945,6 +1452,313 @@ mod tests {
                 let parts: Vec<&str> = action.split('.').collect();
                 assert_eq!(parts.len(), 2, "Action {} should have 2 parts", action);
             }
+
+            // Governance events from the indexer map onto the same actions
+            use crate::services::indexer::governance_audit_action;
+            for event in ["StablecoinInitialized", "RoleAssigned", "RoleRevoked", "AuthorityTransferred"] {
+                let action = governance_audit_action(event).expect("governance event");
+                assert!(actions.contains(&action), "{} is not a known action", action);
+            }
+            assert_eq!(governance_audit_action("Minted"), None);
+        }
+
+        /// Test the indexer reads governance events from program logs
+        #[test]
+        fn test_parse_governance_log_events() {
+            use crate::services::indexer::parse_log_events;
+
+            let logs = vec![
+                "Program SSSToken11111111111111111111111111111111111 invoke [1]".to_string(),
+                "Program log: Instruction: RevokeRole".to_string(),
+                event_log(events::RoleRevoked {
+                    stablecoin: key(STABLECOIN),
+                    role: "Minter".to_string(),
+                    account: key(ACCOUNT),
+                    revoked_by: key(SIGNER),
+                }),
+                event_log(events::AuthorityTransferred {
+                    stablecoin: key(STABLECOIN),
+                    old_authority: key(ACCOUNT),
+                    new_authority: key(SIGNER),
+                }),
+                "Program data: not base64!".to_string(),
+                "Program data: Zm9v".to_string(),
+            ];
+
+            let events = parse_log_events(&logs, Some("sig"), 42);
+            assert_eq!(events.len(), 2);
+            assert_eq!(events[0].event_type, "RoleRevoked");
+            assert_eq!(events[0].stablecoin, "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU");
+            assert_eq!(events[0].data["revoked_by"], "11111111111111111111111111111113");
+            assert_eq!(events[0].signature.as_deref(), Some("sig"));
+            assert_eq!(events[1].event_type, "AuthorityTransferred");
+            assert_eq!(events[1].slot, 42);
+        }
+
+        /// Test a `StablecoinInitialized` log line yields the stablecoin to register
+        #[test]
+        fn test_discovered_stablecoin_from_initialized_event() {
+            use crate::services::indexer::{discovered_stablecoin, parse_log_events};
+
+            let logs = vec![
+                event_log(events::StablecoinInitialized {
+                    stablecoin: key(STABLECOIN),
+                    preset: 2,
+                    name: "Test USD".to_string(),
+                    symbol: "TUSD".to_string(),
+                    decimals: 6,
+                    compliance_enabled: true,
+                    authority: key(ACCOUNT),
+                    asset_mint: key(SIGNER),
+                }),
+                event_log(events::RoleRevoked {
+                    stablecoin: key(STABLECOIN),
+                    role: "Minter".to_string(),
+                    account: key(ACCOUNT),
+                    revoked_by: key(SIGNER),
+                }),
+            ];
+            let events = parse_log_events(&logs, Some("sig"), 42);
+            assert_eq!(events.len(), 2);
+
+            let discovered = discovered_stablecoin(&events[0]).expect("initialized event");
+            assert_eq!(discovered.stablecoin_pda, "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU");
+            assert_eq!(discovered.authority, "11111111111111111111111111111112");
+            assert_eq!(discovered.asset_mint, "11111111111111111111111111111113");
+            assert_eq!((discovered.preset, discovered.decimals), (2, 6));
+            assert_eq!((discovered.name.as_str(), discovered.symbol.as_str()), ("Test USD", "TUSD"));
+
+            assert_eq!(discovered_stablecoin(&events[1]), None);
+
+            // Events from before the program logged the authority can't be registered
+            let mut legacy = events[0].clone();
+            legacy.data.as_object_mut().unwrap().remove("authority");
+            assert_eq!(discovered_stablecoin(&legacy), None);
+        }
+
+        /// Test minter, role and blacklist events map to snapshot changes
+        #[test]
+        fn test_account_change_from_events() {
+            use crate::models::AccountChange;
+            use crate::services::indexer::{account_change, parse_log_events};
+
+            let logs = vec![
+                event_log(events::RoleAssigned {
+                    stablecoin: key(STABLECOIN),
+                    role: "Minter".to_string(),
+                    account: key(ACCOUNT),
+                    assigned_by: key(SIGNER),
+                    expires_at: None,
+                }),
+                event_log(events::ExpiredRoleCleaned {
+                    stablecoin: key(STABLECOIN),
+                    role: "Pauser".to_string(),
+                    account: key(ACCOUNT),
+                    cleaned_by: key(SIGNER),
+                }),
+                event_log(events::QuotaUpdated {
+                    stablecoin: key(STABLECOIN),
+                    minter: key(ACCOUNT),
+                    old_quota: 10,
+                    new_quota: 20,
+                }),
+                event_log(events::BlacklistAdded {
+                    stablecoin: key(STABLECOIN),
+                    account: key(ACCOUNT),
+                    reason: "OFAC".to_string(),
+                }),
+                event_log(events::Paused {
+                    stablecoin: key(STABLECOIN),
+                    authority: key(SIGNER),
+                    reason: "incident".to_string(),
+                    until: 0,
+                }),
+            ];
+            let events = parse_log_events(&logs, Some("sig"), 42);
+            assert_eq!(events.len(), 5);
+
+            match account_change(&events[0]) {
+                Some(AccountChange::RoleAssigned(role)) => {
+                    assert_eq!(role.role, "minter");
+                    assert_eq!(role.assigned_by, "11111111111111111111111111111113");
+                    assert_eq!(role.assigned_at, events[0].timestamp.timestamp());
+                    assert_eq!(role.expires_at, None);
+                }
+                other => panic!("expected a role assignment, got {:?}", other),
+            }
+            assert_eq!(account_change(&events[1]), Some(AccountChange::RoleRemoved {
+                role: "pauser".to_string(),
+                account: "11111111111111111111111111111112".to_string(),
+            }));
+            assert_eq!(account_change(&events[2]), Some(AccountChange::MinterQuota {
+                minter: "11111111111111111111111111111112".to_string(),
+                quota: 20,
+            }));
+            assert_eq!(account_change(&events[3]), Some(AccountChange::Blacklisted {
+                account: "11111111111111111111111111111112".to_string(),
+                reason: "OFAC".to_string(),
+            }));
+            assert_eq!(account_change(&events[4]), None);
+        }
+
+        /// Test role changes update a snapshot in place
+        #[test]
+        fn test_apply_role_change() {
+            use crate::models::{AccountChange, OnchainRoleAssignment};
+
+            let assignment = |role: &str, account: &str, assigned_at: i64| OnchainRoleAssignment {
+                role: role.to_string(),
+                account: account.to_string(),
+                assigned_by: "authority".to_string(),
+                assigned_at,
+                expires_at: None,
+            };
+            let mut roles = vec![assignment("minter", "a", 1), assignment("pauser", "a", 2)];
+
+            // Reassigning replaces the existing assignment
+            AccountChange::RoleAssigned(assignment("minter", "a", 3)).apply_to_roles(&mut roles);
+            assert_eq!(roles.len(), 2);
+            assert!(roles.contains(&assignment("minter", "a", 3)));
+
+            AccountChange::RoleRemoved { role: "pauser".to_string(), account: "a".to_string() }
+                .apply_to_roles(&mut roles);
+            assert_eq!(roles, vec![assignment("minter", "a", 3)]);
+
+            // Non-role changes leave roles alone
+            AccountChange::Unblacklisted { account: "a".to_string() }.apply_to_roles(&mut roles);
+            assert_eq!(roles.len(), 1);
+        }
+
+        /// Test handlers get the middleware's request ID, or a fresh one without it
+        #[tokio::test]
+        async fn test_request_id_extractor() {
+            use axum::extract::FromRequestParts;
+            use crate::app_middleware::request_id::RequestId;
+
+            let (mut parts, _) = axum::http::Request::builder().body(()).unwrap().into_parts();
+            parts.extensions.insert(RequestId("req-123".to_string()));
+            let RequestId(id) = RequestId::from_request_parts(&mut parts, &()).await.unwrap();
+            assert_eq!(id, "req-123");
+
+            let (mut parts, _) = axum::http::Request::builder().body(()).unwrap().into_parts();
+            let RequestId(id) = RequestId::from_request_parts(&mut parts, &()).await.unwrap();
+            assert!(Uuid::parse_str(&id).is_ok());
+        }
+        /// Test audit list query validation
+        #[test]
+        fn test_list_audit_query_validation() {
+            use crate::routes::audit::ListAuditQuery;
+
+            let query = |limit: Option<i64>, from: Option<&str>, to: Option<&str>| ListAuditQuery {
+                limit,
+                offset: None,
+                action: Some("stablecoin.mint".to_string()),
+                from: from.map(|f| f.parse().unwrap()),
+                to: to.map(|t| t.parse().unwrap()),
+            };
+
+            assert!(query(None, None, None).check().is_ok());
+            assert!(query(Some(200), None, None).check().is_ok());
+            assert!(query(Some(201), None, None).check().is_err());
+            assert!(query(Some(0), None, None).check().is_err());
+            assert!(query(Some(10), Some("2024-01-01T00:00:00Z"), Some("2024-02-01T00:00:00Z")).check().is_ok());
+            assert!(query(Some(10), Some("2024-02-01T00:00:00Z"), Some("2024-01-01T00:00:00Z")).check().is_err());
+        }
+
+        /// Test paginated envelope shape
+        #[test]
+        fn test_paginated_response_serialization() {
+            use crate::models::{PaginatedResponse, Pagination};
+
+            let response = PaginatedResponse::<AuditLogEntry> {
+                data: vec![],
+                pagination: Pagination { total: 150, limit: 50, offset: 100 },
+            };
+            let value = serde_json::to_value(&response).unwrap();
+
+            assert!(value["data"].as_array().unwrap().is_empty());
+            assert_eq!(value["pagination"]["total"], 150);
+            assert_eq!(value["pagination"]["limit"], 50);
+            assert_eq!(value["pagination"]["offset"], 100);
+        }
+    }
+
+    // ============================================================================
+    // Event Stream Tests
+    // ============================================================================
+
+    mod event_stream_tests {
+        use super::*;
+        use std::collections::HashSet;
+        use crate::models::{EventStreamMessage, IndexedEvent};
+        use crate::routes::events::{event_matches, handle_client_message};
+        use crate::services::EventIndexer;
+
+        fn mock_event(stablecoin: &str, event_type: &str) -> IndexedEvent {
+            IndexedEvent {
+                stablecoin: stablecoin.to_string(),
+                event_type: event_type.to_string(),
+                signature: Some("5xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU".to_string()),
+                slot: 42,
+                data: json!({"amount": 1000}),
+                timestamp: Utc::now(),
+            }
+        }
+
+        /// Test filtering by stablecoin and subscribed event types
+        #[test]
+        fn test_event_matches() {
+            let minted = mock_event("pda-a", "TokensMinted");
+            let all = HashSet::new();
+            let burns: HashSet<String> = ["TokensBurned".to_string()].into_iter().collect();
+
+            assert!(event_matches(&minted, "pda-a", &all));
+            assert!(!event_matches(&minted, "pda-b", &all));
+            assert!(!event_matches(&minted, "pda-a", &burns));
+            assert!(event_matches(&mock_event("pda-a", "TokensBurned"), "pda-a", &burns));
+        }
+
+        /// Test subscribe messages update the filter
+        #[test]
+        fn test_handle_client_message() {
+            let mut filter = HashSet::new();
+
+            let reply = handle_client_message(r#"{"type":"subscribe","events":["TokensMinted"]}"#, &mut filter);
+            assert!(matches!(reply, EventStreamMessage::Subscribed { ref events } if events == &["TokensMinted"]));
+            assert!(filter.contains("TokensMinted"));
+
+            // An empty subscription goes back to all events
+            handle_client_message(r#"{"type":"subscribe"}"#, &mut filter);
+            assert!(filter.is_empty());
+
+            let reply = handle_client_message("not json", &mut filter);
+            assert!(matches!(reply, EventStreamMessage::Error { .. }));
+        }
+
+        /// Test events are tagged for the client
+        #[test]
+        fn test_event_message_serialization() {
+            let value = serde_json::to_value(EventStreamMessage::Event(mock_event("pda-a", "TokensMinted"))).unwrap();
+            assert_eq!(value["type"], "event");
+            assert_eq!(value["event_type"], "TokensMinted");
+            assert_eq!(value["stablecoin"], "pda-a");
+
+            let value = serde_json::to_value(EventStreamMessage::Lagged { skipped: 3 }).unwrap();
+            assert_eq!(value, json!({"type": "lagged", "skipped": 3}));
+        }
+
+        /// Test published events reach every subscriber
+        #[tokio::test]
+        async fn test_indexer_broadcast() {
+            let indexer = EventIndexer::new("http://localhost:8899", "SSSToken11111111111111111111111111111111111".to_string());
+            assert_eq!(indexer.publish(mock_event("pda-a", "TokensMinted")), 0);
+
+            let mut first = indexer.subscribe();
+            let mut second = indexer.subscribe();
+            let event = mock_event("pda-a", "TokensBurned");
+            assert_eq!(indexer.publish(event.clone()), 2);
+            assert_eq!(first.recv().await.unwrap(), event);
+            assert_eq!(second.recv().await.unwrap(), event);
         }
     }
 
@@ -952,6 +1766,104 @@ mod tests {
     // Webhook Tests
     // ============================================================================
 
+    mod api_key_tests {
+        use super::*;
+        use crate::app_middleware::api_key::{permits, required_permission};
+        use crate::models::{normalize_permissions, ApiKey};
+        use crate::utils::hash_api_key;
+        use axum::http::Method;
+
+        fn api_key(permissions: serde_json::Value, expires_at: Option<chrono::DateTime<Utc>>) -> ApiKey {
+            ApiKey {
+                id: Uuid::new_v4(),
+                user_id: Uuid::new_v4(),
+                key_hash: hash_api_key("sss_test"),
+                name: Some("ci".to_string()),
+                permissions: Some(permissions),
+                last_used_at: None,
+                expires_at,
+                is_active: true,
+                created_at: Utc::now(),
+            }
+        }
+
+        /// Test each route maps to the permission a key needs for it
+        #[test]
+        fn test_required_permission() {
+            let id = Uuid::new_v4();
+            let path = |action: &str| format!("/api/v1/stablecoin/{}/{}", id, action);
+
+            assert_eq!(required_permission(&Method::GET, &path("status")), Some("read"));
+            assert_eq!(required_permission(&Method::POST, &path("mint")), Some("mint"));
+            assert_eq!(required_permission(&Method::POST, &path("burn")), Some("burn"));
+            assert_eq!(required_permission(&Method::POST, &path("estimate")), Some("read"));
+            assert_eq!(required_permission(&Method::POST, &path("pause")), Some("admin"));
+            assert_eq!(required_permission(&Method::POST, &path("seize")), Some("admin"));
+            assert_eq!(required_permission(&Method::POST, &path("tx/submit")), Some("admin"));
+            assert_eq!(required_permission(&Method::DELETE, &path("minters/abc")), Some("admin"));
+            assert_eq!(required_permission(&Method::POST, &path("blacklist")), Some("write"));
+
+            // Paths inside the /api/v1 nest have the prefix stripped
+            assert_eq!(required_permission(&Method::POST, &format!("/stablecoin/{}/mint", id)), Some("mint"));
+
+            // Keys can never manage keys
+            assert_eq!(required_permission(&Method::GET, "/api/v1/api-keys"), None);
+            assert_eq!(required_permission(&Method::POST, "/api-keys"), None);
+        }
+
+        /// Test a mint-only key cannot pause while admin covers everything
+        #[test]
+        fn test_permits() {
+            let mint_only = vec!["mint".to_string()];
+            assert!(permits(&mint_only, "mint"));
+            assert!(!permits(&mint_only, "admin"));
+            assert!(!permits(&mint_only, "read"));
+
+            let admin = vec!["admin".to_string()];
+            assert!(permits(&admin, "mint"));
+            assert!(permits(&admin, "write"));
+        }
+
+        /// Test permissions are lowercased, deduplicated and default to read-only
+        #[test]
+        fn test_normalize_permissions() {
+            assert_eq!(normalize_permissions(None), vec!["read"]);
+            assert_eq!(normalize_permissions(Some(&[][..])), vec!["read"]);
+
+            let requested = vec!["Mint".to_string(), "mint".to_string(), "BURN".to_string()];
+            assert_eq!(normalize_permissions(Some(requested.as_slice())), vec!["mint", "burn"]);
+        }
+
+        /// Test keys are stored as a stable SHA-256 hex digest
+        #[test]
+        fn test_hash_api_key() {
+            let hash = hash_api_key("sss_abc");
+            assert_eq!(hash.len(), 64);
+            assert_eq!(hash, hash_api_key("sss_abc"));
+            assert_ne!(hash, hash_api_key("sss_abd"));
+        }
+
+        /// Test stored permissions and expiry are read back correctly
+        #[test]
+        fn test_api_key_model() {
+            let now = Utc::now();
+
+            let key = api_key(json!(["mint", "read"]), None);
+            assert_eq!(key.permission_list(), vec!["mint", "read"]);
+            assert!(!key.is_expired(now));
+
+            let expired = api_key(json!(["read"]), Some(now - chrono::Duration::hours(1)));
+            assert!(expired.is_expired(now));
+
+            let garbage = api_key(json!("mint"), None);
+            assert!(garbage.permission_list().is_empty());
+
+            // The hash never leaves the server
+            let serialized = serde_json::to_value(&key).unwrap();
+            assert!(serialized.get("key_hash").is_none());
+        }
+    }
+
     mod webhook_tests {
         use super::*;
         use crate::models::{CreateWebhookRequest, Webhook};
@@ -1008,6 +1920,7 @@ mod tests {
         use super::*;
         use crate::error::ApiError;
         use axum::http::StatusCode;
+        use axum::response::IntoResponse;
 
         /// Test API error status codes
         #[test]
@@ -1048,6 +1961,100 @@ mod tests {
             assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
         }
 
+        /// Test each variant maps to a stable (status, code) pair
+        #[test]
+        fn test_api_error_codes() {
+            use crate::error::ErrorCode;
+
+            let cases = vec![
+                (ApiError::NotFound("x".to_string()), StatusCode::NOT_FOUND, "resource.not_found"),
+                (ApiError::StablecoinNotFound, StatusCode::NOT_FOUND, "stablecoin.not_found"),
+                (ApiError::Unauthorized("x".to_string()), StatusCode::UNAUTHORIZED, "auth.unauthorized"),
+                (ApiError::TokenExpired("x".to_string()), StatusCode::UNAUTHORIZED, "auth.token_expired"),
+                (ApiError::Forbidden("x".to_string()), StatusCode::FORBIDDEN, "auth.forbidden"),
+                (ApiError::BadRequest("x".to_string()), StatusCode::BAD_REQUEST, "request.bad_request"),
+                (ApiError::Validation("x".to_string()), StatusCode::UNPROCESSABLE_ENTITY, "request.validation_failed"),
+                (ApiError::InvalidFields(Default::default()), StatusCode::UNPROCESSABLE_ENTITY, "request.validation_failed"),
+                (ApiError::Conflict("x".to_string()), StatusCode::CONFLICT, "resource.conflict"),
+                (ApiError::Internal("x".to_string()), StatusCode::INTERNAL_SERVER_ERROR, "server.internal"),
+                (ApiError::Database("x".to_string()), StatusCode::INTERNAL_SERVER_ERROR, "server.database"),
+                (ApiError::Solana("x".to_string()), StatusCode::INTERNAL_SERVER_ERROR, "solana.error"),
+                (ApiError::RpcRateLimited("x".to_string()), StatusCode::SERVICE_UNAVAILABLE, "solana.rate_limited"),
+                (ApiError::RateLimited, StatusCode::TOO_MANY_REQUESTS, "request.rate_limited"),
+                (ApiError::ServiceUnavailable("x".to_string()), StatusCode::SERVICE_UNAVAILABLE, "server.unavailable"),
+                (
+                    ApiError::InsufficientBalance { available: 1, requested: 2 },
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    "token.insufficient_balance",
+                ),
+                (
+                    ApiError::ConfirmationTimeout("x".to_string()),
+                    StatusCode::GATEWAY_TIMEOUT,
+                    "solana.confirmation_timeout",
+                ),
+            ];
+
+            for (error, status, code) in cases {
+                assert_eq!(error.status(), status, "{:?}", error);
+                assert_eq!(error.code().as_str(), code, "{:?}", error);
+                assert_eq!(serde_json::to_value(error.code()).unwrap(), json!(code));
+            }
+
+            assert_eq!(ErrorCode::TokenExpired.as_str(), "auth.token_expired");
+        }
+
+        /// Test the error body carries the string code alongside the message
+        #[tokio::test]
+        async fn test_api_error_response_body() {
+            let response = ApiError::StablecoinNotFound.into_response();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+
+            assert_eq!(body["error"]["code"], "stablecoin.not_found");
+            assert_eq!(body["error"]["status"], 404);
+            assert_eq!(body["error"]["message"], "Stablecoin not found");
+        }
+
+        /// Test every failing field is reported, not just the first
+        #[tokio::test]
+        async fn test_validation_errors_report_all_fields() {
+            use crate::models::MintRequest;
+            use validator::Validate;
+
+            let req = MintRequest {
+                recipient: "not-a-pubkey".to_string(),
+                amount: 0,
+                recipient_is_token_account: false,
+            };
+            let error: ApiError = req.validate().unwrap_err().into();
+            assert_eq!(error.code().as_str(), "request.validation_failed");
+
+            let response = error.into_response();
+            assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+
+            assert_eq!(body["error"]["fields"], json!({
+                "amount": ["Amount must be greater than 0"],
+                "recipient": ["Invalid Solana pubkey length"],
+            }));
+            assert_eq!(
+                body["error"]["message"],
+                "amount: Amount must be greater than 0; recipient: Invalid Solana pubkey length"
+            );
+        }
+
+        /// Test expired JWTs map to the token_expired code
+        #[test]
+        fn test_expired_jwt_error_code() {
+            let err = jsonwebtoken::errors::Error::from(jsonwebtoken::errors::ErrorKind::ExpiredSignature);
+            let api_error: ApiError = err.into();
+            assert_eq!(api_error.code().as_str(), "auth.token_expired");
+        }
+
         /// Test SQL error conversion
         #[test]
         fn test_sql_error_conversion() {
@@ -1089,6 +2096,28 @@ mod tests {
             assert!(invalid_chars.parse::<solana_sdk::pubkey::Pubkey>().is_err());
         }
 
+        /// Test the request validator rejects the zero pubkey but accepts PDAs
+        #[test]
+        fn test_validate_solana_pubkey_rejects_zero_key() {
+            use crate::models::validate_solana_pubkey;
+            use crate::solana::SolanaService;
+            use solana_sdk::pubkey::Pubkey;
+
+            let zero = Pubkey::default().to_string();
+            let err = validate_solana_pubkey(&zero).unwrap_err();
+            assert_eq!(err.message.as_deref(), Some("Zero pubkey is not a valid address"));
+            assert!(!SolanaService::validate_pubkey(&zero));
+
+            // PDAs are off the ed25519 curve and still valid addresses
+            let (pda, _) = Pubkey::find_program_address(&[b"stablecoin"], &Pubkey::new_unique());
+            assert!(!pda.is_on_curve());
+            assert!(validate_solana_pubkey(&pda.to_string()).is_ok());
+            assert!(SolanaService::validate_pubkey(&pda.to_string()));
+
+            let err = validate_solana_pubkey(&format!("0{}", &pda.to_string()[1..])).unwrap_err();
+            assert_eq!(err.message.as_deref(), Some("Invalid Base58 character in pubkey"));
+        }
+
         /// Test email validation edge cases
         #[test]
         fn test_email_validation_edge_cases() {
@@ -1143,6 +2172,467 @@ mod tests {
         use super::*;
         use solana_sdk::pubkey::Pubkey;
 
+        /// Test compute-budget instructions are only added when configured
+        #[test]
+        fn test_compute_budget_instructions() {
+            use crate::solana::ComputeBudgetConfig;
+            use solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+            assert!(ComputeBudgetConfig::default().instructions().is_empty());
+
+            let config = ComputeBudgetConfig {
+                unit_limit: Some(400_000),
+                unit_price_microlamports: Some(5_000),
+            };
+            let instructions = config.instructions();
+            assert_eq!(instructions.len(), 2);
+            assert_eq!(instructions[0], ComputeBudgetInstruction::set_compute_unit_limit(400_000));
+            assert_eq!(instructions[1], ComputeBudgetInstruction::set_compute_unit_price(5_000));
+
+            let price_only = ComputeBudgetConfig {
+                unit_limit: None,
+                unit_price_microlamports: Some(1),
+            };
+            assert_eq!(price_only.instructions().len(), 1);
+        }
+
+        /// Test fee estimates add the per-signature base fee to the priority fee
+        #[test]
+        fn test_estimate_fee() {
+            use crate::solana::{ComputeBudgetConfig, LAMPORTS_PER_SIGNATURE};
+
+            let no_priority = ComputeBudgetConfig::default().estimate_fee(12_345, 1);
+            assert_eq!(no_priority.base_fee_lamports, LAMPORTS_PER_SIGNATURE);
+            assert_eq!(no_priority.priority_fee_lamports, 0);
+            assert_eq!(no_priority.total_lamports, LAMPORTS_PER_SIGNATURE);
+
+            // 10_001 units at 1_000 micro-lamports = 10_001_000 micro-lamports, 10.001 lamports rounded up
+            let priced = ComputeBudgetConfig {
+                unit_limit: None,
+                unit_price_microlamports: Some(1_000),
+            };
+            let estimate = priced.estimate_fee(10_001, 2);
+            assert_eq!(estimate.compute_units, 10_001);
+            assert_eq!(estimate.base_fee_lamports, 2 * LAMPORTS_PER_SIGNATURE);
+            assert_eq!(estimate.priority_fee_lamports, 11);
+            assert_eq!(estimate.total_lamports, 2 * LAMPORTS_PER_SIGNATURE + 11);
+
+            // A configured limit is what the runtime bills the priority fee on
+            let limited = ComputeBudgetConfig {
+                unit_limit: Some(200_000),
+                unit_price_microlamports: Some(1_000),
+            };
+            assert_eq!(limited.estimate_fee(10_001, 1).priority_fee_lamports, 200);
+        }
+
+        /// Test the memo instruction is prepended to the transfer and signed by the owner
+        #[test]
+        fn test_transfer_instructions_with_memo() {
+            use crate::solana::{build_transfer_instructions, MEMO_PROGRAM_ID, TOKEN_PROGRAM_IDS};
+
+            let token_2022 = TOKEN_PROGRAM_IDS[1];
+
+            let (from, to, mint, owner) =
+                (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+            let plain = build_transfer_instructions(
+                &from, &to, &mint, &owner, 1_000, 6, &token_2022, None,
+            );
+            assert_eq!(plain.len(), 1);
+            assert_eq!(plain[0].program_id, token_2022);
+            assert_eq!(plain[0].data[0], 12);
+            assert_eq!(&plain[0].data[1..9], &1_000u64.to_le_bytes());
+            assert_eq!(plain[0].data[9], 6);
+
+            let with_memo = build_transfer_instructions(
+                &from, &to, &mint, &owner, 1_000, 6, &token_2022, Some("INV-42"),
+            );
+            assert_eq!(with_memo.len(), 2);
+            assert_eq!(with_memo[0].program_id, MEMO_PROGRAM_ID);
+            assert_eq!(with_memo[0].data, b"INV-42");
+            assert_eq!(with_memo[0].accounts.len(), 1);
+            assert_eq!(with_memo[0].accounts[0].pubkey, owner);
+            assert!(with_memo[0].accounts[0].is_signer);
+            assert_eq!(with_memo[1], plain[0]);
+        }
+
+        /// Test mint and burn pass the fee treasury and reserve attestation only when required
+        #[tokio::test]
+        async fn test_mint_burn_optional_accounts() {
+            use crate::solana::{SolanaService, TOKEN_PROGRAM_IDS};
+
+            let program_id = Pubkey::new_unique();
+            let solana = SolanaService::new("http://localhost:8899", program_id).await.unwrap();
+            let token_2022 = TOKEN_PROGRAM_IDS[1];
+            let (stablecoin, mint, authority, token_account, treasury) = (
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            );
+
+            let mint_ix = solana.build_mint_instruction(
+                &stablecoin, &mint, &authority, &token_account, 1_000, 255, None, None, &token_2022, None, None,
+            );
+            assert_eq!(mint_ix.accounts.len(), 7);
+            // Omitted optional accounts use the program ID placeholder
+            assert_eq!(mint_ix.accounts[2].pubkey, program_id);
+            assert_eq!(mint_ix.accounts[3].pubkey, program_id);
+
+            let mint_ix = solana.build_mint_instruction(
+                &stablecoin, &mint, &authority, &token_account, 1_000, 255, None, None, &token_2022, Some(&treasury), None,
+            );
+            assert_eq!(mint_ix.accounts.len(), 8);
+            assert_eq!(mint_ix.accounts[7].pubkey, treasury);
+            assert!(mint_ix.accounts[7].is_writable);
+
+            // The reserve attestation follows the fee treasury or its placeholder
+            let reserve = solana.find_reserve_attestation_pda(&stablecoin).0;
+            let mint_ix = solana.build_mint_instruction(
+                &stablecoin, &mint, &authority, &token_account, 1_000, 255, None, None, &token_2022, None, Some(&reserve),
+            );
+            assert_eq!(mint_ix.accounts.len(), 9);
+            assert_eq!(mint_ix.accounts[7].pubkey, program_id);
+            assert_eq!(mint_ix.accounts[8].pubkey, reserve);
+            assert!(!mint_ix.accounts[8].is_writable);
+
+            let burn_ix = solana.build_burn_instruction(
+                &stablecoin, &mint, &authority, &token_account, 1_000, None, &token_2022, Some(&treasury),
+            );
+            assert_eq!(burn_ix.accounts.len(), 7);
+            assert_eq!(burn_ix.accounts[6].pubkey, treasury);
+            assert!(burn_ix.accounts[6].is_writable);
+        }
+
+        /// Test holder scans follow the token program that owns the mint
+        #[test]
+        fn test_token_account_filters() {
+            use crate::solana::{token_account_filters, TOKEN_PROGRAM_IDS};
+            use solana_client::rpc_filter::RpcFilterType;
+
+            let mint = Pubkey::new_unique();
+            let legacy = token_account_filters(&TOKEN_PROGRAM_IDS[0], &mint).expect("SPL Token");
+            assert_eq!(legacy.len(), 1);
+            assert!(legacy[0].contains(&RpcFilterType::DataSize(165)));
+
+            // Extended Token-2022 accounts have no fixed size, so they get their own scan
+            let token_2022 = token_account_filters(&TOKEN_PROGRAM_IDS[1], &mint).expect("Token-2022");
+            assert_eq!(token_2022.len(), 2);
+            assert!(token_2022[0].contains(&RpcFilterType::DataSize(165)));
+            assert!(!token_2022[1].iter().any(|f| matches!(f, RpcFilterType::DataSize(_))));
+
+            assert!(token_account_filters(&Pubkey::new_unique(), &mint).is_none());
+        }
+
+        /// Test RPC backoff grows exponentially, is jittered and capped
+        #[test]
+        fn test_rpc_retry_delay() {
+            use crate::solana::RpcRetryConfig;
+            use std::time::Duration;
+
+            let config = RpcRetryConfig {
+                max_retries: 5,
+                base_delay: Duration::from_millis(100),
+                max_delay: Duration::from_millis(1_000),
+            };
+            for retry in 0..6 {
+                let full = Duration::from_millis(100 * 2u64.pow(retry)).min(config.max_delay);
+                let delay = config.delay(retry);
+                assert!(delay >= full / 2 && delay <= full, "retry {}: {:?}", retry, delay);
+            }
+        }
+
+        /// Test only rate limits and timeouts are treated as transient
+        #[test]
+        fn test_transient_rpc_errors() {
+            use crate::solana::is_transient_rpc_error;
+            use solana_client::client_error::{ClientError, ClientErrorKind};
+
+            let rate_limited: ClientError = ClientErrorKind::Custom(
+                "HTTP status client error (429 Too Many Requests)".to_string(),
+            ).into();
+            let timed_out: ClientError = ClientErrorKind::Io(
+                std::io::Error::new(std::io::ErrorKind::TimedOut, "operation timed out"),
+            ).into();
+            let rejected: ClientError = ClientErrorKind::Custom(
+                "Invalid param: could not find account".to_string(),
+            ).into();
+
+            assert!(is_transient_rpc_error(&rate_limited));
+            assert!(is_transient_rpc_error(&timed_out));
+            assert!(!is_transient_rpc_error(&rejected));
+        }
+
+        /// Test exhausted RPC retries keep their own API error code
+        #[test]
+        fn test_rpc_rate_limited_error_mapping() {
+            use crate::error::ApiError;
+            use crate::solana::RpcRateLimited;
+
+            let limited = anyhow::Error::from(RpcRateLimited {
+                operation: "get account data",
+                attempts: 4,
+                last_error: "429 Too Many Requests".to_string(),
+            })
+            .context("Failed to fetch on-chain roles");
+            assert!(matches!(ApiError::solana(limited), ApiError::RpcRateLimited(_)));
+
+            let other = anyhow::anyhow!("Account not found");
+            assert!(matches!(ApiError::solana(other), ApiError::Solana(_)));
+        }
+
+        /// Test a transaction that never confirmed maps to its own error, and the
+        /// confirmation policy defaults to resubmitting
+        #[test]
+        fn test_confirmation_timeout_error_mapping() {
+            use crate::error::ApiError;
+            use crate::solana::{ConfirmationConfig, ConfirmationTimeout};
+            use solana_sdk::signature::Signature;
+
+            let timeout = anyhow::Error::from(ConfirmationTimeout {
+                signature: Signature::default(),
+                attempts: 3,
+                timeout: std::time::Duration::from_secs(60),
+            })
+            .context("Failed to mint");
+            assert!(matches!(ApiError::solana(timeout), ApiError::ConfirmationTimeout(_)));
+
+            let config = ConfirmationConfig::default();
+            assert_eq!(config.timeout, std::time::Duration::from_secs(60));
+            assert_eq!(config.max_resubmits, 2);
+        }
+
+        /// Test a short balance surfaces the actual and requested amounts
+        #[tokio::test]
+        async fn test_insufficient_balance_error() {
+            use axum::response::IntoResponse;
+            use crate::error::ApiError;
+            use crate::solana::InsufficientBalance;
+
+            let short = anyhow::Error::from(InsufficientBalance {
+                account: solana_sdk::pubkey::Pubkey::new_unique(),
+                available: 400,
+                requested: 1_000,
+            })
+            .context("Burn precheck failed");
+            let error = ApiError::solana(short);
+            assert!(matches!(error, ApiError::InsufficientBalance { available: 400, requested: 1_000 }));
+
+            let response = error.into_response();
+            assert_eq!(response.status(), axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+            assert_eq!(body["error"]["code"], "token.insufficient_balance");
+            assert_eq!(body["error"]["available"], 400);
+            assert_eq!(body["error"]["requested"], 1_000);
+        }
+
+        /// Test only the documented commitment levels are accepted
+        #[test]
+        fn test_parse_commitment() {
+            use crate::solana::{parse_commitment, ALLOWED_COMMITMENTS};
+            use solana_sdk::commitment_config::CommitmentConfig;
+
+            assert_eq!(parse_commitment("finalized"), Some(CommitmentConfig::finalized()));
+            assert_eq!(parse_commitment("confirmed"), Some(CommitmentConfig::confirmed()));
+            assert_eq!(parse_commitment("processed"), Some(CommitmentConfig::processed()));
+            assert_eq!(parse_commitment("max"), None);
+            assert_eq!(parse_commitment("Finalized"), None);
+
+            for level in ALLOWED_COMMITMENTS {
+                assert!(parse_commitment(level).is_some());
+            }
+        }
+
+        /// Test transaction statuses serialize as the API reports them and settle per commitment
+        #[test]
+        fn test_tx_status() {
+            use crate::solana::TxStatus;
+            use solana_sdk::commitment_config::CommitmentConfig;
+
+            for status in [TxStatus::Pending, TxStatus::Confirmed, TxStatus::Finalized, TxStatus::Failed] {
+                assert_eq!(serde_json::to_value(status).unwrap(), status.as_str());
+            }
+
+            assert_eq!(TxStatus::confirmed_at(CommitmentConfig::finalized()), TxStatus::Finalized);
+            assert_eq!(TxStatus::confirmed_at(CommitmentConfig::confirmed()), TxStatus::Confirmed);
+
+            let finalized = CommitmentConfig::finalized();
+            let confirmed = CommitmentConfig::confirmed();
+            assert!(!TxStatus::Pending.is_settled(confirmed));
+            assert!(TxStatus::Confirmed.is_settled(confirmed));
+            assert!(!TxStatus::Confirmed.is_settled(finalized));
+            assert!(TxStatus::Finalized.is_settled(finalized));
+            assert!(TxStatus::Failed.is_settled(finalized));
+        }
+
+        /// Test offline-signed transactions must be fully signed and scoped to the stablecoin
+        #[test]
+        fn test_validate_offline_transaction() {
+            use crate::solana::{required_signers, validate_offline_transaction};
+            use solana_sdk::hash::Hash;
+            use solana_sdk::instruction::{AccountMeta, Instruction};
+            use solana_sdk::message::Message;
+            use solana_sdk::signature::{Keypair, Signer};
+            use solana_sdk::transaction::Transaction;
+
+            let program_id = Pubkey::new_unique();
+            let stablecoin = Pubkey::new_unique();
+            let authority = Keypair::new();
+            let instruction = |target: Pubkey| Instruction {
+                program_id,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new(target, false),
+                ],
+                data: vec![0; 8],
+            };
+
+            let message = Message::new_with_blockhash(
+                &[instruction(stablecoin)],
+                Some(&authority.pubkey()),
+                &Hash::new_unique(),
+            );
+            assert_eq!(required_signers(&message), vec![authority.pubkey()]);
+
+            let unsigned = Transaction::new_unsigned(message.clone());
+            assert!(validate_offline_transaction(&unsigned, &program_id, &stablecoin).is_err());
+
+            let blockhash = message.recent_blockhash;
+            let signed = Transaction::new(&[&authority], message, blockhash);
+            assert!(validate_offline_transaction(&signed, &program_id, &stablecoin).is_ok());
+
+            // Another stablecoin's state account
+            assert!(validate_offline_transaction(&signed, &program_id, &Pubkey::new_unique()).is_err());
+
+            // A foreign program instruction is rejected even when signed
+            let foreign = Instruction {
+                program_id: Pubkey::new_unique(),
+                accounts: vec![AccountMeta::new(authority.pubkey(), true)],
+                data: vec![],
+            };
+            let mixed = Transaction::new_signed_with_payer(
+                &[instruction(stablecoin), foreign],
+                Some(&authority.pubkey()),
+                &[&authority],
+                blockhash,
+            );
+            let err = validate_offline_transaction(&mixed, &program_id, &stablecoin).unwrap_err();
+            assert!(err.contains("not allowed"));
+        }
+
+        /// Test offline actions deserialize from their `action` tag and validate parameters
+        #[test]
+        fn test_build_transaction_request() {
+            use crate::models::{BuildTransactionRequest, OfflineAction};
+            use validator::Validate;
+
+            let authority = Pubkey::new_unique().to_string();
+            let req: BuildTransactionRequest = serde_json::from_value(serde_json::json!({
+                "action": "seize",
+                "from_account": Pubkey::new_unique().to_string(),
+                "to_account": Pubkey::new_unique().to_string(),
+                "amount": 500,
+                "authority": authority,
+            })).unwrap();
+            assert!(matches!(req.action, OfflineAction::Seize { amount: 500, .. }));
+            assert_eq!(req.action.name(), "seize");
+            assert!(req.validate().is_ok());
+
+            let zero: BuildTransactionRequest = serde_json::from_value(serde_json::json!({
+                "action": "seize",
+                "from_account": Pubkey::new_unique().to_string(),
+                "to_account": Pubkey::new_unique().to_string(),
+                "amount": 0,
+                "authority": authority,
+            })).unwrap();
+            assert!(zero.validate().is_err());
+
+            let transfer: BuildTransactionRequest = serde_json::from_value(serde_json::json!({
+                "action": "transfer_authority",
+                "new_authority": "not-a-pubkey",
+                "authority": authority,
+            })).unwrap();
+            assert!(transfer.validate().is_err());
+
+            let pause: BuildTransactionRequest = serde_json::from_value(serde_json::json!({
+                "action": "pause",
+                "authority": authority,
+            })).unwrap();
+            assert!(matches!(&pause.action, OfflineAction::Pause { reason, until: 0 } if reason.is_empty()));
+            assert!(pause.validate().is_ok());
+
+            let negative_until: BuildTransactionRequest = serde_json::from_value(serde_json::json!({
+                "action": "pause",
+                "until": -1,
+                "authority": authority,
+            })).unwrap();
+            assert!(negative_until.validate().is_err());
+
+            let compliance: BuildTransactionRequest = serde_json::from_value(serde_json::json!({
+                "action": "set_compliance",
+                "enabled": true,
+                "authority": authority,
+            })).unwrap();
+            assert!(matches!(
+                compliance.action,
+                OfflineAction::SetCompliance { enabled: true, upgrade_preset: false }
+            ));
+            assert_eq!(compliance.action.name(), "set_compliance");
+            assert!(compliance.validate().is_ok());
+
+            let allowlist: BuildTransactionRequest = serde_json::from_value(serde_json::json!({
+                "action": "add_to_allowlist",
+                "account": "not-a-pubkey",
+                "authority": authority,
+            })).unwrap();
+            assert_eq!(allowlist.action.name(), "add_to_allowlist");
+            assert!(allowlist.validate().is_err());
+
+            let mode: BuildTransactionRequest = serde_json::from_value(serde_json::json!({
+                "action": "set_allowlist_mode",
+                "enabled": true,
+                "authority": authority,
+            })).unwrap();
+            assert!(matches!(mode.action, OfflineAction::SetAllowlistMode { enabled: true }));
+            assert!(mode.validate().is_ok());
+
+            let unknown = serde_json::from_value::<BuildTransactionRequest>(serde_json::json!({
+                "action": "close",
+                "authority": authority,
+            }));
+            assert!(unknown.is_err());
+        }
+
+        /// Test the idempotent ATA create instruction targets the derived address
+        #[test]
+        fn test_create_associated_token_account_idempotent() {
+            use crate::solana::{
+                build_create_associated_token_account_idempotent, find_associated_token_address,
+                ASSOCIATED_TOKEN_PROGRAM_ID,
+            };
+
+            let payer = Pubkey::new_unique();
+            let owner = Pubkey::new_unique();
+            let mint = Pubkey::new_unique();
+            let token_program: Pubkey = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb".parse().unwrap();
+
+            let ata = find_associated_token_address(&owner, &mint, &token_program);
+            let expected = Pubkey::find_program_address(
+                &[owner.as_ref(), token_program.as_ref(), mint.as_ref()],
+                &ASSOCIATED_TOKEN_PROGRAM_ID,
+            ).0;
+            assert_eq!(ata, expected);
+
+            let ix = build_create_associated_token_account_idempotent(&payer, &owner, &mint, &token_program);
+            assert_eq!(ix.program_id, ASSOCIATED_TOKEN_PROGRAM_ID);
+            assert_eq!(ix.data, vec![1]);
+            assert_eq!(ix.accounts[0].pubkey, payer);
+            assert!(ix.accounts[0].is_signer);
+            assert_eq!(ix.accounts[1].pubkey, ata);
+            assert_eq!(ix.accounts[5].pubkey, token_program);
+        }
+
         /// Test PDA derivation for stablecoin
         #[test]
         fn test_stablecoin_pda_derivation() {
@@ -1180,6 +2670,15 @@ mod tests {
             // Verify bump is valid
             assert!(bump <= 255);
             assert!(bump > 0);
+
+            // Same vector as the program and CLI tests: all three must agree
+            assert_eq!(pda.to_string(), "3ECT1sR2nYBKiJTUbELEtYuCzgTpANnFpcrY5sQjjS4G");
+            assert_eq!(bump, 251);
+            assert_eq!(crate::solana::Role::Minter.to_seed(), role);
+            assert_eq!(
+                sss_token::constants::role_pda(&stablecoin, &account, role, &program_id),
+                (pda, bump),
+            );
         }
 
         /// Test pubkey format validation
@@ -1370,12 +2869,230 @@ mod tests {
             // Should allow ~1.67 requests per second
             assert!(requests_per_sec < 2.0);
         }
+
+        use crate::app_middleware::rate_limit::{RateLimiter, RouteGroup};
+        use crate::config::RateLimitGroups;
+        use axum::http::Method;
+
+        #[test]
+        fn test_route_group_classification() {
+            assert_eq!(RouteGroup::classify(&Method::POST, "/api/v1/auth/login"), RouteGroup::Auth);
+            assert_eq!(RouteGroup::classify(&Method::POST, "/api/v1/stablecoin/abc/mint"), RouteGroup::Mint);
+            assert_eq!(RouteGroup::classify(&Method::POST, "/api/v1/stablecoin/abc/burn"), RouteGroup::Mint);
+            assert_eq!(RouteGroup::classify(&Method::GET, "/api/v1/stablecoin/abc/status"), RouteGroup::Read);
+            assert_eq!(RouteGroup::classify(&Method::POST, "/api/v1/stablecoin/abc/pause"), RouteGroup::Default);
+        }
+
+        #[test]
+        fn test_unconfigured_groups_share_global_bucket() {
+            let limiter = RateLimiter::new(100, 60);
+            for group in [RouteGroup::Auth, RouteGroup::Mint, RouteGroup::Read, RouteGroup::Default] {
+                assert_eq!(limiter.limit_for(group, false), 100);
+                assert_eq!(limiter.limit_for(group, true), 100);
+                assert_eq!(limiter.bucket_key(group, "1.2.3.4"), "1.2.3.4");
+            }
+        }
+
+        #[test]
+        fn test_group_and_admin_limits() {
+            let limiter = RateLimiter::new(100, 60).with_groups(RateLimitGroups {
+                auth: Some(10),
+                mint: Some(20),
+                read: None,
+                admin_multiplier: 5,
+            });
+
+            assert_eq!(limiter.limit_for(RouteGroup::Auth, false), 10);
+            assert_eq!(limiter.limit_for(RouteGroup::Mint, true), 100);
+            assert_eq!(limiter.limit_for(RouteGroup::Read, false), 100);
+            assert_eq!(limiter.limit_for(RouteGroup::Default, true), 500);
+
+            assert_eq!(limiter.bucket_key(RouteGroup::Auth, "1.2.3.4"), "auth:1.2.3.4");
+            assert_eq!(limiter.bucket_key(RouteGroup::Read, "1.2.3.4"), "1.2.3.4");
+        }
+
+        #[tokio::test]
+        async fn test_acquire_reports_remaining() {
+            let limiter = RateLimiter::new(2, 60);
+
+            let first = limiter.acquire("client", 2).await;
+            assert!(first.allowed);
+            assert_eq!((first.limit, first.remaining), (2, 1));
+            assert!(first.reset_at > 0);
+
+            assert!(limiter.acquire("client", 2).await.allowed);
+
+            let blocked = limiter.acquire("client", 2).await;
+            assert!(!blocked.allowed);
+            assert_eq!(blocked.remaining, 0);
+
+            let mut headers = axum::http::HeaderMap::new();
+            blocked.apply_headers(&mut headers);
+            assert_eq!(headers["x-ratelimit-limit"], "2");
+            assert_eq!(headers["x-ratelimit-remaining"], "0");
+
+            // The legacy check uses the global limit on the same buckets
+            assert!(limiter.check("client").await.is_err());
+            assert!(limiter.check("other").await.is_ok());
+        }
+    }
+
+    // ============================================================================
+    // CSRF Tests
+    // ============================================================================
+
+    mod csrf_tests {
+        use axum::http::{header, HeaderMap, HeaderValue, Method, StatusCode};
+        use crate::app_middleware::csrf::{check_request, CSRF_COOKIE, CSRF_HEADER};
+        use crate::routes::csrf::issue_token;
+
+        const SECRET: &str = "csrf-test-secret";
+
+        /// A POST without Origin/Referer is rejected until it echoes an issued token
+        #[tokio::test]
+        async fn test_issued_csrf_token_is_accepted() {
+            let origins = vec!["https://app.example.com".to_string()];
+            let path = "/api/v1/stablecoin";
+
+            let mut headers = HeaderMap::new();
+            assert_eq!(
+                check_request(&Method::POST, path, &headers, &origins, SECRET),
+                Err(StatusCode::FORBIDDEN)
+            );
+
+            let response = issue_token(SECRET, true);
+            let cookie = response
+                .headers()
+                .get(header::SET_COOKIE)
+                .and_then(|v| v.to_str().ok())
+                .expect("cookie set")
+                .to_string();
+            assert!(cookie.starts_with(&format!("{}=", CSRF_COOKIE)));
+            assert!(cookie.contains("SameSite=Strict"));
+            assert!(cookie.contains("Secure"));
+
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(body["header"], CSRF_HEADER);
+            let token = body["csrf_token"].as_str().expect("token in body");
+            assert!(cookie.contains(token));
+
+            headers.insert(CSRF_HEADER, HeaderValue::from_str(token).unwrap());
+            assert_eq!(check_request(&Method::POST, path, &headers, &origins, SECRET), Ok(()));
+
+            // Double-submit: the header has to match the cookie when both are sent
+            let cookie_pair = cookie.split(';').next().unwrap();
+            headers.insert(header::COOKIE, HeaderValue::from_str(cookie_pair).unwrap());
+            assert_eq!(check_request(&Method::POST, path, &headers, &origins, SECRET), Ok(()));
+            let stale_cookie = format!("theme=dark; {}=stale-token", CSRF_COOKIE);
+            headers.insert(header::COOKIE, HeaderValue::from_str(&stale_cookie).unwrap());
+            assert_eq!(
+                check_request(&Method::POST, path, &headers, &origins, SECRET),
+                Err(StatusCode::FORBIDDEN)
+            );
+
+            // A token signed with another secret is rejected
+            let insecure = issue_token(SECRET, false);
+            let insecure_cookie = insecure.headers().get(header::SET_COOKIE).unwrap().to_str().unwrap();
+            assert!(!insecure_cookie.contains("Secure"));
+
+            let mut forged = HeaderMap::new();
+            forged.insert(CSRF_HEADER, HeaderValue::from_str(token).unwrap());
+            assert_eq!(
+                check_request(&Method::POST, path, &forged, &origins, "other-secret"),
+                Err(StatusCode::FORBIDDEN)
+            );
+        }
     }
 
     // ============================================================================
     // Configuration Tests
     // ============================================================================
 
+    mod health_tests {
+        use crate::routes::health::{authority_balance_health, ComponentHealth, ReadinessResponse};
+
+        #[test]
+        fn test_authority_balance_threshold() {
+            assert_eq!(authority_balance_health(10_000_000, 10_000_000).status, "healthy");
+            assert_eq!(authority_balance_health(u64::MAX, 10_000_000).status, "healthy");
+
+            let low = authority_balance_health(9_999_999, 10_000_000);
+            assert_eq!(low.status, "unhealthy");
+            assert!(low.message.unwrap().contains("below minimum"));
+        }
+
+        #[test]
+        fn test_readiness_response_lists_subsystems() {
+            let mut checks = std::collections::HashMap::new();
+            checks.insert("database".to_string(), ComponentHealth::healthy_with_latency(3));
+            checks.insert("authority_balance".to_string(), ComponentHealth::unhealthy("low"));
+
+            let body = serde_json::to_value(ReadinessResponse { ready: false, checks: Some(checks) }).unwrap();
+
+            assert_eq!(body["ready"], false);
+            assert_eq!(body["checks"]["database"]["status"], "healthy");
+            assert_eq!(body["checks"]["database"]["latency_ms"], 3);
+            assert_eq!(body["checks"]["authority_balance"]["status"], "unhealthy");
+            assert_eq!(body["checks"]["authority_balance"]["message"], "low");
+        }
+    }
+
+    mod proof_tests {
+        use crate::routes::proofs::{reserve_proof_message, reserve_ratio_bps};
+        use chrono::{TimeZone, Utc};
+        use solana_sdk::signature::{Keypair, Signer};
+
+        #[test]
+        fn test_reserve_ratio_bps() {
+            assert_eq!(reserve_ratio_bps(1_000, 1_000), Some(10_000));
+            assert_eq!(reserve_ratio_bps(1_025, 1_000), Some(10_250));
+            assert_eq!(reserve_ratio_bps(999, 1_000), Some(9_990));
+            assert_eq!(reserve_ratio_bps(u64::MAX, u64::MAX), Some(10_000));
+            assert_eq!(reserve_ratio_bps(u64::MAX, 1), Some(u64::MAX));
+            assert_eq!(reserve_ratio_bps(1_000, 0), None);
+        }
+
+        #[test]
+        fn test_reserve_proof_signature_verifies() {
+            let attested_at = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+            let timestamp = Utc.timestamp_opt(1_700_000_600, 0).unwrap();
+            let message = reserve_proof_message("7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU", 1_050, 1_000, attested_at, timestamp);
+            assert_eq!(
+                message,
+                "sss-proof-of-reserves:v1|7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU|1050|1000|1700000000|1700000600"
+            );
+
+            let keypair = Keypair::new();
+            let signature = keypair.sign_message(message.as_bytes());
+            assert!(signature.verify(keypair.pubkey().as_ref(), message.as_bytes()));
+            assert!(!signature.verify(keypair.pubkey().as_ref(), message.replace("|1050|", "|2050|").as_bytes()));
+        }
+    }
+
+    mod metrics_tests {
+        use crate::routes::metrics::record_transaction;
+        use prometheus::{Encoder, TextEncoder};
+        use std::time::Duration;
+
+        #[test]
+        fn test_record_transaction_metrics() {
+            record_transaction("metrics_test", true, Duration::from_millis(1500));
+            record_transaction("metrics_test", false, Duration::from_secs(90));
+
+            let mut buffer = Vec::new();
+            TextEncoder::new().encode(&prometheus::gather(), &mut buffer).unwrap();
+            let text = String::from_utf8(buffer).unwrap();
+
+            assert!(text.contains(r#"sss_tx_total{operation="metrics_test",result="success"} 1"#), "{}", text);
+            assert!(text.contains(r#"sss_tx_total{operation="metrics_test",result="failure"} 1"#), "{}", text);
+            // Only the confirmed transaction is timed
+            assert!(text.contains(r#"sss_tx_confirmation_seconds_count{operation="metrics_test"} 1"#), "{}", text);
+            assert!(text.contains(r#"sss_tx_confirmation_seconds_sum{operation="metrics_test"} 1.5"#), "{}", text);
+            assert!(text.contains(r#"sss_tx_confirmation_seconds_bucket{operation="metrics_test",le="2"} 1"#), "{}", text);
+        }
+    }
+
     mod config_tests {
         use super::*;
 
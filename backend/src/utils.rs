@@ -1,4 +1,4 @@
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -12,8 +12,18 @@ pub struct Claims {
     pub exp: usize,       // Expiration time
     pub iat: usize,       // Issued at
     pub jti: Uuid,        // JWT ID for revocation
+    #[serde(default)]
+    pub token_type: String,          // "access" or "refresh"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub family_id: Option<Uuid>,     // Refresh token rotation family
 }
 
+/// Token type claim for access tokens
+pub const ACCESS_TOKEN_TYPE: &str = "access";
+
+/// Token type claim for refresh tokens
+pub const REFRESH_TOKEN_TYPE: &str = "refresh";
+
 /// Token pair response
 #[derive(Debug, Serialize)]
 pub struct TokenPair {
@@ -21,15 +31,49 @@ pub struct TokenPair {
     pub refresh_token: String,
     pub token_type: String,
     pub expires_in: u64,
+    /// Refresh token metadata, persisted to `refresh_sessions`
+    #[serde(skip)]
+    pub refresh_jti: Uuid,
+    #[serde(skip)]
+    pub family_id: Uuid,
+    #[serde(skip)]
+    pub refresh_expires_at: DateTime<Utc>,
 }
 
-/// Generate JWT tokens for a user
+/// Text a wallet signs to prove it holds its key for a sign-in challenge
+pub fn wallet_challenge_message(nonce: &str) -> String {
+    format!("Sign in to the Solana Stablecoin Standard backend.\n\nNonce: {}", nonce)
+}
+
+/// Whether `signature` (base58) is `pubkey`'s ed25519 signature of `message`
+pub fn verify_wallet_signature(pubkey: &str, message: &str, signature: &str) -> bool {
+    use solana_sdk::{pubkey::Pubkey, signature::Signature};
+    
+    let (Ok(pubkey), Ok(signature)) = (pubkey.parse::<Pubkey>(), signature.parse::<Signature>()) else {
+        return false;
+    };
+    signature.verify(pubkey.as_ref(), message.as_bytes())
+}
+
+/// Generate JWT tokens for a user, starting a new refresh token family
 pub fn generate_tokens(
     user_id: Uuid,
     email: &str,
     role: &str,
     jwt_secret: &str,
     expiry_secs: u64,
+) -> Result<TokenPair, jsonwebtoken::errors::Error> {
+    generate_tokens_in_family(user_id, email, role, jwt_secret, expiry_secs, Uuid::new_v4())
+}
+
+/// Generate JWT tokens for a user, continuing an existing refresh token family
+pub fn generate_tokens_in_family(
+    user_id: Uuid,
+    email: &str,
+    role: &str,
+    jwt_secret: &str,
+    expiry_secs: u64,
+    family_id: Uuid,
 ) -> Result<TokenPair, jsonwebtoken::errors::Error> {
     let now = Utc::now();
     
@@ -41,6 +85,8 @@ pub fn generate_tokens(
         exp: (now + Duration::seconds(expiry_secs as i64)).timestamp() as usize,
         iat: now.timestamp() as usize,
         jti: Uuid::new_v4(),
+        token_type: ACCESS_TOKEN_TYPE.to_string(),
+        family_id: None,
     };
     
     let access_token = encode(
@@ -50,13 +96,16 @@ pub fn generate_tokens(
     )?;
     
     // Refresh token (long-lived)
+    let refresh_expires_at = now + Duration::days(30);
     let refresh_claims = Claims {
         sub: user_id,
         email: email.to_string(),
         role: role.to_string(),
-        exp: (now + Duration::days(30)).timestamp() as usize,
+        exp: refresh_expires_at.timestamp() as usize,
         iat: now.timestamp() as usize,
         jti: Uuid::new_v4(),
+        token_type: REFRESH_TOKEN_TYPE.to_string(),
+        family_id: Some(family_id),
     };
     
     let refresh_token = encode(
@@ -70,6 +119,9 @@ pub fn generate_tokens(
         refresh_token,
         token_type: "Bearer".to_string(),
         expires_in: expiry_secs,
+        refresh_jti: refresh_claims.jti,
+        family_id,
+        refresh_expires_at,
     })
 }
 
@@ -128,6 +180,12 @@ pub fn generate_api_key() -> String {
         .collect()
 }
 
+/// Hash an API key for storage and lookup (keys are random, so a plain digest suffices)
+pub fn hash_api_key(key: &str) -> String {
+    use sha2::{Digest, Sha256};
+    hex::encode(Sha256::digest(key.as_bytes()))
+}
+
 /// Validate email format
 pub fn is_valid_email(email: &str) -> bool {
     let email_regex = regex::Regex::new(r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$").unwrap();
@@ -168,6 +226,8 @@ pub struct AuditEntry {
     pub tx_signature: Option<String>,
     pub details: Option<Value>,
     pub ip_address: Option<String>,
+    /// Correlation ID of the HTTP request that caused the entry
+    pub request_id: Option<String>,
 }
 
 /// Log an audit entry with proper error handling
@@ -188,6 +248,7 @@ pub async fn log_audit(db: &Database, entry: AuditEntry) {
         entry.tx_signature.as_deref(),
         entry.details.clone(),
         entry.ip_address.as_deref(),
+        entry.request_id.as_deref(),
     ).await {
         Ok(()) => {
             tracing::debug!(
@@ -195,6 +256,7 @@ pub async fn log_audit(db: &Database, entry: AuditEntry) {
                 user_id = ?entry.user_id,
                 action = %entry.action,
                 tx_signature = ?entry.tx_signature,
+                request_id = ?entry.request_id,
                 "Audit log recorded successfully"
             );
         }
@@ -208,6 +270,7 @@ pub async fn log_audit(db: &Database, entry: AuditEntry) {
                 user_id = ?entry.user_id,
                 action = %entry.action,
                 tx_signature = ?entry.tx_signature,
+                request_id = ?entry.request_id,
                 details = ?entry.details,
                 error = %e,
                 total_failures = failure_count,
@@ -221,6 +284,7 @@ pub async fn log_audit(db: &Database, entry: AuditEntry) {
                     user_id = ?entry.user_id,
                     action = %entry.action,
                     tx_signature = ?entry.tx_signature,
+                request_id = ?entry.request_id,
                     details = ?entry.details,
                     error = %e,
                     "CRITICAL: Audit log failure for sensitive operation - immediate attention required"
@@ -239,6 +303,7 @@ pub async fn audit(
     tx_signature: Option<&str>,
     details: Option<Value>,
     ip_address: Option<&str>,
+    request_id: Option<&str>,
 ) {
     log_audit(db, AuditEntry {
         stablecoin_id,
@@ -247,6 +312,7 @@ pub async fn audit(
         tx_signature: tx_signature.map(|s| s.to_string()),
         details,
         ip_address: ip_address.map(|s| s.to_string()),
+        request_id: request_id.map(|s| s.to_string()),
     }).await;
 }
 
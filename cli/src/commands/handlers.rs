@@ -5,19 +5,31 @@
 
 use anchor_client::Program;
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{
+    get_associated_token_address_with_program_id,
+    spl_associated_token_account::instruction::create_associated_token_account_idempotent,
+};
 use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction,
     pubkey::Pubkey,
-    signature::Keypair,
+    signature::{Keypair, Signature},
     signer::Signer,
     system_program,
     instruction::{AccountMeta, Instruction},
     account::Account as SolanaAccount,
+    message::Message,
+    native_token::LAMPORTS_PER_SOL,
+    packet::PACKET_DATA_SIZE,
 };
+use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::error::CliError;
 use crate::instructions::*;
-use crate::{STABLECOIN_SEED, ROLE_SEED, MINTER_SEED, BLACKLIST_SEED};
+use sss_token::constants::{
+    self, ALLOWLIST_SEED, BLACKLIST_SEED, FREEZE_SEED, MINTER_SEED, ROLE_SEED, VAULT_SEED,
+};
+use sss_token::math::format_token_amount;
 
 // Define a custom Result type to avoid conflict with anchor_lang::prelude::Result
 type CliResult<T> = std::result::Result<T, CliError>;
@@ -25,33 +37,22 @@ type CliResult<T> = std::result::Result<T, CliError>;
 // Role enum re-export for convenience
 pub use crate::instructions::Role;
 
-// PDA derivation helpers matching the program's constants
-fn derive_stablecoin_pda(asset_mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[STABLECOIN_SEED, asset_mint.to_bytes().as_ref()],
-        program_id,
-    )
-}
-
-fn derive_role_pda(stablecoin: &Pubkey, account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[ROLE_SEED, stablecoin.to_bytes().as_ref(), account.to_bytes().as_ref()],
-        program_id,
-    )
-}
-
-fn derive_minter_pda(stablecoin: &Pubkey, minter: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[MINTER_SEED, stablecoin.to_bytes().as_ref(), minter.to_bytes().as_ref()],
-        program_id,
-    )
-}
-
-fn derive_blacklist_pda(stablecoin: &Pubkey, account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[BLACKLIST_SEED, stablecoin.to_bytes().as_ref(), account.to_bytes().as_ref()],
-        program_id,
-    )
+/// Role assignment `authority` signs with for an operation gated on `role`:
+/// its `role` assignment if one exists, otherwise its Master assignment.
+/// Falls back to the `role` PDA so the program reports the missing role.
+fn resolve_role_assignment(
+    program: &Program<Rc<Keypair>>,
+    stablecoin: &Pubkey,
+    authority: &Pubkey,
+    role: Role,
+) -> Pubkey {
+    let program_id = program.id();
+    let role_pda = constants::role_pda(stablecoin, authority, role.seed(), &program_id).0;
+    let master_pda = constants::role_pda(stablecoin, authority, Role::Master.seed(), &program_id).0;
+    match program.rpc().get_multiple_accounts(&[role_pda, master_pda]) {
+        Ok(accounts) if accounts[0].is_none() && accounts[1].is_some() => master_pda,
+        _ => role_pda,
+    }
 }
 
 fn parse_pubkey(s: &str) -> CliResult<Pubkey> {
@@ -65,7 +66,372 @@ fn print_tx_success(signature: &str, action: &str) {
     println!("   Explorer: https://explorer.solana.com/tx/{}", signature);
 }
 
+/// How the amount argument of `mint`, `burn` and `seize` is read
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum AmountUnits {
+    /// Raw base units, e.g. 1500000 for 1.5 tokens at 6 decimals
+    #[default]
+    Base,
+    /// Whole tokens, converted with the mint's decimals
+    Human,
+}
+
+/// Parse an amount with up to `decimals` fractional digits into base units.
+/// `_` may be used as a digit separator (`1_000.5`).
+pub fn parse_token_amount(input: &str, decimals: u8) -> CliResult<u64> {
+    let cleaned = input.replace('_', "");
+    let (whole, fraction) = cleaned.split_once('.').unwrap_or((&cleaned, ""));
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+        return Err(CliError::InvalidArg(format!("Invalid amount '{}'", input)));
+    }
+    if fraction.len() > decimals as usize {
+        return Err(CliError::InvalidArg(if decimals == 0 {
+            format!("Amount '{}' must be a whole number of base units (use --units human for token amounts)", input)
+        } else {
+            format!("Amount '{}' has more than {} decimal places", input, decimals)
+        }));
+    }
+    
+    let overflow = || CliError::InvalidArg(format!("Amount '{}' is too large", input));
+    let scale = 10u64.checked_pow(decimals as u32).ok_or_else(overflow)?;
+    let whole: u64 = if whole.is_empty() { 0 } else { whole.parse().map_err(|_| overflow())? };
+    let fraction: u64 = if fraction.is_empty() {
+        0
+    } else {
+        format!("{:0<width$}", fraction, width = decimals as usize).parse().map_err(|_| overflow())?
+    };
+    whole.checked_mul(scale).and_then(|base| base.checked_add(fraction)).ok_or_else(overflow)
+}
+
+/// Resolve a command's amount argument to base units. Human amounts use the
+/// decimals of the stablecoin's mint, fetched from chain.
+pub fn resolve_amount(
+    program: &Program<Rc<Keypair>>,
+    amount: &str,
+    units: AmountUnits,
+    stablecoin: Option<&Pubkey>,
+) -> CliResult<u64> {
+    match units {
+        AmountUnits::Base => parse_token_amount(amount, 0),
+        AmountUnits::Human => {
+            let stablecoin_pda = stablecoin.ok_or_else(|| {
+                CliError::InvalidArg("Stablecoin PDA is required. Use --stablecoin <address>".to_string())
+            })?;
+            let state = decode_account::<StablecoinStateData>(&program.rpc().get_account_data(stablecoin_pda)?)?;
+            parse_token_amount(amount, fetch_mint_decimals(program, &state.asset_mint)?)
+        }
+    }
+}
+
+/// Transaction options set by the global `--priority-fee` / `--compute-limit` flags.
+///
+/// A value of `None` or `0` means the corresponding compute-budget
+/// instruction is not added to the transaction.
+#[derive(Debug, Clone)]
+pub struct TxOptions {
+    /// Priority fee in micro-lamports per compute unit
+    pub priority_fee: Option<u64>,
+    /// Compute unit limit for the transaction
+    pub compute_limit: Option<u32>,
+    /// When set, instructions are collected here instead of sent (`build-tx`)
+    pub capture: Option<RefCell<Vec<Instruction>>>,
+    /// Signatures of the transactions sent so far, for the audit file
+    pub sent: RefCell<Vec<Signature>>,
+    /// How long to wait for a transaction to confirm before resubmitting it
+    pub confirm_timeout: std::time::Duration,
+    /// Resubmissions with a fresh blockhash after a confirmation timeout
+    pub max_resubmits: u32,
+}
+
+impl Default for TxOptions {
+    fn default() -> Self {
+        TxOptions {
+            priority_fee: None,
+            compute_limit: None,
+            capture: None,
+            sent: RefCell::default(),
+            confirm_timeout: std::time::Duration::from_secs(60),
+            max_resubmits: 2,
+        }
+    }
+}
+
+impl TxOptions {
+    /// Compute-budget instructions to prepend to every transaction
+    pub fn compute_budget_instructions(&self) -> Vec<Instruction> {
+        let mut ixs = Vec::new();
+        if let Some(units) = self.compute_limit.filter(|u| *u > 0) {
+            ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(units));
+        }
+        if let Some(price) = self.priority_fee.filter(|p| *p > 0) {
+            ixs.push(ComputeBudgetInstruction::set_compute_unit_price(price));
+        }
+        ixs
+    }
+    
+    /// Copy of these options that collects instructions instead of sending them
+    pub fn capturing(&self) -> Self {
+        TxOptions {
+            capture: Some(RefCell::new(Vec::new())),
+            ..self.clone()
+        }
+    }
+    
+    /// Everything a captured command would have sent, compute-budget instructions first
+    pub fn take_captured(&self) -> Vec<Instruction> {
+        let captured = self.capture.as_ref().map(|c| c.take()).unwrap_or_default();
+        if captured.is_empty() {
+            return captured;
+        }
+        self.compute_budget_instructions().into_iter().chain(captured).collect()
+    }
+}
+
+/// Output mode for the read-only status and info commands
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable output on stdout
+    #[default]
+    Text,
+    /// Machine-readable JSON on stdout, human messages on stderr
+    Json,
+}
+
+impl OutputFormat {
+    pub fn is_json(self) -> bool {
+        self == OutputFormat::Json
+    }
+}
+
+/// Print a human-readable line; goes to stderr in JSON mode so stdout stays parseable
+macro_rules! human {
+    ($output:expr, $($arg:tt)*) => {
+        if $output.is_json() {
+            eprintln!($($arg)*);
+        } else {
+            println!($($arg)*);
+        }
+    };
+}
+
+/// Pretty-print a value as JSON on stdout
+fn print_json<T: serde::Serialize>(value: &T) -> CliResult<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+/// Send a single program instruction, prefixed by any configured compute-budget instructions
+fn send_instruction(
+    program: &Program<Rc<Keypair>>,
+    tx: &TxOptions,
+    ix: Instruction,
+) -> CliResult<Signature> {
+    send_instructions(program, tx, vec![ix])
+}
+
+/// Fail before sending when a token account holds less than `amount`, instead
+/// of letting the token program reject the transaction with an opaque error
+fn require_balance(program: &Program<Rc<Keypair>>, token_account: &Pubkey, amount: u64) -> CliResult<()> {
+    let balance = program.rpc().get_token_account_balance(token_account)?;
+    let available = balance.amount.parse::<u64>()
+        .map_err(|e| CliError::SerializationError(format!("Invalid balance '{}': {}", balance.amount, e)))?;
+    if available < amount {
+        return Err(CliError::InsufficientBalance(amount, available));
+    }
+    Ok(())
+}
+
+/// Send several program instructions in one transaction
+fn send_instructions(
+    program: &Program<Rc<Keypair>>,
+    tx: &TxOptions,
+    ixs: Vec<Instruction>,
+) -> CliResult<Signature> {
+    send_instructions_signed(program, tx, ixs, &[])
+}
+
+/// Send several program instructions, with keypairs that must sign besides the payer
+fn send_instructions_signed(
+    program: &Program<Rc<Keypair>>,
+    tx: &TxOptions,
+    ixs: Vec<Instruction>,
+    signers: &[&Keypair],
+) -> CliResult<Signature> {
+    if tx.capture.is_some() {
+        return Err(CliError::InvalidArg(
+            "This command sends several transactions and cannot be built offline".to_string()
+        ));
+    }
+    let mut request = program.request();
+    for ix in tx.compute_budget_instructions().into_iter().chain(ixs) {
+        request = request.instruction(ix);
+    }
+    for signer in signers {
+        request = request.signer(*signer);
+    }
+    // Each call signs against the latest blockhash
+    let sign = || request
+        .signed_transaction()
+        .map_err(|e| CliError::TransactionError(e.to_string()));
+    let signature = send_and_confirm(&program.rpc(), tx, sign()?, Some(&sign))?;
+    tx.sent.borrow_mut().push(signature);
+    Ok(signature)
+}
+
+/// How often a sent transaction's status is polled
+const CONFIRM_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Send `transaction` and wait up to `tx.confirm_timeout` for it to confirm.
+/// If it doesn't, wait for its blockhash to expire so it can no longer land,
+/// then sign a fresh copy with `resign` and resubmit, up to `tx.max_resubmits`
+/// times. A slow transaction that lands meanwhile is never sent twice.
+fn send_and_confirm(
+    rpc: &solana_client::rpc_client::RpcClient,
+    tx: &TxOptions,
+    mut transaction: solana_sdk::transaction::Transaction,
+    resign: Option<&dyn Fn() -> CliResult<solana_sdk::transaction::Transaction>>,
+) -> CliResult<Signature> {
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        let signature = rpc.send_transaction(&transaction)?;
+        
+        let started = std::time::Instant::now();
+        while started.elapsed() < tx.confirm_timeout {
+            if signature_confirmed(rpc, &signature)? == Some(true) {
+                return Ok(signature);
+            }
+            std::thread::sleep(CONFIRM_POLL_INTERVAL);
+        }
+        
+        eprintln!("⏳ {} not confirmed after {}s, waiting for its blockhash to expire",
+            signature, tx.confirm_timeout.as_secs());
+        loop {
+            // Check the blockhash before the status, so a transaction that
+            // landed just before expiry is still seen
+            let expired = !rpc.is_blockhash_valid(
+                &transaction.message.recent_blockhash,
+                solana_sdk::commitment_config::CommitmentConfig::processed(),
+            )?;
+            match signature_confirmed(rpc, &signature)? {
+                Some(true) => return Ok(signature),
+                None if expired => break,
+                _ => std::thread::sleep(CONFIRM_POLL_INTERVAL),
+            }
+        }
+        
+        let Some(resign) = resign.filter(|_| attempts <= tx.max_resubmits) else {
+            return Err(CliError::ConfirmationTimeout(signature.to_string(), attempts));
+        };
+        eprintln!("🔁 Resubmitting with a fresh blockhash");
+        transaction = resign()?;
+    }
+}
+
+/// Whether `signature` has reached the client's commitment: `None` if the
+/// cluster has not seen it, an error if it executed and failed
+fn signature_confirmed(
+    rpc: &solana_client::rpc_client::RpcClient,
+    signature: &Signature,
+) -> CliResult<Option<bool>> {
+    let Some(status) = rpc.get_signature_statuses(&[*signature])?.value.remove(0) else {
+        return Ok(None);
+    };
+    if let Some(err) = status.err {
+        return Err(CliError::TransactionError(format!("{} failed: {}", signature, err)));
+    }
+    Ok(Some(status.satisfies_commitment(rpc.commitment())))
+}
+
+/// Send a command's instructions and report success, or collect them when building offline
+fn finish(
+    program: &Program<Rc<Keypair>>,
+    tx: &TxOptions,
+    ixs: Vec<Instruction>,
+    action: &str,
+) -> CliResult<()> {
+    if let Some(capture) = &tx.capture {
+        capture.borrow_mut().extend(ixs);
+        return Ok(());
+    }
+    let signature = send_instructions(program, tx, ixs)?;
+    print_tx_success(&signature.to_string(), action);
+    Ok(())
+}
+
+/// Serialize a Pubkey as its base58 string for JSON output
+fn serialize_pubkey<S: serde::Serializer>(pubkey: &Pubkey, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_str(&pubkey.to_string())
+}
+
+/// Anchor account discriminator: sha256("account:<Name>")[0..8]
+fn account_discriminator(name: &str) -> [u8; 8] {
+    use sha2::{Digest, Sha256};
+    let hash = Sha256::digest(format!("account:{}", name).as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash[..8]);
+    discriminator
+}
+
+/// Strip the 8-byte Anchor discriminator and deserialize the account body.
+/// Trailing bytes (reserved space) are ignored.
+fn decode_account<T: ::borsh::BorshDeserialize>(data: &[u8]) -> CliResult<T> {
+    if data.len() <= 8 {
+        return Err(CliError::SerializationError("Account data too short".to_string()));
+    }
+    let mut body = &data[8..];
+    T::deserialize(&mut body).map_err(|e| CliError::SerializationError(e.to_string()))
+}
+
+/// Fetch every program account of the Anchor type `name`, decoded.
+/// Accounts that fail to decode are skipped.
+fn fetch_program_accounts<T: ::borsh::BorshDeserialize>(
+    program: &Program<Rc<Keypair>>,
+    name: &str,
+) -> CliResult<Vec<(Pubkey, T)>> {
+    use solana_client::rpc_config::RpcProgramAccountsConfig;
+    use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+    
+    let accounts = program.rpc().get_program_accounts_with_config(
+        &program.id(),
+        RpcProgramAccountsConfig {
+            filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                0,
+                account_discriminator(name).to_vec(),
+            ))]),
+            ..Default::default()
+        },
+    )?;
+    
+    Ok(accounts
+        .into_iter()
+        .filter_map(|(pubkey, account)| Some((pubkey, decode_account::<T>(&account.data).ok()?)))
+        .collect())
+}
+
+/// Append the fee treasury account (mint/burn) when the stablecoin charges a fee
+fn append_fee_treasury(
+    program: &Program<Rc<Keypair>>,
+    stablecoin_pda: &Pubkey,
+    accounts: &mut Vec<AccountMeta>,
+) {
+    let state = program
+        .rpc()
+        .get_account_data(stablecoin_pda)
+        .ok()
+        .and_then(|data| decode_account::<StablecoinStateData>(&data).ok());
+    
+    if let Some(state) = state {
+        if state.fee_bps > 0 {
+            println!("   Fee: {} bps → {}", state.fee_bps, state.fee_treasury);
+            accounts.push(AccountMeta::new(state.fee_treasury, false)); // fee_treasury (mut)
+        }
+    }
+}
+
 // ==================== INIT ====================
+#[allow(clippy::too_many_arguments)]
 pub fn handle_init(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
@@ -75,6 +441,11 @@ pub fn handle_init(
     uri: String,
     decimals: u8,
     asset_mint: Option<String>,
+    interest_rate_bps: Option<i16>,
+    transfer_fee: Option<TransferFeeArgs>,
+    max_supply: Option<u64>,
+    force_reinit: bool,
+    tx: &TxOptions,
 ) -> CliResult<()> {
     println!("🚀 Initializing stablecoin...");
     println!("   Preset: SSS-{}", preset);
@@ -103,52 +474,122 @@ pub fn handle_init(
     
     let program_id = program.id();
     
-    // Create or use provided asset mint
-    let asset_mint_pubkey = match asset_mint {
-        Some(mint) => parse_pubkey(&mint)?,
+    if let Some(fee) = &transfer_fee {
+        if fee.transfer_fee_bps > 10_000 {
+            return Err(CliError::InvalidArg("Transfer fee must be at most 10000 bps".to_string()));
+        }
+    }
+    
+    // Without --asset-mint, the program creates a fresh Token-2022 mint
+    // controlled by the stablecoin PDA
+    let (asset_mint_pubkey, new_mint) = match asset_mint {
+        Some(_) if interest_rate_bps.is_some() || transfer_fee.is_some() => {
+            return Err(CliError::InvalidArg(
+                "Mint extensions can only be set on a new mint; omit --asset-mint".to_string()
+            ));
+        }
+        Some(mint) => (parse_pubkey(&mint)?, None),
+        None if tx.capture.is_some() => {
+            return Err(CliError::InvalidArg(
+                "Offline builds can't sign for a new mint; create the mint first and pass --asset-mint".to_string()
+            ));
+        }
         None => {
-            println!("   Note: No asset_mint provided, using authority as asset_mint reference");
-            *authority
+            let mint = Keypair::new();
+            (mint.pubkey(), Some(mint))
         }
     };
     
-    let (stablecoin_pda, bump) = derive_stablecoin_pda(&asset_mint_pubkey, &program_id);
+    let (stablecoin_pda, bump) = constants::stablecoin_pda(&asset_mint_pubkey, &program_id);
     
+    println!("   Asset mint: {}{}", asset_mint_pubkey, if new_mint.is_some() { " (new)" } else { "" });
     println!("   Stablecoin PDA: {}", stablecoin_pda);
     println!("   Bump: {}", bump);
     
-    // Build accounts for Initialize instruction
-    let accounts = vec![
-        AccountMeta::new(*authority, true),                           // authority (signer, mut)
-        AccountMeta::new(stablecoin_pda, false),                      // state (PDA, init)
-        AccountMeta::new_readonly(asset_mint_pubkey, false),          // asset_mint
-        AccountMeta::new_readonly(system_program::id(), false),       // system_program
-    ];
+    // Re-running init for an existing mint is a no-op rather than an
+    // "account already in use" failure from the program
+    if new_mint.is_none() && !force_reinit {
+        let existing = program
+            .rpc()
+            .get_account_data(&stablecoin_pda)
+            .ok()
+            .and_then(|data| decode_account::<StablecoinStateData>(&data).ok());
+        if let Some(state) = existing {
+            print_existing_stablecoin(&stablecoin_pda, &state, authority);
+            return Ok(());
+        }
+    }
+    
+    // Mint extensions are written to the new mint before it is initialized
+    if let Some(rate_bps) = interest_rate_bps {
+        println!("   Interest rate: {} bps", rate_bps);
+    }
+    if let Some(fee) = &transfer_fee {
+        println!("   Transfer fee: {} bps (max {})", fee.transfer_fee_bps, fee.max_fee);
+    }
     
-    // Build instruction data
-    let ix_data = borsh::to_vec(&InitializeArgs {
+    let accounts = if new_mint.is_some() {
+        // Accounts for InitializeWithNewMint
+        vec![
+            AccountMeta::new(*authority, true),                           // authority (signer, mut)
+            AccountMeta::new(stablecoin_pda, false),                      // state (PDA, init)
+            AccountMeta::new(asset_mint_pubkey, true),                    // asset_mint (signer, created)
+            AccountMeta::new_readonly(spl_token_2022::id(), false),       // token_program
+            AccountMeta::new_readonly(system_program::id(), false),       // system_program
+        ]
+    } else {
+        // Accounts for Initialize
+        vec![
+            AccountMeta::new(*authority, true),                           // authority (signer, mut)
+            AccountMeta::new(stablecoin_pda, false),                      // state (PDA, init)
+            AccountMeta::new_readonly(asset_mint_pubkey, false),          // asset_mint
+            AccountMeta::new_readonly(system_program::id(), false),       // system_program
+        ]
+    };
+    
+    // The supply cap is set in the same transaction, right after initialization
+    let max_supply = max_supply.filter(|max_supply| *max_supply > 0);
+    if let Some(max_supply) = max_supply {
+        println!("   Max supply: {}", max_supply);
+    }
+    
+    // Build instruction data (both instructions take the same arguments)
+    let instruction_name = if new_mint.is_some() { "initialize_with_new_mint" } else { "initialize" };
+    let ix_data = build_instruction_data(instruction_name, InitializeArgs {
         preset,
         name,
         symbol,
         uri,
         decimals,
+        interest_rate_bps,
+        transfer_fee,
     }).map_err(|e| CliError::SerializationError(e.to_string()))?;
     
-    // Create instruction
-    let ix = Instruction {
+    // Create instructions
+    let mut ixs = vec![Instruction {
         program_id,
         accounts,
         data: ix_data,
-    };
-    
-    // Send transaction
-    let signature = program
-        .request()
-        .instruction(ix)
-        .send()
-        .map_err(|e| CliError::TransactionError(e.to_string()))?;
+    }];
+    if let Some(max_supply) = max_supply {
+        ixs.push(set_max_supply_instruction(&program_id, authority, &stablecoin_pda, max_supply)?);
+    }
     
-    print_tx_success(&signature.to_string(), "Initialization");
+    // Send transaction (a new mint must also sign for its own creation)
+    let already_initialized = |e: CliError| match e {
+        CliError::TransactionError(msg) if msg.contains("already in use") => CliError::NotAllowed(format!(
+            "Stablecoin {} is already initialized and cannot be initialized again",
+            stablecoin_pda
+        )),
+        e => e,
+    };
+    match &new_mint {
+        Some(mint) => {
+            let signature = send_instructions_signed(program, tx, ixs, &[mint]).map_err(already_initialized)?;
+            print_tx_success(&signature.to_string(), "Initialization");
+        }
+        None => finish(program, tx, ixs, "Initialization").map_err(already_initialized)?,
+    }
     
     println!("\n💡 Save this stablecoin address for future commands:");
     println!("   --stablecoin {}", stablecoin_pda);
@@ -156,13 +597,30 @@ pub fn handle_init(
     Ok(())
 }
 
+/// Summary printed when `init` finds the stablecoin already initialized
+fn print_existing_stablecoin(stablecoin_pda: &Pubkey, state: &StablecoinStateData, signer: &Pubkey) {
+    println!("\nℹ️  Stablecoin already initialized; nothing was sent");
+    println!("   Preset: SSS-{}", state.preset);
+    println!("   Authority: {}{}", state.authority, if state.authority == *signer { " (you)" } else { "" });
+    println!("   Total supply: {}", state.total_supply);
+    println!("   Paused: {}", state.paused);
+    println!("   Compliance: {}", if state.compliance_enabled { "enabled" } else { "disabled" });
+    if state.max_supply > 0 {
+        println!("   Max supply: {}", state.max_supply);
+    }
+    println!("\n💡 Use it with --stablecoin {} (see `status` for the full config).", stablecoin_pda);
+    println!("   Settings passed to init were not applied; change them with the set-* commands.");
+}
+
 // ==================== MINT ====================
 pub fn handle_mint(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
     recipient: &str,
     amount: u64,
+    recipient_is_token_account: bool,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
     let recipient_pubkey = parse_pubkey(recipient)?;
     
@@ -184,36 +642,69 @@ pub fn handle_mint(
         }
     };
     
+    let rpc = program.rpc();
+    let state = decode_account::<StablecoinStateData>(&rpc.get_account_data(&stablecoin_pda)?)?;
+    // The mint's owner tells us whether it is a Token or Token-2022 mint
+    let token_program = rpc.get_account(&state.asset_mint)?.owner;
+    
+    // Resolve a wallet to its associated token account, creating it if missing
+    let mut ixs = Vec::new();
+    let recipient_token_account = if recipient_is_token_account {
+        recipient_pubkey
+    } else {
+        let ata = get_associated_token_address_with_program_id(&recipient_pubkey, &state.asset_mint, &token_program);
+        if rpc.get_account(&ata).is_err() {
+            println!("   Creating associated token account {}", ata);
+            ixs.push(create_associated_token_account_idempotent(
+                authority,
+                &recipient_pubkey,
+                &state.asset_mint,
+                &token_program,
+            ));
+        } else {
+            println!("   Token account: {}", ata);
+        }
+        ata
+    };
+    
     // Derive role PDA for the authority
-    let (role_pda, _) = derive_role_pda(&stablecoin_pda, authority, &program_id);
+    let role_pda = resolve_role_assignment(program, &stablecoin_pda, authority, Role::Minter);
+    // Everyone but the Master mints against their MinterInfo quota
+    let minter_info = if *authority == state.authority {
+        AccountMeta::new_readonly(program_id, false)                  // minter_info placeholder (None)
+    } else {
+        AccountMeta::new(constants::minter_pda(&stablecoin_pda, authority, &program_id).0, false)
+    };
     
     // Build accounts for Mint instruction
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new(*authority, true),                           // authority (signer, mut)
         AccountMeta::new(stablecoin_pda, false),                      // state (PDA, mut)
         AccountMeta::new_readonly(role_pda, false),                   // role_assignment (optional)
-        AccountMeta::new_readonly(Pubkey::default(), false),          // minter_info (optional)
-        AccountMeta::new_readonly(Pubkey::default(), false),          // asset_mint (mut)
-        AccountMeta::new(recipient_pubkey, false),                    // recipient (mut)
-        AccountMeta::new_readonly(spl_token::id(), false),            // token_program
+        minter_info,                                                  // minter_info (mut, Master: None)
+        AccountMeta::new(state.asset_mint, false),                    // asset_mint (mut)
+        AccountMeta::new(recipient_token_account, false),             // recipient (mut)
+        AccountMeta::new_readonly(token_program, false),              // token_program
     ];
+    append_fee_treasury(program, &stablecoin_pda, &mut accounts);
+    if state.reserve_backed {
+        if accounts.len() == 7 {
+            accounts.push(AccountMeta::new_readonly(program_id, false)); // fee_treasury placeholder (None)
+        }
+        let (reserve_attestation, _) = constants::reserve_attestation_pda(&stablecoin_pda, &program_id);
+        accounts.push(AccountMeta::new_readonly(reserve_attestation, false)); // reserve_attestation
+    }
     
-    let ix_data = borsh::to_vec(&MintArgs { amount })
+    let ix_data = build_instruction_data("mint", MintArgs { amount })
         .map_err(|e| CliError::SerializationError(e.to_string()))?;
     
-    let ix = Instruction {
+    ixs.push(Instruction {
         program_id,
         accounts,
         data: ix_data,
-    };
-    
-    let signature = program
-        .request()
-        .instruction(ix)
-        .send()
-        .map_err(|e| CliError::TransactionError(e.to_string()))?;
+    });
     
-    print_tx_success(&signature.to_string(), "Mint");
+    finish(program, tx, ixs, "Mint")?;
     Ok(())
 }
 
@@ -224,6 +715,7 @@ pub fn handle_burn(
     amount: u64,
     from: Option<&Pubkey>,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
     println!("🔥 Burning {} tokens", amount);
     
@@ -242,15 +734,17 @@ pub fn handle_burn(
     };
     
     let from_pubkey = from.unwrap_or(authority);
+    require_balance(program, from_pubkey, amount)?;
     
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new(*authority, true),                           // authority (signer, mut)
         AccountMeta::new(stablecoin_pda, false),                      // state (PDA, mut)
         AccountMeta::new(*from_pubkey, false),                        // from (token account)
         AccountMeta::new_readonly(spl_token::id(), false),            // token_program
     ];
+    append_fee_treasury(program, &stablecoin_pda, &mut accounts);
     
-    let ix_data = borsh::to_vec(&BurnArgs { amount })
+    let ix_data = build_instruction_data("burn", BurnArgs { amount })
         .map_err(|e| CliError::SerializationError(e.to_string()))?;
     
     let ix = Instruction {
@@ -259,26 +753,86 @@ pub fn handle_burn(
         data: ix_data,
     };
     
-    let signature = program
-        .request()
-        .instruction(ix)
-        .send()
-        .map_err(|e| CliError::TransactionError(e.to_string()))?;
-    
-    print_tx_success(&signature.to_string(), "Burn");
+    finish(program, tx, vec![ix], "Burn")?;
     Ok(())
 }
 
 // ==================== FREEZE ====================
+/// Accounts shared by every freeze and thaw of one stablecoin.
+/// The program freezes through the token program with the stablecoin PDA as
+/// the mint's freeze authority, so it needs the mint and its owning program.
+struct FreezeAccounts {
+    asset_mint: Pubkey,
+    /// Owner of the asset mint: Token or Token-2022
+    token_program: Pubkey,
+    /// Role assignment the authority freezes with (Blacklister or Master)
+    role_assignment: Pubkey,
+}
+
+fn fetch_freeze_accounts(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    stablecoin_pda: &Pubkey,
+) -> CliResult<FreezeAccounts> {
+    let rpc = program.rpc();
+    let state = decode_account::<StablecoinStateData>(&rpc.get_account_data(stablecoin_pda)?)?;
+    let token_program = rpc.get_account(&state.asset_mint)?.owner;
+    Ok(FreezeAccounts {
+        asset_mint: state.asset_mint,
+        token_program,
+        role_assignment: resolve_role_assignment(program, stablecoin_pda, authority, Role::Blacklister),
+    })
+}
+
+fn freeze_instruction(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    stablecoin_pda: &Pubkey,
+    freeze_accounts: &FreezeAccounts,
+    account: &Pubkey,
+    reason: &str,
+) -> CliResult<Instruction> {
+    let (freeze_record_pda, _) = constants::freeze_pda(stablecoin_pda, account, program_id);
+    
+    let accounts = vec![
+        AccountMeta::new(*authority, true),                           // authority (signer, mut)
+        AccountMeta::new_readonly(*stablecoin_pda, false),            // state (PDA)
+        AccountMeta::new_readonly(freeze_accounts.role_assignment, false), // role_assignment (optional)
+        AccountMeta::new(freeze_accounts.asset_mint, false),          // asset_mint (mut)
+        AccountMeta::new(*account, false),                            // account to freeze
+        AccountMeta::new(freeze_record_pda, false),                   // freeze_record (PDA, init)
+        AccountMeta::new_readonly(freeze_accounts.token_program, false), // token_program
+        AccountMeta::new_readonly(system_program::id(), false),       // system_program
+    ];
+    
+    let ix_data = build_instruction_data("freeze_account", FreezeArgs { reason: reason.to_string() })
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
 pub fn handle_freeze(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
     account: &str,
+    reason: String,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
     let account_pubkey = parse_pubkey(account)?;
     
+    if reason.len() > 200 {
+        return Err(CliError::InvalidArg("Reason too long (max 200 chars)".to_string()));
+    }
+    
     println!("❄️ Freezing account: {}", account_pubkey);
+    if !reason.is_empty() {
+        println!("   Reason: {}", reason);
+    }
     
     let program_id = program.id();
     let stablecoin_pda = match stablecoin {
@@ -290,43 +844,74 @@ pub fn handle_freeze(
         }
     };
     
+    let freeze_accounts = fetch_freeze_accounts(program, authority, &stablecoin_pda)?;
+    let ix = freeze_instruction(&program_id, authority, &stablecoin_pda, &freeze_accounts, &account_pubkey, &reason)?;
+    
+    finish(program, tx, vec![ix], "Freeze")?;
+    Ok(())
+}
+
+// ==================== THAW ====================
+/// Leading fields of an on-chain `FreezeRecord`
+#[derive(Debug, ::borsh::BorshDeserialize)]
+struct FreezeRecordData {
+    _account: Pubkey,
+    frozen_by: Pubkey,
+}
+
+/// Build a thaw instruction, closing the account's freeze record and returning
+/// its rent to whoever froze the account
+fn thaw_instruction(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    stablecoin_pda: &Pubkey,
+    freeze_accounts: &FreezeAccounts,
+    account: &Pubkey,
+) -> CliResult<Instruction> {
+    let program_id = program.id();
+    
+    let (freeze_record_pda, _) = constants::freeze_pda(stablecoin_pda, account, &program_id);
+    let record = program
+        .rpc()
+        .get_account_data(&freeze_record_pda)
+        .ok()
+        .and_then(|data| decode_account::<FreezeRecordData>(&data).ok())
+        .ok_or_else(|| CliError::InvalidArg(format!(
+            "{} has no freeze record; run `freeze` on it first to record the freeze",
+            account
+        )))?;
+    
     let accounts = vec![
         AccountMeta::new(*authority, true),                           // authority (signer, mut)
-        AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
-        AccountMeta::new(account_pubkey, false),                      // account to freeze
+        AccountMeta::new_readonly(*stablecoin_pda, false),            // state (PDA)
+        AccountMeta::new(freeze_accounts.asset_mint, false),          // asset_mint (mut)
+        AccountMeta::new(*account, false),                            // account to thaw
+        AccountMeta::new_readonly(freeze_accounts.token_program, false), // token_program
+        AccountMeta::new(freeze_record_pda, false),                   // freeze_record (closed)
+        AccountMeta::new(record.frozen_by, false),                    // rent_receiver (mut)
     ];
     
-    let ix_data = borsh::to_vec(&FreezeArgs {})
+    let ix_data = build_instruction_data("thaw_account", ThawArgs {})
         .map_err(|e| CliError::SerializationError(e.to_string()))?;
     
-    let ix = Instruction {
+    Ok(Instruction {
         program_id,
         accounts,
         data: ix_data,
-    };
-    
-    let signature = program
-        .request()
-        .instruction(ix)
-        .send()
-        .map_err(|e| CliError::TransactionError(e.to_string()))?;
-    
-    print_tx_success(&signature.to_string(), "Freeze");
-    Ok(())
+    })
 }
 
-// ==================== THAW ====================
 pub fn handle_thaw(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
     account: &str,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
     let account_pubkey = parse_pubkey(account)?;
     
     println!("🔥 Thawing account: {}", account_pubkey);
     
-    let program_id = program.id();
     let stablecoin_pda = match stablecoin {
         Some(s) => *s,
         None => {
@@ -336,38 +921,120 @@ pub fn handle_thaw(
         }
     };
     
-    let accounts = vec![
-        AccountMeta::new(*authority, true),                           // authority (signer, mut)
-        AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
-        AccountMeta::new(account_pubkey, false),                      // account to thaw
-    ];
-    
-    let ix_data = borsh::to_vec(&ThawArgs {})
-        .map_err(|e| CliError::SerializationError(e.to_string()))?;
-    
-    let ix = Instruction {
-        program_id,
-        accounts,
-        data: ix_data,
-    };
-    
-    let signature = program
-        .request()
-        .instruction(ix)
-        .send()
-        .map_err(|e| CliError::TransactionError(e.to_string()))?;
+    let freeze_accounts = fetch_freeze_accounts(program, authority, &stablecoin_pda)?;
+    let ix = thaw_instruction(program, authority, &stablecoin_pda, &freeze_accounts, &account_pubkey)?;
     
-    print_tx_success(&signature.to_string(), "Thaw");
+    finish(program, tx, vec![ix], "Thaw")?;
     Ok(())
 }
 
-// ==================== PAUSE ====================
-pub fn handle_pause(
+// ==================== BATCHING ====================
+/// Most instructions a bulk command puts in one transaction
+pub const MAX_BATCH_SIZE: usize = 10;
+
+/// Compute units budgeted per bulk instruction (freeze, thaw or blacklist, CPIs included)
+pub const BATCH_IX_COMPUTE_UNITS: u32 = 50_000;
+
+/// Compute units a transaction may use when `--compute-limit` isn't set
+pub const MAX_TX_COMPUTE_UNITS: u32 = 1_400_000;
+
+/// Largest batch allowed: `--batch-size` when given, otherwise `MAX_BATCH_SIZE`
+fn batch_limit(batch_size: Option<usize>) -> CliResult<usize> {
+    match batch_size {
+        Some(size) if !(1..=MAX_BATCH_SIZE).contains(&size) => Err(CliError::InvalidArg(
+            format!("Batch size must be between 1 and {}", MAX_BATCH_SIZE)
+        )),
+        Some(size) => Ok(size),
+        None => Ok(MAX_BATCH_SIZE),
+    }
+}
+
+/// Wire size of a transaction carrying `ixs`, signed by its required signers
+fn transaction_size(ixs: &[Instruction], payer: &Pubkey) -> usize {
+    let message = Message::new(ixs, Some(payer));
+    let signatures = usize::from(message.header.num_required_signatures);
+    // One-byte signature count, then the signatures and the message
+    1 + signatures * 64 + message.serialize().len()
+}
+
+/// Split `ixs` into consecutive batches that each fit one transaction paid
+/// by `payer`: at most `max_per_batch` instructions, within `tx`'s compute
+/// budget, and under the packet size limit with `tx`'s compute-budget
+/// instructions prepended. An instruction too large to share a transaction
+/// gets a batch of its own.
+pub fn plan_batches(
+    ixs: &[Instruction],
+    payer: &Pubkey,
+    max_per_batch: usize,
+    tx: &TxOptions,
+) -> Vec<std::ops::Range<usize>> {
+    let budget = tx.compute_limit.filter(|u| *u > 0).unwrap_or(MAX_TX_COMPUTE_UNITS);
+    let max_by_compute = (budget / BATCH_IX_COMPUTE_UNITS) as usize;
+    let max_count = max_per_batch.min(max_by_compute).max(1);
+    let prefix = tx.compute_budget_instructions();
+    
+    let mut batches = Vec::new();
+    let mut start = 0;
+    while start < ixs.len() {
+        let mut end = start + 1;
+        while end < ixs.len() && end - start < max_count {
+            let candidate: Vec<Instruction> = prefix.iter().chain(&ixs[start..=end]).cloned().collect();
+            if transaction_size(&candidate, payer) > PACKET_DATA_SIZE {
+                break;
+            }
+            end += 1;
+        }
+        batches.push(start..end);
+        start = end;
+    }
+    batches
+}
+
+// ==================== FREEZE / THAW BATCH ====================
+/// Which of freeze or thaw a batch applies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FreezeBatchAction {
+    Freeze,
+    Thaw,
+}
+
+/// Parse a newline-delimited account list.
+/// Blank lines and lines starting with `#` are ignored; duplicates are dropped in order.
+pub fn parse_account_list(contents: &str) -> CliResult<Vec<Pubkey>> {
+    let mut accounts: Vec<Pubkey> = Vec::new();
+    for (line_no, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let account = line.parse::<Pubkey>().map_err(|_| {
+            CliError::InvalidArg(format!("Line {}: invalid address '{}'", line_no + 1, line))
+        })?;
+        if !accounts.contains(&account) {
+            accounts.push(account);
+        }
+    }
+    Ok(accounts)
+}
+
+/// Freeze or thaw every account listed in `file`, packing as many instructions
+/// per transaction as fit (see `plan_batches`), up to `batch_size` when given.
+/// A failed batch is retried one account at a time, and failures are summarized at the end.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_freeze_batch(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
+    action: FreezeBatchAction,
+    file: &str,
+    reason: &str,
+    batch_size: Option<usize>,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
-    println!("⏸️ Pausing stablecoin operations...");
+    let max_per_batch = batch_limit(batch_size)?;
+    if reason.len() > 200 {
+        return Err(CliError::InvalidArg("Reason too long (max 200 chars)".to_string()));
+    }
     
     let program_id = program.id();
     let stablecoin_pda = match stablecoin {
@@ -379,37 +1046,102 @@ pub fn handle_pause(
         }
     };
     
-    let accounts = vec![
-        AccountMeta::new(*authority, true),                           // authority (signer, mut)
-        AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
-    ];
-    
-    let ix_data = borsh::to_vec(&Pause {})
-        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    let contents = std::fs::read_to_string(file)?;
+    let accounts = parse_account_list(&contents)?;
     
-    let ix = Instruction {
-        program_id,
-        accounts,
-        data: ix_data,
+    let freeze_accounts = fetch_freeze_accounts(program, authority, &stablecoin_pda)?;
+    let build = |account: &Pubkey| match action {
+        FreezeBatchAction::Freeze => {
+            freeze_instruction(&program_id, authority, &stablecoin_pda, &freeze_accounts, account, reason)
+        }
+        FreezeBatchAction::Thaw => thaw_instruction(program, authority, &stablecoin_pda, &freeze_accounts, account),
+    };
+    let (title, verb, icon) = match action {
+        FreezeBatchAction::Freeze => ("Freeze", "Freezing", "❄️"),
+        FreezeBatchAction::Thaw => ("Thaw", "Thawing", "🔥"),
     };
     
-    let signature = program
-        .request()
-        .instruction(ix)
-        .send()
-        .map_err(|e| CliError::TransactionError(e.to_string()))?;
+    println!("{} {} {} accounts from {}", icon, verb, accounts.len(), file);
     
-    print_tx_success(&signature.to_string(), "Pause");
-    Ok(())
+    let ixs = accounts.iter().map(build).collect::<CliResult<Vec<_>>>()?;
+    let batches = plan_batches(&ixs, authority, max_per_batch, tx);
+    
+    let mut succeeded = 0usize;
+    let mut failed: Vec<(Pubkey, String)> = Vec::new();
+    let total_batches = batches.len();
+    
+    for (batch_no, range) in batches.into_iter().enumerate() {
+        let batch = &accounts[range.clone()];
+        
+        match send_instructions(program, tx, ixs[range].to_vec()) {
+            Ok(signature) => {
+                succeeded += batch.len();
+                for account in batch {
+                    println!("   [{}/{}] ✅ {} ({})", batch_no + 1, total_batches, account, signature);
+                }
+            }
+            Err(batch_err) => {
+                // Retry individually so one bad account doesn't fail the whole batch
+                println!("   [{}/{}] ⚠️ batch failed, retrying individually: {}", batch_no + 1, total_batches, batch_err);
+                for account in batch {
+                    match build(account).and_then(|ix| send_instruction(program, tx, ix)) {
+                        Ok(signature) => {
+                            succeeded += 1;
+                            println!("   [{}/{}] ✅ {} ({})", batch_no + 1, total_batches, account, signature);
+                        }
+                        Err(e) => {
+                            println!("   [{}/{}] ❌ {}: {}", batch_no + 1, total_batches, account, e);
+                            failed.push((*account, e.to_string()));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    
+    println!("\n📊 {} summary", title);
+    println!("   Succeeded: {}", succeeded);
+    println!("   Failed:    {}", failed.len());
+    for (account, err) in &failed {
+        println!("   ❌ {}: {}", account, err);
+    }
+    
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(CliError::TransactionError(format!("{} accounts failed", failed.len())))
+    }
 }
 
-// ==================== UNPAUSE ====================
-pub fn handle_unpause(
+// ==================== PAUSE ====================
+pub fn handle_pause(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
+    reason: String,
+    until: Option<i64>,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
-    println!("▶️ Unpausing stablecoin operations...");
+    if reason.len() > 200 {
+        return Err(CliError::InvalidArg("Reason too long (max 200 chars)".to_string()));
+    }
+    if let Some(until) = until {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs() as i64)
+            .unwrap_or_default();
+        if until <= now {
+            return Err(CliError::InvalidArg("--until must be a future Unix timestamp".to_string()));
+        }
+    }
+    
+    println!("⏸️ Pausing stablecoin operations...");
+    if !reason.is_empty() {
+        println!("   Reason: {}", reason);
+    }
+    if let Some(until) = until {
+        println!("   Lifts at: {}", until);
+    }
     
     let program_id = program.id();
     let stablecoin_pda = match stablecoin {
@@ -426,7 +1158,7 @@ pub fn handle_unpause(
         AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
     ];
     
-    let ix_data = borsh::to_vec(&Unpause {})
+    let ix_data = build_instruction_data("pause", Pause { reason, until: until.unwrap_or(constants::NO_PAUSE_EXPIRY) })
         .map_err(|e| CliError::SerializationError(e.to_string()))?;
     
     let ix = Instruction {
@@ -435,28 +1167,18 @@ pub fn handle_unpause(
         data: ix_data,
     };
     
-    let signature = program
-        .request()
-        .instruction(ix)
-        .send()
-        .map_err(|e| CliError::TransactionError(e.to_string()))?;
-    
-    print_tx_success(&signature.to_string(), "Unpause");
+    finish(program, tx, vec![ix], "Pause")?;
     Ok(())
 }
 
-// ==================== BLACKLIST ====================
-pub fn handle_blacklist_add(
+// ==================== UNPAUSE ====================
+pub fn handle_unpause(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
-    account: &str,
-    reason: &str,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
-    let account_pubkey = parse_pubkey(account)?;
-    
-    println!("🚫 Adding {} to blacklist", account_pubkey);
-    println!("   Reason: {}", reason);
+    println!("▶️ Unpausing stablecoin operations...");
     
     let program_id = program.id();
     let stablecoin_pda = match stablecoin {
@@ -468,19 +1190,13 @@ pub fn handle_blacklist_add(
         }
     };
     
-    let (entry_pda, _) = derive_blacklist_pda(&stablecoin_pda, &account_pubkey, &program_id);
-    
     let accounts = vec![
         AccountMeta::new(*authority, true),                           // authority (signer, mut)
         AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
-        AccountMeta::new(entry_pda, false),                           // entry (PDA)
-        AccountMeta::new_readonly(account_pubkey, false),             // account to blacklist
-        AccountMeta::new_readonly(system_program::id(), false),       // system_program
     ];
     
-    let ix_data = borsh::to_vec(&AddToBlacklist {
-        reason: reason.to_string(),
-    }).map_err(|e| CliError::SerializationError(e.to_string()))?;
+    let ix_data = build_instruction_data("unpause", Unpause {})
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
     
     let ix = Instruction {
         program_id,
@@ -488,25 +1204,19 @@ pub fn handle_blacklist_add(
         data: ix_data,
     };
     
-    let signature = program
-        .request()
-        .instruction(ix)
-        .send()
-        .map_err(|e| CliError::TransactionError(e.to_string()))?;
-    
-    print_tx_success(&signature.to_string(), "Blacklist add");
+    finish(program, tx, vec![ix], "Unpause")?;
     Ok(())
 }
 
-pub fn handle_blacklist_remove(
+// ==================== SET INTEREST RATE ====================
+pub fn handle_set_interest_rate(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
-    account: &str,
+    rate_bps: i16,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
-    let account_pubkey = parse_pubkey(account)?;
-    
-    println!("✅ Removing {} from blacklist", account_pubkey);
+    println!("📈 Setting interest rate to {} bps", rate_bps);
     
     let program_id = program.id();
     let stablecoin_pda = match stablecoin {
@@ -518,16 +1228,16 @@ pub fn handle_blacklist_remove(
         }
     };
     
-    let (entry_pda, _) = derive_blacklist_pda(&stablecoin_pda, &account_pubkey, &program_id);
+    let state = decode_account::<StablecoinStateData>(&program.rpc().get_account_data(&stablecoin_pda)?)?;
     
     let accounts = vec![
-        AccountMeta::new(*authority, true),                           // authority (signer, mut)
-        AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
-        AccountMeta::new(entry_pda, false),                           // entry (PDA)
-        AccountMeta::new_readonly(account_pubkey, false),             // account to unblacklist
+        AccountMeta::new_readonly(*authority, true),                  // authority (signer)
+        AccountMeta::new_readonly(stablecoin_pda, false),             // state (PDA, rate authority)
+        AccountMeta::new(state.asset_mint, false),                    // asset_mint (mut)
+        AccountMeta::new_readonly(spl_token_2022::id(), false),       // token_program
     ];
     
-    let ix_data = borsh::to_vec(&RemoveFromBlacklist {})
+    let ix_data = build_instruction_data("update_interest_rate", UpdateInterestRateArgs { rate_bps })
         .map_err(|e| CliError::SerializationError(e.to_string()))?;
     
     let ix = Instruction {
@@ -536,44 +1246,67 @@ pub fn handle_blacklist_remove(
         data: ix_data,
     };
     
-    let signature = program
-        .request()
-        .instruction(ix)
-        .send()
-        .map_err(|e| CliError::TransactionError(e.to_string()))?;
-    
-    print_tx_success(&signature.to_string(), "Blacklist remove");
+    finish(program, tx, vec![ix], "Set interest rate")?;
     Ok(())
 }
 
-pub fn handle_blacklist_list(
-    _program: &Program<Rc<Keypair>>,
+// ==================== TRANSFER FEE ====================
+pub fn handle_set_transfer_fee(
+    program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
+    transfer_fee_bps: u16,
+    max_fee: u64,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
-    println!("📋 Listing blacklisted accounts...");
+    if transfer_fee_bps > 10_000 {
+        return Err(CliError::InvalidArg("Transfer fee must be at most 10000 bps".to_string()));
+    }
     
-    let program_id = _program.id();
-    let stablecoin_pda = stablecoin
-        .copied()
-        .unwrap_or_else(|| derive_stablecoin_pda(authority, &program_id).0);
+    println!("💸 Setting transfer fee to {} bps (max {})", transfer_fee_bps, max_fee);
     
-    println!("   Stablecoin: {}", stablecoin_pda);
-    println!("   Note: Use an indexer service to list all blacklist entries");
-    println!("   Or check individual accounts with: sss-token blacklist check <account>");
+    let program_id = program.id();
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let state = decode_account::<StablecoinStateData>(&program.rpc().get_account_data(&stablecoin_pda)?)?;
+    
+    let accounts = vec![
+        AccountMeta::new_readonly(*authority, true),                  // authority (signer)
+        AccountMeta::new_readonly(stablecoin_pda, false),             // state (PDA, fee authority)
+        AccountMeta::new(state.asset_mint, false),                    // asset_mint (mut)
+        AccountMeta::new_readonly(spl_token_2022::id(), false),       // token_program
+    ];
+    
+    let ix_data = build_instruction_data("update_transfer_fee", UpdateTransferFeeArgs { transfer_fee_bps, max_fee })
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    let ix = Instruction {
+        program_id,
+        accounts,
+        data: ix_data,
+    };
     
+    finish(program, tx, vec![ix], "Set transfer fee")?;
     Ok(())
 }
 
-pub fn handle_blacklist_check(
+pub fn handle_withdraw_withheld_fees(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
-    account: &str,
+    to: &str,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
-    let account_pubkey = parse_pubkey(account)?;
+    let to_pubkey = parse_pubkey(to)?;
     
-    println!("🔍 Checking blacklist status for {}", account_pubkey);
+    println!("🏦 Withdrawing withheld transfer fees to {}", to_pubkey);
     
     let program_id = program.id();
     let stablecoin_pda = match stablecoin {
@@ -585,62 +1318,41 @@ pub fn handle_blacklist_check(
         }
     };
     
-    let (entry_pda, _bump) = derive_blacklist_pda(&stablecoin_pda, &account_pubkey, &program_id);
+    let state = decode_account::<StablecoinStateData>(&program.rpc().get_account_data(&stablecoin_pda)?)?;
     
-    // Try to fetch the blacklist entry account using RPC
-    let account_data = program.rpc().get_account_data(&entry_pda);
-    match account_data {
-        Ok(data) => {
-            // Skip 8-byte discriminator
-            if data.len() > 8 {
-                match BlacklistEntryData::try_from_slice(&data[8..]) {
-                    Ok(entry) => {
-                        println!("🚫 Account IS blacklisted");
-                        println!("   Reason: {}", entry.reason);
-                        println!("   Blacklisted by: {}", entry.blacklisted_by);
-                        println!("   At: {}", entry.blacklisted_at);
-                    }
-                    Err(_) => {
-                        println!("⚠️ Could not parse blacklist entry");
-                    }
-                }
-            } else {
-                println!("✅ Account is NOT blacklisted");
-            }
-        }
-        Err(_) => {
-            println!("✅ Account is NOT blacklisted");
-        }
-    }
+    let accounts = vec![
+        AccountMeta::new_readonly(*authority, true),                  // authority (signer)
+        AccountMeta::new_readonly(stablecoin_pda, false),             // state (PDA, withdraw authority)
+        AccountMeta::new(state.asset_mint, false),                    // asset_mint (mut)
+        AccountMeta::new(to_pubkey, false),                           // to (token account, mut)
+        AccountMeta::new_readonly(spl_token_2022::id(), false),       // token_program
+    ];
+    
+    let ix_data = build_instruction_data("withdraw_withheld_fees", WithdrawWithheldFees {})
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    let ix = Instruction {
+        program_id,
+        accounts,
+        data: ix_data,
+    };
     
+    finish(program, tx, vec![ix], "Withdraw withheld fees")?;
     Ok(())
 }
 
-// BlacklistEntryData for deserialization
-#[derive(Debug, ::borsh::BorshDeserialize)]
-struct BlacklistEntryData {
-    account: Pubkey,
-    reason: String,
-    blacklisted_by: Pubkey,
-    blacklisted_at: i64,
-    bump: u8,
-}
-
-// ==================== MINTERS ====================
-pub fn handle_minter_add(
+// ==================== SET MAX TRANSFER ====================
+pub fn handle_set_max_transfer(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
-    account: &str,
-    quota: u64,
+    amount: u64,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
-    let account_pubkey = parse_pubkey(account)?;
-    
-    println!("➕ Adding minter: {}", account_pubkey);
-    if quota > 0 {
-        println!("   Quota: {} tokens", quota);
+    if amount == 0 {
+        println!("📏 Removing per-transfer cap...");
     } else {
-        println!("   Quota: Unlimited");
+        println!("📏 Setting per-transfer cap to {} tokens", amount);
     }
     
     let program_id = program.id();
@@ -653,19 +1365,12 @@ pub fn handle_minter_add(
         }
     };
     
-    let (role_pda, _) = derive_role_pda(&stablecoin_pda, &account_pubkey, &program_id);
-    let (minter_pda, _) = derive_minter_pda(&stablecoin_pda, &account_pubkey, &program_id);
-    
     let accounts = vec![
         AccountMeta::new(*authority, true),                           // authority (signer, mut)
         AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
-        AccountMeta::new(role_pda, false),                            // role_assignment (PDA)
-        AccountMeta::new(minter_pda, false),                          // minter_info (PDA)
-        AccountMeta::new_readonly(account_pubkey, false),             // minter account
-        AccountMeta::new_readonly(system_program::id(), false),       // system_program
     ];
     
-    let ix_data = borsh::to_vec(&AddMinterArgs { quota })
+    let ix_data = build_instruction_data("set_max_transfer", SetMaxTransferArgs { amount })
         .map_err(|e| CliError::SerializationError(e.to_string()))?;
     
     let ix = Instruction {
@@ -674,25 +1379,135 @@ pub fn handle_minter_add(
         data: ix_data,
     };
     
-    let signature = program
-        .request()
-        .instruction(ix)
-        .send()
-        .map_err(|e| CliError::TransactionError(e.to_string()))?;
+    finish(program, tx, vec![ix], "Set max transfer")?;
+    Ok(())
+}
+
+// ==================== SET MAX SUPPLY ====================
+pub fn handle_set_max_supply(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    max_supply: u64,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    if max_supply == 0 {
+        println!("📏 Removing supply cap...");
+    } else {
+        println!("📏 Setting max supply to {} tokens", max_supply);
+    }
+    
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
     
-    print_tx_success(&signature.to_string(), "Minter add");
+    let ix = set_max_supply_instruction(&program.id(), authority, &stablecoin_pda, max_supply)?;
+    finish(program, tx, vec![ix], "Set max supply")?;
     Ok(())
 }
 
-pub fn handle_minter_remove(
+fn set_max_supply_instruction(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    stablecoin_pda: &Pubkey,
+    max_supply: u64,
+) -> CliResult<Instruction> {
+    let accounts = vec![
+        AccountMeta::new(*authority, true),                           // authority (signer, mut)
+        AccountMeta::new(*stablecoin_pda, false),                     // state (PDA)
+    ];
+    
+    let ix_data = build_instruction_data("set_max_supply", SetMaxSupplyArgs { max_supply })
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
+// ==================== SET MIN BURN / MINT ====================
+pub fn handle_set_min_burn(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
-    account: &str,
+    amount: u64,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
-    let account_pubkey = parse_pubkey(account)?;
+    if amount == 0 {
+        println!("📏 Removing minimum burn amount...");
+    } else {
+        println!("📏 Setting minimum burn amount to {} tokens", amount);
+    }
     
-    println!("➖ Removing minter: {}", account_pubkey);
+    let ix_data = build_instruction_data("set_min_burn", SetMinBurnArgs { min_burn_amount: amount })
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    let ix = admin_instruction(&program.id(), authority, stablecoin, ix_data)?;
+    finish(program, tx, vec![ix], "Set min burn")?;
+    Ok(())
+}
+
+pub fn handle_set_min_mint(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    amount: u64,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    if amount == 0 {
+        println!("📏 Removing minimum mint amount...");
+    } else {
+        println!("📏 Setting minimum mint amount to {} tokens", amount);
+    }
+    
+    let ix_data = build_instruction_data("set_min_mint", SetMinMintArgs { min_mint_amount: amount })
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    let ix = admin_instruction(&program.id(), authority, stablecoin, ix_data)?;
+    finish(program, tx, vec![ix], "Set min mint")?;
+    Ok(())
+}
+
+/// Instruction over the `Admin` accounts (authority, state)
+fn admin_instruction(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    stablecoin: Option<&Pubkey>,
+    data: Vec<u8>,
+) -> CliResult<Instruction> {
+    let stablecoin_pda = stablecoin.ok_or_else(|| {
+        CliError::InvalidArg("Stablecoin PDA is required. Use --stablecoin <address>".to_string())
+    })?;
+    
+    let accounts = vec![
+        AccountMeta::new(*authority, true),                           // authority (signer, mut)
+        AccountMeta::new(*stablecoin_pda, false),                     // state (PDA)
+    ];
+    
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+// ==================== RESERVES ====================
+pub fn handle_configure_reserves(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    attester: &Pubkey,
+    max_staleness: i64,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    if max_staleness <= 0 {
+        return Err(CliError::InvalidArg("Max staleness must be greater than zero".to_string()));
+    }
     
     let program_id = program.id();
     let stablecoin_pda = match stablecoin {
@@ -703,19 +1518,20 @@ pub fn handle_minter_remove(
             ));
         }
     };
+    let (reserve_attestation, _) = constants::reserve_attestation_pda(&stablecoin_pda, &program_id);
     
-    let (role_pda, _) = derive_role_pda(&stablecoin_pda, &account_pubkey, &program_id);
-    let (minter_pda, _) = derive_minter_pda(&stablecoin_pda, &account_pubkey, &program_id);
+    println!("🏦 Requiring reserve attestations for mints");
+    println!("   Attester: {}", attester);
+    println!("   Max staleness: {}s", max_staleness);
     
     let accounts = vec![
         AccountMeta::new(*authority, true),                           // authority (signer, mut)
-        AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
-        AccountMeta::new(role_pda, false),                            // role_assignment (PDA)
-        AccountMeta::new(minter_pda, false),                          // minter_info (PDA)
-        AccountMeta::new_readonly(account_pubkey, false),             // minter account
+        AccountMeta::new(stablecoin_pda, false),                      // state (PDA, mut)
+        AccountMeta::new(reserve_attestation, false),                 // reserve_attestation (PDA)
+        AccountMeta::new_readonly(system_program::id(), false),       // system_program
     ];
     
-    let ix_data = borsh::to_vec(&RemoveMinterArgs {})
+    let ix_data = build_instruction_data("configure_reserves", ConfigureReservesArgs { attester: *attester, max_staleness })
         .map_err(|e| CliError::SerializationError(e.to_string()))?;
     
     let ix = Instruction {
@@ -724,44 +1540,56 @@ pub fn handle_minter_remove(
         data: ix_data,
     };
     
-    let signature = program
-        .request()
-        .instruction(ix)
-        .send()
-        .map_err(|e| CliError::TransactionError(e.to_string()))?;
-    
-    print_tx_success(&signature.to_string(), "Minter removal");
+    finish(program, tx, vec![ix], "Configure reserves")?;
     Ok(())
 }
 
-pub fn handle_minter_list(
-    _program: &Program<Rc<Keypair>>,
+pub fn handle_disable_reserves(
+    program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
-    println!("📋 Listing authorized minters...");
+    let program_id = program.id();
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    let (reserve_attestation, _) = constants::reserve_attestation_pda(&stablecoin_pda, &program_id);
     
-    let program_id = _program.id();
-    let stablecoin_pda = stablecoin
-        .copied()
-        .unwrap_or_else(|| derive_stablecoin_pda(authority, &program_id).0);
+    println!("🏦 No longer requiring reserve attestations for mints");
+    println!("   The attestation account's rent is returned to {}", authority);
     
-    println!("   Stablecoin: {}", stablecoin_pda);
-    println!("   Note: Use an indexer service to list all minters");
+    let accounts = vec![
+        AccountMeta::new(*authority, true),                           // authority (signer, mut)
+        AccountMeta::new(stablecoin_pda, false),                      // state (PDA, mut)
+        AccountMeta::new(reserve_attestation, false),                 // reserve_attestation (PDA, closed)
+    ];
+    
+    let ix_data = build_instruction_data("disable_reserves", DisableReserves {})
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    let ix = Instruction {
+        program_id,
+        accounts,
+        data: ix_data,
+    };
     
+    finish(program, tx, vec![ix], "Disable reserves")?;
     Ok(())
 }
 
-pub fn handle_minter_info(
+pub fn handle_attest_reserves(
     program: &Program<Rc<Keypair>>,
-    authority: &Pubkey,
-    account: &str,
+    attester: &Pubkey,
+    amount: u64,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
-    let account_pubkey = parse_pubkey(account)?;
-    
-    println!("ℹ️ Minter info for {}", account_pubkey);
-    
     let program_id = program.id();
     let stablecoin_pda = match stablecoin {
         Some(s) => *s,
@@ -771,82 +1599,44 @@ pub fn handle_minter_info(
             ));
         }
     };
+    let (reserve_attestation, _) = constants::reserve_attestation_pda(&stablecoin_pda, &program_id);
     
-    let (role_pda, _bump) = derive_role_pda(&stablecoin_pda, &account_pubkey, &program_id);
-    let (minter_pda, _bump) = derive_minter_pda(&stablecoin_pda, &account_pubkey, &program_id);
+    println!("🏦 Attesting reserves of {} tokens", amount);
     
-    // Check role using RPC
-    let role_data = program.rpc().get_account_data(&role_pda);
-    match role_data {
-        Ok(data) if data.len() > 8 => {
-            match RoleAssignmentData::try_from_slice(&data[8..]) {
-                Ok(assignment) => {
-                    println!("   Role: {:?}", assignment.role);
-                    println!("   Assigned by: {}", assignment.assigned_by);
-                    println!("   Assigned at: {}", assignment.assigned_at);
-                }
-                Err(_) => {
-                    println!("   Status: Could not parse role data");
-                }
-            }
-        }
-        _ => {
-            println!("   Status: Not a minter");
-        }
-    }
+    let accounts = vec![
+        AccountMeta::new_readonly(*attester, true),                   // attester (signer)
+        AccountMeta::new_readonly(stablecoin_pda, false),             // state (PDA)
+        AccountMeta::new(reserve_attestation, false),                 // reserve_attestation (PDA, mut)
+    ];
     
-    // Check quota using RPC
-    let minter_data = program.rpc().get_account_data(&minter_pda);
-    match minter_data {
-        Ok(data) if data.len() > 8 => {
-            match MinterInfoData::try_from_slice(&data[8..]) {
-                Ok(info) => {
-                    println!("   Quota: {}", info.quota);
-                    println!("   Minted: {}", info.minted_amount);
-                    println!("   Remaining: {}", if info.quota > 0 { info.quota.saturating_sub(info.minted_amount) } else { u64::MAX });
-                }
-                Err(_) => {
-                    println!("   Quota: Could not parse minter data");
-                }
-            }
-        }
-        _ => {
-            println!("   Quota: Not set (unlimited)");
-        }
-    }
+    let ix_data = build_instruction_data("attest_reserves", AttestReservesArgs { reserve_amount: amount })
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    let ix = Instruction {
+        program_id,
+        accounts,
+        data: ix_data,
+    };
     
+    finish(program, tx, vec![ix], "Attest reserves")?;
     Ok(())
 }
 
-#[derive(Debug, ::borsh::BorshDeserialize)]
-struct RoleAssignmentData {
-    role: u8,
-    account: Pubkey,
-    assigned_by: Pubkey,
-    assigned_at: i64,
-    bump: u8,
-}
-
-#[derive(Debug, ::borsh::BorshDeserialize)]
-struct MinterInfoData {
-    minter: Pubkey,
-    quota: u64,
-    minted_amount: u64,
-    bump: u8,
-}
-
-pub fn handle_minter_set_quota(
+// ==================== SET COMPLIANCE ====================
+pub fn handle_set_compliance(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
-    account: &str,
-    quota: u64,
+    enabled: bool,
+    upgrade_preset: bool,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
-    let account_pubkey = parse_pubkey(account)?;
-    
-    println!("📝 Setting quota for {}: {} tokens", account_pubkey, quota);
+    if enabled {
+        println!("🛡️ Enabling compliance{}", if upgrade_preset { " (upgrading preset to SSS-2)" } else { "" });
+    } else {
+        println!("🛡️ Disabling compliance...");
+    }
     
-    let program_id = program.id();
     let stablecoin_pda = match stablecoin {
         Some(s) => *s,
         None => {
@@ -856,48 +1646,41 @@ pub fn handle_minter_set_quota(
         }
     };
     
-    let (minter_pda, _) = derive_minter_pda(&stablecoin_pda, &account_pubkey, &program_id);
-    
     let accounts = vec![
         AccountMeta::new(*authority, true),                           // authority (signer, mut)
         AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
-        AccountMeta::new(minter_pda, false),                          // minter_info (PDA)
-        AccountMeta::new_readonly(account_pubkey, false),             // minter account
     ];
     
-    let ix_data = borsh::to_vec(&SetQuotaArgs { quota })
+    let ix_data = build_instruction_data("set_compliance", SetComplianceArgs { enabled, upgrade_preset })
         .map_err(|e| CliError::SerializationError(e.to_string()))?;
     
     let ix = Instruction {
-        program_id,
+        program_id: program.id(),
         accounts,
         data: ix_data,
     };
     
-    let signature = program
-        .request()
-        .instruction(ix)
-        .send()
-        .map_err(|e| CliError::TransactionError(e.to_string()))?;
-    
-    print_tx_success(&signature.to_string(), "Quota update");
+    finish(program, tx, vec![ix], "Set compliance")?;
     Ok(())
 }
 
-// ==================== SEIZE ====================
-pub fn handle_seize(
+// ==================== SET FEE ====================
+pub fn handle_set_fee(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
-    account: &str,
-    to: &str,
-    amount: u64,
+    fee_bps: u16,
+    treasury: &str,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
-    let account_pubkey = parse_pubkey(account)?;
-    let to_pubkey = parse_pubkey(to)?;
+    let treasury_pubkey = parse_pubkey(treasury)?;
     
-    println!("🔒 Seizing {} tokens from {}", amount, account_pubkey);
-    println!("   Transfer to: {}", to_pubkey);
+    if fee_bps > 10_000 {
+        return Err(CliError::InvalidArg("Fee must be at most 10000 bps".to_string()));
+    }
+    
+    println!("💸 Setting mint/burn fee to {} bps", fee_bps);
+    println!("   Treasury: {}", treasury_pubkey);
     
     let program_id = program.id();
     let stablecoin_pda = match stablecoin {
@@ -909,16 +1692,18 @@ pub fn handle_seize(
         }
     };
     
+    // The program checks a non-zero fee's treasury holds the stablecoin's mint
+    let treasury_account = if fee_bps > 0 { treasury_pubkey } else { program_id };
     let accounts = vec![
         AccountMeta::new(*authority, true),                           // authority (signer, mut)
         AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
-        AccountMeta::new(account_pubkey, false),                      // from (token account)
-        AccountMeta::new(to_pubkey, false),                           // to (token account)
-        AccountMeta::new_readonly(spl_token::id(), false),            // token_program
+        AccountMeta::new_readonly(treasury_account, false),           // fee_treasury (program ID = None)
     ];
     
-    let ix_data = borsh::to_vec(&SeizeArgs { amount })
-        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    let ix_data = build_instruction_data("set_fee", SetFeeArgs {
+        fee_bps,
+        fee_treasury: treasury_pubkey,
+    }).map_err(|e| CliError::SerializationError(e.to_string()))?;
     
     let ix = Instruction {
         program_id,
@@ -926,29 +1711,18 @@ pub fn handle_seize(
         data: ix_data,
     };
     
-    let signature = program
-        .request()
-        .instruction(ix)
-        .send()
-        .map_err(|e| CliError::TransactionError(e.to_string()))?;
-    
-    print_tx_success(&signature.to_string(), "Seize");
+    finish(program, tx, vec![ix], "Set fee")?;
     Ok(())
 }
 
-// ==================== TRANSFER AUTHORITY ====================
-pub fn handle_transfer_authority(
+// ==================== CLOSE ====================
+pub fn handle_close(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
-    new_authority: &str,
+    skip_confirm: bool,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
-    let new_authority_pubkey = parse_pubkey(new_authority)?;
-    
-    println!("🔑 Transferring authority to {}", new_authority_pubkey);
-    println!("   Current authority: {}", authority);
-    println!("   ⚠️  WARNING: This action is irreversible!");
-    
     let program_id = program.id();
     let stablecoin_pda = match stablecoin {
         Some(s) => *s,
@@ -959,14 +1733,58 @@ pub fn handle_transfer_authority(
         }
     };
     
+    println!("🗑️ Closing stablecoin: {}", stablecoin_pda);
+    
+    // Check preconditions locally so the operator gets a clear message before signing
+    let data = program.rpc().get_account_data(&stablecoin_pda)?;
+    let state = decode_account::<StablecoinStateData>(&data)?;
+    if state.total_supply != 0 {
+        return Err(CliError::InvalidArg(format!(
+            "Total supply must be zero before closing (current: {})", state.total_supply
+        )));
+    }
+    if state.paused {
+        return Err(CliError::InvalidArg("Stablecoin is paused; unpause before closing".to_string()));
+    }
+    if !state.minter_count_verified || !state.role_count_verified {
+        return Err(CliError::InvalidArg(
+            "Minter and role counters predate tracking; run `minters recount` and `recount-roles` first".to_string()
+        ));
+    }
+    if state.minter_count != 0 {
+        return Err(CliError::InvalidArg(format!(
+            "Remove all minters before closing ({} remaining)", state.minter_count
+        )));
+    }
+    if state.role_count != 0 {
+        return Err(CliError::InvalidArg(format!(
+            "Revoke all role assignments before closing ({} remaining)", state.role_count
+        )));
+    }
+    let token_program = program.rpc().get_account(&state.asset_mint)?.owner;
+    
+    if !skip_confirm {
+        println!("⚠️  This permanently closes the stablecoin state account and returns its rent to {}.", authority);
+        println!("   The mint and freeze authorities of {} are revoked, so no more tokens can ever be minted.", state.asset_mint);
+        print!("   Type the stablecoin address to confirm: ");
+        std::io::Write::flush(&mut std::io::stdout())?;
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input)?;
+        if input.trim() != stablecoin_pda.to_string() {
+            println!("❌ Confirmation did not match, aborting");
+            return Ok(());
+        }
+    }
+    
     let accounts = vec![
         AccountMeta::new(*authority, true),                           // authority (signer, mut)
-        AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
+        AccountMeta::new(stablecoin_pda, false),                      // state (PDA, closed)
+        AccountMeta::new(state.asset_mint, false),                    // asset_mint (mut)
+        AccountMeta::new_readonly(token_program, false),              // token_program
     ];
     
-    let ix_data = borsh::to_vec(&TransferAuthority {
-        new_authority: new_authority_pubkey,
-    }).map_err(|e| CliError::SerializationError(e.to_string()))?;
+    let ix_data = build_instruction_data("close_stablecoin", CloseStablecoin {})
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
     
     let ix = Instruction {
         program_id,
@@ -974,28 +1792,33 @@ pub fn handle_transfer_authority(
         data: ix_data,
     };
     
-    let signature = program
-        .request()
-        .instruction(ix)
-        .send()
-        .map_err(|e| CliError::TransactionError(e.to_string()))?;
-    
-    print_tx_success(&signature.to_string(), "Authority transfer");
+    finish(program, tx, vec![ix], "Close")?;
     Ok(())
 }
 
-// ==================== ASSIGN ROLE ====================
-pub fn handle_assign_role(
+/// Blacklist entry proving a token account may be seized: its owner's entry,
+/// or else the token account's own, which the program and hook also accept
+fn holder_blacklist_entry(
+    program: &Program<Rc<Keypair>>,
+    stablecoin_pda: &Pubkey,
+    token_account: &Pubkey,
+    owner: &Pubkey,
+) -> Pubkey {
+    let program_id = program.id();
+    let (owner_entry, _) = constants::blacklist_pda(stablecoin_pda, owner, &program_id);
+    if program.rpc().get_account(&owner_entry).is_ok() {
+        return owner_entry;
+    }
+    constants::blacklist_pda(stablecoin_pda, token_account, &program_id).0
+}
+
+// ==================== MIGRATE STATE ====================
+pub fn handle_migrate_state(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
-    role: Role,
-    account: &str,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
-    let account_pubkey = parse_pubkey(account)?;
-    
-    println!("👤 Assigning role {:?} to {}", role, account_pubkey);
-    
     let program_id = program.id();
     let stablecoin_pda = match stablecoin {
         Some(s) => *s,
@@ -1006,19 +1829,23 @@ pub fn handle_assign_role(
         }
     };
     
-    let (role_pda, _) = derive_role_pda(&stablecoin_pda, &account_pubkey, &program_id);
+    let data_len = program.rpc().get_account_data(&stablecoin_pda)?.len();
+    if data_len != constants::LEGACY_STATE_SPACE {
+        println!("✅ {} already has the current layout ({} bytes)", stablecoin_pda, data_len);
+        return Ok(());
+    }
+    
+    println!("📦 Migrating stablecoin state: {}", stablecoin_pda);
+    println!("   The extra rent is paid by {}", authority);
     
     let accounts = vec![
         AccountMeta::new(*authority, true),                           // authority (signer, mut)
-        AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
-        AccountMeta::new(role_pda, false),                            // assignment (PDA)
-        AccountMeta::new_readonly(account_pubkey, false),             // account to assign role
+        AccountMeta::new(stablecoin_pda, false),                      // state (PDA, resized)
         AccountMeta::new_readonly(system_program::id(), false),       // system_program
     ];
     
-    let ix_data = borsh::to_vec(&AssignRoleArgs {
-        role: role.to_u8(),
-    }).map_err(|e| CliError::SerializationError(e.to_string()))?;
+    let ix_data = build_instruction_data("migrate_state", MigrateState {})
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
     
     let ix = Instruction {
         program_id,
@@ -1026,26 +1853,23 @@ pub fn handle_assign_role(
         data: ix_data,
     };
     
-    let signature = program
-        .request()
-        .instruction(ix)
-        .send()
-        .map_err(|e| CliError::TransactionError(e.to_string()))?;
-    
-    print_tx_success(&signature.to_string(), "Role assignment");
+    finish(program, tx, vec![ix], "Migrate state")?;
     Ok(())
 }
 
-// ==================== REVOKE ROLE ====================
-pub fn handle_revoke_role(
+// ==================== BLACKLIST ====================
+pub fn handle_blacklist_add(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
     account: &str,
+    reason: &str,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
     let account_pubkey = parse_pubkey(account)?;
     
-    println!("🚫 Revoking all roles from {}", account_pubkey);
+    println!("🚫 Adding {} to blacklist", account_pubkey);
+    println!("   Reason: {}", reason);
     
     let program_id = program.id();
     let stablecoin_pda = match stablecoin {
@@ -1057,42 +1881,82 @@ pub fn handle_revoke_role(
         }
     };
     
-    let (role_pda, _) = derive_role_pda(&stablecoin_pda, &account_pubkey, &program_id);
+    let ix = blacklist_add_instruction(&program_id, authority, &stablecoin_pda, &account_pubkey, reason)?;
+    
+    finish(program, tx, vec![ix], "Blacklist add")?;
+    Ok(())
+}
+
+fn blacklist_add_instruction(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    stablecoin_pda: &Pubkey,
+    account: &Pubkey,
+    reason: &str,
+) -> CliResult<Instruction> {
+    let (entry_pda, _) = constants::blacklist_pda(stablecoin_pda, account, program_id);
     
     let accounts = vec![
         AccountMeta::new(*authority, true),                           // authority (signer, mut)
-        AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
-        AccountMeta::new(role_pda, false),                            // assignment (PDA)
-        AccountMeta::new_readonly(account_pubkey, false),             // account to revoke role
+        AccountMeta::new(*stablecoin_pda, false),                     // state (PDA)
+        AccountMeta::new(entry_pda, false),                           // entry (PDA)
+        AccountMeta::new_readonly(*account, false),                   // account to blacklist
+        AccountMeta::new_readonly(system_program::id(), false),       // system_program
     ];
     
-    let ix_data = borsh::to_vec(&RevokeRoleArgs {})
-        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    let ix_data = build_instruction_data("add_to_blacklist", AddToBlacklist {
+        reason: reason.to_string(),
+    }).map_err(|e| CliError::SerializationError(e.to_string()))?;
     
-    let ix = Instruction {
-        program_id,
+    Ok(Instruction {
+        program_id: *program_id,
         accounts,
         data: ix_data,
-    };
-    
-    let signature = program
-        .request()
-        .instruction(ix)
-        .send()
-        .map_err(|e| CliError::TransactionError(e.to_string()))?;
-    
-    print_tx_success(&signature.to_string(), "Role revocation");
-    Ok(())
+    })
 }
 
-// ==================== STATUS ====================
-pub fn handle_status(
+/// Parse a blacklist import file: one `address` or `address,reason` per line.
+/// Blank lines and lines starting with `#` are ignored.
+pub fn parse_blacklist_import(contents: &str, default_reason: &str) -> CliResult<Vec<(Pubkey, String)>> {
+    let mut entries = Vec::new();
+    for (line_no, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (address, reason) = match line.split_once(',') {
+            Some((address, reason)) => (address.trim(), reason.trim()),
+            None => (line, ""),
+        };
+        let account = address.parse::<Pubkey>().map_err(|_| {
+            CliError::InvalidArg(format!("Line {}: invalid address '{}'", line_no + 1, address))
+        })?;
+        let reason = if reason.is_empty() { default_reason } else { reason };
+        if reason.len() > 200 {
+            return Err(CliError::InvalidArg(format!(
+                "Line {}: reason too long (max 200 chars)", line_no + 1
+            )));
+        }
+        entries.push((account, reason.to_string()));
+    }
+    Ok(entries)
+}
+
+/// The system program rejects `create_account` for an existing address with "already in use"
+fn is_already_blacklisted_error(err: &CliError) -> bool {
+    matches!(err, CliError::TransactionError(msg) if msg.contains("already in use"))
+}
+
+pub fn handle_blacklist_import(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
+    file: &str,
+    default_reason: &str,
+    batch_size: Option<usize>,
     stablecoin: Option<&Pubkey>,
-    export_path: Option<&str>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
-    println!("📊 Stablecoin Status");
+    let max_per_batch = batch_limit(batch_size)?;
     
     let program_id = program.id();
     let stablecoin_pda = match stablecoin {
@@ -1104,76 +1968,93 @@ pub fn handle_status(
         }
     };
     
-    println!("   Stablecoin PDA: {}", stablecoin_pda);
+    let contents = std::fs::read_to_string(file)?;
+    let mut entries = parse_blacklist_import(&contents, default_reason)?;
+    entries.sort_by_key(|(account, _)| *account);
+    entries.dedup_by_key(|(account, _)| *account);
+    
+    println!("📥 Importing {} addresses into blacklist from {}", entries.len(), file);
+    
+    // Skip accounts that already have a blacklist entry
+    let pdas: Vec<Pubkey> = entries
+        .iter()
+        .map(|(account, _)| constants::blacklist_pda(&stablecoin_pda, account, &program_id).0)
+        .collect();
+    let mut existing = Vec::with_capacity(pdas.len());
+    for chunk in pdas.chunks(100) {
+        existing.extend(program.rpc().get_multiple_accounts(chunk)?.into_iter().map(|a| a.is_some()));
+    }
+    let mut skipped = existing.iter().filter(|exists| **exists).count();
+    let pending: Vec<(Pubkey, String)> = entries
+        .into_iter()
+        .zip(existing)
+        .filter(|(_, exists)| !exists)
+        .map(|(entry, _)| entry)
+        .collect();
+    if skipped > 0 {
+        println!("   Skipping {} already-blacklisted addresses", skipped);
+    }
     
-    // Fetch state using RPC
-    let state_data = program.rpc().get_account_data(&stablecoin_pda);
-    match state_data {
-        Ok(data) if data.len() > 8 => {
-            match StablecoinStateData::try_from_slice(&data[8..]) {
-                Ok(state) => {
-                    println!("\n┌─────────────────────────────────────────┐");
-                    println!("│ STABLECOIN STATE                        │");
-                    println!("├─────────────────────────────────────────┤");
-                    println!("│ Authority:    {:<25}│", state.authority);
-                    println!("│ Asset Mint:   {:<25}│", state.asset_mint);
-                    println!("│ Total Supply: {:<25}│", state.total_supply);
-                    println!("│ Paused:       {:<25}│", if state.paused { "YES" } else { "NO" });
-                    println!("│ Preset:       SSS-{:<22}│", state.preset);
-                    println!("│ Compliance:   {:<25}│", if state.compliance_enabled { "ENABLED" } else { "DISABLED" });
-                    println!("│ Bump:         {:<25}│", state.bump);
-                    println!("└─────────────────────────────────────────┘");
-                    
-                    if let Some(path) = export_path {
-                        let json = serde_json::json!({
-                            "stablecoin_pda": stablecoin_pda.to_string(),
-                            "authority": state.authority.to_string(),
-                            "asset_mint": state.asset_mint.to_string(),
-                            "total_supply": state.total_supply,
-                            "paused": state.paused,
-                            "preset": state.preset,
-                            "compliance_enabled": state.compliance_enabled,
-                            "bump": state.bump,
-                        });
-                        std::fs::write(path, serde_json::to_string_pretty(&json)?)
-                            .map_err(|e| CliError::IoError(e.to_string()))?;
-                        println!("\n💾 Status exported to {}", path);
+    // Long reasons make for large instructions, so batches are sized to fit
+    let ixs = pending
+        .iter()
+        .map(|(account, reason)| blacklist_add_instruction(&program_id, authority, &stablecoin_pda, account, reason))
+        .collect::<CliResult<Vec<_>>>()?;
+    let batches = plan_batches(&ixs, authority, max_per_batch, tx);
+    
+    let mut added = 0usize;
+    let mut failed: Vec<(Pubkey, String)> = Vec::new();
+    let total_batches = batches.len();
+    
+    for (batch_no, range) in batches.into_iter().enumerate() {
+        let batch = &pending[range.clone()];
+        
+        match send_instructions(program, tx, ixs[range].to_vec()) {
+            Ok(signature) => {
+                added += batch.len();
+                println!("   [{}/{}] ✅ {} added ({})", batch_no + 1, total_batches, batch.len(), signature);
+            }
+            Err(batch_err) => {
+                // Retry individually so one bad entry doesn't fail the whole batch
+                println!("   [{}/{}] ⚠️ batch failed, retrying individually: {}", batch_no + 1, total_batches, batch_err);
+                for (account, reason) in batch {
+                    let ix = blacklist_add_instruction(&program_id, authority, &stablecoin_pda, account, reason)?;
+                    match send_instruction(program, tx, ix) {
+                        Ok(_) => added += 1,
+                        Err(e) if is_already_blacklisted_error(&e) => skipped += 1,
+                        Err(e) => failed.push((*account, e.to_string())),
                     }
                 }
-                Err(e) => {
-                    println!("❌ Failed to parse state: {}", e);
-                }
             }
         }
-        Ok(_) => {
-            println!("❌ Account data too short");
-        }
-        Err(e) => {
-            println!("❌ Failed to fetch state: {}", e);
-            println!("   The stablecoin may not be initialized yet.");
-        }
     }
     
-    Ok(())
-}
-
-#[derive(Debug, ::borsh::BorshDeserialize)]
-struct StablecoinStateData {
-    authority: Pubkey,
-    asset_mint: Pubkey,
-    total_supply: u64,
-    paused: bool,
-    preset: u8,
-    compliance_enabled: bool,
-    bump: u8,
+    println!("\n📊 Import summary");
+    println!("   Added:   {}", added);
+    println!("   Skipped: {}", skipped);
+    println!("   Failed:  {}", failed.len());
+    for (account, err) in &failed {
+        println!("   ❌ {}: {}", account, err);
+    }
+    
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(CliError::TransactionError(format!("{} addresses failed to import", failed.len())))
+    }
 }
 
-// ==================== SUPPLY ====================
-pub fn handle_supply(
+pub fn handle_blacklist_remove(
     program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
+    account: &str,
     stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
 ) -> CliResult<()> {
+    let account_pubkey = parse_pubkey(account)?;
+    
+    println!("✅ Removing {} from blacklist", account_pubkey);
+    
     let program_id = program.id();
     let stablecoin_pda = match stablecoin {
         Some(s) => *s,
@@ -1184,59 +2065,2264 @@ pub fn handle_supply(
         }
     };
     
-    match program.rpc().get_account_data(&stablecoin_pda) {
-        Ok(data) if data.len() > 8 => {
-            match StablecoinStateData::try_from_slice(&data[8..]) {
-                Ok(state) => {
-                    println!("💰 Total Supply: {} tokens", state.total_supply);
-                }
-                Err(_) => {
-                    println!("❌ Could not parse supply data.");
-                }
-            }
-        }
-        _ => {
-            println!("❌ Could not fetch supply. Stablecoin may not be initialized.");
-        }
-    }
+    let (entry_pda, _) = constants::blacklist_pda(&stablecoin_pda, &account_pubkey, &program_id);
+    
+    let accounts = vec![
+        AccountMeta::new(*authority, true),                           // authority (signer, mut)
+        AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
+        AccountMeta::new(entry_pda, false),                           // entry (PDA)
+        AccountMeta::new_readonly(account_pubkey, false),             // account to unblacklist
+    ];
+    
+    let ix_data = build_instruction_data("remove_from_blacklist", RemoveFromBlacklist {})
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    let ix = Instruction {
+        program_id,
+        accounts,
+        data: ix_data,
+    };
     
+    finish(program, tx, vec![ix], "Blacklist remove")?;
     Ok(())
 }
 
-// ==================== HOLDERS ====================
-pub fn handle_holders(
+pub fn handle_blacklist_list(
     _program: &Program<Rc<Keypair>>,
     authority: &Pubkey,
-    min_balance: u64,
     stablecoin: Option<&Pubkey>,
 ) -> CliResult<()> {
+    println!("📋 Listing blacklisted accounts...");
+    
     let program_id = _program.id();
     let stablecoin_pda = stablecoin
         .copied()
-        .unwrap_or_else(|| derive_stablecoin_pda(authority, &program_id).0);
+        .unwrap_or_else(|| constants::stablecoin_pda(authority, &program_id).0);
     
-    println!("👥 Token Holders (min balance: {})", min_balance);
     println!("   Stablecoin: {}", stablecoin_pda);
-    println!("\n   Note: Holder list requires an indexer service.");
-    println!("   Consider using:");
-    println!("   - Helius API");
-    println!("   - Solana RPC's getTokenAccountsByDelegate");
-    println!("   - Custom indexer");
+    println!("   Note: Use an indexer service to list all blacklist entries");
+    println!("   Or check individual accounts with: sss-token blacklist check <account>");
     
     Ok(())
 }
 
-// ==================== AUDIT LOG ====================
-pub fn handle_audit_log(
-    _program: &Program<Rc<Keypair>>,
-    _authority: &Pubkey,
-    action: Option<&str>,
-    from: Option<&Pubkey>,
-    to: Option<&Pubkey>,
-    _format: &str,
-    output_path: Option<&str>,
+pub fn handle_blacklist_check(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    account: &str,
+    stablecoin: Option<&Pubkey>,
+    output: OutputFormat,
 ) -> CliResult<()> {
-    println!("📜 Audit Log");
+    let account_pubkey = parse_pubkey(account)?;
+    
+    human!(output, "🔍 Checking blacklist status for {}", account_pubkey);
+    
+    let program_id = program.id();
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let (entry_pda, _bump) = constants::blacklist_pda(&stablecoin_pda, &account_pubkey, &program_id);
+    
+    // Try to fetch the blacklist entry account using RPC
+    let entry = match program.rpc().get_account_data(&entry_pda) {
+        Ok(data) if data.len() > 8 => match decode_account::<BlacklistEntryData>(&data) {
+            Ok(entry) => Some(entry),
+            Err(e) if output.is_json() => return Err(e),
+            Err(_) => {
+                println!("⚠️ Could not parse blacklist entry");
+                return Ok(());
+            }
+        },
+        _ => None,
+    };
+    
+    if output.is_json() {
+        return print_json(&serde_json::json!({
+            "account": account_pubkey.to_string(),
+            "stablecoin_pda": stablecoin_pda.to_string(),
+            "blacklisted": entry.is_some(),
+            "entry": entry,
+        }));
+    }
+    
+    match entry {
+        Some(entry) => {
+            println!("🚫 Account IS blacklisted");
+            println!("   Reason: {}", entry.reason);
+            println!("   Blacklisted by: {}", entry.blacklisted_by);
+            println!("   At: {}", entry.blacklisted_at);
+        }
+        None => {
+            println!("✅ Account is NOT blacklisted");
+        }
+    }
+    
+    Ok(())
+}
+
+// BlacklistEntryData for deserialization
+#[derive(Debug, ::borsh::BorshDeserialize, serde::Serialize)]
+struct BlacklistEntryData {
+    #[serde(serialize_with = "serialize_pubkey")]
+    account: Pubkey,
+    reason: String,
+    #[serde(serialize_with = "serialize_pubkey")]
+    blacklisted_by: Pubkey,
+    blacklisted_at: i64,
+    bump: u8,
+}
+
+/// Accounts passed per recount transaction
+const RECOUNT_BATCH_SIZE: usize = 20;
+
+/// Require the stablecoin PDA and reject states that still use the original layout,
+/// which has no counters to recount
+fn recount_target(program: &Program<Rc<Keypair>>, stablecoin: Option<&Pubkey>) -> CliResult<Pubkey> {
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    if program.rpc().get_account_data(&stablecoin_pda)?.len() == constants::LEGACY_STATE_SPACE {
+        return Err(CliError::InvalidArg(
+            "Stablecoin state uses the original layout; run `migrate-state` first".to_string()
+        ));
+    }
+    Ok(stablecoin_pda)
+}
+
+/// Send `accounts` to a recount instruction in batches, resetting the counter on the first one.
+/// `ix_data` builds the instruction data from the `reset` flag.
+fn send_recount_batches(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    stablecoin_pda: &Pubkey,
+    accounts: &[Pubkey],
+    ix_data: impl Fn(bool) -> CliResult<Vec<u8>>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    let batches: Vec<&[Pubkey]> = if accounts.is_empty() {
+        vec![&[]]
+    } else {
+        accounts.chunks(RECOUNT_BATCH_SIZE).collect()
+    };
+    
+    for (i, batch) in batches.iter().enumerate() {
+        let mut metas = vec![
+            AccountMeta::new(*authority, true),                           // authority (signer, mut)
+            AccountMeta::new(*stablecoin_pda, false),                     // state (PDA)
+        ];
+        metas.extend(batch.iter().map(|pda| AccountMeta::new_readonly(*pda, false))); // counted accounts
+        
+        let ix = Instruction {
+            program_id: program.id(),
+            accounts: metas,
+            data: ix_data(i == 0)?,
+        };
+        
+        let signature = send_instruction(program, tx, ix)?;
+        println!("   [{}/{}] {}", i + 1, batches.len(), signature);
+    }
+    Ok(())
+}
+
+pub fn handle_blacklist_recount(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    let program_id = program.id();
+    let stablecoin_pda = recount_target(program, stablecoin)?;
+    
+    println!("🔢 Recounting blacklist entries for {}", stablecoin_pda);
+    
+    // Entries don't store their stablecoin, so match on the derived PDA
+    let entries: Vec<Pubkey> = fetch_program_accounts::<BlacklistEntryData>(program, "BlacklistEntry")?
+        .into_iter()
+        .filter(|(pubkey, entry)| constants::blacklist_pda(&stablecoin_pda, &entry.account, &program_id).0 == *pubkey)
+        .map(|(pubkey, _)| pubkey)
+        .collect();
+    
+    println!("   Found {} entries", entries.len());
+    
+    send_recount_batches(program, authority, &stablecoin_pda, &entries, |reset| {
+        build_instruction_data("recount_blacklist", RecountBlacklistArgs { reset })
+            .map_err(|e| CliError::SerializationError(e.to_string()))
+    }, tx)?;
+    
+    println!("✅ Blacklist count set to {}", entries.len());
+    Ok(())
+}
+
+// ==================== ALLOWLIST ====================
+pub fn handle_allowlist_add(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    account: &str,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    let account_pubkey = parse_pubkey(account)?;
+    
+    println!("✅ Adding {} to allowlist", account_pubkey);
+    
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let ix_data = build_instruction_data("add_to_allowlist", AddToAllowlist {})
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    let mut ix = allowlist_instruction(&program.id(), authority, &stablecoin_pda, &account_pubkey, ix_data);
+    ix.accounts.push(AccountMeta::new_readonly(system_program::id(), false)); // system_program
+    
+    finish(program, tx, vec![ix], "Allowlist add")?;
+    Ok(())
+}
+
+pub fn handle_allowlist_remove(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    account: &str,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    let account_pubkey = parse_pubkey(account)?;
+    
+    println!("🚫 Removing {} from allowlist", account_pubkey);
+    
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let ix_data = build_instruction_data("remove_from_allowlist", RemoveFromAllowlist {})
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    let ix = allowlist_instruction(&program.id(), authority, &stablecoin_pda, &account_pubkey, ix_data);
+    
+    finish(program, tx, vec![ix], "Allowlist remove")?;
+    Ok(())
+}
+
+/// `add_to_allowlist` and `remove_from_allowlist` share one account list;
+/// only `add_to_allowlist` takes the system program, to create the entry
+fn allowlist_instruction(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    stablecoin_pda: &Pubkey,
+    account: &Pubkey,
+    data: Vec<u8>,
+) -> Instruction {
+    let (entry_pda, _) = constants::allowlist_pda(stablecoin_pda, account, program_id);
+    
+    let accounts = vec![
+        AccountMeta::new(*authority, true),                           // authority (signer, mut)
+        AccountMeta::new_readonly(*stablecoin_pda, false),            // state (PDA)
+        AccountMeta::new(entry_pda, false),                           // entry (PDA)
+        AccountMeta::new_readonly(*account, false),                   // token account
+    ];
+    
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn handle_allowlist_check(
+    program: &Program<Rc<Keypair>>,
+    account: &str,
+    stablecoin: Option<&Pubkey>,
+    output: OutputFormat,
+) -> CliResult<()> {
+    let account_pubkey = parse_pubkey(account)?;
+    
+    human!(output, "🔍 Checking allowlist status for {}", account_pubkey);
+    
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let (entry_pda, _) = constants::allowlist_pda(&stablecoin_pda, &account_pubkey, &program.id());
+    let entry = match program.rpc().get_account_data(&entry_pda) {
+        Ok(data) if data.len() > 8 => Some(decode_account::<AllowlistEntryData>(&data)?),
+        _ => None,
+    };
+    
+    if output.is_json() {
+        return print_json(&serde_json::json!({
+            "account": account_pubkey.to_string(),
+            "stablecoin_pda": stablecoin_pda.to_string(),
+            "allowlisted": entry.is_some(),
+            "entry": entry,
+        }));
+    }
+    
+    match entry {
+        Some(entry) => {
+            println!("✅ Account IS allowlisted");
+            println!("   Added by: {}", entry.added_by);
+            println!("   At: {}", entry.added_at);
+        }
+        None => {
+            println!("🚫 Account is NOT allowlisted");
+        }
+    }
+    
+    Ok(())
+}
+
+pub fn handle_allowlist_mode(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    enabled: bool,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    if enabled {
+        println!("🔒 Enabling allowlist mode...");
+        println!("   Transfers to or from accounts without an allowlist entry will fail");
+    } else {
+        println!("🔓 Disabling allowlist mode...");
+    }
+    
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let accounts = vec![
+        AccountMeta::new(*authority, true),                           // authority (signer, mut)
+        AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
+    ];
+    
+    let ix_data = build_instruction_data("set_allowlist_mode", SetAllowlistModeArgs { enabled })
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    let ix = Instruction {
+        program_id: program.id(),
+        accounts,
+        data: ix_data,
+    };
+    
+    finish(program, tx, vec![ix], "Set allowlist mode")?;
+    Ok(())
+}
+
+// AllowlistEntryData for deserialization
+#[derive(Debug, ::borsh::BorshDeserialize, serde::Serialize)]
+struct AllowlistEntryData {
+    #[serde(serialize_with = "serialize_pubkey")]
+    account: Pubkey,
+    #[serde(serialize_with = "serialize_pubkey")]
+    added_by: Pubkey,
+    added_at: i64,
+    bump: u8,
+}
+
+// ==================== MINTERS ====================
+pub fn handle_minter_add(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    account: &str,
+    quota: u64,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    let account_pubkey = parse_pubkey(account)?;
+    
+    println!("➕ Adding minter: {}", account_pubkey);
+    if quota > 0 {
+        println!("   Quota: {} tokens", quota);
+    } else {
+        println!("   Quota: Unlimited");
+    }
+    
+    let program_id = program.id();
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let (role_pda, _) = constants::role_pda(&stablecoin_pda, &account_pubkey, Role::Minter.seed(), &program_id);
+    let (minter_pda, _) = constants::minter_pda(&stablecoin_pda, &account_pubkey, &program_id);
+    
+    let accounts = vec![
+        AccountMeta::new(*authority, true),                           // authority (signer, mut)
+        AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
+        AccountMeta::new(role_pda, false),                            // role_assignment (PDA)
+        AccountMeta::new(minter_pda, false),                          // minter_info (PDA)
+        AccountMeta::new_readonly(account_pubkey, false),             // minter account
+        AccountMeta::new_readonly(system_program::id(), false),       // system_program
+    ];
+    
+    let ix_data = build_instruction_data("add_minter", AddMinterArgs { quota })
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    let ix = Instruction {
+        program_id,
+        accounts,
+        data: ix_data,
+    };
+    
+    finish(program, tx, vec![ix], "Minter add")?;
+    Ok(())
+}
+
+pub fn handle_minter_remove(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    account: &str,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    let account_pubkey = parse_pubkey(account)?;
+    
+    println!("➖ Removing minter: {}", account_pubkey);
+    
+    let program_id = program.id();
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let (role_pda, _) = constants::role_pda(&stablecoin_pda, &account_pubkey, Role::Minter.seed(), &program_id);
+    let (minter_pda, _) = constants::minter_pda(&stablecoin_pda, &account_pubkey, &program_id);
+    
+    let accounts = vec![
+        AccountMeta::new(*authority, true),                           // authority (signer, mut)
+        AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
+        AccountMeta::new(role_pda, false),                            // role_assignment (PDA)
+        AccountMeta::new(minter_pda, false),                          // minter_info (PDA)
+        AccountMeta::new_readonly(account_pubkey, false),             // minter account
+    ];
+    
+    let ix_data = build_instruction_data("remove_minter", RemoveMinterArgs {})
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    let ix = Instruction {
+        program_id,
+        accounts,
+        data: ix_data,
+    };
+    
+    finish(program, tx, vec![ix], "Minter removal")?;
+    Ok(())
+}
+
+pub fn handle_minter_list(
+    _program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    stablecoin: Option<&Pubkey>,
+) -> CliResult<()> {
+    println!("📋 Listing authorized minters...");
+    
+    let program_id = _program.id();
+    let stablecoin_pda = stablecoin
+        .copied()
+        .unwrap_or_else(|| constants::stablecoin_pda(authority, &program_id).0);
+    
+    println!("   Stablecoin: {}", stablecoin_pda);
+    println!("   Note: Use an indexer service to list all minters");
+    
+    Ok(())
+}
+
+pub fn handle_minter_info(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    account: &str,
+    stablecoin: Option<&Pubkey>,
+    output: OutputFormat,
+) -> CliResult<()> {
+    let account_pubkey = parse_pubkey(account)?;
+    
+    human!(output, "ℹ️ Minter info for {}", account_pubkey);
+    
+    let program_id = program.id();
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let (role_pda, _bump) = constants::role_pda(&stablecoin_pda, &account_pubkey, Role::Minter.seed(), &program_id);
+    let (minter_pda, _bump) = constants::minter_pda(&stablecoin_pda, &account_pubkey, &program_id);
+    
+    if output.is_json() {
+        let role = match program.rpc().get_account_data(&role_pda) {
+            Ok(data) if data.len() > 8 => Some(decode_account::<RoleAssignmentData>(&data)?),
+            _ => None,
+        };
+        let minter = match program.rpc().get_account_data(&minter_pda) {
+            Ok(data) if data.len() > 8 => Some(decode_account::<MinterInfoData>(&data)?),
+            _ => None,
+        };
+        // A quota of 0 (or no minter account) means unlimited, reported as null
+        let remaining = minter
+            .as_ref()
+            .filter(|info| info.quota > 0)
+            .map(|info| info.quota.saturating_sub(info.minted_amount));
+        return print_json(&serde_json::json!({
+            "account": account_pubkey.to_string(),
+            "stablecoin_pda": stablecoin_pda.to_string(),
+            "role": role,
+            "minter": minter,
+            "remaining": remaining,
+        }));
+    }
+    
+    // Check role using RPC
+    let role_data = program.rpc().get_account_data(&role_pda);
+    match role_data {
+        Ok(data) if data.len() > 8 => {
+            match decode_account::<RoleAssignmentData>(&data) {
+                Ok(assignment) => {
+                    println!("   Role: {:?}", assignment.role);
+                    println!("   Assigned by: {}", assignment.assigned_by);
+                    println!("   Assigned at: {}", assignment.assigned_at);
+                    if let Some(expires_at) = assignment.expires_at {
+                        println!("   Expires at: {}", expires_at);
+                    }
+                }
+                Err(_) => {
+                    println!("   Status: Could not parse role data");
+                }
+            }
+        }
+        _ => {
+            println!("   Status: Not a minter");
+        }
+    }
+    
+    // Check quota using RPC
+    let minter_data = program.rpc().get_account_data(&minter_pda);
+    match minter_data {
+        Ok(data) if data.len() > 8 => {
+            match decode_account::<MinterInfoData>(&data) {
+                Ok(info) => {
+                    let decimals = program.rpc().get_account_data(&stablecoin_pda)
+                        .ok()
+                        .and_then(|data| decode_account::<StablecoinStateData>(&data).ok())
+                        .map_or(0, |state| mint_decimals_or_raw(program, &state.asset_mint));
+                    let amount = |raw: u64| format_token_amount(raw, decimals);
+                    if info.quota > 0 {
+                        println!("   Quota: {}", amount(info.quota));
+                        println!("   Minted: {}", amount(info.minted_amount));
+                        println!("   Remaining: {}", amount(info.quota.saturating_sub(info.minted_amount)));
+                    } else {
+                        println!("   Quota: Unlimited");
+                        println!("   Minted: {}", amount(info.minted_amount));
+                    }
+                }
+                Err(_) => {
+                    println!("   Quota: Could not parse minter data");
+                }
+            }
+        }
+        _ => {
+            println!("   Quota: Not set (unlimited)");
+        }
+    }
+    
+    Ok(())
+}
+
+#[derive(Debug, ::borsh::BorshDeserialize, serde::Serialize)]
+struct RoleAssignmentData {
+    role: u8,
+    #[serde(serialize_with = "serialize_pubkey")]
+    account: Pubkey,
+    #[serde(serialize_with = "serialize_pubkey")]
+    assigned_by: Pubkey,
+    assigned_at: i64,
+    bump: u8,
+    expires_at: Option<i64>,
+}
+
+#[derive(Debug, ::borsh::BorshDeserialize, serde::Serialize)]
+struct MinterInfoData {
+    #[serde(serialize_with = "serialize_pubkey")]
+    minter: Pubkey,
+    quota: u64,
+    minted_amount: u64,
+    bump: u8,
+}
+
+pub fn handle_minter_recount(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    let program_id = program.id();
+    let stablecoin_pda = recount_target(program, stablecoin)?;
+    
+    println!("🔢 Recounting minters for {}", stablecoin_pda);
+    
+    // Minters don't store their stablecoin, so match on the derived PDA
+    let minters: Vec<Pubkey> = fetch_program_accounts::<MinterInfoData>(program, "MinterInfo")?
+        .into_iter()
+        .filter(|(pubkey, info)| constants::minter_pda(&stablecoin_pda, &info.minter, &program_id).0 == *pubkey)
+        .map(|(pubkey, _)| pubkey)
+        .collect();
+    
+    println!("   Found {} minters", minters.len());
+    
+    send_recount_batches(program, authority, &stablecoin_pda, &minters, |reset| {
+        build_instruction_data("recount_minters", RecountMintersArgs { reset })
+            .map_err(|e| CliError::SerializationError(e.to_string()))
+    }, tx)?;
+    
+    println!("✅ Minter count set to {}", minters.len());
+    Ok(())
+}
+
+pub fn handle_role_recount(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    let program_id = program.id();
+    let stablecoin_pda = recount_target(program, stablecoin)?;
+    
+    println!("🔢 Recounting role assignments for {}", stablecoin_pda);
+    
+    // Roles don't store their stablecoin, so match on the derived PDA
+    let assignments: Vec<Pubkey> = fetch_program_accounts::<RoleAssignmentData>(program, "RoleAssignment")?
+        .into_iter()
+        .filter(|(pubkey, assignment)| {
+            Role::from_u8(assignment.role).is_some_and(|role| {
+                constants::role_pda(&stablecoin_pda, &assignment.account, role.seed(), &program_id).0 == *pubkey
+            })
+        })
+        .map(|(pubkey, _)| pubkey)
+        .collect();
+    
+    println!("   Found {} role assignments", assignments.len());
+    
+    send_recount_batches(program, authority, &stablecoin_pda, &assignments, |reset| {
+        build_instruction_data("recount_roles", RecountRolesArgs { reset })
+            .map_err(|e| CliError::SerializationError(e.to_string()))
+    }, tx)?;
+    
+    println!("✅ Role count set to {}", assignments.len());
+    Ok(())
+}
+
+pub fn handle_minter_set_quota(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    account: &str,
+    quota: u64,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    let account_pubkey = parse_pubkey(account)?;
+    
+    println!("📝 Setting quota for {}: {} tokens", account_pubkey, quota);
+    
+    let program_id = program.id();
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let (minter_pda, _) = constants::minter_pda(&stablecoin_pda, &account_pubkey, &program_id);
+    
+    let accounts = vec![
+        AccountMeta::new(*authority, true),                           // authority (signer, mut)
+        AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
+        AccountMeta::new(minter_pda, false),                          // minter_info (PDA)
+        AccountMeta::new_readonly(account_pubkey, false),             // minter account
+    ];
+    
+    let ix_data = build_instruction_data("update_quota", SetQuotaArgs { quota })
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    let ix = Instruction {
+        program_id,
+        accounts,
+        data: ix_data,
+    };
+    
+    finish(program, tx, vec![ix], "Quota update")?;
+    Ok(())
+}
+
+pub fn handle_minter_rotate(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    from: &str,
+    to: &str,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    let old_minter = parse_pubkey(from)?;
+    let new_minter = parse_pubkey(to)?;
+    if old_minter == new_minter {
+        return Err(CliError::InvalidArg("--from and --to must be different keys".to_string()));
+    }
+    
+    let program_id = program.id();
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let rpc = program.rpc();
+    let (old_minter_pda, _) = constants::minter_pda(&stablecoin_pda, &old_minter, &program_id);
+    let (new_minter_pda, _) = constants::minter_pda(&stablecoin_pda, &new_minter, &program_id);
+    let old_info = rpc.get_account_data(&old_minter_pda)
+        .map_err(|_| CliError::AccountNotFound(format!("minter {}", old_minter)))?;
+    let old_info = decode_account::<MinterInfoData>(&old_info)?;
+    if rpc.get_account(&new_minter_pda).is_ok() {
+        return Err(CliError::InvalidArg(format!("{} is already a minter", new_minter)));
+    }
+    
+    println!("🔁 Rotating minter {} → {}", old_minter, new_minter);
+    if old_info.quota > 0 {
+        println!("   Quota: {} tokens ({} minted)", old_info.quota, old_info.minted_amount);
+    } else {
+        println!("   Quota: Unlimited ({} minted)", old_info.minted_amount);
+    }
+    
+    // The program moves the Minter role in the same instruction, so no moment
+    // exists where both keys or neither key can mint
+    let (old_role_pda, _) = constants::role_pda(&stablecoin_pda, &old_minter, Role::Minter.seed(), &program_id);
+    let (new_role_pda, _) = constants::role_pda(&stablecoin_pda, &new_minter, Role::Minter.seed(), &program_id);
+    let (old_role, new_role) = if rpc.get_account(&old_role_pda).is_ok() {
+        println!("   Moving Minter role to {}", new_minter);
+        (AccountMeta::new(old_role_pda, false), AccountMeta::new(new_role_pda, false))
+    } else {
+        (AccountMeta::new_readonly(program_id, false), AccountMeta::new_readonly(program_id, false))
+    };
+    
+    let ix_data = build_instruction_data("rotate_minter", RotateMinter {})
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    let ixs = vec![Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(*authority, true),                       // authority (signer, mut)
+            AccountMeta::new(stablecoin_pda, false),                  // state (PDA, mut)
+            AccountMeta::new(old_minter_pda, false),                  // old_minter_info (PDA, closed)
+            AccountMeta::new(new_minter_pda, false),                  // new_minter_info (PDA, created)
+            AccountMeta::new_readonly(new_minter, false),             // new minter account
+            AccountMeta::new_readonly(system_program::id(), false),   // system_program
+            old_role,                                                 // old_role_assignment (optional, closed)
+            new_role,                                                 // new_role_assignment (optional, created)
+        ],
+        data: ix_data,
+    }];
+    
+    finish(program, tx, ixs, "Minter rotation")?;
+    Ok(())
+}
+
+// ==================== SEIZE ====================
+pub fn handle_seize(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    account: &str,
+    to: &str,
+    amount: u64,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    let account_pubkey = parse_pubkey(account)?;
+    let to_pubkey = parse_pubkey(to)?;
+    
+    println!("🔒 Seizing {} tokens from {}", amount, account_pubkey);
+    println!("   Transfer to: {}", to_pubkey);
+    
+    let program_id = program.id();
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    require_balance(program, &account_pubkey, amount)?;
+    
+    // Seizure requires the blacklist entry of the token account or its owner
+    let from_data = program
+        .rpc()
+        .get_account_data(&account_pubkey)?;
+    if from_data.len() < 64 {
+        return Err(CliError::InvalidArg(format!("{} is not a token account", account_pubkey)));
+    }
+    let from_owner = Pubkey::try_from(&from_data[32..64])
+        .map_err(|e| CliError::InvalidPubkey(e.to_string()))?;
+    let blacklist_pda = holder_blacklist_entry(program, &stablecoin_pda, &account_pubkey, &from_owner);
+    
+    let accounts = vec![
+        AccountMeta::new(*authority, true),                           // authority (signer, mut)
+        AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
+        AccountMeta::new(account_pubkey, false),                      // from (token account)
+        AccountMeta::new(to_pubkey, false),                           // to (token account)
+        AccountMeta::new_readonly(blacklist_pda, false),              // blacklist_entry (PDA)
+        AccountMeta::new_readonly(spl_token::id(), false),            // token_program
+    ];
+    
+    let ix_data = build_instruction_data("seize", SeizeArgs { amount })
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    let ix = Instruction {
+        program_id,
+        accounts,
+        data: ix_data,
+    };
+    
+    finish(program, tx, vec![ix], "Seize")?;
+    
+    // Token account layout: mint (32) | owner (32) | amount (u64)
+    match program.rpc().get_account_data(&account_pubkey) {
+        Ok(data) if data.len() >= 72 => {
+            let mut amount_bytes = [0u8; 8];
+            amount_bytes.copy_from_slice(&data[64..72]);
+            println!("   Remaining balance: {}", u64::from_le_bytes(amount_bytes));
+        }
+        _ => println!("   Remaining balance: unavailable"),
+    }
+    Ok(())
+}
+
+// ==================== RESCUE TOKENS ====================
+/// Return `amount` of `mint` held by the stablecoin PDA to `to`.
+/// Tokens of the stablecoin's own mint can't be rescued.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_rescue_tokens(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+    to: &Pubkey,
+    source: Option<&Pubkey>,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    if amount == 0 {
+        return Err(CliError::InvalidArg("Amount must be greater than zero".to_string()));
+    }
+    
+    let program_id = program.id();
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let rpc = program.rpc();
+    let state = decode_account::<StablecoinStateData>(&rpc.get_account_data(&stablecoin_pda)?)?;
+    if *mint == state.asset_mint {
+        return Err(CliError::NotAllowed(
+            "The stablecoin's own mint can't be rescued".to_string()
+        ));
+    }
+    
+    // Stray tokens usually land in the PDA's associated token account
+    let token_program = rpc.get_account(mint)?.owner;
+    let source = source.copied().unwrap_or_else(|| {
+        get_associated_token_address_with_program_id(&stablecoin_pda, mint, &token_program)
+    });
+    
+    println!("🛟 Rescuing {} of mint {} from the stablecoin PDA", amount, mint);
+    println!("   From: {}", source);
+    println!("   To: {}", to);
+    
+    require_balance(program, &source, amount)?;
+    
+    let accounts = vec![
+        AccountMeta::new_readonly(*authority, true),                  // authority (signer)
+        AccountMeta::new_readonly(stablecoin_pda, false),             // state (PDA)
+        AccountMeta::new_readonly(*mint, false),                      // mint
+        AccountMeta::new(source, false),                              // source (token account)
+        AccountMeta::new(*to, false),                                 // destination (token account)
+        AccountMeta::new_readonly(token_program, false),              // token_program
+    ];
+    
+    let ix_data = build_instruction_data("rescue_tokens", RescueTokensArgs { amount })
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    let ix = Instruction {
+        program_id,
+        accounts,
+        data: ix_data,
+    };
+    
+    finish(program, tx, vec![ix], "Rescue")
+}
+
+// ==================== CONFISCATE ====================
+pub fn handle_confiscate(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    account: &str,
+    amount: u64,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    let account_pubkey = parse_pubkey(account)?;
+    
+    println!("🔥 Confiscating and burning {} tokens from {}", amount, account_pubkey);
+    
+    if amount == 0 {
+        return Err(CliError::InvalidArg("Amount must be greater than zero".to_string()));
+    }
+    
+    let program_id = program.id();
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let state = decode_account::<StablecoinStateData>(&program.rpc().get_account_data(&stablecoin_pda)?)?;
+    let token_program = program.rpc().get_account(&state.asset_mint)?.owner;
+    require_balance(program, &account_pubkey, amount)?;
+    
+    // The blacklist entry is keyed by the token account or its owner
+    let from_data = program
+        .rpc()
+        .get_account_data(&account_pubkey)?;
+    if from_data.len() < 64 {
+        return Err(CliError::InvalidArg(format!("{} is not a token account", account_pubkey)));
+    }
+    let from_owner = Pubkey::try_from(&from_data[32..64])
+        .map_err(|e| CliError::InvalidPubkey(e.to_string()))?;
+    let blacklist_pda = holder_blacklist_entry(program, &stablecoin_pda, &account_pubkey, &from_owner);
+    
+    // The Master authority needs no role assignment; the program ID stands in for `None`
+    let role_assignment = if *authority == state.authority {
+        program_id
+    } else {
+        resolve_role_assignment(program, &stablecoin_pda, authority, Role::Seizer)
+    };
+    
+    let accounts = vec![
+        AccountMeta::new(*authority, true),                           // authority (signer, mut)
+        AccountMeta::new(stablecoin_pda, false),                      // state (PDA, mut)
+        AccountMeta::new_readonly(role_assignment, false),            // role_assignment (optional)
+        AccountMeta::new(state.asset_mint, false),                    // asset_mint (mut)
+        AccountMeta::new(account_pubkey, false),                      // from (token account)
+        AccountMeta::new_readonly(blacklist_pda, false),              // blacklist_entry (PDA)
+        AccountMeta::new_readonly(token_program, false),              // token_program
+    ];
+    
+    let ix_data = build_instruction_data("confiscate_burn", ConfiscateBurnArgs { amount })
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    let ix = Instruction {
+        program_id,
+        accounts,
+        data: ix_data,
+    };
+    
+    finish(program, tx, vec![ix], "Confiscate")?;
+    Ok(())
+}
+
+// ==================== TRANSFER AUTHORITY ====================
+pub fn handle_transfer_authority(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    new_authority: &str,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    let new_authority_pubkey = parse_pubkey(new_authority)?;
+    if new_authority_pubkey == Pubkey::default() || new_authority_pubkey == *authority {
+        return Err(CliError::InvalidArg(
+            "New authority must differ from the current one and from the default pubkey".to_string()
+        ));
+    }
+    
+    println!("🔑 Transferring authority to {}", new_authority_pubkey);
+    println!("   Current authority: {}", authority);
+    println!("   ⚠️  WARNING: This action is irreversible!");
+    
+    let program_id = program.id();
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let accounts = vec![
+        AccountMeta::new(*authority, true),                           // authority (signer, mut)
+        AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
+    ];
+    
+    let ix_data = build_instruction_data("transfer_authority", TransferAuthority {
+        new_authority: new_authority_pubkey,
+    }).map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    let ix = Instruction {
+        program_id,
+        accounts,
+        data: ix_data,
+    };
+    
+    finish(program, tx, vec![ix], "Authority transfer")?;
+    Ok(())
+}
+
+// ==================== ASSIGN ROLE ====================
+pub fn handle_assign_role(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    role: Role,
+    account: &str,
+    expires_at: Option<i64>,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    let account_pubkey = parse_pubkey(account)?;
+    
+    println!("👤 Assigning role {:?} to {}", role, account_pubkey);
+    if let Some(expires_at) = expires_at {
+        println!("   Expires at: {}", expires_at);
+    }
+    
+    let program_id = program.id();
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let (role_pda, _) = constants::role_pda(&stablecoin_pda, &account_pubkey, role.seed(), &program_id);
+    
+    let accounts = vec![
+        AccountMeta::new(*authority, true),                           // authority (signer, mut)
+        AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
+        AccountMeta::new(role_pda, false),                            // assignment (PDA)
+        AccountMeta::new_readonly(account_pubkey, false),             // account to assign role
+        AccountMeta::new_readonly(system_program::id(), false),       // system_program
+    ];
+    
+    let ix_data = build_instruction_data("assign_role", AssignRoleArgs {
+        role: role.to_u8(),
+        expires_at,
+    }).map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    let ix = Instruction {
+        program_id,
+        accounts,
+        data: ix_data,
+    };
+    
+    finish(program, tx, vec![ix], "Role assignment")?;
+    Ok(())
+}
+
+// ==================== REVOKE ROLE ====================
+pub fn handle_revoke_role(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    role: Role,
+    account: &str,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    let account_pubkey = parse_pubkey(account)?;
+    
+    println!("🚫 Revoking role {:?} from {}", role, account_pubkey);
+    
+    let program_id = program.id();
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let (role_pda, _) = constants::role_pda(&stablecoin_pda, &account_pubkey, role.seed(), &program_id);
+    
+    let accounts = vec![
+        AccountMeta::new(*authority, true),                           // authority (signer, mut)
+        AccountMeta::new(stablecoin_pda, false),                      // state (PDA)
+        AccountMeta::new(role_pda, false),                            // assignment (PDA)
+        AccountMeta::new_readonly(account_pubkey, false),             // account to revoke role
+    ];
+    
+    let ix_data = build_instruction_data("revoke_role", RevokeRoleArgs {})
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    let ix = Instruction {
+        program_id,
+        accounts,
+        data: ix_data,
+    };
+    
+    finish(program, tx, vec![ix], "Role revocation")?;
+    Ok(())
+}
+
+// ==================== CLEANUP EXPIRED ROLE ====================
+pub fn handle_cleanup_expired_role(
+    program: &Program<Rc<Keypair>>,
+    caller: &Pubkey,
+    role: Role,
+    account: &str,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    let account_pubkey = parse_pubkey(account)?;
+    
+    let program_id = program.id();
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let (role_pda, _) = constants::role_pda(&stablecoin_pda, &account_pubkey, role.seed(), &program_id);
+    let data = program.rpc().get_account_data(&role_pda)
+        .map_err(|_| CliError::AccountNotFound(role_pda.to_string()))?;
+    let assignment = decode_account::<RoleAssignmentData>(&data)?;
+    
+    match assignment.expires_at {
+        Some(expires_at) => println!("🧹 Closing {:?} role of {} (expired at {})", role, account_pubkey, expires_at),
+        None => {
+            return Err(CliError::InvalidArg(format!(
+                "{:?} role of {} has no expiry; revoke it with `sss-token revoke-role` instead",
+                role, account_pubkey
+            )));
+        }
+    }
+    println!("   Rent returns to {}", assignment.assigned_by);
+    
+    let accounts = vec![
+        AccountMeta::new_readonly(*caller, true),                     // caller (signer)
+        AccountMeta::new(stablecoin_pda, false),                      // state (PDA, mut)
+        AccountMeta::new(role_pda, false),                            // assignment (PDA, mut)
+        AccountMeta::new(assignment.assigned_by, false),              // rent_receiver (mut)
+    ];
+    
+    let ix_data = build_instruction_data("cleanup_expired_role", CleanupExpiredRole {})
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    let ix = Instruction {
+        program_id,
+        accounts,
+        data: ix_data,
+    };
+    
+    finish(program, tx, vec![ix], "Expired role cleanup")?;
+    Ok(())
+}
+
+// ==================== STATUS ====================
+pub fn handle_status(
+    program: &Program<Rc<Keypair>>,
+    _authority: &Pubkey,
+    stablecoin: Option<&Pubkey>,
+    export_path: Option<&str>,
+    export_config_path: Option<&str>,
+    watch_interval: Option<std::time::Duration>,
+    output: OutputFormat,
+) -> CliResult<()> {
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    if let Some(interval) = watch_interval {
+        if export_path.is_some() {
+            return Err(CliError::InvalidArg("--export is only supported without --watch".to_string()));
+        }
+        // Runs until interrupted with Ctrl-C
+        for refresh in 1u64.. {
+            if output.is_json() {
+                // One compact JSON object per refresh, suitable for line-based consumers
+                if let Some(state) = fetch_status(program, &stablecoin_pda, output) {
+                    println!("{}", serde_json::to_string(&status_json(&stablecoin_pda, &state)?)?);
+                }
+            } else {
+                // Clear the screen and move the cursor home
+                print!("\x1B[2J\x1B[H");
+                println!("📊 Stablecoin Status (refresh #{} every {}s, Ctrl-C to exit)", refresh, interval.as_secs());
+                println!("   Stablecoin PDA: {}", stablecoin_pda);
+                if let Some(state) = fetch_status(program, &stablecoin_pda, output) {
+                    render_status_panel(&state, mint_decimals_or_raw(program, &state.asset_mint));
+                }
+            }
+            std::io::Write::flush(&mut std::io::stdout())?;
+            std::thread::sleep(interval);
+        }
+        return Ok(());
+    }
+    
+    human!(output, "📊 Stablecoin Status");
+    human!(output, "   Stablecoin PDA: {}", stablecoin_pda);
+    
+    let Some(state) = fetch_status(program, &stablecoin_pda, output) else {
+        if output.is_json() {
+            return Err(CliError::AccountNotFound(stablecoin_pda.to_string()));
+        }
+        return Ok(());
+    };
+    
+    let json = status_json(&stablecoin_pda, &state)?;
+    if output.is_json() {
+        print_json(&json)?;
+    } else {
+        render_status_panel(&state, mint_decimals_or_raw(program, &state.asset_mint));
+    }
+    
+    if let Some(path) = export_path {
+        std::fs::write(path, serde_json::to_string_pretty(&json)?)
+            .map_err(|e| CliError::IoError(e.to_string()))?;
+        human!(output, "\n💾 Status exported to {}", path);
+    }
+    
+    if let Some(path) = export_config_path {
+        let config = fetch_deployment_config(program, &stablecoin_pda, &state)?;
+        config.save(path)?;
+        human!(
+            output,
+            "\n💾 Deployment config exported to {} ({} minters, {} roles)",
+            path,
+            config.minters.len(),
+            config.roles.len(),
+        );
+    }
+    
+    Ok(())
+}
+
+/// Fetch and decode the stablecoin state.
+/// Fetch and decode failures are reported inline and return `None`.
+fn fetch_status(
+    program: &Program<Rc<Keypair>>,
+    stablecoin_pda: &Pubkey,
+    output: OutputFormat,
+) -> Option<StablecoinStateData> {
+    match program.rpc().get_account_data(stablecoin_pda) {
+        Ok(data) if data.len() > 8 => match decode_account::<StablecoinStateData>(&data) {
+            Ok(state) => Some(state),
+            Err(e) => {
+                human!(output, "❌ Failed to parse state: {}", e);
+                None
+            }
+        },
+        Ok(_) => {
+            human!(output, "❌ Account data too short");
+            None
+        }
+        Err(e) => {
+            human!(output, "❌ Failed to fetch state: {}", e);
+            human!(output, "   The stablecoin may not be initialized yet.");
+            None
+        }
+    }
+}
+
+/// Decimals of a mint, read straight from the mint account
+fn fetch_mint_decimals(program: &Program<Rc<Keypair>>, mint: &Pubkey) -> CliResult<u8> {
+    let mint_data = program.rpc().get_account_data(mint)?;
+    mint_data.get(MINT_DECIMALS_OFFSET)
+        .copied()
+        .ok_or_else(|| CliError::SerializationError("Asset mint data too short".to_string()))
+}
+
+/// Decimals to display a mint's amounts with; 0 (raw base units) when the mint can't be read
+fn mint_decimals_or_raw(program: &Program<Rc<Keypair>>, mint: &Pubkey) -> u8 {
+    fetch_mint_decimals(program, mint).unwrap_or(0)
+}
+
+/// JSON view of the stablecoin state, shared by `--output json` and `--export`
+fn status_json(stablecoin_pda: &Pubkey, state: &StablecoinStateData) -> CliResult<serde_json::Value> {
+    let mut json = serde_json::to_value(state)?;
+    json["stablecoin_pda"] = serde_json::Value::String(stablecoin_pda.to_string());
+    Ok(json)
+}
+
+fn render_status_panel(state: &StablecoinStateData, decimals: u8) {
+    let amount = |raw: u64| format_token_amount(raw, decimals);
+    println!("\n┌─────────────────────────────────────────┐");
+    println!("│ STABLECOIN STATE                        │");
+    println!("├─────────────────────────────────────────┤");
+    println!("│ Authority:    {:<25}│", state.authority);
+    println!("│ Asset Mint:   {:<25}│", state.asset_mint);
+    println!("│ Total Supply: {:<25}│", amount(state.total_supply));
+    println!("│ Paused:       {:<25}│", if state.paused { "YES" } else { "NO" });
+    if state.paused {
+        println!("│ Paused By:    {:<25}│", state.paused_by);
+        println!("│ Paused At:    {:<25}│", state.paused_at);
+        if !state.pause_reason.as_str().is_empty() {
+            println!("│ Reason:       {:<25}│", state.pause_reason.as_str());
+        }
+        if state.pause_until != constants::NO_PAUSE_EXPIRY {
+            println!("│ Lifts At:     {:<25}│", state.pause_until);
+        }
+    }
+    println!("│ Preset:       SSS-{:<22}│", state.preset);
+    println!("│ Compliance:   {:<25}│", if state.compliance_enabled { "ENABLED" } else { "DISABLED" });
+    let max_transfer = if state.max_transfer_amount == 0 {
+        "UNLIMITED".to_string()
+    } else {
+        amount(state.max_transfer_amount)
+    };
+    println!("│ Max Transfer: {:<25}│", max_transfer);
+    let max_supply = if state.max_supply == 0 {
+        "UNCAPPED".to_string()
+    } else {
+        amount(state.max_supply)
+    };
+    println!("│ Max Supply:   {:<25}│", max_supply);
+    let minimum = |raw: u64| if raw == 0 { "NONE".to_string() } else { amount(raw) };
+    println!("│ Min Mint:     {:<25}│", minimum(state.min_mint_amount));
+    println!("│ Min Burn:     {:<25}│", minimum(state.min_burn_amount));
+    println!("│ Reserves:     {:<25}│", if state.reserve_backed { "ATTESTED" } else { "NOT REQUIRED" });
+    println!("│ Allowlist:    {:<25}│", if state.allowlist_mode { "ENFORCED" } else { "OFF" });
+    println!("│ Fee:          {:<25}│", format!("{} bps", state.fee_bps));
+    println!("│ Minters:      {:<25}│", state.minter_count);
+    println!("│ Roles:        {:<25}│", state.role_count);
+    println!("│ Blacklisted:  {:<25}│", state.blacklist_count);
+    println!("│ Bump:         {:<25}│", state.bump);
+    println!("└─────────────────────────────────────────┘");
+}
+
+#[derive(Debug, ::borsh::BorshDeserialize, serde::Serialize)]
+struct StablecoinStateData {
+    #[serde(serialize_with = "serialize_pubkey")]
+    authority: Pubkey,
+    #[serde(serialize_with = "serialize_pubkey")]
+    asset_mint: Pubkey,
+    total_supply: u64,
+    paused: bool,
+    preset: u8,
+    compliance_enabled: bool,
+    bump: u8,
+    max_transfer_amount: u64,
+    fee_bps: u16,
+    #[serde(serialize_with = "serialize_pubkey")]
+    fee_treasury: Pubkey,
+    minter_count: u32,
+    blacklist_count: u32,
+    max_supply: u64,
+    min_burn_amount: u64,
+    min_mint_amount: u64,
+    reserve_backed: bool,
+    allowlist_mode: bool,
+    #[serde(serialize_with = "serialize_pubkey")]
+    paused_by: Pubkey,
+    paused_at: i64,
+    pause_until: i64,
+    pause_reason: PauseReason,
+    role_count: u32,
+    minter_count_verified: bool,
+    role_count_verified: bool,
+}
+
+/// `pause_reason_len` followed by the zero-padded `pause_reason` buffer;
+/// serialized as the reason text
+#[derive(Debug, ::borsh::BorshDeserialize)]
+struct PauseReason {
+    len: u8,
+    bytes: [u8; constants::MAX_REASON_LENGTH],
+}
+
+impl PauseReason {
+    fn as_str(&self) -> &str {
+        let len = usize::from(self.len).min(self.bytes.len());
+        std::str::from_utf8(&self.bytes[..len]).unwrap_or_default()
+    }
+}
+
+impl serde::Serialize for PauseReason {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+// ==================== DEPLOYMENT CONFIG ====================
+/// Mint decimals offset, shared by SPL Token and Token-2022 mints
+const MINT_DECIMALS_OFFSET: usize = 44;
+
+/// Portable description of a deployment, written by `status --export-config`
+/// as TOML, or JSON when the path ends in `.json`
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DeploymentConfig {
+    pub program_id: String,
+    pub stablecoin_pda: String,
+    pub asset_mint: String,
+    pub authority: String,
+    pub decimals: u8,
+    pub preset: u8,
+    pub compliance_enabled: bool,
+    pub paused: bool,
+    /// Supply when exported; informational only
+    pub total_supply: u64,
+    pub max_supply: u64,
+    pub max_transfer_amount: u64,
+    pub fee_bps: u16,
+    pub fee_treasury: String,
+    pub blacklist_count: u32,
+    #[serde(default)]
+    pub minters: Vec<MinterConfig>,
+    #[serde(default)]
+    pub roles: Vec<RoleConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MinterConfig {
+    pub minter: String,
+    /// 0 means unlimited
+    pub quota: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RoleConfig {
+    pub account: String,
+    pub role: String,
+}
+
+impl DeploymentConfig {
+    fn is_json_path(path: &str) -> bool {
+        path.to_ascii_lowercase().ends_with(".json")
+    }
+    
+    pub fn to_string_for(&self, path: &str) -> CliResult<String> {
+        if Self::is_json_path(path) {
+            Ok(serde_json::to_string_pretty(self)?)
+        } else {
+            toml::to_string_pretty(self).map_err(|e| CliError::SerializationError(e.to_string()))
+        }
+    }
+    
+    pub fn save(&self, path: &str) -> CliResult<()> {
+        std::fs::write(path, self.to_string_for(path)?).map_err(|e| CliError::IoError(e.to_string()))
+    }
+    
+    pub fn load(path: &str) -> CliResult<Self> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| CliError::IoError(format!("{}: {}", path, e)))?;
+        if Self::is_json_path(path) {
+            Ok(serde_json::from_str(&contents)?)
+        } else {
+            toml::from_str(&contents).map_err(|e| CliError::InvalidConfig(e.to_string()))
+        }
+    }
+}
+
+/// One difference between an expected and a live deployment
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct Drift {
+    pub field: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// Compare a live deployment against the expected one.
+/// Supply and blacklist count change in normal operation and are not compared.
+pub fn diff_deployment(expected: &DeploymentConfig, actual: &DeploymentConfig) -> Vec<Drift> {
+    let mut drift = Vec::new();
+    let mut check = |field: &str, expected: String, actual: String| {
+        if expected != actual {
+            drift.push(Drift { field: field.to_string(), expected, actual });
+        }
+    };
+    
+    check("program_id", expected.program_id.clone(), actual.program_id.clone());
+    check("stablecoin_pda", expected.stablecoin_pda.clone(), actual.stablecoin_pda.clone());
+    check("asset_mint", expected.asset_mint.clone(), actual.asset_mint.clone());
+    check("authority", expected.authority.clone(), actual.authority.clone());
+    check("decimals", expected.decimals.to_string(), actual.decimals.to_string());
+    check("preset", expected.preset.to_string(), actual.preset.to_string());
+    check("compliance_enabled", expected.compliance_enabled.to_string(), actual.compliance_enabled.to_string());
+    check("paused", expected.paused.to_string(), actual.paused.to_string());
+    check("max_supply", expected.max_supply.to_string(), actual.max_supply.to_string());
+    check("max_transfer_amount", expected.max_transfer_amount.to_string(), actual.max_transfer_amount.to_string());
+    check("fee_bps", expected.fee_bps.to_string(), actual.fee_bps.to_string());
+    check("fee_treasury", expected.fee_treasury.clone(), actual.fee_treasury.clone());
+    
+    let absent = || "absent".to_string();
+    let quota = |minter: Option<&MinterConfig>| minter.map_or_else(absent, |m| format!("quota {}", m.quota));
+    let role = |assignment: Option<&RoleConfig>| assignment.map_or_else(absent, |r| r.role.clone());
+    
+    let mut minters: Vec<&str> = expected.minters.iter().chain(&actual.minters).map(|m| m.minter.as_str()).collect();
+    minters.sort_unstable();
+    minters.dedup();
+    for minter in minters {
+        check(
+            &format!("minters.{}", minter),
+            quota(expected.minters.iter().find(|m| m.minter == minter)),
+            quota(actual.minters.iter().find(|m| m.minter == minter)),
+        );
+    }
+    
+    let mut accounts: Vec<&str> = expected.roles.iter().chain(&actual.roles).map(|r| r.account.as_str()).collect();
+    accounts.sort_unstable();
+    accounts.dedup();
+    for account in accounts {
+        check(
+            &format!("roles.{}", account),
+            role(expected.roles.iter().find(|r| r.account == account)),
+            role(actual.roles.iter().find(|r| r.account == account)),
+        );
+    }
+    
+    drift
+}
+
+/// Check a live deployment against a config written by `status --export-config`.
+/// Fails with `DriftDetected` when anything differs.
+pub fn handle_verify(
+    program: &Program<Rc<Keypair>>,
+    config_path: &str,
+    output: OutputFormat,
+) -> CliResult<()> {
+    let expected = DeploymentConfig::load(config_path)?;
+    let stablecoin_pda = parse_pubkey(&expected.stablecoin_pda)?;
+    
+    human!(output, "🔍 Verifying {} against {}", stablecoin_pda, config_path);
+    
+    let data = program.rpc().get_account_data(&stablecoin_pda)
+        .map_err(|_| CliError::AccountNotFound(stablecoin_pda.to_string()))?;
+    let state = decode_account::<StablecoinStateData>(&data)?;
+    let actual = fetch_deployment_config(program, &stablecoin_pda, &state)?;
+    let drift = diff_deployment(&expected, &actual);
+    
+    if output.is_json() {
+        print_json(&serde_json::json!({
+            "config": config_path,
+            "stablecoin_pda": stablecoin_pda.to_string(),
+            "in_sync": drift.is_empty(),
+            "drift": &drift,
+        }))?;
+    } else if drift.is_empty() {
+        println!("✅ Deployment matches the config");
+    } else {
+        println!("❌ {} difference(s):", drift.len());
+        for d in &drift {
+            println!("   {}", d.field);
+            println!("     - expected: {}", d.expected);
+            println!("     + actual:   {}", d.actual);
+        }
+    }
+    
+    if drift.is_empty() {
+        Ok(())
+    } else {
+        Err(CliError::DriftDetected(drift.len()))
+    }
+}
+
+/// Gather the state, mint, minters and roles of a stablecoin into one descriptor
+fn fetch_deployment_config(
+    program: &Program<Rc<Keypair>>,
+    stablecoin_pda: &Pubkey,
+    state: &StablecoinStateData,
+) -> CliResult<DeploymentConfig> {
+    let program_id = program.id();
+    
+    let decimals = fetch_mint_decimals(program, &state.asset_mint)?;
+    
+    // Minters and roles don't store their stablecoin, so match on the derived PDA
+    let mut minters: Vec<MinterConfig> = fetch_program_accounts::<MinterInfoData>(program, "MinterInfo")?
+        .into_iter()
+        .filter(|(pubkey, info)| constants::minter_pda(stablecoin_pda, &info.minter, &program_id).0 == *pubkey)
+        .map(|(_, info)| MinterConfig { minter: info.minter.to_string(), quota: info.quota })
+        .collect();
+    minters.sort_by(|a, b| a.minter.cmp(&b.minter));
+    
+    let mut roles: Vec<RoleConfig> = fetch_program_accounts::<RoleAssignmentData>(program, "RoleAssignment")?
+        .into_iter()
+        .filter(|(pubkey, assignment)| {
+            Role::from_u8(assignment.role).is_some_and(|role| {
+                constants::role_pda(stablecoin_pda, &assignment.account, role.seed(), &program_id).0 == *pubkey
+            })
+        })
+        .map(|(_, assignment)| RoleConfig {
+            account: assignment.account.to_string(),
+            role: Role::from_u8(assignment.role)
+                .map(|role| format!("{:?}", role))
+                .unwrap_or_else(|| format!("Unknown({})", assignment.role)),
+        })
+        .collect();
+    roles.sort_by(|a, b| a.account.cmp(&b.account));
+    
+    Ok(DeploymentConfig {
+        program_id: program_id.to_string(),
+        stablecoin_pda: stablecoin_pda.to_string(),
+        asset_mint: state.asset_mint.to_string(),
+        authority: state.authority.to_string(),
+        decimals,
+        preset: state.preset,
+        compliance_enabled: state.compliance_enabled,
+        paused: state.paused,
+        total_supply: state.total_supply,
+        max_supply: state.max_supply,
+        max_transfer_amount: state.max_transfer_amount,
+        fee_bps: state.fee_bps,
+        fee_treasury: state.fee_treasury.to_string(),
+        blacklist_count: state.blacklist_count,
+        minters,
+        roles,
+    })
+}
+
+// ==================== SETUP HOOK ====================
+/// Create the ExtraAccountMetaList the transfer hook needs, or rewrite an existing
+/// one that doesn't match the accounts the hook reads
+pub fn handle_setup_hook(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    stablecoin: Option<&Pubkey>,
+    tx: &TxOptions,
+) -> CliResult<()> {
+    let program_id = program.id();
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    let data = program.rpc().get_account_data(&stablecoin_pda)
+        .map_err(|_| CliError::AccountNotFound(stablecoin_pda.to_string()))?;
+    let state = decode_account::<StablecoinStateData>(&data)?;
+    let (meta_list, _) = constants::extra_account_metas_pda(&state.asset_mint, &program_id);
+    
+    let existing = program.rpc().get_account(&meta_list).ok();
+    let ix_data = match &existing {
+        Some(account) if parse_extra_account_metas(&account.data).ok()
+            == Some(expected_extra_account_metas(&stablecoin_pda)) =>
+        {
+            println!("✅ ExtraAccountMetaList {} is up to date", meta_list);
+            return Ok(());
+        }
+        Some(_) => {
+            println!("🪝 Updating outdated ExtraAccountMetaList {} for mint {}", meta_list, state.asset_mint);
+            build_instruction_data("update_extra_account_metas", UpdateExtraAccountMetas {})
+        }
+        None => {
+            println!("🪝 Creating ExtraAccountMetaList {} for mint {}", meta_list, state.asset_mint);
+            build_instruction_data("initialize_extra_account_metas", InitializeExtraAccountMetas {})
+        }
+    }
+    .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    
+    let accounts = vec![
+        AccountMeta::new(*authority, true),                           // authority (signer, mut)
+        AccountMeta::new_readonly(stablecoin_pda, false),             // state (PDA)
+        AccountMeta::new_readonly(state.asset_mint, false),           // asset_mint
+        AccountMeta::new(meta_list, false),                           // extra_account_meta_list (PDA)
+        AccountMeta::new_readonly(system_program::id(), false),       // system_program
+    ];
+    
+    let ix = Instruction {
+        program_id,
+        accounts,
+        data: ix_data,
+    };
+    
+    finish(program, tx, vec![ix], "Setup hook")?;
+    Ok(())
+}
+
+// ==================== VERIFY HOOK ====================
+/// Transfer hook accounts before the extras: source, mint, destination, owner, meta list
+const HOOK_SOURCE_INDEX: u8 = 0;
+const HOOK_DESTINATION_INDEX: u8 = 2;
+/// Index of the stablecoin state, the first extra account
+const HOOK_STATE_INDEX: u8 = 5;
+
+/// One `ExtraAccountMeta` of an ExtraAccountMetaList (35 bytes on chain)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtraAccountMetaEntry {
+    /// 0 = fixed address, 1 = PDA of the hook program
+    pub discriminator: u8,
+    pub address_config: [u8; 32],
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl ExtraAccountMetaEntry {
+    const LEN: usize = 35;
+
+    fn fixed(address: &Pubkey) -> Self {
+        Self { discriminator: 0, address_config: address.to_bytes(), is_signer: false, is_writable: false }
+    }
+
+    /// Blacklist or allowlist PDA of the token account at `token_account_index`:
+    /// seeds `[seed, state, token_account]`
+    fn entry(seed: &[u8], token_account_index: u8) -> Self {
+        let mut config = Vec::with_capacity(32);
+        config.extend_from_slice(&[1, seed.len() as u8]);
+        config.extend_from_slice(seed);
+        config.extend_from_slice(&[3, HOOK_STATE_INDEX, 3, token_account_index]);
+        let mut address_config = [0u8; 32];
+        address_config[..config.len()].copy_from_slice(&config);
+        Self { discriminator: 1, address_config, is_signer: false, is_writable: false }
+    }
+
+    /// Blacklist PDA of the wallet owning the token account at `token_account_index`:
+    /// seeds `[BLACKLIST_SEED, state, token_account.owner]`, the owner read from
+    /// bytes 32..64 of the token account
+    fn owner_entry(token_account_index: u8) -> Self {
+        let mut config = Vec::with_capacity(32);
+        config.extend_from_slice(&[1, BLACKLIST_SEED.len() as u8]);
+        config.extend_from_slice(BLACKLIST_SEED);
+        config.extend_from_slice(&[3, HOOK_STATE_INDEX, 4, token_account_index, 32, 32]);
+        let mut address_config = [0u8; 32];
+        address_config[..config.len()].copy_from_slice(&config);
+        Self { discriminator: 1, address_config, is_signer: false, is_writable: false }
+    }
+
+    fn describe(&self) -> String {
+        let address = match self.discriminator {
+            0 => Pubkey::new_from_array(self.address_config).to_string(),
+            1 => format!("PDA [{}]", describe_seeds(&self.address_config)),
+            d => format!("unsupported discriminator {}", d),
+        };
+        format!("{} (signer: {}, writable: {})", address, self.is_signer, self.is_writable)
+    }
+}
+
+/// Render packed PDA seeds: literals as strings, account keys by index
+fn describe_seeds(config: &[u8; 32]) -> String {
+    let mut seeds = Vec::new();
+    let mut rest = &config[..];
+    while let [kind, tail @ ..] = rest {
+        match (kind, tail) {
+            (1, [len, bytes @ ..]) if bytes.len() >= *len as usize => {
+                let (literal, tail) = bytes.split_at(*len as usize);
+                seeds.push(format!("\"{}\"", String::from_utf8_lossy(literal)));
+                rest = tail;
+            }
+            (3, [index, tail @ ..]) => {
+                seeds.push(format!("account {}", index));
+                rest = tail;
+            }
+            (4, [index, offset, len, tail @ ..]) => {
+                seeds.push(format!("account {} data [{}..{}]", index, offset, *offset as usize + *len as usize));
+                rest = tail;
+            }
+            (0, _) => break,
+            (kind, _) => {
+                seeds.push(format!("seed type {}", kind));
+                break;
+            }
+        }
+    }
+    seeds.join(", ")
+}
+
+/// Extra accounts `execute_transfer_hook` expects after the interface accounts:
+/// the stablecoin state, the blacklist PDAs of the source and destination,
+/// their allowlist PDAs, then the blacklist PDAs of their owners
+pub fn expected_extra_account_metas(stablecoin_pda: &Pubkey) -> Vec<ExtraAccountMetaEntry> {
+    vec![
+        ExtraAccountMetaEntry::fixed(stablecoin_pda),
+        ExtraAccountMetaEntry::entry(BLACKLIST_SEED, HOOK_SOURCE_INDEX),
+        ExtraAccountMetaEntry::entry(BLACKLIST_SEED, HOOK_DESTINATION_INDEX),
+        ExtraAccountMetaEntry::entry(ALLOWLIST_SEED, HOOK_SOURCE_INDEX),
+        ExtraAccountMetaEntry::entry(ALLOWLIST_SEED, HOOK_DESTINATION_INDEX),
+        ExtraAccountMetaEntry::owner_entry(HOOK_SOURCE_INDEX),
+        ExtraAccountMetaEntry::owner_entry(HOOK_DESTINATION_INDEX),
+    ]
+}
+
+/// TLV type of the hook's Execute entry: sha256("spl-transfer-hook-interface:execute")[0..8]
+fn execute_discriminator() -> [u8; 8] {
+    use sha2::{Digest, Sha256};
+    let hash = Sha256::digest(b"spl-transfer-hook-interface:execute");
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash[..8]);
+    discriminator
+}
+
+/// Read the metas of the Execute entry of an ExtraAccountMetaList account.
+/// The account is a TLV list; the entry value is a u32 count followed by the metas.
+pub fn parse_extra_account_metas(data: &[u8]) -> CliResult<Vec<ExtraAccountMetaEntry>> {
+    let malformed = |what: &str| CliError::SerializationError(format!("ExtraAccountMetaList: {}", what));
+    let execute = execute_discriminator();
+
+    let mut offset = 0;
+    let value = loop {
+        let header = data.get(offset..offset + 12).ok_or_else(|| malformed("no Execute entry"))?;
+        let length = u32::from_le_bytes(header[8..12].try_into().unwrap()) as usize;
+        let value = data.get(offset + 12..offset + 12 + length).ok_or_else(|| malformed("truncated entry"))?;
+        if header[..8] == execute {
+            break value;
+        }
+        offset += 12 + length;
+    };
+
+    let count = value.get(..4)
+        .map(|count| u32::from_le_bytes(count.try_into().unwrap()) as usize)
+        .ok_or_else(|| malformed("missing meta count"))?;
+    let metas = value[4..].chunks_exact(ExtraAccountMetaEntry::LEN).take(count);
+    if metas.len() < count {
+        return Err(malformed("truncated metas"));
+    }
+    Ok(metas
+        .map(|meta| ExtraAccountMetaEntry {
+            discriminator: meta[0],
+            address_config: meta[1..33].try_into().unwrap(),
+            is_signer: meta[33] != 0,
+            is_writable: meta[34] != 0,
+        })
+        .collect())
+}
+
+/// Check that the mint's TransferHook extension points at this program and that
+/// the ExtraAccountMetaList carries the accounts the hook reads.
+/// Fails with `HookMisconfigured` listing each problem.
+pub fn handle_verify_hook(
+    program: &Program<Rc<Keypair>>,
+    stablecoin_pda: &Pubkey,
+    output: OutputFormat,
+) -> CliResult<()> {
+    use spl_token_2022::extension::{
+        transfer_hook::TransferHook, BaseStateWithExtensions, StateWithExtensions,
+    };
+
+    let program_id = program.id();
+    let data = program.rpc().get_account_data(stablecoin_pda)
+        .map_err(|_| CliError::AccountNotFound(stablecoin_pda.to_string()))?;
+    let state = decode_account::<StablecoinStateData>(&data)?;
+    let (meta_list, _) = constants::extra_account_metas_pda(&state.asset_mint, &program_id);
+
+    human!(output, "🔍 Verifying transfer hook of {}", state.asset_mint);
+
+    let mut problems = Vec::new();
+    let mut problem = |field: &str, expected: String, actual: String| {
+        problems.push(Drift { field: field.to_string(), expected, actual });
+    };
+
+    let mint = program.rpc().get_account(&state.asset_mint)?;
+    let hook_program = if mint.owner != spl_token_2022::id() {
+        problem("mint.owner", spl_token_2022::id().to_string(), mint.owner.to_string());
+        None
+    } else {
+        let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint.data)
+            .map_err(|e| CliError::SerializationError(e.to_string()))?;
+        match mint_state.get_extension::<TransferHook>() {
+            Ok(hook) => Option::<Pubkey>::from(hook.program_id),
+            Err(_) => {
+                problem("mint.transfer_hook", "TransferHook extension".to_string(), "absent".to_string());
+                None
+            }
+        }
+    };
+    match hook_program {
+        Some(hook_program) if hook_program == program_id => {}
+        Some(hook_program) => problem("mint.transfer_hook.program_id", program_id.to_string(), hook_program.to_string()),
+        None => problem("mint.transfer_hook.program_id", program_id.to_string(), "unset".to_string()),
+    }
+
+    let expected = expected_extra_account_metas(stablecoin_pda);
+    match program.rpc().get_account(&meta_list) {
+        Ok(account) if account.owner != program_id => {
+            problem("extra_account_metas.owner", program_id.to_string(), account.owner.to_string());
+        }
+        Ok(account) => match parse_extra_account_metas(&account.data) {
+            Ok(actual) => {
+                for i in 0..expected.len().max(actual.len()) {
+                    let describe = |meta: Option<&ExtraAccountMetaEntry>| meta.map_or_else(|| "absent".to_string(), |m| m.describe());
+                    if expected.get(i) != actual.get(i) {
+                        problem(&format!("extra_account_metas[{}]", i), describe(expected.get(i)), describe(actual.get(i)));
+                    }
+                }
+            }
+            Err(e) => problem("extra_account_metas", "Execute entry".to_string(), e.to_string()),
+        },
+        Err(_) => problem("extra_account_metas", format!("initialized at {}", meta_list), "missing".to_string()),
+    }
+
+    if output.is_json() {
+        print_json(&serde_json::json!({
+            "stablecoin_pda": stablecoin_pda.to_string(),
+            "asset_mint": state.asset_mint.to_string(),
+            "hook_program": hook_program.map(|p| p.to_string()),
+            "extra_account_metas": meta_list.to_string(),
+            "ok": problems.is_empty(),
+            "problems": &problems,
+        }))?;
+    } else if problems.is_empty() {
+        println!("✅ Transfer hook points at {}", program_id);
+        println!("✅ ExtraAccountMetaList {} lists the expected accounts", meta_list);
+    } else {
+        println!("❌ {} problem(s):", problems.len());
+        for p in &problems {
+            println!("   {}", p.field);
+            println!("     - expected: {}", p.expected);
+            println!("     + actual:   {}", p.actual);
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(CliError::HookMisconfigured(problems.len()))
+    }
+}
+
+// ==================== SUPPLY ====================
+pub fn handle_supply(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    stablecoin: Option<&Pubkey>,
+    output: OutputFormat,
+) -> CliResult<()> {
+    let program_id = program.id();
+    let stablecoin_pda = match stablecoin {
+        Some(s) => *s,
+        None => {
+            return Err(CliError::InvalidArg(
+                "Stablecoin PDA is required. Use --stablecoin <address>".to_string()
+            ));
+        }
+    };
+    
+    if output.is_json() {
+        let data = program.rpc().get_account_data(&stablecoin_pda)?;
+        let state = decode_account::<StablecoinStateData>(&data)?;
+        return print_json(&serde_json::json!({
+            "stablecoin_pda": stablecoin_pda.to_string(),
+            "total_supply": state.total_supply,
+        }));
+    }
+    
+    match program.rpc().get_account_data(&stablecoin_pda) {
+        Ok(data) if data.len() > 8 => {
+            match decode_account::<StablecoinStateData>(&data) {
+                Ok(state) => {
+                    let decimals = mint_decimals_or_raw(program, &state.asset_mint);
+                    println!("💰 Total Supply: {} tokens", format_token_amount(state.total_supply, decimals));
+                }
+                Err(_) => {
+                    println!("❌ Could not parse supply data.");
+                }
+            }
+        }
+        _ => {
+            println!("❌ Could not fetch supply. Stablecoin may not be initialized.");
+        }
+    }
+    
+    Ok(())
+}
+
+// ==================== WHOAMI ====================
+/// Print the configured keypair and, for a stablecoin, whether it is the
+/// Master authority and which role assignments it holds
+pub fn handle_whoami(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    stablecoin: Option<&Pubkey>,
+    output: OutputFormat,
+) -> CliResult<()> {
+    let Some(stablecoin_pda) = stablecoin.copied() else {
+        if output.is_json() {
+            return print_json(&serde_json::json!({ "pubkey": authority.to_string() }));
+        }
+        println!("🔑 Pubkey: {}", authority);
+        println!("   Pass --stablecoin <address> to list its roles");
+        return Ok(());
+    };
+    
+    let rpc = program.rpc();
+    let data = rpc.get_account_data(&stablecoin_pda)
+        .map_err(|_| CliError::AccountNotFound(stablecoin_pda.to_string()))?;
+    let state = decode_account::<StablecoinStateData>(&data)?;
+    let is_authority = state.authority == *authority;
+    
+    let program_id = program.id();
+    let role_pdas: Vec<Pubkey> = Role::ALL
+        .iter()
+        .map(|role| constants::role_pda(&stablecoin_pda, authority, role.seed(), &program_id).0)
+        .collect();
+    let mut roles = Vec::new();
+    for (role, account) in Role::ALL.into_iter().zip(rpc.get_multiple_accounts(&role_pdas)?) {
+        if let Some(account) = account {
+            roles.push((role, decode_account::<RoleAssignmentData>(&account.data)?));
+        }
+    }
+    
+    // Expired assignments still exist until cleaned up, but the program rejects them
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or_default();
+    let is_expired = |assignment: &RoleAssignmentData| assignment.expires_at.is_some_and(|at| at <= now);
+    
+    if output.is_json() {
+        let roles: Vec<serde_json::Value> = roles
+            .iter()
+            .map(|(role, assignment)| serde_json::json!({
+                "role": format!("{:?}", role),
+                "expires_at": assignment.expires_at,
+                "expired": is_expired(assignment),
+            }))
+            .collect();
+        return print_json(&serde_json::json!({
+            "pubkey": authority.to_string(),
+            "stablecoin_pda": stablecoin_pda.to_string(),
+            "is_authority": is_authority,
+            "roles": roles,
+        }));
+    }
+    
+    println!("🔑 Pubkey: {}", authority);
+    println!("   Stablecoin PDA: {}", stablecoin_pda);
+    println!("   Master authority: {}", if is_authority { "YES" } else { "NO" });
+    if roles.is_empty() {
+        println!("   Roles: none");
+    } else {
+        println!("   Roles:");
+        for (role, assignment) in &roles {
+            match assignment.expires_at {
+                Some(expires_at) if is_expired(assignment) => println!("     - {:?} (expired at {})", role, expires_at),
+                Some(expires_at) => println!("     - {:?} (expires at {})", role, expires_at),
+                None => println!("     - {:?}", role),
+            }
+        }
+    }
+    
+    Ok(())
+}
+
+// ==================== HOLDERS ====================
+/// Base token account size; Token-2022 accounts with extensions are larger
+const TOKEN_ACCOUNT_BASE_LEN: usize = 165;
+/// Token-2022 `AccountType::Account` marker stored right after the base layout
+const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+/// Above this many accounts, public RPCs commonly time out or truncate
+const HOLDERS_LARGE_RESULT_WARNING: usize = 10_000;
+
+/// `getProgramAccounts` filter sets that together select every token account of `mint`.
+///
+/// SPL Token accounts are always 165 bytes. Token-2022 accounts are 165 bytes
+/// without extensions, or longer with the account type byte at offset 165, so
+/// they take two scans. Associated token accounts under Token-2022 always carry
+/// the `ImmutableOwner` extension, so the second scan can't be skipped even when
+/// the mint itself has no extensions.
+pub fn holder_filters(
+    token_program: &Pubkey,
+    mint: &Pubkey,
+) -> CliResult<Vec<Vec<solana_client::rpc_filter::RpcFilterType>>> {
+    use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+    
+    // Token account layout: mint (32) | owner (32) | amount (u64) | ...
+    let mint_filter = RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, mint.to_bytes().to_vec()));
+    let base_size = RpcFilterType::DataSize(TOKEN_ACCOUNT_BASE_LEN as u64);
+    
+    if *token_program == spl_token::id() {
+        Ok(vec![vec![mint_filter, base_size]])
+    } else if *token_program == spl_token_2022::id() {
+        let extended = RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            TOKEN_ACCOUNT_BASE_LEN,
+            vec![ACCOUNT_TYPE_ACCOUNT],
+        ));
+        Ok(vec![vec![mint_filter.clone(), base_size], vec![mint_filter, extended]])
+    } else {
+        Err(CliError::InvalidArg(format!(
+            "Mint {} is owned by {}, which is not a token program",
+            mint, token_program
+        )))
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct Holder {
+    token_account: String,
+    owner: String,
+    amount: u64,
+}
+
+pub fn handle_holders(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    min_balance: u64,
+    limit: Option<usize>,
+    format: &str,
+    stablecoin: Option<&Pubkey>,
+) -> CliResult<()> {
+    use solana_client::rpc_config::RpcProgramAccountsConfig;
+    
+    let json_output = match format {
+        "json" => true,
+        "text" => false,
+        other => return Err(CliError::InvalidArg(format!("Unknown format '{}' (expected text or json)", other))),
+    };
+    
+    let program_id = program.id();
+    let stablecoin_pda = stablecoin
+        .copied()
+        .unwrap_or_else(|| constants::stablecoin_pda(authority, &program_id).0);
+    
+    let rpc = program.rpc();
+    let state = decode_account::<StablecoinStateData>(&rpc.get_account_data(&stablecoin_pda)?)?;
+    let mint_account = rpc.get_account(&state.asset_mint)?;
+    let decimals = mint_account.data.get(MINT_DECIMALS_OFFSET).copied().unwrap_or(0);
+    let token_program = mint_account.owner;
+    
+    if !json_output {
+        println!("👥 Token Holders (min balance: {})", min_balance);
+        println!("   Stablecoin: {}", stablecoin_pda);
+        println!("   Mint:       {}", state.asset_mint);
+    }
+    
+    // The filter sets select disjoint accounts, so the results need no deduplication
+    let mut accounts = Vec::new();
+    for filters in holder_filters(&token_program, &state.asset_mint)? {
+        accounts.extend(rpc.get_program_accounts_with_config(
+            &token_program,
+            RpcProgramAccountsConfig {
+                filters: Some(filters),
+                ..Default::default()
+            },
+        )?);
+    }
+    
+    if accounts.len() > HOLDERS_LARGE_RESULT_WARNING {
+        eprintln!(
+            "⚠️ {} token accounts returned; some RPC providers truncate or reject large getProgramAccounts results",
+            accounts.len()
+        );
+    }
+    
+    let mut holders: Vec<Holder> = accounts
+        .into_iter()
+        .filter(|(_, account)| account.data.len() >= 72)
+        .map(|(pubkey, account)| {
+            let mut amount_bytes = [0u8; 8];
+            amount_bytes.copy_from_slice(&account.data[64..72]);
+            let owner = Pubkey::try_from(&account.data[32..64]).unwrap_or_default();
+            Holder {
+                token_account: pubkey.to_string(),
+                owner: owner.to_string(),
+                amount: u64::from_le_bytes(amount_bytes),
+            }
+        })
+        .filter(|h| h.amount > 0 && h.amount >= min_balance)
+        .collect();
+    
+    holders.sort_by_key(|h| std::cmp::Reverse(h.amount));
+    let total_holders = holders.len();
+    if let Some(limit) = limit {
+        holders.truncate(limit);
+    }
+    
+    if json_output {
+        let output = serde_json::to_string_pretty(&holders)
+            .map_err(|e| CliError::SerializationError(e.to_string()))?;
+        println!("{}", output);
+        return Ok(());
+    }
+    
+    if holders.is_empty() {
+        println!("\n   No holders found");
+        return Ok(());
+    }
+    
+    println!("\n{:<5} {:<44} {:<44} {:>20}", "#", "Owner", "Token Account", "Balance");
+    println!("{}", "─".repeat(116));
+    for (i, holder) in holders.iter().enumerate() {
+        println!(
+            "{:<5} {:<44} {:<44} {:>20}",
+            i + 1,
+            holder.owner,
+            holder.token_account,
+            format_token_amount(holder.amount, decimals)
+        );
+    }
+    println!("\n   Showing {} of {} holders", holders.len(), total_holders);
+    
+    Ok(())
+}
+
+// ==================== AUDIT LOG ====================
+pub fn handle_audit_log(
+    _program: &Program<Rc<Keypair>>,
+    _authority: &Pubkey,
+    action: Option<&str>,
+    from: Option<&Pubkey>,
+    to: Option<&Pubkey>,
+    _format: &str,
+    output_path: Option<&str>,
+) -> CliResult<()> {
+    println!("📜 Audit Log");
     
     if let Some(a) = action {
         println!("   Filter action: {}", a);
@@ -1266,6 +4352,54 @@ pub fn handle_audit_log(
     Ok(())
 }
 
+// ==================== DECODE ====================
+pub fn handle_decode(
+    program: &Program<Rc<Keypair>>,
+    account: &str,
+    account_type: &str,
+) -> CliResult<()> {
+    let account_pubkey = parse_pubkey(account)?;
+    
+    let type_name = match account_type.to_lowercase().as_str() {
+        "stablecoin" => "StablecoinState",
+        "minter" => "MinterInfo",
+        "blacklist" => "BlacklistEntry",
+        "allowlist" => "AllowlistEntry",
+        "role" => "RoleAssignment",
+        _ => {
+            return Err(CliError::InvalidArg(format!(
+                "Invalid account type '{}'. Valid types: stablecoin, minter, blacklist, allowlist, role",
+                account_type
+            )));
+        }
+    };
+    
+    let data = program
+        .rpc()
+        .get_account_data(&account_pubkey)
+        .map_err(|_| CliError::AccountNotFound(account_pubkey.to_string()))?;
+    
+    if data.len() < 8 || data[..8] != account_discriminator(type_name) {
+        return Err(CliError::InvalidArg(format!(
+            "Account {} is not a {} account",
+            account_pubkey, type_name
+        )));
+    }
+    
+    let json = match type_name {
+        "StablecoinState" => serde_json::to_value(decode_account::<StablecoinStateData>(&data)?)?,
+        "MinterInfo" => serde_json::to_value(decode_account::<MinterInfoData>(&data)?)?,
+        "BlacklistEntry" => serde_json::to_value(decode_account::<BlacklistEntryData>(&data)?)?,
+        "AllowlistEntry" => serde_json::to_value(decode_account::<AllowlistEntryData>(&data)?)?,
+        _ => serde_json::to_value(decode_account::<RoleAssignmentData>(&data)?)?,
+    };
+    
+    println!("🔎 {} ({})", type_name, account_pubkey);
+    println!("{}", serde_json::to_string_pretty(&json)?);
+    
+    Ok(())
+}
+
 // ==================== DERIVE ====================
 pub fn handle_derive(
     program: &Program<Rc<Keypair>>,
@@ -1290,16 +4424,16 @@ pub fn handle_derive(
     println!("│ PDA Type         │ Public Key                           │");
     println!("├─────────────────────────────────────────────────────────┤");
     
-    // Role PDAs for authority
-    let (role_pda, bump) = derive_role_pda(&stablecoin_pda, authority, &program_id);
+    // Master role PDA for authority
+    let (role_pda, bump) = constants::role_pda(&stablecoin_pda, authority, Role::Master.seed(), &program_id);
     println!("│ Role (authority) │ {} (bump: {})│", role_pda, bump);
     
     // Minter PDA for authority
-    let (minter_pda, bump) = derive_minter_pda(&stablecoin_pda, authority, &program_id);
+    let (minter_pda, bump) = constants::minter_pda(&stablecoin_pda, authority, &program_id);
     println!("│ Minter (auth)    │ {} (bump: {})│", minter_pda, bump);
     
     // Blacklist PDA for authority
-    let (blacklist_pda, bump) = derive_blacklist_pda(&stablecoin_pda, authority, &program_id);
+    let (blacklist_pda, bump) = constants::blacklist_pda(&stablecoin_pda, authority, &program_id);
     println!("│ Blacklist (auth) │ {} (bump: {})│", blacklist_pda, bump);
     
     println!("└─────────────────────────────────────────────────────────┘");
@@ -1307,4 +4441,368 @@ pub fn handle_derive(
     println!("\n💡 Use these PDAs when calling program instructions");
     
     Ok(())
-}
\ No newline at end of file
+}
+// ==================== DEVNET AIRDROP ====================
+/// How long to wait for an airdrop to confirm before giving up
+const AIRDROP_CONFIRM_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Named Solana cluster, selectable with `--cluster` instead of a raw `--url`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ClusterName {
+    #[default]
+    Devnet,
+    Testnet,
+    Mainnet,
+    Localnet,
+}
+
+impl ClusterName {
+    /// Canonical public RPC endpoint for the cluster
+    pub fn url(self) -> &'static str {
+        match self {
+            ClusterName::Devnet => "https://api.devnet.solana.com",
+            ClusterName::Testnet => "https://api.testnet.solana.com",
+            ClusterName::Mainnet => "https://api.mainnet-beta.solana.com",
+            ClusterName::Localnet => "http://127.0.0.1:8899",
+        }
+    }
+
+    /// Cluster an RPC URL points at, using the same detection as the backend
+    /// (anything not recognisably mainnet, testnet or local is treated as devnet)
+    pub fn from_url(url: &str) -> Self {
+        let url = url.to_ascii_lowercase();
+        if url.contains("mainnet") {
+            ClusterName::Mainnet
+        } else if url.contains("testnet") {
+            ClusterName::Testnet
+        } else if url.contains("localhost") || url.contains("127.0.0.1") {
+            ClusterName::Localnet
+        } else {
+            ClusterName::Devnet
+        }
+    }
+}
+
+/// Whether an RPC URL points at mainnet, where airdrops must never be requested
+pub fn is_mainnet_url(url: &str) -> bool {
+    ClusterName::from_url(url) == ClusterName::Mainnet
+}
+
+/// Request a devnet/testnet SOL airdrop for the configured keypair and wait for it to land
+pub fn handle_devnet_airdrop(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    amount_sol: f64,
+    output: OutputFormat,
+) -> CliResult<()> {
+    let rpc = program.rpc();
+    let url = rpc.url();
+    if is_mainnet_url(&url) {
+        return Err(CliError::NotAllowed(format!(
+            "refusing to request an airdrop from mainnet RPC {}",
+            url
+        )));
+    }
+    if amount_sol.is_nan() || amount_sol <= 0.0 {
+        return Err(CliError::InvalidArg("--amount must be greater than 0".to_string()));
+    }
+    let lamports = (amount_sol * LAMPORTS_PER_SOL as f64) as u64;
+    
+    human!(output, "🪂 Requesting {} SOL for {}", amount_sol, authority);
+    let signature = rpc.request_airdrop(authority, lamports)?;
+    human!(output, "   Signature: {}", signature);
+    
+    let started = std::time::Instant::now();
+    while !rpc.confirm_transaction(&signature)? {
+        if started.elapsed() > AIRDROP_CONFIRM_TIMEOUT {
+            return Err(CliError::Timeout(format!(
+                "airdrop {} not confirmed after {}s",
+                signature,
+                AIRDROP_CONFIRM_TIMEOUT.as_secs()
+            )));
+        }
+        std::thread::sleep(std::time::Duration::from_millis(500));
+    }
+    
+    let balance = rpc.get_balance(authority)?;
+    let balance_sol = balance as f64 / LAMPORTS_PER_SOL as f64;
+    
+    if output.is_json() {
+        return print_json(&serde_json::json!({
+            "recipient": authority.to_string(),
+            "signature": signature.to_string(),
+            "lamports": lamports,
+            "balance_lamports": balance,
+        }));
+    }
+    
+    println!("✅ Airdrop confirmed");
+    println!("   Balance: {} SOL", balance_sol);
+    
+    Ok(())
+}
+
+// ==================== OFFLINE SIGNING ====================
+/// Write the unsigned message for instructions captured by `build-tx`.
+///
+/// With a durable nonce account the message uses the nonce as its blockhash
+/// (and advances it first), so it stays valid until the nonce is used;
+/// otherwise it expires with the latest blockhash after about a minute.
+pub fn handle_build_tx(
+    program: &Program<Rc<Keypair>>,
+    tx: &TxOptions,
+    fee_payer: &Pubkey,
+    nonce_account: Option<&Pubkey>,
+    out: &str,
+) -> CliResult<()> {
+    use solana_client::nonce_utils;
+    use solana_sdk::message::Message;
+    
+    let ixs = tx.take_captured();
+    if ixs.is_empty() {
+        return Err(CliError::InvalidArg(
+            "Command does not send a transaction, nothing to build".to_string()
+        ));
+    }
+    
+    let rpc = program.rpc();
+    let message = match nonce_account {
+        Some(nonce_account) => {
+            let account = nonce_utils::get_account_with_commitment(&rpc, nonce_account, rpc.commitment())
+                .map_err(|e| CliError::AccountNotFound(format!("nonce account {}: {}", nonce_account, e)))?;
+            let nonce = nonce_utils::data_from_account(&account)
+                .map_err(|e| CliError::InvalidArg(format!("{} is not a nonce account: {}", nonce_account, e)))?;
+            let mut message = Message::new_with_nonce(ixs, Some(fee_payer), nonce_account, &nonce.authority);
+            message.recent_blockhash = nonce.blockhash();
+            message
+        }
+        None => Message::new_with_blockhash(&ixs, Some(fee_payer), &rpc.get_latest_blockhash()?),
+    };
+    
+    std::fs::write(out, message.serialize())?;
+    
+    let signers = &message.account_keys[..message.header.num_required_signatures as usize];
+    println!("\n📝 Unsigned transaction written to {}", out);
+    println!("   Blockhash: {}", message.recent_blockhash);
+    match nonce_account {
+        Some(nonce_account) => println!("   Durable nonce: {}", nonce_account),
+        None => println!("   ⚠️ Expires with the blockhash - sign and submit within about a minute"),
+    }
+    println!("   Required signers:");
+    for signer in signers {
+        println!("     {}", signer);
+    }
+    println!("\n💡 Sign with: sss-token --keypair <signer> sign-tx --in {} --out signed.bin", out);
+    
+    Ok(())
+}
+
+/// Add the local keypair's signature to a message from `build-tx` or to a
+/// partially signed transaction from a previous `sign-tx`
+pub fn handle_sign_tx(keypair: &Keypair, input: &str, out: &str) -> CliResult<()> {
+    use solana_sdk::{message::Message, transaction::Transaction};
+    
+    let bytes = std::fs::read(input)?;
+    let mut transaction = match read_exact::<Transaction>(&bytes) {
+        Some(transaction) => transaction,
+        None => {
+            let message = read_exact::<Message>(&bytes).ok_or_else(|| {
+                CliError::SerializationError(format!("{} is not a message or transaction", input))
+            })?;
+            Transaction::new_unsigned(message)
+        }
+    };
+    
+    let blockhash = transaction.message.recent_blockhash;
+    transaction
+        .try_partial_sign(&[keypair], blockhash)
+        .map_err(|e| CliError::KeypairError(format!("{} cannot sign this transaction: {}", keypair.pubkey(), e)))?;
+    
+    let serialized = bincode::serialize(&transaction)
+        .map_err(|e| CliError::SerializationError(e.to_string()))?;
+    std::fs::write(out, serialized)?;
+    
+    println!("✍️ Signed by {}", keypair.pubkey());
+    let missing = missing_signers(&transaction);
+    if missing.is_empty() {
+        println!("   All signatures present - submit with: sss-token submit-tx --in {}", out);
+    } else {
+        println!("   Still missing signatures from:");
+        for signer in missing {
+            println!("     {}", signer);
+        }
+    }
+    
+    Ok(())
+}
+
+/// Broadcast a fully signed transaction produced by `sign-tx`
+pub fn handle_submit_tx(program: &Program<Rc<Keypair>>, input: &str, tx: &TxOptions) -> CliResult<()> {
+    use solana_sdk::transaction::Transaction;
+    
+    let bytes = std::fs::read(input)?;
+    let transaction = read_exact::<Transaction>(&bytes)
+        .ok_or_else(|| CliError::SerializationError(format!("{} is not a signed transaction", input)))?;
+    
+    let missing = missing_signers(&transaction);
+    if !missing.is_empty() {
+        let missing: Vec<String> = missing.iter().map(|s| s.to_string()).collect();
+        return Err(CliError::InvalidArg(format!("Missing signatures from: {}", missing.join(", "))));
+    }
+    transaction
+        .verify()
+        .map_err(|e| CliError::TransactionError(format!("Signature verification failed: {}", e)))?;
+    
+    println!("📡 Submitting {}", input);
+    // Already signed, so it can't take a fresh blockhash if it times out
+    let signature = send_and_confirm(&program.rpc(), tx, transaction, None)?;
+    tx.sent.borrow_mut().push(signature);
+    print_tx_success(&signature.to_string(), "Submit");
+    
+    Ok(())
+}
+
+/// Deserialize a bincode value, rejecting trailing bytes so a message isn't misread as a transaction
+fn read_exact<T: serde::de::DeserializeOwned + serde::Serialize>(bytes: &[u8]) -> Option<T> {
+    let value: T = bincode::deserialize(bytes).ok()?;
+    (bincode::serialized_size(&value).ok()? == bytes.len() as u64).then_some(value)
+}
+
+/// Required signers whose signature slot is still empty
+fn missing_signers(transaction: &solana_sdk::transaction::Transaction) -> Vec<Pubkey> {
+    let required = transaction.message.header.num_required_signatures as usize;
+    transaction
+        .message
+        .account_keys
+        .iter()
+        .take(required)
+        .zip(&transaction.signatures)
+        .filter(|(_, signature)| **signature == Signature::default())
+        .map(|(key, _)| *key)
+        .collect()
+}
+
+// ==================== MULTISIG PROPOSALS ====================
+/// A program PDA referenced by a proposal, with the seeds that derive it
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct DerivedPda {
+    #[serde(serialize_with = "serialize_pubkey")]
+    pub address: Pubkey,
+    pub kind: &'static str,
+    pub seeds: Vec<String>,
+    pub bump: u8,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ProposedAccount {
+    #[serde(serialize_with = "serialize_pubkey")]
+    pubkey: Pubkey,
+    is_signer: bool,
+    is_writable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pda: Option<&'static str>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ProposedInstruction {
+    #[serde(serialize_with = "serialize_pubkey")]
+    program_id: Pubkey,
+    accounts: Vec<ProposedAccount>,
+    /// Instruction data, base64
+    data: String,
+}
+
+/// Find every account in `ixs` that is one of the program's PDAs.
+///
+/// Each candidate is re-derived from the other accounts in the proposal, so
+/// a reviewer can check the listed seeds independently of the CLI.
+pub fn find_derived_pdas(program_id: &Pubkey, ixs: &[Instruction]) -> Vec<DerivedPda> {
+    let mut keys: Vec<Pubkey> = Vec::new();
+    for meta in ixs.iter().flat_map(|ix| &ix.accounts) {
+        if !keys.contains(&meta.pubkey) {
+            keys.push(meta.pubkey);
+        }
+    }
+    
+    let mut found: Vec<DerivedPda> = Vec::new();
+    let mut record = |kind: &'static str, seed: &[u8], parents: &[&Pubkey], role: Option<Role>, (address, bump): (Pubkey, u8)| {
+        if keys.contains(&address) && !found.iter().any(|pda| pda.address == address) {
+            let mut seeds = vec![String::from_utf8_lossy(seed).into_owned()];
+            seeds.extend(parents.iter().map(|p| p.to_string()));
+            seeds.extend(role.map(|role| String::from_utf8_lossy(role.seed()).into_owned()));
+            found.push(DerivedPda { address, kind, seeds, bump });
+        }
+    };
+    
+    for asset_mint in &keys {
+        record("stablecoin", VAULT_SEED, &[asset_mint], None, constants::stablecoin_pda(asset_mint, program_id));
+    }
+    for stablecoin in &keys {
+        for account in keys.iter().filter(|account| *account != stablecoin) {
+            for role in Role::ALL {
+                record("role", ROLE_SEED, &[stablecoin, account], Some(role), constants::role_pda(stablecoin, account, role.seed(), program_id));
+            }
+            record("minter", MINTER_SEED, &[stablecoin, account], None, constants::minter_pda(stablecoin, account, program_id));
+            record("blacklist", BLACKLIST_SEED, &[stablecoin, account], None, constants::blacklist_pda(stablecoin, account, program_id));
+            record("allowlist", ALLOWLIST_SEED, &[stablecoin, account], None, constants::allowlist_pda(stablecoin, account, program_id));
+            record("freeze", FREEZE_SEED, &[stablecoin, account], None, constants::freeze_pda(stablecoin, account, program_id));
+        }
+    }
+    found
+}
+
+/// Write instructions captured by `propose` as JSON for a multisig proposal.
+///
+/// The output lists each instruction's program id, accounts and base64 data
+/// (the fields a Squads "add instruction" import needs) plus the PDAs they
+/// reference, so co-signers can verify the accounts before approving.
+pub fn handle_propose(
+    program: &Program<Rc<Keypair>>,
+    tx: &TxOptions,
+    vault: &Pubkey,
+    out: &str,
+) -> CliResult<()> {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    
+    let ixs = tx.take_captured();
+    if ixs.is_empty() {
+        return Err(CliError::InvalidArg(
+            "Command does not send a transaction, nothing to propose".to_string()
+        ));
+    }
+    
+    let derived_pdas = find_derived_pdas(&program.id(), &ixs);
+    let instructions: Vec<ProposedInstruction> = ixs
+        .iter()
+        .map(|ix| ProposedInstruction {
+            program_id: ix.program_id,
+            accounts: ix
+                .accounts
+                .iter()
+                .map(|meta| ProposedAccount {
+                    pubkey: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                    pda: derived_pdas.iter().find(|pda| pda.address == meta.pubkey).map(|pda| pda.kind),
+                })
+                .collect(),
+            data: STANDARD.encode(&ix.data),
+        })
+        .collect();
+    
+    let proposal = serde_json::json!({
+        "vault": vault.to_string(),
+        "instructions": instructions,
+        "derived_pdas": derived_pdas,
+    });
+    std::fs::write(out, serde_json::to_string_pretty(&proposal)?)?;
+    
+    println!("\n🗳️ Proposal written to {}", out);
+    println!("   Vault: {}", vault);
+    println!("   Instructions: {}", instructions.len());
+    for pda in &derived_pdas {
+        println!("   {:<10} {} = [{}]", pda.kind, pda.address, pda.seeds.join(", "));
+    }
+    
+    Ok(())
+}
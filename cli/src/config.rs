@@ -3,25 +3,28 @@ use std::fs;
 use std::path::Path;
 use crate::error::CliError;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Defaults read from `sss-config.toml`, used when neither a flag nor its
+/// environment variable is set. Every field is optional.
+///
+/// ```toml
+/// default_url = "https://api.devnet.solana.com"
+/// default_keypair = "~/.config/solana/issuer.json"
+/// default_stablecoin = "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
 pub struct SssConfig {
-    pub rpc_url: String,
-    pub keypair_path: String,
-    pub stablecoin_preset: u8,
-    pub default_decimals: u8,
-}
-
-impl Default for SssConfig {
-    fn default() -> Self {
-        Self {
-            rpc_url: "https://api.devnet.solana.com".to_string(),
-            keypair_path: "~/.config/solana/id.json".to_string(),
-            stablecoin_preset: 2,
-            default_decimals: 6,
-        }
-    }
+    /// RPC URL used without `--url`, `--cluster` or `SSS_RPC_URL`
+    #[serde(alias = "rpc_url")]
+    pub default_url: Option<String>,
+    /// Keypair file used without `--keypair`, `SSS_KEYPAIR_PATH` or `SSS_KEYPAIR_B58`
+    #[serde(alias = "keypair_path")]
+    pub default_keypair: Option<String>,
+    /// Stablecoin PDA used without `--stablecoin` or `SSS_STABLECOIN`
+    pub default_stablecoin: Option<String>,
 }
 
+/// Load the config file at `path`; a missing file means no defaults
 pub fn load_config(path: &str) -> Result<SssConfig, CliError> {
     if !Path::new(path).exists() {
         return Ok(SssConfig::default());
@@ -31,7 +34,7 @@ pub fn load_config(path: &str) -> Result<SssConfig, CliError> {
         .map_err(|e| CliError::ConfigNotFound(e.to_string()))?;
         
     let config: SssConfig = toml::from_str(&contents)
-        .map_err(|e| CliError::InvalidConfig(e.to_string()))?;
+        .map_err(|e| CliError::InvalidConfig(format!("{}: {}", path, e)))?;
         
     Ok(config)
 }
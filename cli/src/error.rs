@@ -47,9 +47,18 @@ pub enum CliError {
     #[error("Network error: {0}")]
     NetworkError(String),
     
+    #[error("Deployment drift: {0} difference(s) from the expected config")]
+    DriftDetected(usize),
+    
+    #[error("Transfer hook misconfigured: {0} problem(s)")]
+    HookMisconfigured(usize),
+    
     #[error("Timeout: {0}")]
     Timeout(String),
     
+    #[error("Confirmation timeout: {0} not confirmed after {1} attempt(s)")]
+    ConfirmationTimeout(String, u32),
+    
     #[error("Unknown Error: {0}")]
     Unknown(String),
 }
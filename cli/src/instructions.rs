@@ -20,10 +20,33 @@ pub enum Role {
 }
 
 impl Role {
+    pub const ALL: [Role; 6] = [
+        Role::Master,
+        Role::Minter,
+        Role::Burner,
+        Role::Blacklister,
+        Role::Pauser,
+        Role::Seizer,
+    ];
+
+    /// Wire value of the role (matches the program's `Role::to_u8`)
     pub fn to_u8(&self) -> u8 {
         *self as u8
     }
+
+    /// Last seed of the role's RoleAssignment PDA (matches the program's `Role::seed`)
+    pub fn seed(&self) -> &'static [u8] {
+        match self {
+            Role::Master => b"master",
+            Role::Minter => b"minter",
+            Role::Burner => b"burner",
+            Role::Blacklister => b"blacklister",
+            Role::Pauser => b"pauser",
+            Role::Seizer => b"seizer",
+        }
+    }
     
+    /// Role with wire value `value` (matches the program's `Role::from_u8`)
     pub fn from_u8(value: u8) -> Option<Self> {
         match value {
             0 => Some(Role::Master),
@@ -75,6 +98,17 @@ pub struct InitializeArgs {
     pub symbol: String,
     pub uri: String,
     pub decimals: u8,
+    /// Enable the Token-2022 interest-bearing extension with this rate
+    pub interest_rate_bps: Option<i16>,
+    /// Enable the Token-2022 transfer fee extension
+    pub transfer_fee: Option<TransferFeeArgs>,
+}
+
+/// Transfer fee settings for Initialize
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct TransferFeeArgs {
+    pub transfer_fee_bps: u16,
+    pub max_fee: u64,
 }
 
 /// Args for Mint instruction
@@ -89,22 +123,44 @@ pub struct BurnArgs {
     pub amount: u64,
 }
 
-/// Args for FreezeAccount instruction (empty)
+/// Args for FreezeAccount instruction
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
-pub struct FreezeArgs {}
+pub struct FreezeArgs {
+    pub reason: String,
+}
 
 /// Args for ThawAccount instruction (empty)
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct ThawArgs {}
 
-/// Pause instruction marker (empty args)
+/// Args for Pause instruction
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
-pub struct Pause {}
+pub struct Pause {
+    pub reason: String,
+    /// Unix time the pause lifts on its own (0 = until unpaused)
+    pub until: i64,
+}
 
 /// Unpause instruction marker (empty args)
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct Unpause {}
 
+/// CloseStablecoin instruction marker (empty args)
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct CloseStablecoin {}
+
+/// MigrateState instruction marker (empty args)
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct MigrateState {}
+
+/// InitializeExtraAccountMetas instruction marker (empty args)
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct InitializeExtraAccountMetas {}
+
+/// UpdateExtraAccountMetas instruction marker (empty args)
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct UpdateExtraAccountMetas {}
+
 /// Args for AddToBlacklist instruction
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct AddToBlacklist {
@@ -115,16 +171,56 @@ pub struct AddToBlacklist {
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct RemoveFromBlacklist {}
 
+/// AddToAllowlist instruction marker (empty args)
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct AddToAllowlist {}
+
+/// RemoveFromAllowlist instruction marker (empty args)
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct RemoveFromAllowlist {}
+
+/// Args for SetAllowlistMode instruction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetAllowlistModeArgs {
+    pub enabled: bool,
+}
+
+/// Args for RecountBlacklist instruction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct RecountBlacklistArgs {
+    /// Start counting from zero (set on the first transaction only)
+    pub reset: bool,
+}
+
+/// Args for RecountMinters instruction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct RecountMintersArgs {
+    /// Start counting from zero (set on the first transaction only)
+    pub reset: bool,
+}
+
+/// Args for RecountRoles instruction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct RecountRolesArgs {
+    /// Start counting from zero (set on the first transaction only)
+    pub reset: bool,
+}
+
 /// Args for AssignRole instruction
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct AssignRoleArgs {
     pub role: u8,
+    pub expires_at: Option<i64>,
 }
 
 /// Args for RevokeRole instruction (empty)
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct RevokeRoleArgs {}
 
+/// CleanupExpiredRole instruction marker (empty args)
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct CleanupExpiredRole {}
+
 /// Args for SetQuota instruction
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct SetQuotaArgs {
@@ -141,18 +237,106 @@ pub struct AddMinterArgs {
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct RemoveMinterArgs {}
 
+/// RotateMinter instruction marker (empty args)
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct RotateMinter {}
+
 /// Args for Seize instruction
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct SeizeArgs {
     pub amount: u64,
 }
 
+/// Args for RescueTokens instruction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct RescueTokensArgs {
+    pub amount: u64,
+}
+
+/// Args for ConfiscateBurn instruction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct ConfiscateBurnArgs {
+    pub amount: u64,
+}
+
 /// Args for TransferAuthority instruction
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct TransferAuthority {
     pub new_authority: Pubkey,
 }
 
+/// Args for UpdateInterestRate instruction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct UpdateInterestRateArgs {
+    pub rate_bps: i16,
+}
+
+/// Args for UpdateTransferFee instruction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct UpdateTransferFeeArgs {
+    pub transfer_fee_bps: u16,
+    pub max_fee: u64,
+}
+
+/// WithdrawWithheldFees instruction marker (empty args)
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct WithdrawWithheldFees {}
+
+/// Args for SetMaxTransfer instruction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetMaxTransferArgs {
+    pub amount: u64,
+}
+
+/// Args for SetMaxSupply instruction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetMaxSupplyArgs {
+    pub max_supply: u64,
+}
+
+/// Args for SetMinBurn instruction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetMinBurnArgs {
+    pub min_burn_amount: u64,
+}
+
+/// Args for SetMinMint instruction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetMinMintArgs {
+    pub min_mint_amount: u64,
+}
+
+/// Args for ConfigureReserves instruction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct ConfigureReservesArgs {
+    pub attester: Pubkey,
+    pub max_staleness: i64,
+}
+
+/// DisableReserves instruction marker (empty args)
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct DisableReserves {}
+
+/// Args for AttestReserves instruction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct AttestReservesArgs {
+    pub reserve_amount: u64,
+}
+
+/// Args for SetCompliance instruction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetComplianceArgs {
+    pub enabled: bool,
+    pub upgrade_preset: bool,
+}
+
+/// Args for SetFee instruction
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetFeeArgs {
+    pub fee_bps: u16,
+    pub fee_treasury: Pubkey,
+}
+
 // ==================== HELPER FUNCTIONS ====================
 
 /// Build instruction data with Anchor discriminant prefix
@@ -1,8 +1,8 @@
 use anchor_client::{Client, Cluster, Program};
-use clap::{Parser, Subcommand};
+use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser, Subcommand};
 use solana_sdk::{
     pubkey::Pubkey,
-    signature::{read_keypair_file, Keypair},
+    signature::{read_keypair_file, Keypair, Signature},
     signer::Signer,
     commitment_config::CommitmentConfig,
 };
@@ -20,32 +20,68 @@ use error::CliError;
 /// Program ID for the SSS Token program
 const PROGRAM_ID: &str = "SSSToken11111111111111111111111111111111111";
 
-/// PDA seeds
-const STABLECOIN_SEED: &[u8] = b"stablecoin";
-const ROLE_SEED: &[u8] = b"role";
-const MINTER_SEED: &[u8] = b"minter";
-const BLACKLIST_SEED: &[u8] = b"blacklist";
+/// Keypair sources, in addition to the --keypair flag
+const KEYPAIR_PATH_ENV: &str = "SSS_KEYPAIR_PATH";
+const KEYPAIR_B58_ENV: &str = "SSS_KEYPAIR_B58";
+const DEFAULT_KEYPAIR_PATH: &str = "~/.config/solana/id.json";
+
+/// Stablecoin used by commands run without `--stablecoin`
+const STABLECOIN_ENV: &str = "SSS_STABLECOIN";
 
 #[derive(Parser)]
 #[command(name = "sss-token")]
 #[command(about = "Solana Stablecoin Standard CLI - Production Ready", version)]
 struct Cli {
     /// Solana RPC URL (or set SSS_RPC_URL env var)
-    #[arg(long, env = "SSS_RPC_URL", default_value = "https://api.devnet.solana.com")]
-    url: String,
+    #[arg(long, conflicts_with = "cluster")]
+    url: Option<String>,
+
+    /// Named cluster whose public RPC URL to use (default: devnet)
+    #[arg(long, value_enum)]
+    cluster: Option<commands::ClusterName>,
+
+    /// Append a JSON line per executed command (timestamp, command, stablecoin,
+    /// signatures or error) to this file
+    #[arg(long, env = "SSS_AUDIT_FILE", global = true)]
+    audit_file: Option<String>,
+
+    /// Confirm running seize, transfer-authority, close or a bulk operation against mainnet
+    #[arg(long = "i-understand-mainnet", global = true)]
+    i_understand_mainnet: bool,
 
-    /// Path to keypair file (or set SSS_KEYPAIR_PATH env var)
-    #[arg(long, env = "SSS_KEYPAIR_PATH", default_value = "~/.config/solana/id.json")]
-    keypair: String,
+    /// Path to keypair file (overrides SSS_KEYPAIR_PATH and SSS_KEYPAIR_B58)
+    #[arg(long)]
+    keypair: Option<String>,
 
     /// Commitment level
     #[arg(long, default_value = "confirmed")]
     commitment: String,
 
-    /// Path to config file
+    /// Path to config file with fallback URL, keypair and stablecoin (see `SssConfig`)
     #[arg(long, default_value = "sss-config.toml")]
     config: String,
 
+    /// Priority fee in micro-lamports per compute unit (0 or unset: no instruction added)
+    #[arg(long, global = true)]
+    priority_fee: Option<u64>,
+
+    /// Compute unit limit per transaction (0 or unset: no instruction added)
+    #[arg(long, global = true)]
+    compute_limit: Option<u32>,
+
+    /// Seconds to wait for a transaction to confirm before resubmitting it
+    #[arg(long, global = true, default_value_t = 60, value_parser = clap::value_parser!(u64).range(1..))]
+    confirm_timeout: u64,
+
+    /// Times to resubmit an unconfirmed transaction with a fresh blockhash (0: never)
+    #[arg(long, global = true, default_value_t = 2)]
+    max_resubmits: u32,
+
+    /// Output format for status, supply, whoami, minters info, blacklist check and allowlist check.
+    /// In json mode, human-readable messages go to stderr.
+    #[arg(long, value_enum, default_value_t = commands::OutputFormat::Text)]
+    output: commands::OutputFormat,
+
     /// The administrative command to execute
     #[command(subcommand)]
     command: Commands,
@@ -65,21 +101,49 @@ enum Commands {
         uri: String,
         #[arg(long, default_value = "6")]
         decimals: u8,
+        /// Existing mint to manage (default: create a new Token-2022 mint owned by the stablecoin)
         #[arg(long)]
         asset_mint: Option<String>,
+        /// Enable the interest-bearing extension at this rate (the mint must not be initialized yet)
+        #[arg(long, allow_hyphen_values = true)]
+        interest_rate_bps: Option<i16>,
+        /// Enable the transfer fee extension at this rate (requires --max-fee)
+        #[arg(long, requires = "max_fee")]
+        transfer_fee_bps: Option<u16>,
+        /// Maximum transfer fee per transfer, in base units
+        #[arg(long, requires = "transfer_fee_bps")]
+        max_fee: Option<u64>,
+        /// Authorized supply ceiling in base units (0 or unset: uncapped)
+        #[arg(long)]
+        max_supply: Option<u64>,
+        /// Send the initialization even if the stablecoin already exists
+        /// (the program still refuses to initialize it twice)
+        #[arg(long)]
+        force_reinit: bool,
     },
 
-    /// Mint tokens to a recipient
+    /// Mint tokens to a recipient wallet's associated token account (created if missing)
     Mint {
         recipient: String,
-        amount: u64,
+        /// Amount in base units, or whole tokens with --units human (e.g. 1.5 or 1_000)
+        amount: String,
+        /// Unit of AMOUNT
+        #[arg(long, value_enum, default_value_t = commands::AmountUnits::Base)]
+        units: commands::AmountUnits,
         #[arg(long)]
         stablecoin: Option<String>,
+        /// Treat RECIPIENT as an existing token account instead of a wallet
+        #[arg(long)]
+        token_account: bool,
     },
 
     /// Burn tokens
     Burn {
-        amount: u64,
+        /// Amount in base units, or whole tokens with --units human (e.g. 1.5 or 1_000)
+        amount: String,
+        /// Unit of AMOUNT
+        #[arg(long, value_enum, default_value_t = commands::AmountUnits::Base)]
+        units: commands::AmountUnits,
         #[arg(long)]
         from: Option<String>,
         #[arg(long)]
@@ -89,6 +153,32 @@ enum Commands {
     /// Freeze a token account
     Freeze {
         account: String,
+        #[arg(long, default_value = "")]
+        reason: String,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Freeze every account listed in a file (one address per line)
+    FreezeBatch {
+        file: String,
+        #[arg(long, default_value = "")]
+        reason: String,
+        /// Cap on freeze instructions per transaction (1-10); by default
+        /// batches are as large as the transaction size and compute limits allow
+        #[arg(long)]
+        batch_size: Option<usize>,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Thaw every account listed in a file (one address per line)
+    ThawBatch {
+        file: String,
+        /// Cap on thaw instructions per transaction (1-10); by default
+        /// batches are as large as the transaction size and compute limits allow
+        #[arg(long)]
+        batch_size: Option<usize>,
         #[arg(long)]
         stablecoin: Option<String>,
     },
@@ -102,6 +192,12 @@ enum Commands {
 
     /// Pause all operations
     Pause {
+        /// Why operations are paused (max 200 chars), shown in status
+        #[arg(long, default_value = "")]
+        reason: String,
+        /// Lift the pause automatically at this Unix timestamp (default: until unpaused)
+        #[arg(long)]
+        until: Option<i64>,
         #[arg(long)]
         stablecoin: Option<String>,
     },
@@ -112,12 +208,129 @@ enum Commands {
         stablecoin: Option<String>,
     },
 
+    /// Set the maximum amount per transfer (0 = unlimited)
+    SetMaxTransfer {
+        amount: u64,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Set the authorized supply ceiling (0 = uncapped; cannot be below current supply)
+    SetMaxSupply {
+        max_supply: u64,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Set the smallest amount a single burn may request (0 = no minimum)
+    SetMinBurn {
+        amount: u64,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Set the smallest amount a single mint may request (0 = no minimum)
+    SetMinMint {
+        amount: u64,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Require mints to stay within reserves attested by ATTESTER, no older than MAX_STALENESS seconds
+    ConfigureReserves {
+        #[arg(long)]
+        attester: String,
+        #[arg(long)]
+        max_staleness: i64,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Stop requiring reserve attestations for mints and close the attestation account
+    DisableReserves {
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Attest the reserves backing the supply (signed by the configured attester)
+    AttestReserves {
+        amount: u64,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Turn compliance checks on or off (cannot disable while accounts are blacklisted)
+    SetCompliance {
+        #[arg(action = clap::ArgAction::Set)]
+        enabled: bool,
+        /// When enabling, also record the stablecoin as SSS-2
+        #[arg(long)]
+        upgrade_preset: bool,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Update the interest-bearing mint rate in basis points (may be negative)
+    SetInterestRate {
+        #[arg(allow_hyphen_values = true)]
+        rate_bps: i16,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Update the Token-2022 transfer fee
+    SetTransferFee {
+        transfer_fee_bps: u16,
+        #[arg(long)]
+        max_fee: u64,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Withdraw withheld transfer fees (harvested to the mint) to a token account
+    WithdrawWithheldFees {
+        #[arg(long)]
+        to: String,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Set the mint/burn fee in basis points and its treasury token account
+    SetFee {
+        fee_bps: u16,
+        #[arg(long)]
+        treasury: String,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Close a retired stablecoin and reclaim its rent
+    Close {
+        /// Skip the confirmation prompt
+        #[arg(long)]
+        yes: bool,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Grow a stablecoin state created with the original layout to the current one
+    MigrateState {
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
     /// Manage blacklist
     Blacklist {
         #[command(subcommand)]
         command: BlacklistCommands,
     },
 
+    /// Manage the allowlist enforced by the transfer hook in allowlist mode
+    Allowlist {
+        #[command(subcommand)]
+        command: AllowlistCommands,
+    },
+
     /// Manage minters
     Minters {
         #[command(subcommand)]
@@ -129,11 +342,41 @@ enum Commands {
         account: String,
         #[arg(long)]
         to: String,
+        /// Amount in base units, or whole tokens with --units human (e.g. 1.5 or 1_000)
+        amount: String,
+        /// Unit of AMOUNT
+        #[arg(long, value_enum, default_value_t = commands::AmountUnits::Base)]
+        units: commands::AmountUnits,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Burn tokens from a blacklisted account instead of transferring them (SSS-2)
+    Confiscate {
+        /// Token account of the blacklisted holder
+        account: String,
         amount: u64,
         #[arg(long)]
         stablecoin: Option<String>,
     },
 
+    /// Return tokens of another mint sent to the stablecoin PDA by mistake (Master only)
+    RescueTokens {
+        /// Mint of the stray tokens (the stablecoin's own mint is refused)
+        #[arg(long)]
+        mint: String,
+        /// Amount in base units of MINT
+        amount: u64,
+        /// Token account to send the tokens to
+        #[arg(long)]
+        to: String,
+        /// Token account holding the tokens (default: the PDA's associated token account)
+        #[arg(long)]
+        source: Option<String>,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
     /// Transfer master authority
     TransferAuthority {
         new_authority: String,
@@ -145,23 +388,87 @@ enum Commands {
     AssignRole {
         role: String,
         account: String,
+        /// Unix timestamp after which the role no longer applies (default: never)
+        #[arg(long)]
+        expires_at: Option<i64>,
         #[arg(long)]
         stablecoin: Option<String>,
     },
 
     /// Revoke a role from an account
     RevokeRole {
+        role: String,
+        account: String,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Close an expired role assignment, returning its rent to whoever assigned it (anyone may run this)
+    CleanupExpiredRole {
+        role: String,
         account: String,
         #[arg(long)]
         stablecoin: Option<String>,
     },
 
+    /// Backfill the on-chain role counter from existing role assignments (run `migrate-state` first on older stablecoins)
+    RecountRoles {
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
     /// Display stablecoin status
     Status {
         #[arg(long)]
         stablecoin: Option<String>,
-        #[arg(long)]
+        #[arg(long, conflicts_with = "watch")]
         export: Option<String>,
+        /// Write the full deployment descriptor (mint, minters, roles) as TOML, or JSON for .json paths
+        #[arg(long, conflicts_with = "watch")]
+        export_config: Option<String>,
+        /// Re-fetch and redraw the status panel until Ctrl-C
+        #[arg(long)]
+        watch: bool,
+        /// Refresh interval in seconds for --watch
+        #[arg(long, default_value = "5", value_parser = clap::value_parser!(u64).range(1..))]
+        interval: u64,
+    },
+
+    /// Compare the live deployment against a config from `status --export-config`.
+    /// Exits non-zero when anything has drifted.
+    #[command(alias = "verify-deployment")]
+    Verify {
+        /// Expected deployment config (TOML, or JSON for .json paths)
+        #[arg(long)]
+        config: String,
+    },
+
+    /// Create the ExtraAccountMetaList so Token-2022 can invoke the transfer hook,
+    /// or update one created by an older program version
+    SetupHook {
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+
+    /// Check that the mint's transfer hook points at this program and its
+    /// ExtraAccountMetaList is initialized with the accounts the hook reads
+    VerifyHook {
+        #[arg(long)]
+        stablecoin: String,
+    },
+
+    /// Request SOL for the configured keypair (devnet/testnet only)
+    DevnetAirdrop {
+        /// Amount of SOL to request
+        #[arg(long, default_value = "1")]
+        amount: f64,
+    },
+
+    /// Show the configured keypair, whether it is the stablecoin's Master
+    /// authority and which on-chain roles it holds
+    Whoami {
+        #[arg(long)]
+        stablecoin: Option<String>,
     },
 
     /// Display total supply
@@ -174,6 +481,12 @@ enum Commands {
     Holders {
         #[arg(long, default_value = "0")]
         min_balance: u64,
+        /// Show at most this many holders
+        #[arg(long)]
+        limit: Option<usize>,
+        /// Output format: text or json
+        #[arg(long, default_value = "text")]
+        format: String,
         #[arg(long)]
         stablecoin: Option<String>,
     },
@@ -192,11 +505,64 @@ enum Commands {
         output: Option<String>,
     },
 
+    /// Decode a program account and print it as JSON
+    Decode {
+        account: String,
+        /// Account type: stablecoin, minter, blacklist, allowlist, role
+        #[arg(long = "type")]
+        account_type: String,
+    },
+
     /// Derive PDAs for a stablecoin
     Derive {
         #[arg(long)]
         stablecoin: Option<String>,
     },
+
+    /// Build another command's transaction without sending it, for signing
+    /// on an air-gapped machine, a hardware wallet or by several parties
+    BuildTx {
+        /// File to write the unsigned message to
+        #[arg(long)]
+        out: String,
+        /// Offline authority and fee payer (defaults to the local keypair)
+        #[arg(long)]
+        signer: Option<String>,
+        /// Durable nonce account, so the message doesn't expire with the blockhash
+        #[arg(long)]
+        nonce_account: Option<String>,
+        /// The command to build, with its arguments
+        #[arg(required = true, trailing_var_arg = true, allow_hyphen_values = true)]
+        command: Vec<String>,
+    },
+
+    /// Sign a build-tx message (or a partially signed transaction) with the local keypair
+    SignTx {
+        #[arg(long = "in")]
+        input: String,
+        #[arg(long)]
+        out: String,
+    },
+
+    /// Broadcast a transaction signed with sign-tx
+    SubmitTx {
+        #[arg(long = "in")]
+        input: String,
+    },
+
+    /// Export another command's instructions as JSON for a multisig (e.g. Squads)
+    /// proposal instead of sending them
+    Propose {
+        /// Multisig vault that holds the stablecoin authority
+        #[arg(long)]
+        vault: String,
+        /// File to write the proposal JSON to
+        #[arg(long)]
+        out: String,
+        /// The command to propose, with its arguments
+        #[arg(required = true, trailing_var_arg = true, allow_hyphen_values = true)]
+        command: Vec<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -222,12 +588,57 @@ pub enum BlacklistCommands {
         #[arg(long)]
         stablecoin: Option<String>,
     },
+    /// Import addresses from a file (one `address` or `address,reason` per line)
+    Import {
+        file: String,
+        /// Reason used for lines without one
+        #[arg(long, default_value = "Bulk import")]
+        reason: String,
+        /// Cap on add_to_blacklist instructions per transaction (1-10); by default
+        /// batches are as large as the transaction size and compute limits allow
+        #[arg(long)]
+        batch_size: Option<usize>,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+    /// Backfill the on-chain blacklist counter from existing entries (run `migrate-state` first on older stablecoins)
+    Recount {
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum AllowlistCommands {
+    Add {
+        account: String,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+    Remove {
+        account: String,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+    Check {
+        account: String,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+    /// Turn allowlist mode on or off
+    Mode {
+        #[arg(action = clap::ArgAction::Set)]
+        enabled: bool,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
 pub enum MinterCommands {
     Add {
         account: String,
+        /// Cumulative mint quota in base units (0 = unlimited)
         #[arg(long, default_value = "0")]
         quota: u64,
         #[arg(long)]
@@ -249,10 +660,25 @@ pub enum MinterCommands {
     },
     SetQuota {
         account: String,
+        /// New quota in base units (0 = unlimited)
         quota: u64,
         #[arg(long)]
         stablecoin: Option<String>,
     },
+    /// Replace a minter key, carrying over its quota, usage and Minter role in one transaction
+    Rotate {
+        #[arg(long)]
+        from: String,
+        #[arg(long)]
+        to: String,
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
+    /// Backfill the on-chain minter counter from existing minters (run `migrate-state` first on older stablecoins)
+    Recount {
+        #[arg(long)]
+        stablecoin: Option<String>,
+    },
 }
 
 fn expand_tilde(path: &str) -> String {
@@ -278,55 +704,103 @@ fn get_commitment(s: &str) -> CommitmentConfig {
     }
 }
 
-fn derive_stablecoin_pda(asset_mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[STABLECOIN_SEED, asset_mint.to_bytes().as_ref()],
-        program_id,
+/// Load the signing keypair. Precedence:
+/// 1. `--keypair <path>`
+/// 2. `SSS_KEYPAIR_PATH` (JSON byte-array keypair file)
+/// 3. `SSS_KEYPAIR_B58` (base58-encoded secret key)
+/// 4. `default_keypair` in the config file
+/// 5. `~/.config/solana/id.json`
+fn load_signer(cli: &Cli, config: &SssConfig) -> Result<Keypair, CliError> {
+    resolve_signer(
+        cli.keypair.as_deref(),
+        std::env::var(KEYPAIR_PATH_ENV).ok().as_deref(),
+        std::env::var(KEYPAIR_B58_ENV).ok().as_deref(),
+        config.default_keypair.as_deref(),
     )
 }
 
-fn derive_role_pda(
-    stablecoin: &Pubkey,
-    account: &Pubkey,
-    program_id: &Pubkey,
-) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[ROLE_SEED, stablecoin.to_bytes().as_ref(), account.to_bytes().as_ref()],
-        program_id,
-    )
+fn resolve_signer(
+    flag_path: Option<&str>,
+    env_path: Option<&str>,
+    env_b58: Option<&str>,
+    config_path: Option<&str>,
+) -> Result<Keypair, CliError> {
+    if let Some(path) = flag_path.or(env_path) {
+        return keypair_from_file(path);
+    }
+    if let Some(secret) = env_b58 {
+        return keypair_from_base58(secret);
+    }
+    keypair_from_file(config_path.unwrap_or(DEFAULT_KEYPAIR_PATH))
 }
 
-fn derive_minter_pda(
-    stablecoin: &Pubkey,
-    minter: &Pubkey,
-    program_id: &Pubkey,
-) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[MINTER_SEED, stablecoin.to_bytes().as_ref(), minter.to_bytes().as_ref()],
-        program_id,
-    )
+fn keypair_from_file(path: &str) -> Result<Keypair, CliError> {
+    let expanded_path = expand_tilde(path);
+    read_keypair_file(&expanded_path)
+        .map_err(|e| CliError::KeypairError(format!("Failed to read keypair {}: {}", expanded_path, e)))
+}
+
+fn keypair_from_base58(secret: &str) -> Result<Keypair, CliError> {
+    let bytes = bs58::decode(secret.trim())
+        .into_vec()
+        .map_err(|e| CliError::KeypairError(format!("Invalid base58 in {}: {}", KEYPAIR_B58_ENV, e)))?;
+    Keypair::try_from(bytes.as_slice())
+        .map_err(|e| CliError::KeypairError(format!("Invalid keypair in {}: {}", KEYPAIR_B58_ENV, e)))
 }
 
-fn derive_blacklist_pda(
-    stablecoin: &Pubkey,
-    account: &Pubkey,
-    program_id: &Pubkey,
-) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[BLACKLIST_SEED, stablecoin.to_bytes().as_ref(), account.to_bytes().as_ref()],
-        program_id,
+/// Commands that need `--i-understand-mainnet` before they may run against mainnet
+fn requires_mainnet_confirmation(command: &Commands) -> bool {
+    matches!(
+        command,
+        Commands::Seize { .. }
+            | Commands::TransferAuthority { .. }
+            | Commands::Close { .. }
+            | Commands::FreezeBatch { .. }
+            | Commands::ThawBatch { .. }
+            | Commands::Blacklist { command: BlacklistCommands::Import { .. } }
     )
 }
 
+/// Refuse a destructive or bulk command on mainnet unless `--i-understand-mainnet` was passed
+fn check_mainnet_guard(rpc_url: &str, command: &Commands, confirmed: bool) -> Result<(), CliError> {
+    if confirmed || !requires_mainnet_confirmation(command) || !commands::is_mainnet_url(rpc_url) {
+        return Ok(());
+    }
+    Err(CliError::NotAllowed(format!(
+        "{} is a mainnet RPC; re-run with --i-understand-mainnet to confirm this command",
+        rpc_url
+    )))
+}
+
+/// Resolve the RPC URL: `--url`, then `--cluster`, then SSS_RPC_URL, then
+/// `default_url` from the config file, then devnet
+fn resolve_rpc_url(url: Option<&str>, cluster: Option<commands::ClusterName>, config_url: Option<&str>) -> String {
+    url.map(str::to_string)
+        .or_else(|| cluster.map(|cluster| cluster.url().to_string()))
+        .or_else(|| std::env::var("SSS_RPC_URL").ok().filter(|url| !url.is_empty()))
+        .or_else(|| config_url.map(str::to_string))
+        .unwrap_or_else(|| commands::ClusterName::default().url().to_string())
+}
+
+/// Stablecoin for commands run without `--stablecoin`: SSS_STABLECOIN, then
+/// `default_stablecoin` from the config file
+fn default_stablecoin(env: Option<String>, config: &SssConfig) -> Option<String> {
+    env.filter(|s| !s.is_empty()).or_else(|| config.default_stablecoin.clone())
+}
+
+/// Parse a command's `--stablecoin`, falling back to `default` when it wasn't given
+fn resolve_stablecoin(flag: Option<String>, default: Option<&str>) -> Result<Option<Pubkey>, CliError> {
+    flag.as_deref()
+        .or(default)
+        .map(parse_pubkey)
+        .transpose()
+}
+
 fn setup_client(
     url: &str,
-    keypair_path: &str,
+    keypair: Keypair,
     commitment: &str,
 ) -> Result<(Program<Rc<Keypair>>, Pubkey, Pubkey), CliError> {
-    let expanded_path = expand_tilde(keypair_path);
-    let keypair = read_keypair_file(&expanded_path)
-        .map_err(|e| CliError::KeypairError(format!("Failed to read keypair {}: {}", expanded_path, e)))?;
-    
     let authority = keypair.pubkey();
     let commitment_config = get_commitment(commitment);
     
@@ -361,13 +835,54 @@ fn parse_role(role_str: &str) -> Result<commands::Role, CliError> {
 }
 
 fn main() -> anyhow::Result<()> {
-    let cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    let audit_file = cli.audit_file.clone();
+    
+    // Load optional config file; flags and env vars take precedence over it
+    let config = match config::load_config(&cli.config) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("❌ Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let default_stablecoin = default_stablecoin(std::env::var(STABLECOIN_ENV).ok(), &config);
+    
+    // Signing happens offline, so sign-tx never needs the RPC node
+    if let Commands::SignTx { input, out } = &cli.command {
+        let result = load_signer(&cli, &config).and_then(|keypair| commands::handle_sign_tx(&keypair, input, out));
+        if let Some(path) = &audit_file {
+            write_audit_record(path, &matches, &[], &result);
+        }
+        if let Err(e) = result {
+            eprintln!("❌ Error: {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+    
+    let rpc_url = resolve_rpc_url(cli.url.as_deref(), cli.cluster, config.default_url.as_deref());
+    if let Err(e) = check_mainnet_guard(&rpc_url, &cli.command, cli.i_understand_mainnet) {
+        eprintln!("❌ Error: {}", e);
+        std::process::exit(1);
+    }
     
-    // Load optional config file
-    let _config = config::load_config(&cli.config).unwrap_or_default();
+    // build-tx --signer, submit-tx and propose never sign, so they don't need a local keypair
+    let keypair = match load_signer(&cli, &config) {
+        Err(_) if matches!(
+            cli.command,
+            Commands::BuildTx { signer: Some(_), .. } | Commands::SubmitTx { .. } | Commands::Propose { .. }
+        ) => {
+            Ok(Keypair::new())
+        }
+        result => result,
+    };
     
     // Setup client
-    let (program, program_id, authority) = match setup_client(&cli.url, &cli.keypair, &cli.commitment) {
+    let (program, _, authority) = match keypair
+        .and_then(|keypair| setup_client(&rpc_url, keypair, &cli.commitment))
+    {
         Ok(result) => result,
         Err(e) => {
             eprintln!("❌ Error setting up client: {}", e);
@@ -375,149 +890,382 @@ fn main() -> anyhow::Result<()> {
         }
     };
     
-    let result = match cli.command {
-        Commands::Init { preset, name, symbol, uri, decimals, asset_mint } => {
-            commands::handle_init(&program, &authority, preset, name, symbol, uri, decimals, asset_mint)
+    // Compute-budget and confirmation options applied to every transaction
+    let tx_options = commands::TxOptions {
+        priority_fee: cli.priority_fee.filter(|fee| *fee > 0),
+        compute_limit: cli.compute_limit.filter(|units| *units > 0),
+        confirm_timeout: std::time::Duration::from_secs(cli.confirm_timeout),
+        max_resubmits: cli.max_resubmits,
+        ..Default::default()
+    };
+    
+    let result = run_command(&program, &authority, cli.command, cli.output, &tx_options, default_stablecoin.as_deref());
+    if let Some(path) = &audit_file {
+        write_audit_record(path, &matches, &tx_options.sent.borrow(), &result);
+    }
+    
+    if let Err(e) = result {
+        eprintln!("❌ Error: {}", e);
+        std::process::exit(1);
+    }
+    
+    Ok(())
+}
+
+/// One line of the `--audit-file` log
+#[derive(serde::Serialize)]
+struct AuditRecord {
+    /// Unix timestamp (seconds) when the command finished
+    timestamp: u64,
+    /// Subcommand path, e.g. `blacklist add` or `build-tx seize`
+    command: String,
+    stablecoin: Option<String>,
+    signatures: Vec<String>,
+    success: bool,
+    error: Option<String>,
+}
+
+impl AuditRecord {
+    fn new(matches: &ArgMatches, signatures: &[Signature], result: &Result<(), CliError>) -> Self {
+        let mut command = Vec::new();
+        let mut stablecoin = None;
+        let mut current = matches.clone();
+        loop {
+            if let Some((name, sub_matches)) = current.subcommand() {
+                command.push(name.to_string());
+                if let Ok(Some(value)) = sub_matches.try_get_one::<String>("stablecoin") {
+                    stablecoin = Some(value.clone());
+                }
+                current = sub_matches.clone();
+                continue;
+            }
+            // `build-tx` and `propose` hold the command they wrap as raw arguments
+            let wrapped = current
+                .try_get_many::<String>("command")
+                .ok()
+                .flatten()
+                .and_then(|args| WrappedCommand::command().try_get_matches_from(args).ok());
+            match wrapped {
+                Some(wrapped) => current = wrapped,
+                None => break,
+            }
         }
-        Commands::Mint { recipient, amount, stablecoin } => {
-            let stablecoin_pubkey = stablecoin
-                .map(|s| parse_pubkey(&s))
-                .transpose()?;
-            commands::handle_mint(&program, &authority, &recipient, amount, stablecoin_pubkey.as_ref())
+        AuditRecord {
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs())
+                .unwrap_or_default(),
+            command: command.join(" "),
+            stablecoin,
+            signatures: signatures.iter().map(|s| s.to_string()).collect(),
+            success: result.is_ok(),
+            error: result.as_ref().err().map(|e| e.to_string()),
         }
-        Commands::Burn { amount, from, stablecoin } => {
-            let from_pubkey = from
-                .map(|s| parse_pubkey(&s))
-                .transpose()?;
-            let stablecoin_pubkey = stablecoin
-                .map(|s| parse_pubkey(&s))
-                .transpose()?;
-            commands::handle_burn(&program, &authority, amount, from_pubkey.as_ref(), stablecoin_pubkey.as_ref())
+    }
+}
+
+/// Append the outcome of a command to the audit file. Failing to write the
+/// audit record only warns, so it never masks the command's own result.
+fn write_audit_record(path: &str, matches: &ArgMatches, signatures: &[Signature], result: &Result<(), CliError>) {
+    use std::io::Write;
+
+    let record = AuditRecord::new(matches, signatures, result);
+    let written = serde_json::to_string(&record)
+        .map_err(std::io::Error::from)
+        .and_then(|line| {
+            let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+            writeln!(file, "{}", line)
+        });
+    if let Err(e) = written {
+        eprintln!("⚠️  Could not write audit record to {}: {}", path, e);
+    }
+}
+
+/// The command wrapped by `build-tx` or `propose`. It's captured as raw
+/// arguments and parsed here, since a `Commands` variant holding `Commands`
+/// as a subcommand makes clap build the command tree without end.
+#[derive(Parser)]
+#[command(no_binary_name = true)]
+struct WrappedCommand {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+fn parse_wrapped_command(args: &[String]) -> Result<Commands, CliError> {
+    WrappedCommand::try_parse_from(args)
+        .map(|wrapped| wrapped.command)
+        .map_err(|e| CliError::InvalidArg(e.to_string()))
+}
+
+/// Commands that build, sign, send or export another command's transaction
+fn is_offline_command(command: &Commands) -> bool {
+    matches!(
+        command,
+        Commands::BuildTx { .. } | Commands::SignTx { .. } | Commands::SubmitTx { .. } | Commands::Propose { .. }
+    )
+}
+
+/// Dispatch a parsed command. `build-tx` calls back into this with the
+/// offline signer (or `propose` with the multisig vault) as authority and
+/// instruction capture switched on.
+fn run_command(
+    program: &Program<Rc<Keypair>>,
+    authority: &Pubkey,
+    command: Commands,
+    output: commands::OutputFormat,
+    tx_options: &commands::TxOptions,
+    default_stablecoin: Option<&str>,
+) -> Result<(), CliError> {
+    match command {
+        Commands::Init { preset, name, symbol, uri, decimals, asset_mint, interest_rate_bps, transfer_fee_bps, max_fee, max_supply, force_reinit } => {
+            let transfer_fee = transfer_fee_bps
+                .zip(max_fee)
+                .map(|(transfer_fee_bps, max_fee)| instructions::TransferFeeArgs { transfer_fee_bps, max_fee });
+            commands::handle_init(program, authority, preset, name, symbol, uri, decimals, asset_mint, interest_rate_bps, transfer_fee, max_supply, force_reinit, tx_options)
         }
-        Commands::Freeze { account, stablecoin } => {
-            let stablecoin_pubkey = stablecoin
+        Commands::Mint { recipient, amount, units, stablecoin, token_account } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            let amount = commands::resolve_amount(program, &amount, units, stablecoin_pubkey.as_ref())?;
+            commands::handle_mint(program, authority, &recipient, amount, token_account, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::Burn { amount, units, from, stablecoin } => {
+            let from_pubkey = from
                 .map(|s| parse_pubkey(&s))
                 .transpose()?;
-            commands::handle_freeze(&program, &authority, &account, stablecoin_pubkey.as_ref())
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            let amount = commands::resolve_amount(program, &amount, units, stablecoin_pubkey.as_ref())?;
+            commands::handle_burn(program, authority, amount, from_pubkey.as_ref(), stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::Freeze { account, reason, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_freeze(program, authority, &account, reason, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::FreezeBatch { file, reason, batch_size, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_freeze_batch(program, authority, commands::FreezeBatchAction::Freeze, &file, &reason, batch_size, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::ThawBatch { file, batch_size, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_freeze_batch(program, authority, commands::FreezeBatchAction::Thaw, &file, "", batch_size, stablecoin_pubkey.as_ref(), tx_options)
         }
         Commands::Thaw { account, stablecoin } => {
-            let stablecoin_pubkey = stablecoin
-                .map(|s| parse_pubkey(&s))
-                .transpose()?;
-            commands::handle_thaw(&program, &authority, &account, stablecoin_pubkey.as_ref())
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_thaw(program, authority, &account, stablecoin_pubkey.as_ref(), tx_options)
         }
-        Commands::Pause { stablecoin } => {
-            let stablecoin_pubkey = stablecoin
-                .map(|s| parse_pubkey(&s))
-                .transpose()?;
-            commands::handle_pause(&program, &authority, stablecoin_pubkey.as_ref())
+        Commands::Pause { reason, until, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_pause(program, authority, reason, until, stablecoin_pubkey.as_ref(), tx_options)
         }
         Commands::Unpause { stablecoin } => {
-            let stablecoin_pubkey = stablecoin
-                .map(|s| parse_pubkey(&s))
-                .transpose()?;
-            commands::handle_unpause(&program, &authority, stablecoin_pubkey.as_ref())
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_unpause(program, authority, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::SetMaxTransfer { amount, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_set_max_transfer(program, authority, amount, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::SetMaxSupply { max_supply, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_set_max_supply(program, authority, max_supply, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::SetMinBurn { amount, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_set_min_burn(program, authority, amount, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::SetMinMint { amount, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_set_min_mint(program, authority, amount, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::ConfigureReserves { attester, max_staleness, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_configure_reserves(program, authority, &parse_pubkey(&attester)?, max_staleness, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::DisableReserves { stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_disable_reserves(program, authority, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::AttestReserves { amount, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_attest_reserves(program, authority, amount, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::SetCompliance { enabled, upgrade_preset, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_set_compliance(program, authority, enabled, upgrade_preset, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::SetInterestRate { rate_bps, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_set_interest_rate(program, authority, rate_bps, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::SetTransferFee { transfer_fee_bps, max_fee, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_set_transfer_fee(program, authority, transfer_fee_bps, max_fee, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::WithdrawWithheldFees { to, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_withdraw_withheld_fees(program, authority, &to, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::SetFee { fee_bps, treasury, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_set_fee(program, authority, fee_bps, &treasury, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::Close { yes, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_close(program, authority, yes, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::MigrateState { stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_migrate_state(program, authority, stablecoin_pubkey.as_ref(), tx_options)
         }
         Commands::Blacklist { command } => match command {
             BlacklistCommands::Add { account, reason, stablecoin } => {
-                let stablecoin_pubkey = stablecoin
-                    .map(|s| parse_pubkey(&s))
-                    .transpose()?;
-                commands::handle_blacklist_add(&program, &authority, &account, &reason, stablecoin_pubkey.as_ref())
+                let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+                commands::handle_blacklist_add(program, authority, &account, &reason, stablecoin_pubkey.as_ref(), tx_options)
             }
             BlacklistCommands::Remove { account, stablecoin } => {
-                let stablecoin_pubkey = stablecoin
-                    .map(|s| parse_pubkey(&s))
-                    .transpose()?;
-                commands::handle_blacklist_remove(&program, &authority, &account, stablecoin_pubkey.as_ref())
+                let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+                commands::handle_blacklist_remove(program, authority, &account, stablecoin_pubkey.as_ref(), tx_options)
             }
             BlacklistCommands::List { stablecoin } => {
-                let stablecoin_pubkey = stablecoin
-                    .map(|s| parse_pubkey(&s))
-                    .transpose()?;
-                commands::handle_blacklist_list(&program, &authority, stablecoin_pubkey.as_ref())
+                let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+                commands::handle_blacklist_list(program, authority, stablecoin_pubkey.as_ref())
             }
             BlacklistCommands::Check { account, stablecoin } => {
-                let stablecoin_pubkey = stablecoin
-                    .map(|s| parse_pubkey(&s))
-                    .transpose()?;
-                commands::handle_blacklist_check(&program, &authority, &account, stablecoin_pubkey.as_ref())
+                let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+                commands::handle_blacklist_check(program, authority, &account, stablecoin_pubkey.as_ref(), output)
+            }
+            BlacklistCommands::Import { file, reason, batch_size, stablecoin } => {
+                let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+                commands::handle_blacklist_import(program, authority, &file, &reason, batch_size, stablecoin_pubkey.as_ref(), tx_options)
+            }
+            BlacklistCommands::Recount { stablecoin } => {
+                let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+                commands::handle_blacklist_recount(program, authority, stablecoin_pubkey.as_ref(), tx_options)
+            }
+        },
+        Commands::Allowlist { command } => match command {
+            AllowlistCommands::Add { account, stablecoin } => {
+                let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+                commands::handle_allowlist_add(program, authority, &account, stablecoin_pubkey.as_ref(), tx_options)
+            }
+            AllowlistCommands::Remove { account, stablecoin } => {
+                let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+                commands::handle_allowlist_remove(program, authority, &account, stablecoin_pubkey.as_ref(), tx_options)
+            }
+            AllowlistCommands::Check { account, stablecoin } => {
+                let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+                commands::handle_allowlist_check(program, &account, stablecoin_pubkey.as_ref(), output)
+            }
+            AllowlistCommands::Mode { enabled, stablecoin } => {
+                let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+                commands::handle_allowlist_mode(program, authority, enabled, stablecoin_pubkey.as_ref(), tx_options)
             }
         },
         Commands::Minters { command } => match command {
             MinterCommands::Add { account, quota, stablecoin } => {
-                let stablecoin_pubkey = stablecoin
-                    .map(|s| parse_pubkey(&s))
-                    .transpose()?;
-                commands::handle_minter_add(&program, &authority, &account, quota, stablecoin_pubkey.as_ref())
+                let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+                commands::handle_minter_add(program, authority, &account, quota, stablecoin_pubkey.as_ref(), tx_options)
             }
             MinterCommands::Remove { account, stablecoin } => {
-                let stablecoin_pubkey = stablecoin
-                    .map(|s| parse_pubkey(&s))
-                    .transpose()?;
-                commands::handle_minter_remove(&program, &authority, &account, stablecoin_pubkey.as_ref())
+                let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+                commands::handle_minter_remove(program, authority, &account, stablecoin_pubkey.as_ref(), tx_options)
             }
             MinterCommands::List { stablecoin } => {
-                let stablecoin_pubkey = stablecoin
-                    .map(|s| parse_pubkey(&s))
-                    .transpose()?;
-                commands::handle_minter_list(&program, &authority, stablecoin_pubkey.as_ref())
+                let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+                commands::handle_minter_list(program, authority, stablecoin_pubkey.as_ref())
             }
             MinterCommands::Info { account, stablecoin } => {
-                let stablecoin_pubkey = stablecoin
-                    .map(|s| parse_pubkey(&s))
-                    .transpose()?;
-                commands::handle_minter_info(&program, &authority, &account, stablecoin_pubkey.as_ref())
+                let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+                commands::handle_minter_info(program, authority, &account, stablecoin_pubkey.as_ref(), output)
             }
             MinterCommands::SetQuota { account, quota, stablecoin } => {
-                let stablecoin_pubkey = stablecoin
-                    .map(|s| parse_pubkey(&s))
-                    .transpose()?;
-                commands::handle_minter_set_quota(&program, &authority, &account, quota, stablecoin_pubkey.as_ref())
+                let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+                commands::handle_minter_set_quota(program, authority, &account, quota, stablecoin_pubkey.as_ref(), tx_options)
+            }
+            MinterCommands::Rotate { from, to, stablecoin } => {
+                let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+                commands::handle_minter_rotate(program, authority, &from, &to, stablecoin_pubkey.as_ref(), tx_options)
+            }
+            MinterCommands::Recount { stablecoin } => {
+                let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+                commands::handle_minter_recount(program, authority, stablecoin_pubkey.as_ref(), tx_options)
             }
         },
-        Commands::Seize { account, to, amount, stablecoin } => {
-            let stablecoin_pubkey = stablecoin
+        Commands::Seize { account, to, amount, units, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            let amount = commands::resolve_amount(program, &amount, units, stablecoin_pubkey.as_ref())?;
+            commands::handle_seize(program, authority, &account, &to, amount, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::RescueTokens { mint, amount, to, source, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            let source_pubkey = source
                 .map(|s| parse_pubkey(&s))
                 .transpose()?;
-            commands::handle_seize(&program, &authority, &account, &to, amount, stablecoin_pubkey.as_ref())
+            commands::handle_rescue_tokens(program, authority, &parse_pubkey(&mint)?, amount, &parse_pubkey(&to)?, source_pubkey.as_ref(), stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::Confiscate { account, amount, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_confiscate(program, authority, &account, amount, stablecoin_pubkey.as_ref(), tx_options)
         }
         Commands::TransferAuthority { new_authority, stablecoin } => {
-            let stablecoin_pubkey = stablecoin
-                .map(|s| parse_pubkey(&s))
-                .transpose()?;
-            commands::handle_transfer_authority(&program, &authority, &new_authority, stablecoin_pubkey.as_ref())
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_transfer_authority(program, authority, &new_authority, stablecoin_pubkey.as_ref(), tx_options)
         }
-        Commands::AssignRole { role, account, stablecoin } => {
+        Commands::AssignRole { role, account, expires_at, stablecoin } => {
             let role_enum = parse_role(&role)?;
-            let stablecoin_pubkey = stablecoin
-                .map(|s| parse_pubkey(&s))
-                .transpose()?;
-            commands::handle_assign_role(&program, &authority, role_enum, &account, stablecoin_pubkey.as_ref())
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_assign_role(program, authority, role_enum, &account, expires_at, stablecoin_pubkey.as_ref(), tx_options)
         }
-        Commands::RevokeRole { account, stablecoin } => {
-            let stablecoin_pubkey = stablecoin
-                .map(|s| parse_pubkey(&s))
-                .transpose()?;
-            commands::handle_revoke_role(&program, &authority, &account, stablecoin_pubkey.as_ref())
+        Commands::RevokeRole { role, account, stablecoin } => {
+            let role_enum = parse_role(&role)?;
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_revoke_role(program, authority, role_enum, &account, stablecoin_pubkey.as_ref(), tx_options)
         }
-        Commands::Status { stablecoin, export } => {
-            let stablecoin_pubkey = stablecoin
-                .map(|s| parse_pubkey(&s))
-                .transpose()?;
-            commands::handle_status(&program, &authority, stablecoin_pubkey.as_ref(), export.as_deref())
+        Commands::CleanupExpiredRole { role, account, stablecoin } => {
+            let role_enum = parse_role(&role)?;
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_cleanup_expired_role(program, authority, role_enum, &account, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::RecountRoles { stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_role_recount(program, authority, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::Status { stablecoin, export, export_config, watch, interval } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            let watch_interval = watch.then(|| Duration::from_secs(interval));
+            commands::handle_status(
+                program,
+                authority,
+                stablecoin_pubkey.as_ref(),
+                export.as_deref(),
+                export_config.as_deref(),
+                watch_interval,
+                output,
+            )
+        }
+        Commands::Verify { config } => {
+            commands::handle_verify(program, &config, output)
+        }
+        Commands::SetupHook { stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_setup_hook(program, authority, stablecoin_pubkey.as_ref(), tx_options)
+        }
+        Commands::VerifyHook { stablecoin } => {
+            commands::handle_verify_hook(program, &parse_pubkey(&stablecoin)?, output)
+        }
+        Commands::DevnetAirdrop { amount } => {
+            commands::handle_devnet_airdrop(program, authority, amount, output)
+        }
+        Commands::Whoami { stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_whoami(program, authority, stablecoin_pubkey.as_ref(), output)
         }
         Commands::Supply { stablecoin } => {
-            let stablecoin_pubkey = stablecoin
-                .map(|s| parse_pubkey(&s))
-                .transpose()?;
-            commands::handle_supply(&program, &authority, stablecoin_pubkey.as_ref())
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_supply(program, authority, stablecoin_pubkey.as_ref(), output)
         }
-        Commands::Holders { min_balance, stablecoin } => {
-            let stablecoin_pubkey = stablecoin
-                .map(|s| parse_pubkey(&s))
-                .transpose()?;
-            commands::handle_holders(&program, &authority, min_balance, stablecoin_pubkey.as_ref())
+        Commands::Holders { min_balance, limit, format, stablecoin } => {
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_holders(program, authority, min_balance, limit, &format, stablecoin_pubkey.as_ref())
         }
         Commands::AuditLog { action, from, to, format, output } => {
             let from_pubkey = from
@@ -526,20 +1274,830 @@ fn main() -> anyhow::Result<()> {
             let to_pubkey = to
                 .map(|s| parse_pubkey(&s))
                 .transpose()?;
-            commands::handle_audit_log(&program, &authority, action.as_deref(), from_pubkey.as_ref(), to_pubkey.as_ref(), &format, output.as_deref())
+            commands::handle_audit_log(program, authority, action.as_deref(), from_pubkey.as_ref(), to_pubkey.as_ref(), &format, output.as_deref())
+        }
+        Commands::Decode { account, account_type } => {
+            commands::handle_decode(program, &account, &account_type)
         }
         Commands::Derive { stablecoin } => {
-            let stablecoin_pubkey = stablecoin
+            let stablecoin_pubkey = resolve_stablecoin(stablecoin, default_stablecoin)?;
+            commands::handle_derive(program, authority, stablecoin_pubkey.as_ref())
+        }
+        Commands::BuildTx { out, signer, nonce_account, command } => {
+            let command = parse_wrapped_command(&command)?;
+            if is_offline_command(&command) {
+                return Err(CliError::InvalidArg("build-tx needs a command that sends a transaction".to_string()));
+            }
+            let signer = signer
+                .map(|s| parse_pubkey(&s))
+                .transpose()?
+                .unwrap_or(*authority);
+            let nonce_pubkey = nonce_account
                 .map(|s| parse_pubkey(&s))
                 .transpose()?;
-            commands::handle_derive(&program, &authority, stablecoin_pubkey.as_ref())
+            let capture = tx_options.capturing();
+            run_command(program, &signer, command, output, &capture, default_stablecoin)?;
+            commands::handle_build_tx(program, &capture, &signer, nonce_pubkey.as_ref(), &out)
+        }
+        Commands::SignTx { .. } => unreachable!("sign-tx is handled before the client is set up"),
+        Commands::SubmitTx { input } => {
+            commands::handle_submit_tx(program, &input, tx_options)
+        }
+        Commands::Propose { vault, out, command } => {
+            let command = parse_wrapped_command(&command)?;
+            if is_offline_command(&command) {
+                return Err(CliError::InvalidArg("propose needs a command that sends a transaction".to_string()));
+            }
+            let vault = parse_pubkey(&vault)?;
+            let capture = tx_options.capturing();
+            run_command(program, &vault, command, output, &capture, default_stablecoin)?;
+            commands::handle_propose(program, &capture, &vault, &out)
         }
-    };
-    
-    if let Err(e) = result {
-        eprintln!("❌ Error: {}", e);
-        std::process::exit(1);
     }
-    
-    Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::InstructionData;
+    use solana_sdk::signature::write_keypair_file;
+    use sss_token::constants;
+
+    #[test]
+    fn test_base58_keypair_round_trip() {
+        let keypair = Keypair::new();
+        let loaded = keypair_from_base58(&keypair.to_base58_string()).expect("should decode");
+        assert_eq!(loaded.pubkey(), keypair.pubkey());
+        assert_eq!(loaded.to_bytes(), keypair.to_bytes());
+    }
+
+    #[test]
+    fn test_base58_keypair_rejects_garbage() {
+        assert!(keypair_from_base58("not-base58!").is_err());
+        assert!(keypair_from_base58(&bs58::encode([1u8; 16]).into_string()).is_err());
+    }
+
+    #[test]
+    fn test_resolve_signer_precedence() {
+        let file_keypair = Keypair::new();
+        let path = std::env::temp_dir().join(format!("sss-cli-test-{}.json", file_keypair.pubkey()));
+        write_keypair_file(&file_keypair, &path).expect("should write keypair");
+        let path = path.to_str().expect("utf-8 path");
+
+        let env_keypair = Keypair::new();
+        let b58 = env_keypair.to_base58_string();
+
+        // Flag and env path both win over the base58 secret
+        let loaded = resolve_signer(Some(path), None, Some(&b58), None).expect("should load");
+        assert_eq!(loaded.pubkey(), file_keypair.pubkey());
+        let loaded = resolve_signer(None, Some(path), Some(&b58), None).expect("should load");
+        assert_eq!(loaded.pubkey(), file_keypair.pubkey());
+
+        // Flag wins over the env path
+        assert!(resolve_signer(Some("/nonexistent/id.json"), Some(path), None, None).is_err());
+
+        let loaded = resolve_signer(None, None, Some(&b58), None).expect("should load");
+        assert_eq!(loaded.pubkey(), env_keypair.pubkey());
+
+        // The config file's keypair is used only when nothing else is set
+        let loaded = resolve_signer(None, None, Some(&b58), Some(path)).expect("should load");
+        assert_eq!(loaded.pubkey(), env_keypair.pubkey());
+        let loaded = resolve_signer(None, None, None, Some(path)).expect("should load");
+        assert_eq!(loaded.pubkey(), file_keypair.pubkey());
+
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_config_file_defaults() {
+        let stablecoin = Pubkey::new_unique();
+        let path = std::env::temp_dir().join(format!("sss-config-{}.toml", stablecoin));
+        std::fs::write(&path, format!(
+            "default_url = \"https://rpc.example.com\"\ndefault_keypair = \"~/issuer.json\"\ndefault_stablecoin = \"{}\"\n",
+            stablecoin
+        )).expect("should write config");
+
+        let config = config::load_config(path.to_str().expect("utf-8 path")).expect("should load");
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(config.default_url.as_deref(), Some("https://rpc.example.com"));
+        assert_eq!(config.default_keypair.as_deref(), Some("~/issuer.json"));
+
+        // Flag > env > config
+        let flag = Pubkey::new_unique();
+        let env = Pubkey::new_unique();
+        let fallback = default_stablecoin(None, &config);
+        assert_eq!(resolve_stablecoin(None, fallback.as_deref()).unwrap(), Some(stablecoin));
+        assert_eq!(resolve_stablecoin(Some(flag.to_string()), fallback.as_deref()).unwrap(), Some(flag));
+        let fallback = default_stablecoin(Some(env.to_string()), &config);
+        assert_eq!(resolve_stablecoin(None, fallback.as_deref()).unwrap(), Some(env));
+        assert_eq!(resolve_stablecoin(None, None).unwrap(), None);
+        assert!(resolve_stablecoin(None, Some("not-a-pubkey")).is_err());
+
+        // The config URL comes after the flags
+        assert_eq!(
+            resolve_rpc_url(Some("https://flag.example.com"), None, config.default_url.as_deref()),
+            "https://flag.example.com"
+        );
+
+        // Old key names still load; a missing file means no defaults
+        let legacy: SssConfig = toml::from_str("rpc_url = \"http://127.0.0.1:8899\"\nkeypair_path = \"id.json\"\n").expect("should parse");
+        assert_eq!(legacy.default_url.as_deref(), Some("http://127.0.0.1:8899"));
+        assert_eq!(legacy.default_keypair.as_deref(), Some("id.json"));
+        assert_eq!(config::load_config("/nonexistent/sss-config.toml").unwrap(), SssConfig::default());
+    }
+
+    #[test]
+    fn test_tx_options_skip_unset_and_zero() {
+        assert!(commands::TxOptions::default().compute_budget_instructions().is_empty());
+
+        let zero = commands::TxOptions { priority_fee: Some(0), compute_limit: Some(0), ..Default::default() };
+        assert!(zero.compute_budget_instructions().is_empty());
+
+        let both = commands::TxOptions { priority_fee: Some(5_000), compute_limit: Some(200_000), ..Default::default() };
+        let ixs = both.compute_budget_instructions();
+        assert_eq!(ixs.len(), 2);
+        assert!(ixs.iter().all(|ix| ix.program_id == solana_sdk::compute_budget::id()));
+    }
+
+    #[test]
+    fn test_output_flag_parsing() {
+        let cli = Cli::try_parse_from(["sss-token", "supply"]).expect("should parse");
+        assert_eq!(cli.output, commands::OutputFormat::Text);
+
+        let cli = Cli::try_parse_from(["sss-token", "--output", "json", "status"]).expect("should parse");
+        assert_eq!(cli.output, commands::OutputFormat::Json);
+
+        assert!(Cli::try_parse_from(["sss-token", "--output", "yaml", "status"]).is_err());
+
+        // audit-log keeps its own --output file flag
+        let cli = Cli::try_parse_from(["sss-token", "audit-log", "--output", "log.csv"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::AuditLog { output: Some(ref path), .. } if path == "log.csv"));
+    }
+
+    #[test]
+    fn test_mint_token_account_flag() {
+        let recipient = "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU";
+
+        let cli = Cli::try_parse_from(["sss-token", "mint", recipient, "100"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::Mint { token_account: false, .. }));
+
+        let cli = Cli::try_parse_from(["sss-token", "mint", recipient, "100", "--token-account"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::Mint { token_account: true, .. }));
+    }
+
+    #[test]
+    fn test_set_compliance_args() {
+        let cli = Cli::try_parse_from(["sss-token", "set-compliance", "true", "--upgrade-preset"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::SetCompliance { enabled: true, upgrade_preset: true, .. }));
+
+        let cli = Cli::try_parse_from(["sss-token", "set-compliance", "false"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::SetCompliance { enabled: false, upgrade_preset: false, .. }));
+
+        // The target state is required
+        assert!(Cli::try_parse_from(["sss-token", "set-compliance"]).is_err());
+    }
+
+    #[test]
+    fn test_set_min_amounts_args() {
+        let cli = Cli::try_parse_from(["sss-token", "set-min-burn", "1000"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::SetMinBurn { amount: 1000, stablecoin: None }));
+        let cli = Cli::try_parse_from(["sss-token", "set-min-mint", "0"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::SetMinMint { amount: 0, stablecoin: None }));
+        assert!(Cli::try_parse_from(["sss-token", "set-min-burn", "-1"]).is_err());
+
+        // Anchor discriminator, then the little-endian amount
+        let data = instructions::build_instruction_data("set_min_burn", instructions::SetMinBurnArgs { min_burn_amount: 1000 }).unwrap();
+        assert_eq!(data, sss_token::instruction::SetMinBurn { min_burn_amount: 1000 }.data());
+        assert_eq!(data[8..], 1000u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_reserve_commands_args() {
+        let attester = Pubkey::new_unique().to_string();
+        let cli = Cli::try_parse_from([
+            "sss-token", "configure-reserves", "--attester", &attester, "--max-staleness", "86400",
+        ]).expect("should parse");
+        assert!(matches!(cli.command, Commands::ConfigureReserves { max_staleness: 86400, .. }));
+        assert!(Cli::try_parse_from(["sss-token", "configure-reserves", "--max-staleness", "60"]).is_err());
+
+        let cli = Cli::try_parse_from(["sss-token", "attest-reserves", "5000000"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::AttestReserves { amount: 5_000_000, stablecoin: None }));
+
+        let cli = Cli::try_parse_from(["sss-token", "disable-reserves"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::DisableReserves { stablecoin: None }));
+
+        let data = instructions::build_instruction_data("attest_reserves", instructions::AttestReservesArgs { reserve_amount: 5_000_000 }).unwrap();
+        assert_eq!(data, sss_token::instruction::AttestReserves { reserve_amount: 5_000_000 }.data());
+        assert_eq!(data[8..], 5_000_000u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_expiring_role_args() {
+        let account = Pubkey::new_unique().to_string();
+        let cli = Cli::try_parse_from([
+            "sss-token", "assign-role", "minter", &account, "--expires-at", "1735689600",
+        ]).expect("should parse");
+        assert!(matches!(cli.command, Commands::AssignRole { expires_at: Some(1_735_689_600), .. }));
+        let cli = Cli::try_parse_from(["sss-token", "assign-role", "minter", &account]).expect("should parse");
+        assert!(matches!(cli.command, Commands::AssignRole { expires_at: None, .. }));
+
+        let cli = Cli::try_parse_from(["sss-token", "cleanup-expired-role", "minter", &account])
+            .expect("should parse");
+        assert!(matches!(cli.command, Commands::CleanupExpiredRole { stablecoin: None, .. }));
+
+        // After the discriminator, Option<i64> is a presence byte followed by the little-endian timestamp
+        let data = instructions::build_instruction_data(
+            "assign_role",
+            instructions::AssignRoleArgs { role: 1, expires_at: Some(1_735_689_600) },
+        ).unwrap();
+        assert_eq!(
+            data,
+            sss_token::instruction::AssignRole { role: sss_token::state::Role::Minter, expires_at: Some(1_735_689_600) }.data()
+        );
+        assert_eq!(data[8..10], [1, 1]);
+        assert_eq!(data[10..], 1_735_689_600i64.to_le_bytes());
+        let data = instructions::build_instruction_data("assign_role", instructions::AssignRoleArgs { role: 1, expires_at: None }).unwrap();
+        assert_eq!(data[8..], [1, 0]);
+    }
+
+    #[test]
+    fn test_minters_rotate_args() {
+        let from = Pubkey::new_unique().to_string();
+        let to = Pubkey::new_unique().to_string();
+        let cli = Cli::try_parse_from(["sss-token", "minters", "rotate", "--from", &from, "--to", &to])
+            .expect("should parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Minters { command: MinterCommands::Rotate { from: ref old, to: ref new, stablecoin: None } }
+                if *old == from && *new == to
+        ));
+        assert!(Cli::try_parse_from(["sss-token", "minters", "rotate", "--from", &from]).is_err());
+    }
+
+    #[test]
+    fn test_recount_args() {
+        let cli = Cli::try_parse_from(["sss-token", "minters", "recount"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::Minters { command: MinterCommands::Recount { stablecoin: None } }));
+        let cli = Cli::try_parse_from(["sss-token", "recount-roles", "--stablecoin", "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU"])
+            .expect("should parse");
+        assert!(matches!(cli.command, Commands::RecountRoles { stablecoin: Some(_) }));
+    }
+
+    #[test]
+    fn test_build_tx_wraps_a_command() {
+        let signer = "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU";
+
+        let cli = Cli::try_parse_from([
+            "sss-token", "build-tx", "--out", "message.bin", "--signer", signer, "pause", "--reason", "incident",
+        ]).expect("should parse");
+        match cli.command {
+            Commands::BuildTx { out, signer: Some(ref s), nonce_account: None, command } => {
+                assert_eq!(out, "message.bin");
+                assert_eq!(s, signer);
+                let command = parse_wrapped_command(&command).expect("should parse the wrapped command");
+                assert!(matches!(command, Commands::Pause { ref reason, .. } if reason == "incident"));
+            }
+            _ => panic!("expected build-tx"),
+        }
+
+        // The inner command is required
+        assert!(Cli::try_parse_from(["sss-token", "build-tx", "--out", "message.bin"]).is_err());
+
+        let cli = Cli::try_parse_from(["sss-token", "submit-tx", "--in", "signed.bin"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::SubmitTx { ref input } if input == "signed.bin"));
+    }
+
+    #[test]
+    fn test_role_pda_matches_program_seeds() {
+        // Same vector as the program and backend tests: all three derivations must agree
+        let program_id = Pubkey::try_from(PROGRAM_ID).expect("valid program id");
+        let stablecoin = Pubkey::try_from("7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU").expect("valid pubkey");
+        let account = Pubkey::try_from("9xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU").expect("valid pubkey");
+
+        let (pda, bump) = constants::role_pda(&stablecoin, &account, commands::Role::Minter.seed(), &program_id);
+        assert_eq!(pda.to_string(), "3ECT1sR2nYBKiJTUbELEtYuCzgTpANnFpcrY5sQjjS4G");
+        assert_eq!(bump, 251);
+
+        // Each role of the same account gets its own assignment
+        let (burner, _) = constants::role_pda(&stablecoin, &account, commands::Role::Burner.seed(), &program_id);
+        assert_ne!(pda, burner);
+
+        // The CLI's role seeds and wire values are the program's
+        let roles = [
+            (commands::Role::Master, sss_token::Role::Master),
+            (commands::Role::Minter, sss_token::Role::Minter),
+            (commands::Role::Burner, sss_token::Role::Burner),
+            (commands::Role::Blacklister, sss_token::Role::Blacklister),
+            (commands::Role::Pauser, sss_token::Role::Pauser),
+            (commands::Role::Seizer, sss_token::Role::Seizer),
+        ];
+        for (cli_role, program_role) in roles {
+            assert_eq!(cli_role.seed(), program_role.seed());
+            assert_eq!(cli_role.to_u8(), program_role.to_u8());
+        }
+        for value in 0..=u8::MAX {
+            assert_eq!(
+                commands::Role::from_u8(value).map(|role| role.to_u8()),
+                sss_token::Role::from_u8(value).map(|role| role.to_u8()),
+            );
+        }
+    }
+
+    #[test]
+    fn test_propose_labels_derived_pdas() {
+        let program_id = Pubkey::try_from(PROGRAM_ID).expect("valid program id");
+        let vault = Pubkey::new_unique();
+        let asset_mint = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+        let (stablecoin, _) = constants::stablecoin_pda(&asset_mint, &program_id);
+        let (entry, bump) = constants::blacklist_pda(&stablecoin, &account, &program_id);
+
+        let ix = solana_sdk::instruction::Instruction::new_with_bytes(
+            program_id,
+            &[],
+            vec![
+                solana_sdk::instruction::AccountMeta::new(vault, true),
+                solana_sdk::instruction::AccountMeta::new(stablecoin, false),
+                solana_sdk::instruction::AccountMeta::new(entry, false),
+                solana_sdk::instruction::AccountMeta::new_readonly(account, false),
+                solana_sdk::instruction::AccountMeta::new_readonly(asset_mint, false),
+            ],
+        );
+
+        let pdas = commands::find_derived_pdas(&program_id, &[ix]);
+        assert_eq!(pdas.len(), 2);
+        assert_eq!(pdas[0].address, stablecoin);
+        assert_eq!(pdas[0].seeds, vec!["stablecoin".to_string(), asset_mint.to_string()]);
+        assert_eq!(pdas[1], commands::DerivedPda {
+            address: entry,
+            kind: "blacklist",
+            seeds: vec!["blacklist".to_string(), stablecoin.to_string(), account.to_string()],
+            bump,
+        });
+
+        let cli = Cli::try_parse_from([
+            "sss-token", "propose", "--vault", vault.to_string().as_str(), "--out", "proposal.json", "unpause",
+        ]).expect("should parse");
+        match cli.command {
+            Commands::Propose { command, .. } => {
+                assert!(matches!(parse_wrapped_command(&command), Ok(Commands::Unpause { .. })));
+            }
+            _ => panic!("expected propose"),
+        }
+    }
+
+    #[test]
+    fn test_confirmation_flags() {
+        let cli = Cli::try_parse_from(["sss-token", "supply"]).expect("should parse");
+        assert_eq!(cli.confirm_timeout, 60);
+        assert_eq!(cli.max_resubmits, 2);
+        let defaults = commands::TxOptions::default();
+        assert_eq!(defaults.confirm_timeout.as_secs(), cli.confirm_timeout);
+        assert_eq!(defaults.max_resubmits, cli.max_resubmits);
+
+        let cli = Cli::try_parse_from(["sss-token", "supply", "--confirm-timeout", "15", "--max-resubmits", "0"])
+            .expect("should parse");
+        assert_eq!(cli.confirm_timeout, 15);
+        assert_eq!(cli.max_resubmits, 0);
+
+        assert!(Cli::try_parse_from(["sss-token", "--confirm-timeout", "0", "supply"]).is_err());
+    }
+
+    #[test]
+    fn test_tx_options_capture() {
+        let options = commands::TxOptions { priority_fee: Some(5_000), ..Default::default() };
+        assert!(options.take_captured().is_empty());
+
+        let capture = options.capturing();
+        assert_eq!(capture.priority_fee, Some(5_000));
+        capture.capture.as_ref().expect("capturing").borrow_mut().push(
+            solana_sdk::instruction::Instruction::new_with_bytes(Pubkey::new_unique(), &[], vec![]),
+        );
+
+        // Compute-budget instructions come first, and taking empties the buffer
+        let ixs = capture.take_captured();
+        assert_eq!(ixs.len(), 2);
+        assert_eq!(ixs[0].program_id, solana_sdk::compute_budget::id());
+        assert!(capture.take_captured().is_empty());
+    }
+
+    #[test]
+    fn test_parse_blacklist_import() {
+        let first = Keypair::new().pubkey();
+        let second = Keypair::new().pubkey();
+        let contents = format!("# sanctions list\n{}\n\n{}, OFAC match\n", first, second);
+
+        let entries = commands::parse_blacklist_import(&contents, "Bulk import").expect("should parse");
+        assert_eq!(entries, vec![
+            (first, "Bulk import".to_string()),
+            (second, "OFAC match".to_string()),
+        ]);
+
+        let err = commands::parse_blacklist_import("not-an-address\n", "Bulk import").unwrap_err();
+        assert!(err.to_string().contains("Line 1"));
+    }
+
+    #[test]
+    fn test_parse_account_list() {
+        let first = Keypair::new().pubkey();
+        let second = Keypair::new().pubkey();
+        let contents = format!("# incident 42\n{}\n\n  {}  \n{}\n", first, second, first);
+
+        let accounts = commands::parse_account_list(&contents).expect("should parse");
+        assert_eq!(accounts, vec![first, second]);
+
+        let err = commands::parse_account_list(&format!("{}\nnot-an-address\n", first)).unwrap_err();
+        assert!(err.to_string().contains("Line 2"));
+
+        let cli = Cli::try_parse_from(["sss-token", "thaw-batch", "accounts.txt", "--batch-size", "8"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::ThawBatch { batch_size: Some(8), .. }));
+    }
+
+    #[test]
+    fn test_holder_filters_follow_token_program() {
+        use solana_client::rpc_filter::RpcFilterType;
+
+        let mint = Pubkey::new_unique();
+        let legacy = commands::holder_filters(&spl_token::id(), &mint).expect("legacy filters");
+        assert_eq!(legacy.len(), 1);
+        assert!(legacy[0].contains(&RpcFilterType::DataSize(165)));
+
+        // Plain and extended Token-2022 accounts need separate scans
+        let token_2022 = commands::holder_filters(&spl_token_2022::id(), &mint).expect("2022 filters");
+        assert_eq!(token_2022.len(), 2);
+        assert!(token_2022[0].contains(&RpcFilterType::DataSize(165)));
+        assert!(!token_2022[1].iter().any(|f| matches!(f, RpcFilterType::DataSize(_))));
+
+        assert!(commands::holder_filters(&Pubkey::new_unique(), &mint).is_err());
+    }
+
+    #[test]
+    fn test_plan_batches_splits_on_size_and_compute() {
+        use solana_sdk::instruction::{AccountMeta, Instruction};
+
+        let payer = Keypair::new().pubkey();
+        let program_id = Pubkey::new_unique();
+        let ix = |data_len: usize| Instruction::new_with_bytes(
+            program_id,
+            &vec![0u8; data_len],
+            vec![AccountMeta::new(payer, true), AccountMeta::new(Pubkey::new_unique(), false)],
+        );
+        let lengths = |batches: Vec<std::ops::Range<usize>>| batches.into_iter().map(|r| r.len()).collect::<Vec<_>>();
+        let tx = commands::TxOptions::default();
+
+        // Small instructions are only capped by the batch size
+        let small: Vec<Instruction> = (0..7).map(|_| ix(16)).collect();
+        assert_eq!(lengths(commands::plan_batches(&small, &payer, 3, &tx)), vec![3, 3, 1]);
+
+        // ~300-byte instructions (long reasons) fit three to a packet
+        let large: Vec<Instruction> = (0..7).map(|_| ix(300)).collect();
+        let batches = commands::plan_batches(&large, &payer, commands::MAX_BATCH_SIZE, &tx);
+        assert_eq!(batches.first(), Some(&(0..3)));
+        assert_eq!(batches.iter().map(|r| r.len()).sum::<usize>(), 7);
+
+        // An instruction that can't share a transaction goes alone
+        let mixed = vec![ix(16), ix(1100), ix(16)];
+        assert_eq!(lengths(commands::plan_batches(&mixed, &payer, commands::MAX_BATCH_SIZE, &tx)), vec![1, 1, 1]);
+
+        // A compute limit caps instructions per transaction
+        let limited = commands::TxOptions { compute_limit: Some(2 * commands::BATCH_IX_COMPUTE_UNITS), ..Default::default() };
+        assert_eq!(lengths(commands::plan_batches(&small, &payer, commands::MAX_BATCH_SIZE, &limited)), vec![2, 2, 2, 1]);
+
+        assert!(commands::plan_batches(&[], &payer, 5, &tx).is_empty());
+    }
+
+    #[test]
+    fn test_init_force_reinit_flag() {
+        let args = ["sss-token", "init", "--name", "Test USD", "--symbol", "TUSD", "--uri", "https://example.com"];
+        let cli = Cli::try_parse_from(args).expect("should parse");
+        assert!(matches!(cli.command, Commands::Init { force_reinit: false, .. }));
+
+        let cli = Cli::try_parse_from(args.iter().copied().chain(["--force-reinit"])).expect("should parse");
+        assert!(matches!(cli.command, Commands::Init { force_reinit: true, .. }));
+    }
+
+    #[test]
+    fn test_pause_until_parse() {
+        let cli = Cli::try_parse_from(["sss-token", "pause", "--reason", "maintenance", "--until", "1900000000"])
+            .expect("should parse");
+        assert!(matches!(cli.command, Commands::Pause { until: Some(1_900_000_000), .. }));
+
+        let cli = Cli::try_parse_from(["sss-token", "pause"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::Pause { until: None, .. }));
+    }
+
+    #[test]
+    fn test_rescue_tokens_parse() {
+        let mint = Pubkey::new_unique().to_string();
+        let to = Pubkey::new_unique().to_string();
+        let cli = Cli::try_parse_from(["sss-token", "rescue-tokens", "250", "--mint", &mint, "--to", &to])
+            .expect("should parse");
+        match cli.command {
+            Commands::RescueTokens { mint: m, amount, to: t, source, stablecoin } => {
+                assert_eq!(m, mint);
+                assert_eq!(amount, 250);
+                assert_eq!(t, to);
+                assert!(source.is_none());
+                assert!(stablecoin.is_none());
+            }
+            _ => panic!("expected rescue-tokens"),
+        }
+    }
+
+    fn sample_deployment_config() -> commands::DeploymentConfig {
+        commands::DeploymentConfig {
+            program_id: Pubkey::new_unique().to_string(),
+            stablecoin_pda: Pubkey::new_unique().to_string(),
+            asset_mint: Pubkey::new_unique().to_string(),
+            authority: Pubkey::new_unique().to_string(),
+            decimals: 6,
+            preset: 2,
+            compliance_enabled: true,
+            paused: false,
+            total_supply: 1_000_000,
+            max_supply: 0,
+            max_transfer_amount: 50_000,
+            fee_bps: 10,
+            fee_treasury: Pubkey::new_unique().to_string(),
+            blacklist_count: 3,
+            minters: vec![commands::MinterConfig { minter: Pubkey::new_unique().to_string(), quota: 0 }],
+            roles: vec![commands::RoleConfig { account: Pubkey::new_unique().to_string(), role: "Pauser".to_string() }],
+        }
+    }
+
+    #[test]
+    fn test_deployment_config_round_trip() {
+        let config = sample_deployment_config();
+
+        let toml_text = config.to_string_for("config.toml").expect("should serialize");
+        assert_eq!(toml::from_str::<commands::DeploymentConfig>(&toml_text).unwrap(), config);
+
+        let json_text = config.to_string_for("config.JSON").expect("should serialize");
+        assert_eq!(serde_json::from_str::<commands::DeploymentConfig>(&json_text).unwrap(), config);
+
+        let cli = Cli::try_parse_from(["sss-token", "status", "--export-config", "config.toml"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::Status { export_config: Some(ref path), .. } if path == "config.toml"));
+        assert!(Cli::try_parse_from(["sss-token", "status", "--export-config", "c.toml", "--watch"]).is_err());
+    }
+
+    #[test]
+    fn test_diff_deployment() {
+        let expected = sample_deployment_config();
+        assert!(commands::diff_deployment(&expected, &expected.clone()).is_empty());
+
+        let mut actual = expected.clone();
+        actual.authority = Pubkey::new_unique().to_string();
+        actual.paused = true;
+        // Supply moves in normal operation and is not drift
+        actual.total_supply += 1;
+        actual.minters.clear();
+        let intruder = Pubkey::new_unique().to_string();
+        actual.roles.push(commands::RoleConfig { account: intruder.clone(), role: "Master".to_string() });
+
+        let drift = commands::diff_deployment(&expected, &actual);
+        let fields: Vec<&str> = drift.iter().map(|d| d.field.as_str()).collect();
+        assert_eq!(drift.len(), 4, "{:?}", fields);
+        assert!(fields.contains(&"authority"));
+        assert!(fields.contains(&"paused"));
+
+        let missing = drift.iter().find(|d| d.field.starts_with("minters.")).expect("missing minter");
+        assert_eq!((missing.expected.as_str(), missing.actual.as_str()), ("quota 0", "absent"));
+        let unexpected = drift.iter().find(|d| d.field == format!("roles.{}", intruder)).expect("unexpected role");
+        assert_eq!((unexpected.expected.as_str(), unexpected.actual.as_str()), ("absent", "Master"));
+
+        let cli = Cli::try_parse_from(["sss-token", "verify-deployment", "--config", "config.toml"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::Verify { ref config } if config == "config.toml"));
+    }
+
+    #[test]
+    fn test_extra_account_metas_round_trip() {
+        let stablecoin = Pubkey::new_unique();
+        let expected = commands::expected_extra_account_metas(&stablecoin);
+        assert_eq!(expected.len(), 7);
+        assert_eq!(expected[0].address_config, stablecoin.to_bytes());
+
+        // Blacklist seeds: literal "blacklist", state (account 5), token account
+        let source = &expected[1].address_config;
+        assert_eq!(expected[1].discriminator, 1);
+        assert_eq!(&source[..11], b"\x01\x09blacklist");
+        assert_eq!(&source[11..15], &[3, 5, 3, 0]);
+        assert!(source[15..].iter().all(|b| *b == 0));
+        assert_eq!(&expected[2].address_config[11..15], &[3, 5, 3, 2]);
+
+        // Allowlist seeds follow the same layout
+        let source = &expected[3].address_config;
+        assert_eq!(&source[..11], b"\x01\x09allowlist");
+        assert_eq!(&source[11..15], &[3, 5, 3, 0]);
+        assert_eq!(&expected[4].address_config[11..15], &[3, 5, 3, 2]);
+
+        // Owner blacklist seeds read the owner from bytes 32..64 of the token account
+        let source = &expected[5].address_config;
+        assert_eq!(&source[..11], b"\x01\x09blacklist");
+        assert_eq!(&source[11..17], &[3, 5, 4, 0, 32, 32]);
+        assert!(source[17..].iter().all(|b| *b == 0));
+        assert_eq!(&expected[6].address_config[11..17], &[3, 5, 4, 2, 32, 32]);
+
+        // TLV layout written by spl-tlv-account-resolution for the Execute entry
+        use sha2::{Digest, Sha256};
+        let mut value = (expected.len() as u32).to_le_bytes().to_vec();
+        for meta in &expected {
+            value.push(meta.discriminator);
+            value.extend_from_slice(&meta.address_config);
+            value.extend_from_slice(&[meta.is_signer as u8, meta.is_writable as u8]);
+        }
+        let mut data = Sha256::digest(b"spl-transfer-hook-interface:execute")[..8].to_vec();
+        data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        data.extend_from_slice(&value);
+        assert_eq!(commands::parse_extra_account_metas(&data).expect("should parse"), expected);
+
+        assert!(commands::parse_extra_account_metas(&data[..data.len() - 1]).is_err());
+        assert!(commands::parse_extra_account_metas(&[0u8; 16]).is_err());
+
+        let address = stablecoin.to_string();
+        let cli = Cli::try_parse_from(["sss-token", "verify-hook", "--stablecoin", &address]).expect("should parse");
+        assert!(matches!(cli.command, Commands::VerifyHook { ref stablecoin } if *stablecoin == address));
+        let cli = Cli::try_parse_from(["sss-token", "setup-hook", "--stablecoin", &address]).expect("should parse");
+        assert!(matches!(cli.command, Commands::SetupHook { stablecoin: Some(ref s) } if *s == address));
+    }
+
+    #[test]
+    fn test_devnet_airdrop_refuses_mainnet() {
+        assert!(commands::is_mainnet_url("https://api.mainnet-beta.solana.com"));
+        assert!(commands::is_mainnet_url("https://example-Mainnet.rpc.io/?key=abc"));
+        assert!(!commands::is_mainnet_url("https://api.devnet.solana.com"));
+        assert!(!commands::is_mainnet_url("http://127.0.0.1:8899"));
+
+        let cli = Cli::try_parse_from(["sss-token", "devnet-airdrop"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::DevnetAirdrop { amount } if amount == 1.0));
+        let cli = Cli::try_parse_from(["sss-token", "devnet-airdrop", "--amount", "2.5"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::DevnetAirdrop { amount } if amount == 2.5));
+    }
+
+    #[test]
+    fn test_cluster_flag() {
+        use commands::ClusterName;
+
+        let cli = Cli::try_parse_from(["sss-token", "--cluster", "mainnet", "status"]).expect("should parse");
+        assert_eq!(cli.cluster, Some(ClusterName::Mainnet));
+        assert_eq!(
+            resolve_rpc_url(cli.url.as_deref(), cli.cluster, None),
+            "https://api.mainnet-beta.solana.com"
+        );
+        assert_eq!(resolve_rpc_url(None, Some(ClusterName::Localnet), None), "http://127.0.0.1:8899");
+        assert_eq!(
+            resolve_rpc_url(Some("https://rpc.example.com"), None, None),
+            "https://rpc.example.com"
+        );
+        assert!(Cli::try_parse_from([
+            "sss-token", "--cluster", "devnet", "--url", "https://rpc.example.com", "status"
+        ]).is_err());
+        assert!(Cli::try_parse_from(["sss-token", "--cluster", "mainnet-beta", "status"]).is_err());
+
+        for cluster in [ClusterName::Devnet, ClusterName::Testnet, ClusterName::Mainnet, ClusterName::Localnet] {
+            assert_eq!(ClusterName::from_url(cluster.url()), cluster);
+        }
+        assert_eq!(ClusterName::from_url("https://my-rpc.example.com"), ClusterName::Devnet);
+        assert_eq!(ClusterName::from_url("http://localhost:8899"), ClusterName::Localnet);
+    }
+
+    #[test]
+    fn test_mainnet_guard() {
+        let mainnet = commands::ClusterName::Mainnet.url();
+        let devnet = commands::ClusterName::Devnet.url();
+        let address = Pubkey::new_unique().to_string();
+
+        let cli = Cli::try_parse_from(["sss-token", "--cluster", "mainnet", "transfer-authority", &address])
+            .expect("should parse");
+        assert!(!cli.i_understand_mainnet);
+        assert!(matches!(
+            check_mainnet_guard(mainnet, &cli.command, cli.i_understand_mainnet),
+            Err(CliError::NotAllowed(_))
+        ));
+        assert!(check_mainnet_guard(devnet, &cli.command, cli.i_understand_mainnet).is_ok());
+
+        let cli = Cli::try_parse_from(["sss-token", "close", "--yes", "--i-understand-mainnet"])
+            .expect("should parse");
+        assert!(cli.i_understand_mainnet);
+        assert!(check_mainnet_guard(mainnet, &cli.command, cli.i_understand_mainnet).is_ok());
+
+        for args in [
+            vec!["sss-token", "seize", address.as_str(), "--to", address.as_str(), "1"],
+            vec!["sss-token", "close"],
+            vec!["sss-token", "freeze-batch", "accounts.txt"],
+            vec!["sss-token", "thaw-batch", "accounts.txt"],
+            vec!["sss-token", "blacklist", "import", "accounts.txt"],
+        ] {
+            let cli = Cli::try_parse_from(args).expect("should parse");
+            assert!(requires_mainnet_confirmation(&cli.command));
+        }
+        let cli = Cli::try_parse_from(["sss-token", "freeze", &address]).expect("should parse");
+        assert!(!requires_mainnet_confirmation(&cli.command));
+        assert!(check_mainnet_guard(mainnet, &cli.command, false).is_ok());
+    }
+
+    #[test]
+    fn test_migrate_state_parse() {
+        let stablecoin = Pubkey::new_unique().to_string();
+        let cli = Cli::try_parse_from(["sss-token", "migrate-state", "--stablecoin", &stablecoin])
+            .expect("should parse");
+        match &cli.command {
+            Commands::MigrateState { stablecoin: s } => assert_eq!(s.as_deref(), Some(stablecoin.as_str())),
+            _ => panic!("expected migrate-state"),
+        }
+        assert!(!requires_mainnet_confirmation(&cli.command));
+    }
+
+    #[test]
+    fn test_audit_record() {
+        let stablecoin = Pubkey::new_unique().to_string();
+        let matches = Cli::command()
+            .try_get_matches_from(["sss-token", "--audit-file", "audit.jsonl", "blacklist", "add", "acct", "--reason", "ofac", "--stablecoin", &stablecoin])
+            .expect("should parse");
+        let cli = Cli::from_arg_matches(&matches).expect("should parse");
+        assert_eq!(cli.audit_file.as_deref(), Some("audit.jsonl"));
+
+        let signature = Signature::new_unique();
+        let record = AuditRecord::new(&matches, &[signature], &Ok(()));
+        assert_eq!(record.command, "blacklist add");
+        assert_eq!(record.stablecoin.as_deref(), Some(stablecoin.as_str()));
+        assert_eq!(record.signatures, vec![signature.to_string()]);
+        assert!(record.success);
+        assert!(record.error.is_none());
+
+        let matches = Cli::command()
+            .try_get_matches_from(["sss-token", "build-tx", "--out", "tx.bin", "pause"])
+            .expect("should parse");
+        let record = AuditRecord::new(&matches, &[], &Err(CliError::InvalidArg("bad".to_string())));
+        assert_eq!(record.command, "build-tx pause");
+        assert!(record.stablecoin.is_none());
+        assert!(!record.success);
+        assert!(record.error.as_deref().unwrap().contains("bad"));
+
+        let path = std::env::temp_dir().join(format!("sss-audit-{}.jsonl", Pubkey::new_unique()));
+        let path = path.to_str().unwrap();
+        write_audit_record(path, &matches, &[], &Ok(()));
+        write_audit_record(path, &matches, &[], &Ok(()));
+        let contents = std::fs::read_to_string(path).expect("audit file written");
+        std::fs::remove_file(path).ok();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let line: serde_json::Value = serde_json::from_str(lines[0]).expect("valid JSON");
+        assert_eq!(line["command"], "build-tx pause");
+        assert_eq!(line["success"], true);
+    }
+
+    #[test]
+    fn test_amount_units() {
+        use commands::{parse_token_amount, AmountUnits};
+
+        assert_eq!(parse_token_amount("1.5", 6).unwrap(), 1_500_000);
+        assert_eq!(parse_token_amount("1_000", 6).unwrap(), 1_000_000_000);
+        assert_eq!(parse_token_amount(".25", 2).unwrap(), 25);
+        assert_eq!(parse_token_amount("2.", 2).unwrap(), 200);
+        assert_eq!(parse_token_amount("0.000001", 6).unwrap(), 1);
+        assert_eq!(parse_token_amount("1_000_000", 0).unwrap(), 1_000_000);
+        assert!(matches!(parse_token_amount("1.0000001", 6), Err(CliError::InvalidArg(_))));
+        assert!(matches!(parse_token_amount("1.5", 0), Err(CliError::InvalidArg(_))));
+        assert!(matches!(parse_token_amount("18446744073709.551616", 6), Err(CliError::InvalidArg(_))));
+        for bad in ["", ".", "-1", "1.2.3", "1e6", "abc"] {
+            assert!(matches!(parse_token_amount(bad, 6), Err(CliError::InvalidArg(_))), "{}", bad);
+        }
+
+        let recipient = "11111111111111111111111111111111";
+        let cli = Cli::try_parse_from(["sss-token", "mint", recipient, "100"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::Mint { units: AmountUnits::Base, .. }));
+        let cli = Cli::try_parse_from(["sss-token", "burn", "1.5", "--units", "human"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::Burn { ref amount, units: AmountUnits::Human, .. } if amount == "1.5"));
+        let cli = Cli::try_parse_from(["sss-token", "seize", recipient, "--to", recipient, "1_000", "--units", "base"])
+            .expect("should parse");
+        assert!(matches!(cli.command, Commands::Seize { ref amount, units: AmountUnits::Base, .. } if amount == "1_000"));
+        assert!(Cli::try_parse_from(["sss-token", "mint", recipient, "1", "--units", "tokens"]).is_err());
+    }
+
+    #[test]
+    fn test_whoami_args() {
+        let cli = Cli::try_parse_from(["sss-token", "whoami"]).expect("should parse");
+        assert!(matches!(cli.command, Commands::Whoami { stablecoin: None }));
+
+        let address = Pubkey::new_unique().to_string();
+        let cli = Cli::try_parse_from(["sss-token", "--output", "json", "whoami", "--stablecoin", &address])
+            .expect("should parse");
+        assert!(matches!(cli.command, Commands::Whoami { stablecoin: Some(ref s) } if *s == address));
+        assert!(cli.output.is_json());
+    }
+
+    #[test]
+    fn test_allowlist_args() {
+        let address = Pubkey::new_unique().to_string();
+        let cli = Cli::try_parse_from(["sss-token", "allowlist", "mode", "true", "--stablecoin", &address])
+            .expect("should parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Allowlist { command: AllowlistCommands::Mode { enabled: true, stablecoin: Some(ref s) } } if *s == address
+        ));
+        assert!(Cli::try_parse_from(["sss-token", "allowlist", "mode"]).is_err());
+
+        let cli = Cli::try_parse_from(["sss-token", "allowlist", "add", &address]).expect("should parse");
+        assert!(matches!(cli.command, Commands::Allowlist { command: AllowlistCommands::Add { ref account, .. } } if *account == address));
+    }
+}
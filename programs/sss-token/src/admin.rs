@@ -1,7 +1,17 @@
+use crate::constants::{
+    BPS_DENOMINATOR, LEGACY_STATE_SPACE, NO_PAUSE_EXPIRY, PRESET_SSS_2, UNCAPPED_SUPPLY,
+    VAULT_SEED,
+};
 use crate::error::StablecoinError;
 use crate::events::*;
+use crate::extensions;
+use crate::math::validate_fee_treasury;
 use crate::state::*;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{self, Mint, SetAuthority, TokenAccount, TokenInterface};
 
 #[derive(Accounts)]
 pub struct Admin<'info> {
@@ -15,14 +25,105 @@ pub struct Admin<'info> {
     pub state: Account<'info, StablecoinState>,
 }
 
-pub fn pause(ctx: Context<Admin>) -> Result<()> {
+#[derive(Accounts)]
+pub struct SetFee<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ StablecoinError::Unauthorized
+    )]
+    pub state: Account<'info, StablecoinState>,
+
+    /// Treasury token account, required when `fee_bps > 0`
+    pub fee_treasury: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseStablecoin<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ StablecoinError::Unauthorized,
+        has_one = asset_mint,
+        close = authority
+    )]
+    pub state: Account<'info, StablecoinState>,
+
+    /// Its mint and freeze authorities are revoked so the closed PDA can't be
+    /// reused by a fresh `initialize` on the same mint
+    #[account(mut)]
+    pub asset_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateState<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: an older layout `Account<StablecoinState>` cannot deserialize;
+    /// discriminator, authority and PDA are checked in `migrate_state`
+    #[account(mut, owner = crate::ID)]
+    pub state: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Leading `StablecoinState` fields, identical in every layout
+#[derive(AnchorDeserialize)]
+struct StateHeader {
+    authority: Pubkey,
+    asset_mint: Pubkey,
+    _total_supply: u64,
+    _paused: bool,
+    _preset: u8,
+    _compliance_enabled: bool,
+    bump: u8,
+}
+
+#[derive(Accounts)]
+pub struct UpdateInterestRate<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority @ StablecoinError::Unauthorized,
+        has_one = asset_mint
+    )]
+    pub state: Account<'info, StablecoinState>,
+
+    /// CHECK: Token-2022 mint with the interest-bearing extension, validated by the token program
+    #[account(mut)]
+    pub asset_mint: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Pause the stablecoin, indefinitely or until the `until` timestamp.
+/// A pause that has lifted on its own can be replaced by a new one.
+pub fn pause(ctx: Context<Admin>, reason: String, until: i64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        until == NO_PAUSE_EXPIRY || until > now,
+        StablecoinError::InvalidPauseExpiry
+    );
     let state = &mut ctx.accounts.state;
-    require!(!state.paused, StablecoinError::VaultPaused);
+    require!(!state.is_paused(now), StablecoinError::VaultPaused);
     state.paused = true;
+    state.paused_by = ctx.accounts.authority.key();
+    state.paused_at = now;
+    state.set_pause_reason(&reason)?;
+    state.pause_until = until;
 
-    emit!(Paused {
+    emit_event(Paused {
         stablecoin: state.key(),
         authority: ctx.accounts.authority.key(),
+        reason,
+        until,
     });
     Ok(())
 }
@@ -31,23 +132,280 @@ pub fn unpause(ctx: Context<Admin>) -> Result<()> {
     let state = &mut ctx.accounts.state;
     require!(state.paused, StablecoinError::VaultPaused);
     state.paused = false;
+    state.paused_by = Pubkey::default();
+    state.paused_at = 0;
+    state.set_pause_reason("")?;
+    state.pause_until = NO_PAUSE_EXPIRY;
 
-    emit!(Unpaused {
+    emit_event(Unpaused {
         stablecoin: state.key(),
         authority: ctx.accounts.authority.key(),
     });
     Ok(())
 }
 
+/// Hand the stablecoin to `new_authority`. Irreversible, so the default key
+/// (which nobody can sign for) and the current authority are rejected.
 pub fn transfer_authority(ctx: Context<Admin>, new_authority: Pubkey) -> Result<()> {
     let state = &mut ctx.accounts.state;
     let old_authority = state.authority;
+    require!(
+        new_authority != Pubkey::default() && new_authority != old_authority,
+        StablecoinError::InvalidAuthority
+    );
     state.authority = new_authority;
 
-    emit!(AuthorityTransferred {
+    emit_event(AuthorityTransferred {
         stablecoin: state.key(),
         old_authority,
         new_authority,
     });
     Ok(())
 }
+
+pub fn set_max_transfer(ctx: Context<Admin>, amount: u64) -> Result<()> {
+    let state = &mut ctx.accounts.state;
+    let old_amount = state.max_transfer_amount;
+    state.max_transfer_amount = amount;
+
+    emit_event(MaxTransferUpdated {
+        stablecoin: state.key(),
+        old_amount,
+        new_amount: amount,
+    });
+    Ok(())
+}
+
+/// Set the smallest amount a single burn may request (`NO_MINIMUM_AMOUNT` removes the floor)
+pub fn set_min_burn(ctx: Context<Admin>, min_burn_amount: u64) -> Result<()> {
+    let state = &mut ctx.accounts.state;
+    let old_amount = state.min_burn_amount;
+    state.min_burn_amount = min_burn_amount;
+
+    emit_event(MinBurnUpdated {
+        stablecoin: state.key(),
+        old_amount,
+        new_amount: min_burn_amount,
+    });
+    Ok(())
+}
+
+/// Set the smallest amount a single mint may request (`NO_MINIMUM_AMOUNT` removes the floor)
+pub fn set_min_mint(ctx: Context<Admin>, min_mint_amount: u64) -> Result<()> {
+    let state = &mut ctx.accounts.state;
+    let old_amount = state.min_mint_amount;
+    state.min_mint_amount = min_mint_amount;
+
+    emit_event(MinMintUpdated {
+        stablecoin: state.key(),
+        old_amount,
+        new_amount: min_mint_amount,
+    });
+    Ok(())
+}
+
+/// Set the authorized supply ceiling (`UNCAPPED_SUPPLY` removes it).
+/// A cap cannot be set below the supply already outstanding.
+pub fn set_max_supply(ctx: Context<Admin>, max_supply: u64) -> Result<()> {
+    let state = &mut ctx.accounts.state;
+    require!(
+        max_supply == UNCAPPED_SUPPLY || max_supply >= state.total_supply,
+        StablecoinError::MaxSupplyBelowSupply
+    );
+    let old_max_supply = state.max_supply;
+    state.max_supply = max_supply;
+
+    emit_event(MaxSupplyUpdated {
+        stablecoin: state.key(),
+        old_max_supply,
+        new_max_supply: max_supply,
+    });
+    Ok(())
+}
+
+/// Turn SSS-2 compliance checks on or off after initialization.
+/// Enabling with `upgrade_preset` also records the coin as SSS-2 (ignored when
+/// disabling). Compliance stays on while any blacklist entry exists.
+pub fn set_compliance(ctx: Context<Admin>, enabled: bool, upgrade_preset: bool) -> Result<()> {
+    let state = &mut ctx.accounts.state;
+    require!(
+        state.compliance_enabled != enabled,
+        StablecoinError::ComplianceUnchanged
+    );
+    if !enabled {
+        require!(
+            state.blacklist_count == 0,
+            StablecoinError::BlacklistNotEmpty
+        );
+    }
+
+    let old_preset = state.preset;
+    state.compliance_enabled = enabled;
+    if enabled && upgrade_preset {
+        state.preset = PRESET_SSS_2;
+    }
+
+    emit_event(ComplianceToggled {
+        stablecoin: state.key(),
+        authority: ctx.accounts.authority.key(),
+        enabled,
+        old_preset,
+        new_preset: state.preset,
+    });
+    Ok(())
+}
+
+/// Set the mint/burn fee. A non-zero fee needs the treasury passed as a token
+/// account of the stablecoin's mint, so fees can't be lost to an unusable account.
+pub fn set_fee(ctx: Context<SetFee>, fee_bps: u16, fee_treasury: Pubkey) -> Result<()> {
+    require!(fee_bps <= BPS_DENOMINATOR, StablecoinError::InvalidBasisPoints);
+
+    let state = &mut ctx.accounts.state;
+    if fee_bps > 0 {
+        let treasury = ctx
+            .accounts
+            .fee_treasury
+            .as_ref()
+            .ok_or(StablecoinError::InvalidFeeTreasury)?;
+        require_keys_eq!(treasury.key(), fee_treasury, StablecoinError::InvalidFeeTreasury);
+        validate_fee_treasury(&treasury.mint, &state.asset_mint)?;
+    }
+    state.fee_bps = fee_bps;
+    state.fee_treasury = fee_treasury;
+
+    emit_event(FeeUpdated {
+        stablecoin: state.key(),
+        fee_bps,
+        fee_treasury,
+    });
+    Ok(())
+}
+
+pub fn close_stablecoin(ctx: Context<CloseStablecoin>) -> Result<()> {
+    let state = &ctx.accounts.state;
+    require!(state.total_supply == 0, StablecoinError::SupplyNotZero);
+    require!(
+        !state.is_paused(Clock::get()?.unix_timestamp),
+        StablecoinError::VaultPaused
+    );
+    // Counters of older states may miss accounts until recounted
+    require!(
+        state.minter_count_verified && state.role_count_verified,
+        StablecoinError::CountersNotVerified
+    );
+    require!(state.minter_count == 0, StablecoinError::OutstandingMinters);
+    require!(state.role_count == 0, StablecoinError::OutstandingRoles);
+
+    let asset_mint = &ctx.accounts.asset_mint;
+    let asset_mint_key = state.asset_mint;
+    let authority_seeds = &[VAULT_SEED, asset_mint_key.as_ref(), &[state.bump]];
+    let signer = &[&authority_seeds[..]];
+    let held = [
+        (asset_mint.mint_authority, AuthorityType::MintTokens),
+        (asset_mint.freeze_authority, AuthorityType::FreezeAccount),
+    ];
+    for (current, authority_type) in held {
+        if current != COption::Some(state.key()) {
+            continue;
+        }
+        token_interface::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: state.to_account_info(),
+                    account_or_mint: asset_mint.to_account_info(),
+                },
+                signer,
+            ),
+            authority_type,
+            None,
+        )?;
+    }
+
+    emit_event(StablecoinClosed {
+        stablecoin: state.key(),
+        authority: ctx.accounts.authority.key(),
+        lamports: state.to_account_info().lamports(),
+    });
+    Ok(())
+}
+
+/// Grow a state account created with the `LEGACY_STATE_SPACE` layout to the
+/// current size. The appended fields read as zero, which is each one's
+/// "off" value. The authority pays the extra rent.
+pub fn migrate_state(ctx: Context<MigrateState>) -> Result<()> {
+    let state = &ctx.accounts.state;
+    let authority = &ctx.accounts.authority;
+    let old_len = state.data_len();
+    let new_len = 8 + StablecoinState::INIT_SPACE;
+
+    let header = {
+        let data = state.try_borrow_data()?;
+        require!(
+            data.len() > 8 && data.starts_with(StablecoinState::DISCRIMINATOR),
+            StablecoinError::InvalidStateAccount
+        );
+        StateHeader::deserialize(&mut &data[8..])?
+    };
+    require_keys_eq!(
+        header.authority,
+        authority.key(),
+        StablecoinError::Unauthorized
+    );
+    let expected = Pubkey::create_program_address(
+        &[VAULT_SEED, header.asset_mint.as_ref(), &[header.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| StablecoinError::InvalidStateAccount)?;
+    require_keys_eq!(expected, state.key(), StablecoinError::InvalidStateAccount);
+    require!(old_len != new_len, StablecoinError::StateAlreadyMigrated);
+    require!(
+        old_len == LEGACY_STATE_SPACE,
+        StablecoinError::InvalidStateAccount
+    );
+
+    let shortfall = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(state.lamports());
+    if shortfall > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: authority.to_account_info(),
+                    to: state.to_account_info(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    state.to_account_info().resize(new_len)?;
+
+    emit_event(StateMigrated {
+        stablecoin: state.key(),
+        authority: authority.key(),
+        old_len: old_len as u64,
+        new_len: new_len as u64,
+    });
+    Ok(())
+}
+
+pub fn update_interest_rate(ctx: Context<UpdateInterestRate>, rate_bps: i16) -> Result<()> {
+    let state = &ctx.accounts.state;
+    let asset_mint_key = state.asset_mint;
+    let authority_seeds = &[VAULT_SEED, asset_mint_key.as_ref(), &[state.bump]];
+
+    extensions::update_interest_rate(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.asset_mint.to_account_info(),
+        &state.to_account_info(),
+        &[&authority_seeds[..]],
+        rate_bps,
+    )?;
+
+    emit_event(InterestRateUpdated {
+        stablecoin: state.key(),
+        rate_bps,
+    });
+    Ok(())
+}
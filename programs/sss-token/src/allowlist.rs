@@ -0,0 +1,94 @@
+use crate::admin::Admin;
+use crate::constants::ALLOWLIST_SEED;
+use crate::error::StablecoinError;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct Allowlist<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority @ StablecoinError::Unauthorized
+    )]
+    pub state: Account<'info, StablecoinState>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AllowlistEntry::INIT_SPACE,
+        seeds = [ALLOWLIST_SEED, state.key().as_ref(), account.key().as_ref()],
+        bump
+    )]
+    pub entry: Account<'info, AllowlistEntry>,
+
+    /// CHECK: Token account to allowlist
+    pub account: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveFromAllowlist<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority @ StablecoinError::Unauthorized
+    )]
+    pub state: Account<'info, StablecoinState>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [ALLOWLIST_SEED, state.key().as_ref(), account.key().as_ref()],
+        bump = entry.bump
+    )]
+    pub entry: Account<'info, AllowlistEntry>,
+
+    /// CHECK: Token account to remove from the allowlist
+    pub account: AccountInfo<'info>,
+}
+
+/// Approve a token account. Entries can be added before allowlist mode is
+/// switched on, so existing holders aren't locked out when it is.
+pub fn add(ctx: Context<Allowlist>) -> Result<()> {
+    let entry = &mut ctx.accounts.entry;
+    entry.account = ctx.accounts.account.key();
+    entry.added_by = ctx.accounts.authority.key();
+    entry.added_at = Clock::get()?.unix_timestamp;
+    entry.bump = ctx.bumps.entry;
+
+    emit_event(AllowlistAdded {
+        stablecoin: ctx.accounts.state.key(),
+        account: ctx.accounts.account.key(),
+        added_by: ctx.accounts.authority.key(),
+    });
+    Ok(())
+}
+
+/// Close an existing entry, returning its rent to the authority.
+/// Fails if the token account has no entry.
+pub fn remove(ctx: Context<RemoveFromAllowlist>) -> Result<()> {
+    emit_event(AllowlistRemoved {
+        stablecoin: ctx.accounts.state.key(),
+        account: ctx.accounts.account.key(),
+    });
+    Ok(())
+}
+
+/// While on, the transfer hook rejects transfers whose source or destination
+/// token account has no `AllowlistEntry`
+pub fn set_mode(ctx: Context<Admin>, enabled: bool) -> Result<()> {
+    let state = &mut ctx.accounts.state;
+    state.allowlist_mode = enabled;
+
+    emit_event(AllowlistModeUpdated {
+        stablecoin: state.key(),
+        authority: ctx.accounts.authority.key(),
+        enabled,
+    });
+    Ok(())
+}
@@ -2,6 +2,7 @@ use crate::constants::BLACKLIST_SEED;
 use crate::error::StablecoinError;
 use crate::events::*;
 use crate::state::*;
+use crate::admin::Admin;
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -36,6 +37,15 @@ pub fn add(ctx: Context<Blacklist>, reason: String) -> Result<()> {
         StablecoinError::ComplianceNotEnabled
     );
 
+    // init_if_needed: only a freshly created entry still has a default account
+    if ctx.accounts.entry.account == Pubkey::default() {
+        let state = &mut ctx.accounts.state;
+        state.blacklist_count = state
+            .blacklist_count
+            .checked_add(1)
+            .ok_or(StablecoinError::MathOverflow)?;
+    }
+
     let entry = &mut ctx.accounts.entry;
     entry.account = ctx.accounts.account.key();
     entry.reason = reason.clone();
@@ -43,7 +53,7 @@ pub fn add(ctx: Context<Blacklist>, reason: String) -> Result<()> {
     entry.blacklisted_at = Clock::get()?.unix_timestamp;
     entry.bump = ctx.bumps.entry;
 
-    emit!(BlacklistAdded {
+    emit_event(BlacklistAdded {
         stablecoin: ctx.accounts.state.key(),
         account: ctx.accounts.account.key(),
         reason,
@@ -58,13 +68,92 @@ pub fn remove(ctx: Context<Blacklist>) -> Result<()> {
     );
 
     let account_key = ctx.accounts.entry.account;
+    if account_key != Pubkey::default() {
+        let state = &mut ctx.accounts.state;
+        state.blacklist_count = state.blacklist_count.saturating_sub(1);
+    }
     ctx.accounts
         .entry
         .close(ctx.accounts.authority.to_account_info())?;
 
-    emit!(BlacklistRemoved {
+    emit_event(BlacklistRemoved {
         stablecoin: ctx.accounts.state.key(),
         account: account_key,
     });
     Ok(())
 }
+
+/// Require `entry_info` to be a live blacklist entry for the token account
+/// `token_account` or for its `owner`, the same two keys the transfer hook checks.
+/// Used by `seize` and `confiscate_burn`, whose entry may not exist.
+pub(crate) fn require_blacklisted(
+    entry_info: &AccountInfo,
+    stablecoin: &Pubkey,
+    token_account: &Pubkey,
+    owner: &Pubkey,
+) -> Result<()> {
+    require!(
+        *entry_info.owner == crate::ID && !entry_info.data_is_empty(),
+        StablecoinError::NotBlacklisted
+    );
+    let entry = BlacklistEntry::try_deserialize(&mut &entry_info.try_borrow_data()?[..])
+        .map_err(|_| StablecoinError::NotBlacklisted)?;
+    require!(
+        entry.account == *owner || entry.account == *token_account,
+        StablecoinError::NotBlacklisted
+    );
+    let expected = Pubkey::create_program_address(
+        &[
+            BLACKLIST_SEED,
+            stablecoin.as_ref(),
+            entry.account.as_ref(),
+            &[entry.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| StablecoinError::NotBlacklisted)?;
+    require_keys_eq!(expected, entry_info.key(), StablecoinError::NotBlacklisted);
+    Ok(())
+}
+
+/// Backfill `blacklist_count` for deployments created before the counter existed.
+/// Those use the legacy state layout, so `migrate_state` must run first.
+/// Existing entries are passed as remaining accounts; large lists can be split
+/// across transactions with `reset` set only on the first one.
+pub fn recount(ctx: Context<Admin>, reset: bool) -> Result<()> {
+    let state_key = ctx.accounts.state.key();
+    let mut counted: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+
+    for info in ctx.remaining_accounts.iter() {
+        require_keys_eq!(*info.owner, crate::ID, StablecoinError::InvalidBlacklistEntry);
+        let data = info.try_borrow_data()?;
+        let entry = BlacklistEntry::try_deserialize(&mut &data[..])
+            .map_err(|_| error!(StablecoinError::InvalidBlacklistEntry))?;
+
+        let expected = Pubkey::create_program_address(
+            &[
+                BLACKLIST_SEED,
+                state_key.as_ref(),
+                entry.account.as_ref(),
+                &[entry.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| error!(StablecoinError::InvalidBlacklistEntry))?;
+        require_keys_eq!(expected, info.key(), StablecoinError::InvalidBlacklistEntry);
+        require!(!counted.contains(info.key), StablecoinError::InvalidBlacklistEntry);
+        counted.push(info.key());
+    }
+
+    let state = &mut ctx.accounts.state;
+    let base = if reset { 0 } else { state.blacklist_count };
+    state.blacklist_count = base
+        .checked_add(counted.len() as u32)
+        .ok_or(StablecoinError::MathOverflow)?;
+
+    emit_event(BlacklistRecounted {
+        stablecoin: state_key,
+        blacklist_count: state.blacklist_count,
+    });
+    Ok(())
+}
@@ -1,10 +1,13 @@
-use crate::constants::ROLE_SEED;
+use crate::constants::{ROLE_SEED, VAULT_SEED};
 use crate::error::StablecoinError;
 use crate::events::*;
-use crate::math::update_supply;
+use crate::math::{
+    apply_bps, decrease_supply, safe_sub, validate_balance, validate_fee_treasury,
+    validate_minimum, validate_token_account_mint,
+};
 use crate::state::*;
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::{self, Burn as SplBurn};
+use anchor_spl::token_2022::{self, Burn as SplBurn, MintTo};
 use anchor_spl::token_interface::{Mint as TokenMint, TokenAccount, TokenInterface};
 
 #[derive(Accounts)]
@@ -19,8 +22,13 @@ pub struct Burn<'info> {
     pub state: Account<'info, StablecoinState>,
 
     #[account(
-        seeds = [ROLE_SEED, state.key().as_ref(), authority.key().as_ref()],
-        bump,
+        seeds = [
+            ROLE_SEED,
+            state.key().as_ref(),
+            authority.key().as_ref(),
+            role_assignment.role.seed(),
+        ],
+        bump = role_assignment.bump,
     )]
     pub role_assignment: Option<Account<'info, RoleAssignment>>,
 
@@ -31,26 +39,38 @@ pub struct Burn<'info> {
     pub from: InterfaceAccount<'info, TokenAccount>,
 
     pub token_program: Interface<'info, TokenInterface>,
+
+    /// Optional: Fee treasury token account, required when `state.fee_bps > 0`
+    #[account(mut)]
+    pub fee_treasury: Option<InterfaceAccount<'info, TokenAccount>>,
 }
 
 pub fn handler(ctx: Context<Burn>, amount: u64) -> Result<()> {
     require!(amount > 0, StablecoinError::ZeroAmount);
-    require!(!ctx.accounts.state.paused, StablecoinError::VaultPaused);
+    require!(
+        !ctx.accounts.state.is_paused(Clock::get()?.unix_timestamp),
+        StablecoinError::VaultPaused
+    );
+    validate_minimum(amount, ctx.accounts.state.min_burn_amount)?;
 
     // RBAC Check: Must be Master (state.authority) or have Burner role
     let is_master = ctx.accounts.authority.key() == ctx.accounts.state.authority;
-    let is_burner = if let Some(assignment) = &ctx.accounts.role_assignment {
-        assignment.role == Role::Burner || assignment.role == Role::Master
-    } else {
-        false
-    };
+    require_permission(
+        is_master,
+        ctx.accounts.role_assignment.as_deref(),
+        Action::Burn,
+        Clock::get()?.unix_timestamp,
+    )?;
+    validate_token_account_mint(&ctx.accounts.from.mint, &ctx.accounts.state.asset_mint)?;
+    // The token program would reject this too, but only with an opaque CPI error
+    validate_balance(ctx.accounts.from.amount, amount)?;
 
-    require!(is_master || is_burner, StablecoinError::Unauthorized);
+    let fee = apply_bps(amount, ctx.accounts.state.fee_bps)?;
+    let burn_amount = safe_sub(amount, fee)?;
 
-    let state = &mut ctx.accounts.state;
-    state.total_supply = update_supply(state.total_supply, amount, false)?;
-
-    // CPI to SPL Token-2022 to actual burn tokens
+    // The whole amount is burned and the fee re-minted to the treasury.
+    // Transferring the fee would run the transfer hook, which is this
+    // program, and Token-2022 rejects calling back into it as reentrancy.
     let cpi_accounts = SplBurn {
         mint: ctx.accounts.asset_mint.to_account_info(),
         from: ctx.accounts.from.to_account_info(),
@@ -61,10 +81,46 @@ pub fn handler(ctx: Context<Burn>, amount: u64) -> Result<()> {
 
     token_2022::burn(cpi_ctx, amount)?;
 
-    emit!(Burned {
-        stablecoin: state.key(),
+    if fee > 0 {
+        let fee_treasury = ctx
+            .accounts
+            .fee_treasury
+            .as_ref()
+            .ok_or(StablecoinError::InvalidFeeTreasury)?;
+        require_keys_eq!(
+            fee_treasury.key(),
+            ctx.accounts.state.fee_treasury,
+            StablecoinError::InvalidFeeTreasury
+        );
+        validate_fee_treasury(&fee_treasury.mint, &ctx.accounts.state.asset_mint)?;
+
+        let state = &ctx.accounts.state;
+        let asset_mint_key = state.asset_mint.key();
+        let authority_seeds = &[VAULT_SEED, asset_mint_key.as_ref(), &[state.bump]];
+        let signer = &[&authority_seeds[..]];
+
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.asset_mint.to_account_info(),
+            to: fee_treasury.to_account_info(),
+            authority: state.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer,
+        );
+
+        token_2022::mint_to(cpi_ctx, fee)?;
+    }
+
+    decrease_supply(&mut ctx.accounts.state, burn_amount)?;
+
+    emit_event(Burned {
+        stablecoin: ctx.accounts.state.key(),
         from: ctx.accounts.from.key(),
-        amount,
+        amount: burn_amount,
+        fee,
     });
 
     Ok(())
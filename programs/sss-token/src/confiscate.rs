@@ -0,0 +1,109 @@
+use crate::blacklist::require_blacklisted;
+use crate::constants::{ROLE_SEED, VAULT_SEED};
+use crate::error::StablecoinError;
+use crate::events::*;
+use crate::math::{decrease_supply, validate_balance};
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{self, Burn as SplBurn};
+use anchor_spl::token_interface::{Mint as TokenMint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct ConfiscateBurn<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = asset_mint
+    )]
+    pub state: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [
+            ROLE_SEED,
+            state.key().as_ref(),
+            authority.key().as_ref(),
+            role_assignment.role.seed(),
+        ],
+        bump = role_assignment.bump,
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    #[account(mut)]
+    pub asset_mint: InterfaceAccount<'info, TokenMint>,
+
+    #[account(mut)]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Blacklist entry for `from` or its owner (may not exist); validated in the handler
+    pub blacklist_entry: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Burn tokens straight out of a blacklisted holder's account.
+/// Unlike `seize` nothing is transferred, and unlike `burn` the holder
+/// doesn't sign: the state PDA burns as the mint's permanent delegate.
+pub fn handler(ctx: Context<ConfiscateBurn>, amount: u64) -> Result<()> {
+    let state = &ctx.accounts.state;
+
+    // RBAC Check: Must be Master or have Seizer role
+    let is_master = ctx.accounts.authority.key() == state.authority;
+    require_permission(
+        is_master,
+        ctx.accounts.role_assignment.as_deref(),
+        Action::Confiscate,
+        Clock::get()?.unix_timestamp,
+    )?;
+    require!(amount > 0, StablecoinError::ZeroAmount);
+    require!(
+        !state.is_paused(Clock::get()?.unix_timestamp),
+        StablecoinError::VaultPaused
+    );
+    require!(
+        state.compliance_enabled,
+        StablecoinError::ComplianceNotEnabled
+    );
+
+    // SSS-2: only blacklisted holders can have their tokens confiscated
+    require_blacklisted(
+        &ctx.accounts.blacklist_entry,
+        &state.key(),
+        &ctx.accounts.from.key(),
+        &ctx.accounts.from.owner,
+    )?;
+    validate_balance(ctx.accounts.from.amount, amount)?;
+
+    let asset_mint_key = state.asset_mint.key();
+    let authority_seeds = &[VAULT_SEED, asset_mint_key.as_ref(), &[state.bump]];
+    let signer = &[&authority_seeds[..]];
+
+    let cpi_accounts = SplBurn {
+        mint: ctx.accounts.asset_mint.to_account_info(),
+        from: ctx.accounts.from.to_account_info(),
+        authority: ctx.accounts.state.to_account_info(),
+    };
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+
+    token_2022::burn(cpi_ctx, amount)?;
+
+    decrease_supply(&mut ctx.accounts.state, amount)?;
+
+    // Re-read the source account to report what is left after a partial burn
+    ctx.accounts.from.reload()?;
+
+    emit_event(Confiscated {
+        stablecoin: ctx.accounts.state.key(),
+        from: ctx.accounts.from.key(),
+        amount,
+        remaining_balance: ctx.accounts.from.amount,
+        authority: ctx.accounts.authority.key(),
+    });
+    Ok(())
+}
@@ -1,7 +1,17 @@
+use anchor_lang::prelude::Pubkey;
+
 pub const VAULT_SEED: &[u8] = b"stablecoin";
 pub const ROLE_SEED: &[u8] = b"role";
 pub const BLACKLIST_SEED: &[u8] = b"blacklist";
 pub const MINTER_SEED: &[u8] = b"minter";
+pub const FREEZE_SEED: &[u8] = b"freeze";
+pub const RESERVE_SEED: &[u8] = b"reserve";
+pub const ALLOWLIST_SEED: &[u8] = b"allowlist";
+/// Seed of the ExtraAccountMetaList read by Token-2022 before invoking the hook
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+/// Discriminator Token-2022 sends to the hook program on every transfer:
+/// sha256("spl-transfer-hook-interface:execute")[..8]
+pub const TRANSFER_HOOK_EXECUTE_DISCRIMINATOR: &[u8] = &[105, 37, 101, 197, 75, 251, 102, 26];
 
 pub const PRESET_SSS_1: u8 = 1;
 pub const PRESET_SSS_2: u8 = 2;
@@ -9,3 +19,86 @@ pub const PRESET_SSS_2: u8 = 2;
 pub const MAX_NAME_LENGTH: usize = 32;
 pub const MAX_SYMBOL_LENGTH: usize = 10;
 pub const MAX_URI_LENGTH: usize = 200;
+pub const MAX_REASON_LENGTH: usize = 200;
+
+/// `MinterInfo::quota` value meaning the minter has no limit
+pub const UNLIMITED_QUOTA: u64 = 0;
+
+/// `StablecoinState::max_supply` value meaning supply is uncapped
+pub const UNCAPPED_SUPPLY: u64 = 0;
+
+/// `StablecoinState::min_burn_amount` / `min_mint_amount` value meaning no floor
+pub const NO_MINIMUM_AMOUNT: u64 = 0;
+
+/// Size of `StablecoinState` accounts created before `max_supply` and the pause
+/// fields were appended: discriminator, 126 bytes of fields and 14 reserved bytes
+pub const LEGACY_STATE_SPACE: usize = 148;
+
+/// `StablecoinState::pause_until` value meaning the pause lasts until `unpause`
+pub const NO_PAUSE_EXPIRY: i64 = 0;
+
+/// Basis-point denominator (100% = 10_000 bps)
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+// PDA derivations, exported so off-chain clients use the program's seeds
+// instead of copying them
+
+/// `StablecoinState` PDA for `asset_mint`
+pub fn stablecoin_pda(asset_mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED, asset_mint.as_ref()], program_id)
+}
+
+/// `RoleAssignment` PDA for one role of `account`; `role_seed` is `Role::seed()`
+pub fn role_pda(
+    stablecoin: &Pubkey,
+    account: &Pubkey,
+    role_seed: &[u8],
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ROLE_SEED, stablecoin.as_ref(), account.as_ref(), role_seed],
+        program_id,
+    )
+}
+
+/// `MinterInfo` PDA for `minter`
+pub fn minter_pda(stablecoin: &Pubkey, minter: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MINTER_SEED, stablecoin.as_ref(), minter.as_ref()],
+        program_id,
+    )
+}
+
+/// `BlacklistEntry` PDA for `account`
+pub fn blacklist_pda(stablecoin: &Pubkey, account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BLACKLIST_SEED, stablecoin.as_ref(), account.as_ref()],
+        program_id,
+    )
+}
+
+/// `AllowlistEntry` PDA for the token `account`
+pub fn allowlist_pda(stablecoin: &Pubkey, account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ALLOWLIST_SEED, stablecoin.as_ref(), account.as_ref()],
+        program_id,
+    )
+}
+
+/// `FreezeRecord` PDA for the token `account`
+pub fn freeze_pda(stablecoin: &Pubkey, account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[FREEZE_SEED, stablecoin.as_ref(), account.as_ref()],
+        program_id,
+    )
+}
+
+/// `ReserveAttestation` PDA of a stablecoin
+pub fn reserve_attestation_pda(stablecoin: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RESERVE_SEED, stablecoin.as_ref()], program_id)
+}
+
+/// ExtraAccountMetaList PDA of `asset_mint`, derived under the hook program
+pub fn extra_account_metas_pda(asset_mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EXTRA_ACCOUNT_METAS_SEED, asset_mint.as_ref()], program_id)
+}
@@ -36,4 +36,84 @@ pub enum StablecoinError {
     UriTooLong,
     #[msg("Invalid decimals - must be <= 9")]
     InvalidDecimals,
+    #[msg("Transfer amount exceeds the configured maximum")]
+    TransferAmountExceeded,
+    #[msg("Invalid basis points - must be <= 10000")]
+    InvalidBasisPoints,
+    #[msg("Fee treasury account missing or does not match configuration")]
+    InvalidFeeTreasury,
+    #[msg("Account is not blacklisted")]
+    NotBlacklisted,
+    #[msg("Reason too long (max 200 chars)")]
+    ReasonTooLong,
+    #[msg("Total supply must be zero")]
+    SupplyNotZero,
+    #[msg("Stablecoin still has active minters")]
+    OutstandingMinters,
+    #[msg("Invalid blacklist entry account")]
+    InvalidBlacklistEntry,
+    #[msg("Supply underflow")]
+    SupplyUnderflow,
+    #[msg("Token-2022 program account required to configure mint extensions")]
+    MissingTokenProgram,
+    #[msg("Mint would exceed the authorized max supply")]
+    SupplyCapExceeded,
+    #[msg("Max supply cannot be below the current total supply")]
+    MaxSupplyBelowSupply,
+    #[msg("Decimals do not match the existing asset mint")]
+    DecimalsMismatch,
+    #[msg("Asset mint authority must be the stablecoin PDA - transfer it before initializing")]
+    MintAuthorityMismatch,
+    #[msg("Compliance is already in the requested state")]
+    ComplianceUnchanged,
+    #[msg("Compliance cannot be disabled while blacklist entries exist")]
+    BlacklistNotEmpty,
+    #[msg("Amount is below the configured minimum")]
+    BelowMinimum,
+    #[msg("Mint would exceed the attested reserves")]
+    InsufficientReserves,
+    #[msg("Reserve attestation is older than the configured max staleness")]
+    ReserveAttestationStale,
+    #[msg("Reserve attestation account required for a reserve-backed stablecoin")]
+    ReserveAttestationRequired,
+    #[msg("Max staleness must be greater than zero")]
+    InvalidMaxStaleness,
+    #[msg("Role assignment has expired")]
+    RoleExpired,
+    #[msg("Role assignment has not expired")]
+    RoleNotExpired,
+    #[msg("Token account does not belong to the stablecoin's asset mint")]
+    TokenAccountMintMismatch,
+    #[msg("Asset mint freeze authority must be the stablecoin PDA")]
+    FreezeAuthorityMismatch,
+    #[msg("Account is not on the allowlist")]
+    NotAllowlisted,
+    #[msg("New authority must be a non-default key other than the current authority")]
+    InvalidAuthority,
+    #[msg("The stablecoin's own asset mint cannot be rescued")]
+    CannotRescueAssetMint,
+    #[msg("Pause expiry must be in the future, or 0 for no expiry")]
+    InvalidPauseExpiry,
+    #[msg("Stablecoin state already has the current layout")]
+    StateAlreadyMigrated,
+    #[msg("Account is not a stablecoin state this program can migrate")]
+    InvalidStateAccount,
+    #[msg("Stablecoin still has active role assignments")]
+    OutstandingRoles,
+    #[msg("Minters other than the Master must pass their MinterInfo")]
+    MinterInfoRequired,
+    #[msg("Moving the Minter role requires the new key's role assignment account")]
+    MinterRoleRequired,
+    #[msg("Account is not a minter info of this stablecoin")]
+    InvalidMinterInfo,
+    #[msg("Account is not a role assignment of this stablecoin")]
+    InvalidRoleAssignment,
+    #[msg("Recount minters and roles (recount_minters, recount_roles) before closing")]
+    CountersNotVerified,
+    #[msg("Asset mint must be an initialized SPL Token or Token-2022 mint")]
+    InvalidMint,
+    #[msg("Mint extensions can only be configured by initialize_with_new_mint")]
+    ExtensionsRequireNewMint,
+    #[msg("The transfer hook's account list is outdated; run update_extra_account_metas")]
+    ExtraAccountMetasOutdated,
 }
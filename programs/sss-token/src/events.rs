@@ -1,5 +1,146 @@
 use anchor_lang::prelude::*;
+use anchor_lang::{Discriminator, Event};
+use std::fmt::Write;
 
+/// Prefix of the single-line JSON log written by the `json-event-logs` feature
+pub const EVENT_LOG_PREFIX: &str = "SSS_EVENT:";
+
+/// Emit `event` with `emit!`. With the `json-event-logs` feature it is also
+/// logged as `SSS_EVENT:{"event":"<Name>",<fields>}` for consumers reading
+/// plain logs; that copy costs compute on every call, so it is off by default.
+/// Off-chain, `decode_event_json` renders the same JSON from the `emit!` data.
+pub fn emit_event<E: Event + LoggedEvent>(event: E) {
+    #[cfg(feature = "json-event-logs")]
+    msg!("{}{}", EVENT_LOG_PREFIX, event.to_log_json());
+    emit!(event);
+}
+
+/// Event that can be written as one line of compact JSON
+pub trait LoggedEvent {
+    const NAME: &'static str;
+
+    fn write_fields(&self, json: &mut JsonLog);
+
+    fn to_log_json(&self) -> String {
+        let mut json = JsonLog::new(Self::NAME);
+        self.write_fields(&mut json);
+        json.finish()
+    }
+}
+
+/// Builder for a compact JSON object, starting with the event name
+pub struct JsonLog(String);
+
+impl JsonLog {
+    fn new(name: &str) -> Self {
+        let mut out = String::with_capacity(256);
+        out.push_str("{\"event\":");
+        name.write_json(&mut out);
+        Self(out)
+    }
+
+    pub fn field<T: JsonValue + ?Sized>(&mut self, name: &str, value: &T) {
+        self.0.push(',');
+        name.write_json(&mut self.0);
+        self.0.push(':');
+        value.write_json(&mut self.0);
+    }
+
+    fn finish(mut self) -> String {
+        self.0.push('}');
+        self.0
+    }
+}
+
+/// A value that can be written into a `JsonLog`
+pub trait JsonValue {
+    fn write_json(&self, out: &mut String);
+}
+
+impl JsonValue for str {
+    fn write_json(&self, out: &mut String) {
+        out.push('"');
+        for c in self.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                c if (c as u32) < 0x20 => {
+                    let _ = write!(out, "\\u{:04x}", c as u32);
+                }
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+    }
+}
+
+impl JsonValue for String {
+    fn write_json(&self, out: &mut String) {
+        self.as_str().write_json(out);
+    }
+}
+
+impl JsonValue for Pubkey {
+    fn write_json(&self, out: &mut String) {
+        let _ = write!(out, "\"{}\"", self);
+    }
+}
+
+impl JsonValue for bool {
+    fn write_json(&self, out: &mut String) {
+        out.push_str(if *self { "true" } else { "false" });
+    }
+}
+
+macro_rules! json_number {
+    ($($ty:ty),*) => {
+        $(impl JsonValue for $ty {
+            fn write_json(&self, out: &mut String) {
+                let _ = write!(out, "{}", self);
+            }
+        })*
+    };
+}
+
+json_number!(u8, u16, u32, u64, i16, i64);
+
+impl<T: JsonValue> JsonValue for Option<T> {
+    fn write_json(&self, out: &mut String) {
+        match self {
+            Some(value) => value.write_json(out),
+            None => out.push_str("null"),
+        }
+    }
+}
+
+/// Implement `LoggedEvent` for each event, listing every field, and
+/// `decode_event_json` over all of them.
+/// Destructuring makes a field missing from the list a compile error.
+macro_rules! logged_events {
+    ($($event:ident { $($field:ident),* $(,)? }),* $(,)?) => {
+        $(impl LoggedEvent for $event {
+            const NAME: &'static str = stringify!($event);
+
+            fn write_fields(&self, json: &mut JsonLog) {
+                let Self { $($field),* } = self;
+                $(json.field(stringify!($field), $field);)*
+            }
+        })*
+
+        /// Render an `emit!` payload (the base64-decoded `Program data:` log)
+        /// as the event's JSON. `None` for unknown or malformed payloads.
+        pub fn decode_event_json(data: &[u8]) -> Option<String> {
+            $(if let Some(mut fields) = data.strip_prefix(<$event as Discriminator>::DISCRIMINATOR) {
+                return <$event as AnchorDeserialize>::deserialize(&mut fields)
+                    .ok()
+                    .map(|event| event.to_log_json());
+            })*
+            None
+        }
+    };
+}
+
+/// A new stablecoin; carries everything the backend needs to register it
 #[event]
 pub struct StablecoinInitialized {
     pub stablecoin: Pubkey,
@@ -8,39 +149,58 @@ pub struct StablecoinInitialized {
     pub symbol: String,
     pub decimals: u8,
     pub compliance_enabled: bool,
+    pub authority: Pubkey,
+    pub asset_mint: Pubkey,
 }
 
 #[event]
 pub struct Minted {
     pub stablecoin: Pubkey,
     pub recipient: Pubkey,
+    /// Amount minted to the recipient (after fee)
     pub amount: u64,
     pub minter: Pubkey,
+    /// Amount minted to the fee treasury
+    pub fee: u64,
 }
 
 #[event]
 pub struct Burned {
     pub stablecoin: Pubkey,
     pub from: Pubkey,
+    /// Amount burned (after fee)
     pub amount: u64,
+    /// Amount transferred to the fee treasury instead of burned
+    pub fee: u64,
 }
 
 #[event]
 pub struct Frozen {
     pub stablecoin: Pubkey,
     pub account: Pubkey,
+    pub frozen_by: Pubkey,
+    pub frozen_at: i64,
+    pub reason: String,
 }
 
 #[event]
 pub struct Thawed {
     pub stablecoin: Pubkey,
     pub account: Pubkey,
+    pub thawed_by: Pubkey,
+    pub thawed_at: i64,
+    /// Who froze the account, if a freeze record existed
+    pub frozen_by: Option<Pubkey>,
+    pub frozen_at: Option<i64>,
 }
 
 #[event]
 pub struct Paused {
     pub stablecoin: Pubkey,
     pub authority: Pubkey,
+    pub reason: String,
+    /// When the pause lifts on its own (0 = until unpaused)
+    pub until: i64,
 }
 
 #[event]
@@ -56,6 +216,100 @@ pub struct AuthorityTransferred {
     pub new_authority: Pubkey,
 }
 
+#[event]
+pub struct StablecoinClosed {
+    pub stablecoin: Pubkey,
+    pub authority: Pubkey,
+    /// Rent lamports returned to the authority
+    pub lamports: u64,
+}
+
+#[event]
+pub struct StateMigrated {
+    pub stablecoin: Pubkey,
+    pub authority: Pubkey,
+    pub old_len: u64,
+    pub new_len: u64,
+}
+
+#[event]
+pub struct InterestRateUpdated {
+    pub stablecoin: Pubkey,
+    pub rate_bps: i16,
+}
+
+#[event]
+pub struct TransferFeeUpdated {
+    pub stablecoin: Pubkey,
+    pub transfer_fee_bps: u16,
+    pub max_fee: u64,
+}
+
+#[event]
+pub struct WithheldFeesWithdrawn {
+    pub stablecoin: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MaxTransferUpdated {
+    pub stablecoin: Pubkey,
+    pub old_amount: u64,
+    pub new_amount: u64,
+}
+
+#[event]
+pub struct MinBurnUpdated {
+    pub stablecoin: Pubkey,
+    pub old_amount: u64,
+    pub new_amount: u64,
+}
+
+#[event]
+pub struct MinMintUpdated {
+    pub stablecoin: Pubkey,
+    pub old_amount: u64,
+    pub new_amount: u64,
+}
+
+#[event]
+pub struct MaxSupplyUpdated {
+    pub stablecoin: Pubkey,
+    pub old_max_supply: u64,
+    pub new_max_supply: u64,
+}
+
+#[event]
+pub struct ComplianceToggled {
+    pub stablecoin: Pubkey,
+    pub authority: Pubkey,
+    pub enabled: bool,
+    pub old_preset: u8,
+    pub new_preset: u8,
+}
+
+#[event]
+pub struct TransferHookInitialized {
+    pub stablecoin: Pubkey,
+    pub asset_mint: Pubkey,
+    pub extra_account_meta_list: Pubkey,
+}
+
+#[event]
+pub struct TransferHookUpdated {
+    pub stablecoin: Pubkey,
+    pub asset_mint: Pubkey,
+    pub extra_account_meta_list: Pubkey,
+}
+
+#[event]
+pub struct FeeUpdated {
+    pub stablecoin: Pubkey,
+    pub fee_bps: u16,
+    pub fee_treasury: Pubkey,
+}
+
 #[event]
 pub struct MinterAdded {
     pub stablecoin: Pubkey,
@@ -77,6 +331,16 @@ pub struct QuotaUpdated {
     pub new_quota: u64,
 }
 
+#[event]
+pub struct MinterRotated {
+    pub stablecoin: Pubkey,
+    pub old_minter: Pubkey,
+    pub new_minter: Pubkey,
+    pub quota: u64,
+    /// Usage carried over from the old key
+    pub minted_amount: u64,
+}
+
 #[event]
 pub struct BlacklistAdded {
     pub stablecoin: Pubkey,
@@ -90,12 +354,97 @@ pub struct BlacklistRemoved {
     pub account: Pubkey,
 }
 
+#[event]
+pub struct BlacklistRecounted {
+    pub stablecoin: Pubkey,
+    pub blacklist_count: u32,
+}
+
+#[event]
+pub struct MintersRecounted {
+    pub stablecoin: Pubkey,
+    pub minter_count: u32,
+}
+
+#[event]
+pub struct RolesRecounted {
+    pub stablecoin: Pubkey,
+    pub role_count: u32,
+}
+
+#[event]
+pub struct AllowlistAdded {
+    pub stablecoin: Pubkey,
+    pub account: Pubkey,
+    pub added_by: Pubkey,
+}
+
+#[event]
+pub struct AllowlistRemoved {
+    pub stablecoin: Pubkey,
+    pub account: Pubkey,
+}
+
+#[event]
+pub struct AllowlistModeUpdated {
+    pub stablecoin: Pubkey,
+    pub authority: Pubkey,
+    pub enabled: bool,
+}
+
 #[event]
 pub struct Seized {
     pub stablecoin: Pubkey,
     pub from: Pubkey,
     pub to: Pubkey,
     pub amount: u64,
+    /// Balance left in `from` after the seizure
+    pub remaining_balance: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct Confiscated {
+    pub stablecoin: Pubkey,
+    pub from: Pubkey,
+    /// Amount burned from the holder
+    pub amount: u64,
+    /// Balance left in `from` after the burn
+    pub remaining_balance: u64,
+    pub authority: Pubkey,
+}
+
+/// Tokens of another mint returned from the stablecoin PDA
+#[event]
+pub struct TokensRescued {
+    pub stablecoin: Pubkey,
+    pub mint: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ReservesConfigured {
+    pub stablecoin: Pubkey,
+    pub attester: Pubkey,
+    pub max_staleness: i64,
+}
+
+#[event]
+pub struct ReservesDisabled {
+    pub stablecoin: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ReservesAttested {
+    pub stablecoin: Pubkey,
+    pub attester: Pubkey,
+    pub old_amount: u64,
+    pub new_amount: u64,
+    pub updated_at: i64,
 }
 
 #[event]
@@ -104,6 +453,8 @@ pub struct RoleAssigned {
     pub role: String,
     pub account: Pubkey,
     pub assigned_by: Pubkey,
+    /// When the assignment stops granting the role (`None` = never)
+    pub expires_at: Option<i64>,
 }
 
 #[event]
@@ -111,4 +462,116 @@ pub struct RoleRevoked {
     pub stablecoin: Pubkey,
     pub role: String,
     pub account: Pubkey,
+    pub revoked_by: Pubkey,
+}
+
+#[event]
+pub struct ExpiredRoleCleaned {
+    pub stablecoin: Pubkey,
+    pub role: String,
+    pub account: Pubkey,
+    pub cleaned_by: Pubkey,
+}
+
+logged_events! {
+    StablecoinInitialized { stablecoin, preset, name, symbol, decimals, compliance_enabled, authority, asset_mint },
+    Minted { stablecoin, recipient, amount, minter, fee },
+    Burned { stablecoin, from, amount, fee },
+    Frozen { stablecoin, account, frozen_by, frozen_at, reason },
+    Thawed { stablecoin, account, thawed_by, thawed_at, frozen_by, frozen_at },
+    Paused { stablecoin, authority, reason, until },
+    Unpaused { stablecoin, authority },
+    AuthorityTransferred { stablecoin, old_authority, new_authority },
+    StablecoinClosed { stablecoin, authority, lamports },
+    StateMigrated { stablecoin, authority, old_len, new_len },
+    InterestRateUpdated { stablecoin, rate_bps },
+    TransferFeeUpdated { stablecoin, transfer_fee_bps, max_fee },
+    WithheldFeesWithdrawn { stablecoin, to, amount },
+    MaxTransferUpdated { stablecoin, old_amount, new_amount },
+    MinBurnUpdated { stablecoin, old_amount, new_amount },
+    MinMintUpdated { stablecoin, old_amount, new_amount },
+    MaxSupplyUpdated { stablecoin, old_max_supply, new_max_supply },
+    ComplianceToggled { stablecoin, authority, enabled, old_preset, new_preset },
+    TransferHookInitialized { stablecoin, asset_mint, extra_account_meta_list },
+    TransferHookUpdated { stablecoin, asset_mint, extra_account_meta_list },
+    FeeUpdated { stablecoin, fee_bps, fee_treasury },
+    MinterAdded { stablecoin, minter, quota },
+    MinterRemoved { stablecoin, minter },
+    QuotaUpdated { stablecoin, minter, old_quota, new_quota },
+    MinterRotated { stablecoin, old_minter, new_minter, quota, minted_amount },
+    BlacklistAdded { stablecoin, account, reason },
+    BlacklistRemoved { stablecoin, account },
+    BlacklistRecounted { stablecoin, blacklist_count },
+    MintersRecounted { stablecoin, minter_count },
+    RolesRecounted { stablecoin, role_count },
+    AllowlistAdded { stablecoin, account, added_by },
+    AllowlistRemoved { stablecoin, account },
+    AllowlistModeUpdated { stablecoin, authority, enabled },
+    Seized { stablecoin, from, to, amount, remaining_balance, authority },
+    Confiscated { stablecoin, from, amount, remaining_balance, authority },
+    TokensRescued { stablecoin, mint, from, to, amount, authority },
+    ReservesConfigured { stablecoin, attester, max_staleness },
+    ReservesDisabled { stablecoin, authority },
+    ReservesAttested { stablecoin, attester, old_amount, new_amount, updated_at },
+    RoleAssigned { stablecoin, role, account, assigned_by, expires_at },
+    RoleRevoked { stablecoin, role, account, revoked_by },
+    ExpiredRoleCleaned { stablecoin, role, account, cleaned_by },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_log_json() {
+        let stablecoin = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let event = Paused {
+            stablecoin,
+            authority,
+            reason: "incident \"42\"\nsee runbook".to_string(),
+            until: 1_700_000_000,
+        };
+
+        assert_eq!(
+            event.to_log_json(),
+            format!(
+                r#"{{"event":"Paused","stablecoin":"{}","authority":"{}","reason":"incident \"42\"\u000asee runbook","until":1700000000}}"#,
+                stablecoin, authority,
+            ),
+        );
+    }
+
+    #[test]
+    fn test_event_log_json_numbers_and_options() {
+        let stablecoin = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+        let event = Thawed {
+            stablecoin,
+            account,
+            thawed_by: account,
+            thawed_at: -1,
+            frozen_by: None,
+            frozen_at: Some(1_700_000_000),
+        };
+
+        let json = event.to_log_json();
+        assert!(json.starts_with(r#"{"event":"Thawed","#));
+        assert!(json.ends_with(r#","thawed_at":-1,"frozen_by":null,"frozen_at":1700000000}"#));
+    }
+
+    #[test]
+    fn test_decode_event_json() {
+        let event = Minted {
+            stablecoin: Pubkey::new_unique(),
+            recipient: Pubkey::new_unique(),
+            amount: 1_000_000,
+            minter: Pubkey::new_unique(),
+            fee: 0,
+        };
+
+        assert_eq!(decode_event_json(&event.data()), Some(event.to_log_json()));
+        assert_eq!(decode_event_json(&event.data()[..12]), None);
+        assert_eq!(decode_event_json(b"not an event"), None);
+    }
 }
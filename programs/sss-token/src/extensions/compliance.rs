@@ -0,0 +1,40 @@
+// Token-2022 Permanent Delegate and Transfer Hook Extensions (SSS-2)
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use spl_token_2022::extension::transfer_hook::instruction as transfer_hook;
+
+/// Make the stablecoin PDA the mint's permanent delegate, so seize and
+/// confiscate can move tokens out of any account.
+/// Like other mint extensions, this must run before `InitializeMint2`.
+pub fn initialize_permanent_delegate<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    delegate: &Pubkey,
+) -> Result<()> {
+    let ix = spl_token_2022::instruction::initialize_permanent_delegate(
+        token_program.key,
+        mint.key,
+        delegate,
+    )?;
+    invoke(&ix, &[mint.clone(), token_program.clone()])?;
+    Ok(())
+}
+
+/// Route every transfer of the mint through this program's transfer hook.
+/// The stablecoin PDA becomes the hook authority.
+/// Like other mint extensions, this must run before `InitializeMint2`.
+pub fn initialize_transfer_hook<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    authority: &Pubkey,
+) -> Result<()> {
+    let ix = transfer_hook::initialize(
+        token_program.key,
+        mint.key,
+        Some(*authority),
+        Some(crate::ID),
+    )?;
+    invoke(&ix, &[mint.clone(), token_program.clone()])?;
+    Ok(())
+}
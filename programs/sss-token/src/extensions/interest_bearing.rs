@@ -0,0 +1,47 @@
+// Token-2022 Interest-Bearing Mint Extension
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use spl_token_2022::extension::interest_bearing_mint::instruction as interest_bearing;
+
+/// Initialize the interest-bearing extension on the mint.
+/// The mint must be allocated with room for the extension but not yet
+/// initialized, i.e. this runs before `InitializeMint2` in the same transaction.
+pub fn initialize_interest_bearing_mint<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    rate_authority: &Pubkey,
+    rate_bps: i16,
+) -> Result<()> {
+    let ix = interest_bearing::initialize(
+        token_program.key,
+        mint.key,
+        Some(*rate_authority),
+        rate_bps,
+    )?;
+    invoke(&ix, &[mint.clone(), token_program.clone()])?;
+    Ok(())
+}
+
+/// Update the interest rate, signed by the stablecoin PDA as rate authority
+pub fn update_interest_rate<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    rate_authority: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    rate_bps: i16,
+) -> Result<()> {
+    let ix = interest_bearing::update_rate(
+        token_program.key,
+        mint.key,
+        rate_authority.key,
+        &[],
+        rate_bps,
+    )?;
+    invoke_signed(
+        &ix,
+        &[mint.clone(), rate_authority.clone(), token_program.clone()],
+        signer_seeds,
+    )?;
+    Ok(())
+}
@@ -1,7 +1,12 @@
 // SSS-3 Extensions - Confidential Transfers (Proof-of-Concept)
+// Token-2022 mint extensions configured at initialization
 
 pub mod confidential_transfer;
-pub mod allowlist;
+pub mod compliance;
+pub mod interest_bearing;
+pub mod transfer_fee;
 
 pub use confidential_transfer::*;
-pub use allowlist::*;
+pub use compliance::*;
+pub use interest_bearing::*;
+pub use transfer_fee::*;
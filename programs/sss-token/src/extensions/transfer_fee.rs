@@ -0,0 +1,87 @@
+// Token-2022 Transfer Fee Extension
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use spl_token_2022::extension::transfer_fee::instruction as transfer_fee;
+
+/// Transfer fee settings supplied at initialization
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct TransferFeeArgs {
+    pub transfer_fee_bps: u16,
+    pub max_fee: u64,
+}
+
+/// Initialize the transfer fee extension on the mint.
+/// Like other mint extensions, this must run before `InitializeMint2`.
+/// The stablecoin PDA becomes both the fee config and withdraw authority.
+pub fn initialize_transfer_fee_config<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    authority: &Pubkey,
+    transfer_fee_bps: u16,
+    max_fee: u64,
+) -> Result<()> {
+    let ix = transfer_fee::initialize_transfer_fee_config(
+        token_program.key,
+        mint.key,
+        Some(authority),
+        Some(authority),
+        transfer_fee_bps,
+        max_fee,
+    )?;
+    invoke(&ix, &[mint.clone(), token_program.clone()])?;
+    Ok(())
+}
+
+/// Update the transfer fee, signed by the stablecoin PDA
+pub fn set_transfer_fee<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    transfer_fee_bps: u16,
+    max_fee: u64,
+) -> Result<()> {
+    let ix = transfer_fee::set_transfer_fee(
+        token_program.key,
+        mint.key,
+        authority.key,
+        &[],
+        transfer_fee_bps,
+        max_fee,
+    )?;
+    invoke_signed(
+        &ix,
+        &[mint.clone(), authority.clone(), token_program.clone()],
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+/// Withdraw fees harvested to the mint into `destination`, signed by the stablecoin PDA
+pub fn withdraw_withheld_from_mint<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = transfer_fee::withdraw_withheld_tokens_from_mint(
+        token_program.key,
+        mint.key,
+        destination.key,
+        authority.key,
+        &[],
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            mint.clone(),
+            destination.clone(),
+            authority.clone(),
+            token_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+    Ok(())
+}
@@ -1,8 +1,10 @@
-use crate::constants::{ROLE_SEED, VAULT_SEED};
+use crate::constants::{FREEZE_SEED, MAX_REASON_LENGTH, ROLE_SEED, VAULT_SEED};
 use crate::error::StablecoinError;
 use crate::events::*;
+use crate::math::validate_token_account_mint;
 use crate::state::*;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
 use anchor_spl::token_2022::{self, FreezeAccount as SplFreeze};
 use anchor_spl::token_interface::{Mint as TokenMint, TokenAccount, TokenInterface};
 
@@ -17,8 +19,13 @@ pub struct FreezeAccount<'info> {
     pub state: Account<'info, StablecoinState>,
 
     #[account(
-        seeds = [ROLE_SEED, state.key().as_ref(), authority.key().as_ref()],
-        bump,
+        seeds = [
+            ROLE_SEED,
+            state.key().as_ref(),
+            authority.key().as_ref(),
+            role_assignment.role.seed(),
+        ],
+        bump = role_assignment.bump,
     )]
     pub role_assignment: Option<Account<'info, RoleAssignment>>,
 
@@ -28,44 +35,94 @@ pub struct FreezeAccount<'info> {
     #[account(mut)]
     pub account: InterfaceAccount<'info, TokenAccount>,
 
+    /// Overwritten if an older thaw left it behind
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + FreezeRecord::INIT_SPACE,
+        seeds = [FREEZE_SEED, state.key().as_ref(), account.key().as_ref()],
+        bump
+    )]
+    pub freeze_record: Account<'info, FreezeRecord>,
+
     pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Freezes and thaws are CPIs signed by the stablecoin PDA, so they only take
+/// effect at the token level when that PDA is the mint's freeze authority
+pub(crate) fn require_freeze_authority(asset_mint: &TokenMint, stablecoin: &Pubkey) -> Result<()> {
+    require!(
+        asset_mint.freeze_authority == COption::Some(*stablecoin),
+        StablecoinError::FreezeAuthorityMismatch
+    );
+    Ok(())
 }
 
-pub fn handler(ctx: Context<FreezeAccount>) -> Result<()> {
+pub fn handler(ctx: Context<FreezeAccount>, reason: String) -> Result<()> {
     let state = &ctx.accounts.state;
 
     // RBAC Check: Must be Master or have Blacklister role
     let is_master = ctx.accounts.authority.key() == state.authority;
-    let is_blacklister = if let Some(assignment) = &ctx.accounts.role_assignment {
-        assignment.role == Role::Blacklister || assignment.role == Role::Master
-    } else {
-        false
-    };
-
-    require!(is_master || is_blacklister, StablecoinError::Unauthorized);
-    require!(!state.paused, StablecoinError::VaultPaused);
+    require_permission(
+        is_master,
+        ctx.accounts.role_assignment.as_deref(),
+        Action::Freeze,
+        Clock::get()?.unix_timestamp,
+    )?;
+    require!(
+        !state.is_paused(Clock::get()?.unix_timestamp),
+        StablecoinError::VaultPaused
+    );
+    require!(
+        reason.len() <= MAX_REASON_LENGTH,
+        StablecoinError::ReasonTooLong
+    );
+    require_freeze_authority(&ctx.accounts.asset_mint, &state.key())?;
+    validate_token_account_mint(&ctx.accounts.account.mint, &state.asset_mint)?;
 
     let asset_mint_key = state.asset_mint.key();
     let authority_seeds = &[VAULT_SEED, asset_mint_key.as_ref(), &[state.bump]];
     let signer = &[&authority_seeds[..]];
 
-    let cpi_accounts = SplFreeze {
-        account: ctx.accounts.account.to_account_info(),
-        mint: ctx.accounts.asset_mint.to_account_info(),
-        authority: state.to_account_info(),
-    };
+    // An account that is already frozen (before freeze records existed) only
+    // gets its record, which `thaw_account` requires
+    if ctx.accounts.account.is_frozen() {
+        require!(
+            ctx.accounts.freeze_record.account == Pubkey::default(),
+            StablecoinError::AccountFrozen
+        );
+    } else {
+        let cpi_accounts = SplFreeze {
+            account: ctx.accounts.account.to_account_info(),
+            mint: ctx.accounts.asset_mint.to_account_info(),
+            authority: state.to_account_info(),
+        };
 
-    let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        cpi_accounts,
-        signer,
-    );
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer,
+        );
+
+        token_2022::freeze_account(cpi_ctx)?;
+    }
 
-    token_2022::freeze_account(cpi_ctx)?;
+    let frozen_at = Clock::get()?.unix_timestamp;
+    let record = &mut ctx.accounts.freeze_record;
+    record.account = ctx.accounts.account.key();
+    record.frozen_by = ctx.accounts.authority.key();
+    record.frozen_at = frozen_at;
+    record.reason = reason.clone();
+    record.bump = ctx.bumps.freeze_record;
 
-    emit!(Frozen {
-        stablecoin: state.key(),
+    emit_event(Frozen {
+        stablecoin: ctx.accounts.state.key(),
         account: ctx.accounts.account.key(),
+        frozen_by: ctx.accounts.authority.key(),
+        frozen_at,
+        reason,
     });
 
     Ok(())
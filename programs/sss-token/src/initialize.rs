@@ -1,8 +1,16 @@
 use crate::constants::*;
 use crate::error::StablecoinError;
 use crate::events::*;
+use crate::extensions::{
+    initialize_interest_bearing_mint, initialize_permanent_delegate,
+    initialize_transfer_fee_config, initialize_transfer_hook, TransferFeeArgs,
+};
 use crate::state::*;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_spl::token_2022::{initialize_mint2, InitializeMint2, Token2022};
+use spl_token_2022::extension::{ExtensionType, StateWithExtensions};
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
@@ -22,6 +30,28 @@ pub struct Initialize<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct InitializeWithNewMint<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + StablecoinState::INIT_SPACE,
+        seeds = [VAULT_SEED, asset_mint.key().as_ref()],
+        bump
+    )]
+    pub state: Account<'info, StablecoinState>,
+
+    /// Fresh keypair; the Token-2022 mint is created at this address
+    #[account(mut)]
+    pub asset_mint: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
 pub fn handler(
     ctx: Context<Initialize>,
     preset: u8,
@@ -29,9 +59,123 @@ pub fn handler(
     symbol: String,
     uri: String,
     decimals: u8,
+    interest_rate_bps: Option<i16>,
+    transfer_fee: Option<TransferFeeArgs>,
 ) -> Result<()> {
-    let state = &mut ctx.accounts.state;
+    validate_params(preset, &name, &symbol, &uri, decimals, transfer_fee)?;
+    // Extensions must be written before `InitializeMint2`, which an existing mint has already run
+    require!(
+        interest_rate_bps.is_none() && transfer_fee.is_none(),
+        StablecoinError::ExtensionsRequireNewMint
+    );
+    validate_existing_mint(&ctx.accounts.asset_mint, &ctx.accounts.state.key(), decimals)?;
+
+    let authority = ctx.accounts.authority.key();
+    let asset_mint = ctx.accounts.asset_mint.key();
+    let bump = ctx.bumps.state;
+    seed_state(
+        &mut ctx.accounts.state,
+        authority,
+        asset_mint,
+        bump,
+        preset,
+        name,
+        symbol,
+        decimals,
+        interest_rate_bps,
+        transfer_fee,
+    )
+}
+
+/// Create a Token-2022 mint owned by the stablecoin PDA, then initialize the
+/// stablecoin on it.
+///
+/// The mint is sized for the requested extensions, and the stablecoin PDA is
+/// both its mint and freeze authority, so supply can only change through
+/// this program. SSS-2 mints also get the PDA as permanent delegate and this
+/// program as transfer hook, which seize and blacklist enforcement rely on.
+#[allow(clippy::too_many_arguments)]
+pub fn with_new_mint_handler(
+    ctx: Context<InitializeWithNewMint>,
+    preset: u8,
+    name: String,
+    symbol: String,
+    uri: String,
+    decimals: u8,
+    interest_rate_bps: Option<i16>,
+    transfer_fee: Option<TransferFeeArgs>,
+) -> Result<()> {
+    validate_params(preset, &name, &symbol, &uri, decimals, transfer_fee)?;
+
+    let state_key = ctx.accounts.state.key();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let mint = ctx.accounts.asset_mint.to_account_info();
+
+    let mut extensions = Vec::new();
+    if preset == PRESET_SSS_2 {
+        extensions.push(ExtensionType::PermanentDelegate);
+        extensions.push(ExtensionType::TransferHook);
+    }
+    if interest_rate_bps.is_some() {
+        extensions.push(ExtensionType::InterestBearingConfig);
+    }
+    if transfer_fee.is_some() {
+        extensions.push(ExtensionType::TransferFeeConfig);
+    }
+    let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&extensions)?;
+
+    create_account(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.authority.to_account_info(),
+                to: mint.clone(),
+            },
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        token_program.key,
+    )?;
 
+    // Extensions must be written before the mint itself is initialized
+    if preset == PRESET_SSS_2 {
+        initialize_permanent_delegate(&token_program, &mint, &state_key)?;
+        initialize_transfer_hook(&token_program, &mint, &state_key)?;
+    }
+    initialize_extensions(&token_program, &mint, &state_key, interest_rate_bps, transfer_fee)?;
+
+    initialize_mint2(
+        CpiContext::new(token_program, InitializeMint2 { mint }),
+        decimals,
+        &state_key,
+        Some(&state_key),
+    )?;
+
+    let authority = ctx.accounts.authority.key();
+    let asset_mint = ctx.accounts.asset_mint.key();
+    let bump = ctx.bumps.state;
+    seed_state(
+        &mut ctx.accounts.state,
+        authority,
+        asset_mint,
+        bump,
+        preset,
+        name,
+        symbol,
+        decimals,
+        interest_rate_bps,
+        transfer_fee,
+    )
+}
+
+fn validate_params(
+    preset: u8,
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    decimals: u8,
+    transfer_fee: Option<TransferFeeArgs>,
+) -> Result<()> {
     require!(
         preset == PRESET_SSS_1 || preset == PRESET_SSS_2,
         StablecoinError::InvalidPreset
@@ -43,23 +187,124 @@ pub fn handler(
     );
     require!(uri.len() <= MAX_URI_LENGTH, StablecoinError::UriTooLong);
     require!(decimals <= 9, StablecoinError::InvalidDecimals);
+    if let Some(fee) = transfer_fee {
+        require!(
+            fee.transfer_fee_bps <= BPS_DENOMINATOR,
+            StablecoinError::InvalidBasisPoints
+        );
+    }
+    Ok(())
+}
 
-    state.authority = ctx.accounts.authority.key();
-    state.asset_mint = ctx.accounts.asset_mint.key();
+/// Require `asset_mint` to be an initialized SPL Token or Token-2022 mint with
+/// `decimals` decimals whose mint authority is already the stablecoin PDA.
+/// The state PDA is seeded by the mint alone, so anyone could otherwise
+/// initialize a stablecoin on a mint whose authority has not been handed over.
+/// Only `initialize_with_new_mint` may start from an uninitialized mint, since it creates it.
+fn validate_existing_mint(asset_mint: &AccountInfo, state_key: &Pubkey, decimals: u8) -> Result<()> {
+    require!(
+        *asset_mint.owner == spl_token_2022::ID || *asset_mint.owner == anchor_spl::token::ID,
+        StablecoinError::InvalidMint
+    );
+    let data = asset_mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)
+        .map_err(|_| error!(StablecoinError::InvalidMint))?;
+    require!(
+        mint.base.decimals == decimals,
+        StablecoinError::DecimalsMismatch
+    );
+    require!(
+        mint.base.mint_authority == COption::Some(*state_key),
+        StablecoinError::MintAuthorityMismatch
+    );
+    Ok(())
+}
+
+/// Configure the requested mint extensions with the stablecoin PDA as their authority
+fn initialize_extensions<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    state_key: &Pubkey,
+    interest_rate_bps: Option<i16>,
+    transfer_fee: Option<TransferFeeArgs>,
+) -> Result<()> {
+    if let Some(rate_bps) = interest_rate_bps {
+        initialize_interest_bearing_mint(token_program, mint, state_key, rate_bps)?;
+    }
+    if let Some(fee) = transfer_fee {
+        initialize_transfer_fee_config(
+            token_program,
+            mint,
+            state_key,
+            fee.transfer_fee_bps,
+            fee.max_fee,
+        )?;
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn seed_state(
+    state: &mut Account<StablecoinState>,
+    authority: Pubkey,
+    asset_mint: Pubkey,
+    bump: u8,
+    preset: u8,
+    name: String,
+    symbol: String,
+    decimals: u8,
+    interest_rate_bps: Option<i16>,
+    transfer_fee: Option<TransferFeeArgs>,
+) -> Result<()> {
+    let state_key = state.key();
+
+    state.authority = authority;
+    state.asset_mint = asset_mint;
     state.total_supply = 0;
     state.paused = false;
     state.preset = preset;
     state.compliance_enabled = preset == PRESET_SSS_2;
-    state.bump = ctx.bumps.state;
+    state.max_transfer_amount = 0;
+    state.max_supply = UNCAPPED_SUPPLY;
+    state.min_burn_amount = NO_MINIMUM_AMOUNT;
+    state.min_mint_amount = NO_MINIMUM_AMOUNT;
+    state.reserve_backed = false;
+    state.allowlist_mode = false;
+    state.pause_until = NO_PAUSE_EXPIRY;
+    state.fee_bps = 0;
+    state.fee_treasury = Pubkey::default();
+    state.minter_count = 0;
+    state.blacklist_count = 0;
+    state.role_count = 0;
+    state.minter_count_verified = true;
+    state.role_count_verified = true;
+    state.bump = bump;
 
-    emit!(StablecoinInitialized {
-        stablecoin: state.key(),
+    emit_event(StablecoinInitialized {
+        stablecoin: state_key,
         preset,
         name,
         symbol,
         decimals,
         compliance_enabled: state.compliance_enabled,
+        authority,
+        asset_mint,
     });
 
+    if let Some(rate_bps) = interest_rate_bps {
+        emit_event(InterestRateUpdated {
+            stablecoin: state_key,
+            rate_bps,
+        });
+    }
+
+    if let Some(fee) = transfer_fee {
+        emit_event(TransferFeeUpdated {
+            stablecoin: state_key,
+            transfer_fee_bps: fee.transfer_fee_bps,
+            max_fee: fee.max_fee,
+        });
+    }
+
     Ok(())
 }
@@ -8,15 +8,20 @@ pub mod state;
 
 // Instruction modules - placed at crate root for Anchor compatibility
 pub mod admin;
+pub mod allowlist;
 pub mod blacklist;
 pub mod burn;
+pub mod confiscate;
 pub mod freeze;
 pub mod initialize;
 pub mod minter_management;
 pub mod mint;
+pub mod rescue;
+pub mod reserves;
 pub mod role_management;
 pub mod seize;
 pub mod thaw;
+pub mod transfer_fee;
 pub mod transfer_hook;
 
 // Extensions
@@ -24,17 +29,23 @@ pub mod extensions;
 
 // Re-export all instruction structs to crate root for Anchor client code generation
 pub use admin::*;
+pub use allowlist::*;
 pub use blacklist::*;
 pub use burn::*;
+pub use confiscate::*;
 pub use freeze::*;
 pub use initialize::*;
 pub use minter_management::*;
 pub use mint::*;
+pub use rescue::*;
+pub use reserves::*;
 pub use role_management::*;
 pub use seize::*;
 pub use thaw::*;
+pub use transfer_fee::*;
 pub use transfer_hook::*;
 pub use state::Role;
+pub use extensions::TransferFeeArgs;
 
 declare_id!("SSSToken11111111111111111111111111111111111");
 
@@ -49,8 +60,42 @@ pub mod sss_token {
         symbol: String,
         uri: String,
         decimals: u8,
+        interest_rate_bps: Option<i16>,
+        transfer_fee: Option<TransferFeeArgs>,
     ) -> Result<()> {
-        initialize::handler(ctx, preset, name, symbol, uri, decimals)
+        initialize::handler(
+            ctx,
+            preset,
+            name,
+            symbol,
+            uri,
+            decimals,
+            interest_rate_bps,
+            transfer_fee,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_with_new_mint(
+        ctx: Context<InitializeWithNewMint>,
+        preset: u8,
+        name: String,
+        symbol: String,
+        uri: String,
+        decimals: u8,
+        interest_rate_bps: Option<i16>,
+        transfer_fee: Option<TransferFeeArgs>,
+    ) -> Result<()> {
+        initialize::with_new_mint_handler(
+            ctx,
+            preset,
+            name,
+            symbol,
+            uri,
+            decimals,
+            interest_rate_bps,
+            transfer_fee,
+        )
     }
 
     pub fn mint(ctx: Context<Mint>, amount: u64) -> Result<()> {
@@ -61,16 +106,16 @@ pub mod sss_token {
         burn::handler(ctx, amount)
     }
 
-    pub fn freeze_account(ctx: Context<FreezeAccount>) -> Result<()> {
-        freeze::handler(ctx)
+    pub fn freeze_account(ctx: Context<FreezeAccount>, reason: String) -> Result<()> {
+        freeze::handler(ctx, reason)
     }
 
     pub fn thaw_account(ctx: Context<ThawAccount>) -> Result<()> {
         thaw::handler(ctx)
     }
 
-    pub fn pause(ctx: Context<Admin>) -> Result<()> {
-        admin::pause(ctx)
+    pub fn pause(ctx: Context<Admin>, reason: String, until: i64) -> Result<()> {
+        admin::pause(ctx, reason, until)
     }
 
     pub fn unpause(ctx: Context<Admin>) -> Result<()> {
@@ -81,6 +126,70 @@ pub mod sss_token {
         admin::transfer_authority(ctx, new_authority)
     }
 
+    pub fn set_max_transfer(ctx: Context<Admin>, amount: u64) -> Result<()> {
+        admin::set_max_transfer(ctx, amount)
+    }
+
+    pub fn set_max_supply(ctx: Context<Admin>, max_supply: u64) -> Result<()> {
+        admin::set_max_supply(ctx, max_supply)
+    }
+
+    pub fn set_min_burn(ctx: Context<Admin>, min_burn_amount: u64) -> Result<()> {
+        admin::set_min_burn(ctx, min_burn_amount)
+    }
+
+    pub fn set_min_mint(ctx: Context<Admin>, min_mint_amount: u64) -> Result<()> {
+        admin::set_min_mint(ctx, min_mint_amount)
+    }
+
+    pub fn configure_reserves(
+        ctx: Context<ConfigureReserves>,
+        attester: Pubkey,
+        max_staleness: i64,
+    ) -> Result<()> {
+        reserves::configure_reserves(ctx, attester, max_staleness)
+    }
+
+    pub fn disable_reserves(ctx: Context<DisableReserves>) -> Result<()> {
+        reserves::disable_reserves(ctx)
+    }
+
+    pub fn attest_reserves(ctx: Context<AttestReserves>, reserve_amount: u64) -> Result<()> {
+        reserves::attest_reserves(ctx, reserve_amount)
+    }
+
+    pub fn set_compliance(ctx: Context<Admin>, enabled: bool, upgrade_preset: bool) -> Result<()> {
+        admin::set_compliance(ctx, enabled, upgrade_preset)
+    }
+
+    pub fn set_fee(ctx: Context<SetFee>, fee_bps: u16, fee_treasury: Pubkey) -> Result<()> {
+        admin::set_fee(ctx, fee_bps, fee_treasury)
+    }
+
+    pub fn update_interest_rate(ctx: Context<UpdateInterestRate>, rate_bps: i16) -> Result<()> {
+        admin::update_interest_rate(ctx, rate_bps)
+    }
+
+    pub fn update_transfer_fee(
+        ctx: Context<UpdateTransferFee>,
+        transfer_fee_bps: u16,
+        max_fee: u64,
+    ) -> Result<()> {
+        transfer_fee::update_handler(ctx, transfer_fee_bps, max_fee)
+    }
+
+    pub fn withdraw_withheld_fees(ctx: Context<WithdrawWithheldFees>) -> Result<()> {
+        transfer_fee::withdraw_handler(ctx)
+    }
+
+    pub fn close_stablecoin(ctx: Context<CloseStablecoin>) -> Result<()> {
+        admin::close_stablecoin(ctx)
+    }
+
+    pub fn migrate_state(ctx: Context<MigrateState>) -> Result<()> {
+        admin::migrate_state(ctx)
+    }
+
     pub fn add_to_blacklist(ctx: Context<Blacklist>, reason: String) -> Result<()> {
         blacklist::add(ctx, reason)
     }
@@ -89,18 +198,54 @@ pub mod sss_token {
         blacklist::remove(ctx)
     }
 
+    pub fn recount_blacklist(ctx: Context<Admin>, reset: bool) -> Result<()> {
+        blacklist::recount(ctx, reset)
+    }
+
+    pub fn add_to_allowlist(ctx: Context<Allowlist>) -> Result<()> {
+        allowlist::add(ctx)
+    }
+
+    pub fn remove_from_allowlist(ctx: Context<RemoveFromAllowlist>) -> Result<()> {
+        allowlist::remove(ctx)
+    }
+
+    pub fn set_allowlist_mode(ctx: Context<Admin>, enabled: bool) -> Result<()> {
+        allowlist::set_mode(ctx, enabled)
+    }
+
     pub fn seize(ctx: Context<Seize>, amount: u64) -> Result<()> {
         seize::handler(ctx, amount)
     }
 
-    pub fn assign_role(ctx: Context<AssignRole>, role: Role) -> Result<()> {
-        role_management::handler(ctx, role)
+    pub fn confiscate_burn(ctx: Context<ConfiscateBurn>, amount: u64) -> Result<()> {
+        confiscate::handler(ctx, amount)
+    }
+
+    pub fn rescue_tokens(ctx: Context<RescueTokens>, amount: u64) -> Result<()> {
+        rescue::handler(ctx, amount)
+    }
+
+    pub fn assign_role(
+        ctx: Context<AssignRole>,
+        role: Role,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        role_management::handler(ctx, role, expires_at)
     }
 
     pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
         role_management::revoke_handler(ctx)
     }
 
+    pub fn cleanup_expired_role(ctx: Context<CleanupExpiredRole>) -> Result<()> {
+        role_management::cleanup_expired_handler(ctx)
+    }
+
+    pub fn recount_roles(ctx: Context<Admin>, reset: bool) -> Result<()> {
+        role_management::recount_handler(ctx, reset)
+    }
+
     pub fn add_minter(ctx: Context<AddMinter>, quota: u64) -> Result<()> {
         minter_management::add_minter_handler(ctx, quota)
     }
@@ -109,14 +254,30 @@ pub mod sss_token {
         minter_management::remove_minter_handler(ctx)
     }
 
+    pub fn recount_minters(ctx: Context<Admin>, reset: bool) -> Result<()> {
+        minter_management::recount_minters_handler(ctx, reset)
+    }
+
     pub fn update_quota(ctx: Context<UpdateQuota>, new_quota: u64) -> Result<()> {
         minter_management::update_quota_handler(ctx, new_quota)
     }
 
-    // Transfer hook is called by SPL Token-2022 during transfers.
-    // This is exposed as a standard instruction for testing purposes.
-    // Note: In production, this is invoked via the transfer hook interface.
+    pub fn rotate_minter(ctx: Context<RotateMinter>) -> Result<()> {
+        minter_management::rotate_minter_handler(ctx)
+    }
+
+    // Transfer hook called by SPL Token-2022 during transfers. It answers to
+    // the transfer hook interface's Execute discriminator, not Anchor's.
+    #[instruction(discriminator = crate::constants::TRANSFER_HOOK_EXECUTE_DISCRIMINATOR)]
     pub fn execute_transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
         transfer_hook::enforce_transfer(ctx, amount)
     }
+
+    pub fn initialize_extra_account_metas(ctx: Context<InitializeExtraAccountMetas>) -> Result<()> {
+        transfer_hook::initialize_extra_account_metas(ctx)
+    }
+
+    pub fn update_extra_account_metas(ctx: Context<UpdateExtraAccountMetas>) -> Result<()> {
+        transfer_hook::update_extra_account_metas(ctx)
+    }
 }
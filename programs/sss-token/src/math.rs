@@ -1,4 +1,6 @@
+use crate::constants::{BPS_DENOMINATOR, NO_MINIMUM_AMOUNT, UNCAPPED_SUPPLY, UNLIMITED_QUOTA};
 use crate::error::StablecoinError;
+use crate::state::StablecoinState;
 use anchor_lang::prelude::*;
 
 pub fn safe_add(a: u64, b: u64) -> Result<u64> {
@@ -13,9 +15,64 @@ pub fn safe_mul(a: u64, b: u64) -> Result<u64> {
     a.checked_mul(b).ok_or(StablecoinError::MathOverflow.into())
 }
 
+/// A quota of `UNLIMITED_QUOTA` (0) never rejects a mint
 pub fn validate_quota(minted: u64, amount: u64, quota: u64) -> Result<()> {
     let new_total = safe_add(minted, amount)?;
-    require!(new_total <= quota, StablecoinError::QuotaExceeded);
+    require!(
+        quota == UNLIMITED_QUOTA || new_total <= quota,
+        StablecoinError::QuotaExceeded
+    );
+    Ok(())
+}
+
+/// A max supply of `UNCAPPED_SUPPLY` (0) never rejects a mint
+pub fn validate_supply_cap(total_supply: u64, amount: u64, max_supply: u64) -> Result<()> {
+    let new_supply = safe_add(total_supply, amount)?;
+    require!(
+        max_supply == UNCAPPED_SUPPLY || new_supply <= max_supply,
+        StablecoinError::SupplyCapExceeded
+    );
+    Ok(())
+}
+
+/// A minimum of `NO_MINIMUM_AMOUNT` (0) never rejects an amount
+pub fn validate_minimum(amount: u64, minimum: u64) -> Result<()> {
+    require!(
+        minimum == NO_MINIMUM_AMOUNT || amount >= minimum,
+        StablecoinError::BelowMinimum
+    );
+    Ok(())
+}
+
+/// Rejects a token account of another mint before the token program does,
+/// so callers see a clear error instead of a generic CPI failure
+pub fn validate_token_account_mint(token_account_mint: &Pubkey, asset_mint: &Pubkey) -> Result<()> {
+    require_keys_eq!(
+        *token_account_mint,
+        *asset_mint,
+        StablecoinError::TokenAccountMintMismatch
+    );
+    Ok(())
+}
+
+/// Rejects a fee treasury holding another mint, so fees are never minted or
+/// moved into an account where they would be stranded
+pub fn validate_fee_treasury(treasury_mint: &Pubkey, asset_mint: &Pubkey) -> Result<()> {
+    require_keys_eq!(
+        *treasury_mint,
+        *asset_mint,
+        StablecoinError::InvalidFeeTreasury
+    );
+    Ok(())
+}
+
+/// Rejects an amount above the token account's balance before the token
+/// program does, logging both so the caller sees the shortfall
+pub fn validate_balance(balance: u64, amount: u64) -> Result<()> {
+    if amount > balance {
+        msg!("Insufficient balance: {} available, {} requested", balance, amount);
+        return err!(StablecoinError::InsufficientBalance);
+    }
     Ok(())
 }
 
@@ -27,9 +84,51 @@ pub fn update_supply(current: u64, amount: u64, increase: bool) -> Result<u64> {
     }
 }
 
+/// Removes `amount` from the tracked supply, failing with `SupplyUnderflow`
+/// rather than wrapping if it exceeds the current supply.
+pub fn decrease_supply(state: &mut StablecoinState, amount: u64) -> Result<()> {
+    state.total_supply = state
+        .total_supply
+        .checked_sub(amount)
+        .ok_or(StablecoinError::SupplyUnderflow)?;
+    Ok(())
+}
+
+/// Computes `amount * bps / 10_000`, rounding down.
+/// Uses a u128 intermediate so `amount * bps` cannot overflow.
+pub fn apply_bps(amount: u64, bps: u16) -> Result<u64> {
+    require!(bps <= BPS_DENOMINATOR, StablecoinError::InvalidBasisPoints);
+    let result = (amount as u128)
+        .checked_mul(bps as u128)
+        .ok_or(StablecoinError::MathOverflow)?
+        / BPS_DENOMINATOR as u128;
+    u64::try_from(result).map_err(|_| StablecoinError::MathOverflow.into())
+}
+
+/// Render a raw base-unit amount in whole tokens, with `decimals` fractional
+/// digits and thousands separators (1234567890 at 6 decimals is `1,234.567890`)
+pub fn format_token_amount(raw: u64, decimals: u8) -> String {
+    let decimals = decimals as usize;
+    let digits = format!("{:0>width$}", raw, width = decimals + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals);
+    let mut result = String::new();
+    for (i, c) in whole.chars().enumerate() {
+        if i > 0 && (whole.len() - i) % 3 == 0 {
+            result.push(',');
+        }
+        result.push(c);
+    }
+    if decimals > 0 {
+        result.push('.');
+        result.push_str(fraction);
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::{LEGACY_STATE_SPACE, MAX_REASON_LENGTH, NO_PAUSE_EXPIRY};
 
     #[test]
     fn test_safe_add() {
@@ -55,6 +154,56 @@ mod tests {
         assert!(validate_quota(400, 200, 500).is_err());
     }
 
+    #[test]
+    fn test_validate_quota_unlimited() {
+        assert!(validate_quota(0, 1, UNLIMITED_QUOTA).is_ok());
+        assert!(validate_quota(1_000_000, u64::MAX - 1_000_000, UNLIMITED_QUOTA).is_ok());
+        assert!(validate_quota(u64::MAX, 1, UNLIMITED_QUOTA).is_err());
+    }
+
+    #[test]
+    fn test_validate_supply_cap() {
+        assert!(validate_supply_cap(900, 100, 1_000).is_ok());
+        assert!(validate_supply_cap(900, 101, 1_000).is_err());
+        assert!(validate_supply_cap(u64::MAX - 1, 1, UNCAPPED_SUPPLY).is_ok());
+        assert!(validate_supply_cap(u64::MAX, 1, UNCAPPED_SUPPLY).is_err());
+    }
+
+    #[test]
+    fn test_validate_minimum() {
+        assert!(validate_minimum(1_000, 1_000).is_ok());
+        assert!(validate_minimum(u64::MAX, 1_000).is_ok());
+        let err = validate_minimum(999, 1_000).unwrap_err();
+        assert_eq!(err, StablecoinError::BelowMinimum.into());
+        assert!(validate_minimum(1, NO_MINIMUM_AMOUNT).is_ok());
+    }
+
+    #[test]
+    fn test_validate_token_account_mint() {
+        let asset_mint = Pubkey::new_unique();
+        assert!(validate_token_account_mint(&asset_mint, &asset_mint).is_ok());
+        let err = validate_token_account_mint(&Pubkey::new_unique(), &asset_mint).unwrap_err();
+        assert_eq!(err, StablecoinError::TokenAccountMintMismatch.into());
+        assert!(validate_token_account_mint(&Pubkey::default(), &asset_mint).is_err());
+    }
+
+    #[test]
+    fn test_validate_fee_treasury() {
+        let asset_mint = Pubkey::new_unique();
+        assert!(validate_fee_treasury(&asset_mint, &asset_mint).is_ok());
+        let err = validate_fee_treasury(&Pubkey::new_unique(), &asset_mint).unwrap_err();
+        assert_eq!(err, StablecoinError::InvalidFeeTreasury.into());
+    }
+
+    #[test]
+    fn test_validate_balance() {
+        assert!(validate_balance(1_000, 1_000).is_ok());
+        assert!(validate_balance(1_000, 1).is_ok());
+        let err = validate_balance(999, 1_000).unwrap_err();
+        assert_eq!(err, StablecoinError::InsufficientBalance.into());
+        assert!(validate_balance(0, 1).is_err());
+    }
+
     #[test]
     fn test_update_supply_increase() {
         assert_eq!(update_supply(100, 50, true).expect("should increase"), 150);
@@ -64,4 +213,158 @@ mod tests {
     fn test_update_supply_decrease() {
         assert_eq!(update_supply(100, 50, false).expect("should decrease"), 50);
     }
+
+    fn state_with_supply(total_supply: u64) -> StablecoinState {
+        StablecoinState {
+            authority: Pubkey::default(),
+            asset_mint: Pubkey::default(),
+            total_supply,
+            paused: false,
+            preset: 1,
+            compliance_enabled: false,
+            bump: 255,
+            max_transfer_amount: 0,
+            fee_bps: 0,
+            fee_treasury: Pubkey::default(),
+            minter_count: 0,
+            blacklist_count: 0,
+            max_supply: UNCAPPED_SUPPLY,
+            min_burn_amount: NO_MINIMUM_AMOUNT,
+            min_mint_amount: NO_MINIMUM_AMOUNT,
+            reserve_backed: false,
+            allowlist_mode: false,
+            paused_by: Pubkey::default(),
+            paused_at: 0,
+            pause_until: NO_PAUSE_EXPIRY,
+            pause_reason_len: 0,
+            pause_reason: [0; 200],
+            role_count: 0,
+            minter_count_verified: true,
+            role_count_verified: true,
+            _reserved: [0; 58],
+        }
+    }
+
+    #[test]
+    fn test_is_paused_with_expiry() {
+        let mut state = state_with_supply(0);
+        assert!(!state.is_paused(100));
+
+        state.paused = true;
+        assert!(state.is_paused(i64::MAX), "no expiry lasts until unpause");
+
+        state.pause_until = 200;
+        assert!(state.is_paused(199));
+        assert!(!state.is_paused(200));
+        assert!(!state.is_paused(201));
+    }
+
+    #[test]
+    fn test_state_layout_extends_legacy() {
+        let mut state = state_with_supply(0);
+        state.blacklist_count = 7;
+        state.max_supply = 9;
+        let mut data = Vec::new();
+        AnchorSerialize::serialize(&state, &mut data).unwrap();
+
+        assert_eq!(data.len(), StablecoinState::INIT_SPACE);
+        const { assert!(8 + StablecoinState::INIT_SPACE > LEGACY_STATE_SPACE) };
+        // Fields shared with the legacy layout keep their offsets and new
+        // ones start after them
+        assert_eq!(data[122..126], 7u32.to_le_bytes());
+        assert_eq!(data[126..134], 9u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_shorter_pause_reason_leaves_no_stale_bytes() {
+        let mut state = state_with_supply(0);
+        state.min_burn_amount = 11;
+        state.pause_until = 22;
+        let serialize = |state: &StablecoinState| {
+            let mut data = Vec::new();
+            AnchorSerialize::serialize(state, &mut data).unwrap();
+            data
+        };
+        let before = serialize(&state);
+
+        state.set_pause_reason(&"x".repeat(MAX_REASON_LENGTH)).unwrap();
+        state.set_pause_reason("short").unwrap();
+        assert_eq!(state.pause_reason_str(), "short");
+        assert!(state.pause_reason[5..].iter().all(|&b| b == 0));
+
+        state.set_pause_reason("").unwrap();
+        assert_eq!(serialize(&state), before, "clearing restores every byte");
+        assert_eq!(state.min_burn_amount, 11);
+        assert_eq!(state.pause_until, 22);
+
+        let err = state
+            .set_pause_reason(&"x".repeat(MAX_REASON_LENGTH + 1))
+            .unwrap_err();
+        assert_eq!(err, StablecoinError::ReasonTooLong.into());
+    }
+
+    #[test]
+    fn test_decrease_supply() {
+        let mut state = state_with_supply(100);
+        decrease_supply(&mut state, 40).expect("should decrease");
+        assert_eq!(state.total_supply, 60);
+    }
+
+    #[test]
+    fn test_decrease_supply_exact() {
+        let mut state = state_with_supply(100);
+        decrease_supply(&mut state, 100).expect("should decrease to zero");
+        assert_eq!(state.total_supply, 0);
+    }
+
+    #[test]
+    fn test_decrease_supply_underflow() {
+        let mut state = state_with_supply(100);
+        let err = decrease_supply(&mut state, 101).unwrap_err();
+        assert_eq!(err, StablecoinError::SupplyUnderflow.into());
+        // Supply is left untouched on failure
+        assert_eq!(state.total_supply, 100);
+    }
+
+    #[test]
+    fn test_apply_bps() {
+        assert_eq!(apply_bps(1_000_000, 25).expect("should apply"), 2_500);
+        assert_eq!(apply_bps(1_000_000, 10_000).expect("should apply"), 1_000_000);
+    }
+
+    #[test]
+    fn test_apply_bps_rounds_down() {
+        // 999 * 1 / 10_000 = 0.0999
+        assert_eq!(apply_bps(999, 1).expect("should apply"), 0);
+        // 19_999 * 1 / 10_000 = 1.9999
+        assert_eq!(apply_bps(19_999, 1).expect("should apply"), 1);
+        // 333 * 3_333 / 10_000 = 110.9889
+        assert_eq!(apply_bps(333, 3_333).expect("should apply"), 110);
+    }
+
+    #[test]
+    fn test_apply_bps_edge_cases() {
+        assert_eq!(apply_bps(u64::MAX, 0).expect("should apply"), 0);
+        assert_eq!(apply_bps(u64::MAX, 10_000).expect("should apply"), u64::MAX);
+        assert_eq!(apply_bps(u64::MAX, 5_000).expect("should apply"), u64::MAX / 2);
+        assert_eq!(apply_bps(0, 10_000).expect("should apply"), 0);
+    }
+
+    #[test]
+    fn test_apply_bps_rejects_over_100_percent() {
+        assert!(apply_bps(1_000, 10_001).is_err());
+        assert!(apply_bps(1_000, u16::MAX).is_err());
+    }
+
+    #[test]
+    fn test_format_token_amount() {
+        assert_eq!(format_token_amount(1_000_000, 6), "1.000000");
+        assert_eq!(format_token_amount(1_234_567_890, 6), "1,234.567890");
+        assert_eq!(format_token_amount(5, 6), "0.000005");
+        assert_eq!(format_token_amount(42, 6), "0.000042");
+        assert_eq!(format_token_amount(0, 2), "0.00");
+        assert_eq!(format_token_amount(999, 0), "999");
+        assert_eq!(format_token_amount(1_000_000, 0), "1,000,000");
+        assert_eq!(format_token_amount(u64::MAX, 9), "18,446,744,073.709551615");
+    }
 }
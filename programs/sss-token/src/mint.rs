@@ -1,7 +1,10 @@
-use crate::constants::{MINTER_SEED, ROLE_SEED, VAULT_SEED};
+use crate::constants::{MINTER_SEED, RESERVE_SEED, ROLE_SEED, VAULT_SEED};
 use crate::error::StablecoinError;
 use crate::events::*;
-use crate::math::update_supply;
+use crate::math::{
+    apply_bps, safe_add, safe_sub, update_supply, validate_fee_treasury, validate_minimum,
+    validate_supply_cap, validate_token_account_mint,
+};
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token_2022::{self, MintTo};
@@ -19,12 +22,17 @@ pub struct Mint<'info> {
     pub state: Account<'info, StablecoinState>,
 
     #[account(
-        seeds = [ROLE_SEED, state.key().as_ref(), authority.key().as_ref()],
-        bump,
+        seeds = [
+            ROLE_SEED,
+            state.key().as_ref(),
+            authority.key().as_ref(),
+            role_assignment.role.seed(),
+        ],
+        bump = role_assignment.bump,
     )]
     pub role_assignment: Option<Account<'info, RoleAssignment>>,
 
-    /// Optional: Minter info for quota enforcement
+    /// Minter info for quota enforcement; required unless the signer is the Master
     #[account(
         mut,
         seeds = [MINTER_SEED, state.key().as_ref(), authority.key().as_ref()],
@@ -39,6 +47,17 @@ pub struct Mint<'info> {
     pub recipient: InterfaceAccount<'info, TokenAccount>,
 
     pub token_program: Interface<'info, TokenInterface>,
+
+    /// Optional: Fee treasury token account, required when `state.fee_bps > 0`
+    #[account(mut)]
+    pub fee_treasury: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Optional: Reserve attestation, required when `state.reserve_backed`
+    #[account(
+        seeds = [RESERVE_SEED, state.key().as_ref()],
+        bump = reserve_attestation.bump,
+    )]
+    pub reserve_attestation: Option<Account<'info, ReserveAttestation>>,
 }
 
 pub fn handler(ctx: Context<Mint>, amount: u64) -> Result<()> {
@@ -46,58 +65,95 @@ pub fn handler(ctx: Context<Mint>, amount: u64) -> Result<()> {
 
     // RBAC Check: Must be Master (state.authority) or have Minter role
     let is_master = ctx.accounts.authority.key() == state.authority;
-    let is_minter = if let Some(assignment) = &ctx.accounts.role_assignment {
-        assignment.role == Role::Minter || assignment.role == Role::Master
-    } else {
-        false
-    };
-
-    require!(is_master || is_minter, StablecoinError::Unauthorized);
+    require_permission(
+        is_master,
+        ctx.accounts.role_assignment.as_deref(),
+        Action::Mint,
+        Clock::get()?.unix_timestamp,
+    )?;
     require!(amount > 0, StablecoinError::ZeroAmount);
-    require!(!state.paused, StablecoinError::VaultPaused);
-
-    // Quota enforcement for non-master minters
-    if let Some(minter_info) = &mut ctx.accounts.minter_info {
-        // Check if minter has exceeded their quota
-        let new_minted_amount = minter_info
-            .minted_amount
-            .checked_add(amount)
-            .ok_or(StablecoinError::MathOverflow)?;
-        
-        require!(
-            new_minted_amount <= minter_info.quota,
-            StablecoinError::QuotaExceeded
-        );
-        
-        // Update minted amount
-        minter_info.minted_amount = new_minted_amount;
+    require!(
+        !state.is_paused(Clock::get()?.unix_timestamp),
+        StablecoinError::VaultPaused
+    );
+    validate_minimum(amount, state.min_mint_amount)?;
+    validate_token_account_mint(&ctx.accounts.recipient.mint, &state.asset_mint)?;
+
+    // Quota enforcement: a non-master minter can't skip it by omitting its MinterInfo
+    match &mut ctx.accounts.minter_info {
+        Some(minter_info) => minter_info.record_mint(amount)?,
+        None => require!(is_master, StablecoinError::MinterInfoRequired),
     }
 
+    validate_supply_cap(state.total_supply, amount, state.max_supply)?;
+    if state.reserve_backed {
+        let attestation = ctx
+            .accounts
+            .reserve_attestation
+            .as_ref()
+            .ok_or(StablecoinError::ReserveAttestationRequired)?;
+        let new_supply = safe_add(state.total_supply, amount)?;
+        attestation.check_backing(new_supply, Clock::get()?.unix_timestamp)?;
+    }
     state.total_supply = update_supply(state.total_supply, amount, true)?;
 
+    let fee = apply_bps(amount, state.fee_bps)?;
+    let net_amount = safe_sub(amount, fee)?;
+
     let asset_mint_key = state.asset_mint.key();
     let authority_seeds = &[VAULT_SEED, asset_mint_key.as_ref(), &[state.bump]];
     let signer = &[&authority_seeds[..]];
 
-    let cpi_accounts = MintTo {
-        mint: ctx.accounts.asset_mint.to_account_info(),
-        to: ctx.accounts.recipient.to_account_info(),
-        authority: state.to_account_info(),
-    };
+    if fee > 0 {
+        let fee_treasury = ctx
+            .accounts
+            .fee_treasury
+            .as_ref()
+            .ok_or(StablecoinError::InvalidFeeTreasury)?;
+        require_keys_eq!(
+            fee_treasury.key(),
+            state.fee_treasury,
+            StablecoinError::InvalidFeeTreasury
+        );
+        validate_fee_treasury(&fee_treasury.mint, &state.asset_mint)?;
+
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.asset_mint.to_account_info(),
+            to: fee_treasury.to_account_info(),
+            authority: state.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer,
+        );
 
-    let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        cpi_accounts,
-        signer,
-    );
+        token_2022::mint_to(cpi_ctx, fee)?;
+    }
+
+    if net_amount > 0 {
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.asset_mint.to_account_info(),
+            to: ctx.accounts.recipient.to_account_info(),
+            authority: state.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer,
+        );
 
-    token_2022::mint_to(cpi_ctx, amount)?;
+        token_2022::mint_to(cpi_ctx, net_amount)?;
+    }
 
-    emit!(Minted {
+    emit_event(Minted {
         stablecoin: state.key(),
         recipient: ctx.accounts.recipient.key(),
-        amount,
+        amount: net_amount,
         minter: ctx.accounts.authority.key(),
+        fee,
     });
 
     Ok(())
@@ -1,4 +1,5 @@
-use crate::constants::MINTER_SEED;
+use crate::admin::Admin;
+use crate::constants::{MINTER_SEED, ROLE_SEED};
 use crate::error::StablecoinError;
 use crate::events::*;
 use crate::state::*;
@@ -10,6 +11,7 @@ pub struct AddMinter<'info> {
     pub authority: Signer<'info>,
 
     #[account(
+        mut,
         has_one = authority @ StablecoinError::Unauthorized
     )]
     pub state: Account<'info, StablecoinState>,
@@ -29,6 +31,8 @@ pub struct AddMinter<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Register a minter with a cumulative mint quota.
+/// A quota of `UNLIMITED_QUOTA` (0) lets the minter mint without limit.
 pub fn add_minter_handler(ctx: Context<AddMinter>, quota: u64) -> Result<()> {
     let minter_info = &mut ctx.accounts.minter_info;
     minter_info.minter = ctx.accounts.minter.key();
@@ -36,7 +40,13 @@ pub fn add_minter_handler(ctx: Context<AddMinter>, quota: u64) -> Result<()> {
     minter_info.minted_amount = 0;
     minter_info.bump = ctx.bumps.minter_info;
 
-    emit!(MinterAdded {
+    let state = &mut ctx.accounts.state;
+    state.minter_count = state
+        .minter_count
+        .checked_add(1)
+        .ok_or(StablecoinError::MathOverflow)?;
+
+    emit_event(MinterAdded {
         stablecoin: ctx.accounts.state.key(),
         minter: ctx.accounts.minter.key(),
         quota,
@@ -51,6 +61,7 @@ pub struct RemoveMinter<'info> {
     pub authority: Signer<'info>,
 
     #[account(
+        mut,
         has_one = authority @ StablecoinError::Unauthorized
     )]
     pub state: Account<'info, StablecoinState>,
@@ -67,7 +78,11 @@ pub struct RemoveMinter<'info> {
 pub fn remove_minter_handler(ctx: Context<RemoveMinter>) -> Result<()> {
     let minter = ctx.accounts.minter_info.minter;
 
-    emit!(MinterRemoved {
+    // Minters added before the counter existed are not tracked, so never underflow
+    let state = &mut ctx.accounts.state;
+    state.minter_count = state.minter_count.saturating_sub(1);
+
+    emit_event(MinterRemoved {
         stablecoin: ctx.accounts.state.key(),
         minter,
     });
@@ -75,6 +90,51 @@ pub fn remove_minter_handler(ctx: Context<RemoveMinter>) -> Result<()> {
     Ok(())
 }
 
+/// Backfill `minter_count` for deployments created before the counter existed,
+/// so `close_stablecoin` cannot run while untracked minters remain.
+/// Existing `MinterInfo` accounts are passed as remaining accounts; large sets can
+/// be split across transactions with `reset` set only on the first one.
+pub fn recount_minters_handler(ctx: Context<Admin>, reset: bool) -> Result<()> {
+    let state_key = ctx.accounts.state.key();
+    let mut counted: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+
+    for info in ctx.remaining_accounts.iter() {
+        require_keys_eq!(*info.owner, crate::ID, StablecoinError::InvalidMinterInfo);
+        let data = info.try_borrow_data()?;
+        let minter_info = MinterInfo::try_deserialize(&mut &data[..])
+            .map_err(|_| error!(StablecoinError::InvalidMinterInfo))?;
+
+        let expected = Pubkey::create_program_address(
+            &[
+                MINTER_SEED,
+                state_key.as_ref(),
+                minter_info.minter.as_ref(),
+                &[minter_info.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| error!(StablecoinError::InvalidMinterInfo))?;
+        require_keys_eq!(expected, info.key(), StablecoinError::InvalidMinterInfo);
+        require!(!counted.contains(info.key), StablecoinError::InvalidMinterInfo);
+        counted.push(info.key());
+    }
+
+    let state = &mut ctx.accounts.state;
+    let base = if reset { 0 } else { state.minter_count };
+    state.minter_count = base
+        .checked_add(counted.len() as u32)
+        .ok_or(StablecoinError::MathOverflow)?;
+    if reset {
+        state.minter_count_verified = true;
+    }
+
+    emit_event(MintersRecounted {
+        stablecoin: state_key,
+        minter_count: state.minter_count,
+    });
+    Ok(())
+}
+
 #[derive(Accounts)]
 pub struct UpdateQuota<'info> {
     #[account(mut)]
@@ -93,12 +153,15 @@ pub struct UpdateQuota<'info> {
     pub minter_info: Account<'info, MinterInfo>,
 }
 
+/// Change a minter's quota; `minted_amount` is kept.
+/// Setting `UNLIMITED_QUOTA` (0) lifts the limit. A quota below `minted_amount`
+/// blocks further mints until it is raised.
 pub fn update_quota_handler(ctx: Context<UpdateQuota>, new_quota: u64) -> Result<()> {
     let minter_info = &mut ctx.accounts.minter_info;
     let old_quota = minter_info.quota;
     minter_info.quota = new_quota;
 
-    emit!(QuotaUpdated {
+    emit_event(QuotaUpdated {
         stablecoin: ctx.accounts.state.key(),
         minter: minter_info.minter,
         old_quota,
@@ -107,3 +170,134 @@ pub fn update_quota_handler(ctx: Context<UpdateQuota>, new_quota: u64) -> Result
 
     Ok(())
 }
+
+#[derive(Accounts)]
+pub struct RotateMinter<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ StablecoinError::Unauthorized
+    )]
+    pub state: Account<'info, StablecoinState>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [MINTER_SEED, state.key().as_ref(), old_minter_info.minter.as_ref()],
+        bump = old_minter_info.bump
+    )]
+    pub old_minter_info: Account<'info, MinterInfo>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + MinterInfo::INIT_SPACE,
+        seeds = [MINTER_SEED, state.key().as_ref(), new_minter.key().as_ref()],
+        bump
+    )]
+    pub new_minter_info: Account<'info, MinterInfo>,
+
+    /// CHECK: The key taking over the old minter's quota
+    pub new_minter: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// The old key's Minter role, if it holds one; closed so the old key stops minting
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            ROLE_SEED,
+            state.key().as_ref(),
+            old_minter_info.minter.as_ref(),
+            Role::Minter.seed(),
+        ],
+        bump = old_role_assignment.bump
+    )]
+    pub old_role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    /// The new key's Minter role; required when `old_role_assignment` is passed
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RoleAssignment::INIT_SPACE,
+        seeds = [
+            ROLE_SEED,
+            state.key().as_ref(),
+            new_minter.key().as_ref(),
+            Role::Minter.seed(),
+        ],
+        bump
+    )]
+    pub new_role_assignment: Option<Account<'info, RoleAssignment>>,
+}
+
+/// Move a minter's quota and `minted_amount` to a new key and close the old
+/// `MinterInfo`, so a compromised key can be replaced without resetting usage.
+/// `minter_count` is unchanged: one minter replaces another. When the old key
+/// holds the Minter role, the role moves too (keeping its expiry), so the old
+/// key can't keep minting through its RoleAssignment.
+pub fn rotate_minter_handler(ctx: Context<RotateMinter>) -> Result<()> {
+    let old_minter_info = &ctx.accounts.old_minter_info;
+    let new_minter_info = &mut ctx.accounts.new_minter_info;
+    new_minter_info.minter = ctx.accounts.new_minter.key();
+    new_minter_info.quota = old_minter_info.quota;
+    new_minter_info.minted_amount = old_minter_info.minted_amount;
+    new_minter_info.bump = ctx.bumps.new_minter_info;
+
+    if let Some(old_role) = &ctx.accounts.old_role_assignment {
+        let new_role = ctx
+            .accounts
+            .new_role_assignment
+            .as_mut()
+            .ok_or(StablecoinError::MinterRoleRequired)?;
+        // The new key may already hold the role; only a fresh assignment is counted
+        let created = new_role.account == Pubkey::default();
+        new_role.role = Role::Minter;
+        new_role.account = ctx.accounts.new_minter.key();
+        new_role.assigned_by = ctx.accounts.authority.key();
+        new_role.assigned_at = Clock::get()?.unix_timestamp;
+        new_role.bump = ctx.bumps.new_role_assignment.unwrap_or_default();
+        new_role.expires_at = old_role.expires_at;
+
+        // The old assignment is closed. Roles assigned before the counter
+        // existed are not tracked, so never underflow
+        let state = &mut ctx.accounts.state;
+        state.role_count = state.role_count.saturating_sub(1);
+        if created {
+            state.role_count = state
+                .role_count
+                .checked_add(1)
+                .ok_or(StablecoinError::MathOverflow)?;
+        }
+
+        emit_event(RoleRevoked {
+            stablecoin: state.key(),
+            role: Role::Minter.name().to_string(),
+            account: old_role.account,
+            revoked_by: ctx.accounts.authority.key(),
+        });
+        emit_event(RoleAssigned {
+            stablecoin: state.key(),
+            role: Role::Minter.name().to_string(),
+            account: ctx.accounts.new_minter.key(),
+            assigned_by: ctx.accounts.authority.key(),
+            expires_at: old_role.expires_at,
+        });
+    }
+
+    let old_minter_info = &ctx.accounts.old_minter_info;
+    let new_minter_info = &ctx.accounts.new_minter_info;
+
+    emit_event(MinterRotated {
+        stablecoin: ctx.accounts.state.key(),
+        old_minter: old_minter_info.minter,
+        new_minter: new_minter_info.minter,
+        quota: new_minter_info.quota,
+        minted_amount: new_minter_info.minted_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use crate::constants::VAULT_SEED;
+use crate::error::StablecoinError;
+use crate::events::*;
+use crate::math::validate_balance;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{self, TransferChecked};
+use anchor_spl::token_interface::{Mint as TokenMint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct RescueTokens<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(has_one = authority @ StablecoinError::Unauthorized)]
+    pub state: Account<'info, StablecoinState>,
+
+    /// Mint of the tokens sent to the stablecoin PDA by mistake
+    pub mint: InterfaceAccount<'info, TokenMint>,
+
+    /// Token account owned by the stablecoin PDA holding the stray tokens
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = state,
+        token::token_program = token_program,
+    )]
+    pub source: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Return tokens someone sent to the stablecoin PDA by mistake.
+/// The stablecoin's own mint is refused, so this can never move its supply.
+pub fn handler(ctx: Context<RescueTokens>, amount: u64) -> Result<()> {
+    let state = &ctx.accounts.state;
+    require!(amount > 0, StablecoinError::ZeroAmount);
+    require_keys_neq!(
+        ctx.accounts.mint.key(),
+        state.asset_mint,
+        StablecoinError::CannotRescueAssetMint
+    );
+    validate_balance(ctx.accounts.source.amount, amount)?;
+
+    let asset_mint_key = state.asset_mint.key();
+    let authority_seeds = &[VAULT_SEED, asset_mint_key.as_ref(), &[state.bump]];
+    let signer = &[&authority_seeds[..]];
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.source.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.destination.to_account_info(),
+        authority: state.to_account_info(),
+    };
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+
+    token_2022::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    emit_event(TokensRescued {
+        stablecoin: state.key(),
+        mint: ctx.accounts.mint.key(),
+        from: ctx.accounts.source.key(),
+        to: ctx.accounts.destination.key(),
+        amount,
+        authority: ctx.accounts.authority.key(),
+    });
+    Ok(())
+}
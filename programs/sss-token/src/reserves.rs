@@ -0,0 +1,122 @@
+use crate::constants::RESERVE_SEED;
+use crate::error::StablecoinError;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ConfigureReserves<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ StablecoinError::Unauthorized
+    )]
+    pub state: Account<'info, StablecoinState>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ReserveAttestation::INIT_SPACE,
+        seeds = [RESERVE_SEED, state.key().as_ref()],
+        bump
+    )]
+    pub reserve_attestation: Account<'info, ReserveAttestation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DisableReserves<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ StablecoinError::Unauthorized
+    )]
+    pub state: Account<'info, StablecoinState>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [RESERVE_SEED, state.key().as_ref()],
+        bump = reserve_attestation.bump
+    )]
+    pub reserve_attestation: Account<'info, ReserveAttestation>,
+}
+
+#[derive(Accounts)]
+pub struct AttestReserves<'info> {
+    pub attester: Signer<'info>,
+
+    pub state: Account<'info, StablecoinState>,
+
+    #[account(
+        mut,
+        has_one = attester @ StablecoinError::Unauthorized,
+        seeds = [RESERVE_SEED, state.key().as_ref()],
+        bump = reserve_attestation.bump
+    )]
+    pub reserve_attestation: Account<'info, ReserveAttestation>,
+}
+
+/// Make the stablecoin reserve-backed, or change its attester and staleness window.
+/// A new attestation account starts unattested, so mints fail until the first `attest_reserves`.
+/// Changing the attester clears the previous attestation, so the new attester starts unattested too.
+pub fn configure_reserves(
+    ctx: Context<ConfigureReserves>,
+    attester: Pubkey,
+    max_staleness: i64,
+) -> Result<()> {
+    require!(max_staleness > 0, StablecoinError::InvalidMaxStaleness);
+
+    let state = &mut ctx.accounts.state;
+    let attestation = &mut ctx.accounts.reserve_attestation;
+    if attestation.attester != attester {
+        attestation.reserve_amount = 0;
+        attestation.updated_at = 0;
+    }
+    attestation.stablecoin = state.key();
+    attestation.attester = attester;
+    attestation.max_staleness = max_staleness;
+    attestation.bump = ctx.bumps.reserve_attestation;
+    state.reserve_backed = true;
+
+    emit_event(ReservesConfigured {
+        stablecoin: state.key(),
+        attester,
+        max_staleness,
+    });
+    Ok(())
+}
+
+/// Stop requiring reserve attestations for mints and close the attestation account.
+pub fn disable_reserves(ctx: Context<DisableReserves>) -> Result<()> {
+    let state = &mut ctx.accounts.state;
+    state.reserve_backed = false;
+
+    emit_event(ReservesDisabled {
+        stablecoin: state.key(),
+        authority: ctx.accounts.authority.key(),
+    });
+    Ok(())
+}
+
+pub fn attest_reserves(ctx: Context<AttestReserves>, reserve_amount: u64) -> Result<()> {
+    let attestation = &mut ctx.accounts.reserve_attestation;
+    let old_amount = attestation.reserve_amount;
+    let updated_at = Clock::get()?.unix_timestamp;
+    attestation.reserve_amount = reserve_amount;
+    attestation.updated_at = updated_at;
+
+    emit_event(ReservesAttested {
+        stablecoin: ctx.accounts.state.key(),
+        attester: attestation.attester,
+        old_amount,
+        new_amount: reserve_amount,
+        updated_at,
+    });
+    Ok(())
+}
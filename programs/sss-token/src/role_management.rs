@@ -1,3 +1,4 @@
+use crate::admin::Admin;
 use crate::constants::ROLE_SEED;
 use crate::error::StablecoinError;
 use crate::events::*;
@@ -5,11 +6,13 @@ use crate::state::*;
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
+#[instruction(role: Role)]
 pub struct AssignRole<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
+        mut,
         has_one = authority @ StablecoinError::Unauthorized
     )]
     pub state: Account<'info, StablecoinState>,
@@ -18,7 +21,7 @@ pub struct AssignRole<'info> {
         init,
         payer = authority,
         space = 8 + RoleAssignment::INIT_SPACE,
-        seeds = [ROLE_SEED, state.key().as_ref(), account.key().as_ref()],
+        seeds = [ROLE_SEED, state.key().as_ref(), account.key().as_ref(), role.seed()],
         bump
     )]
     pub assignment: Account<'info, RoleAssignment>,
@@ -27,28 +30,32 @@ pub struct AssignRole<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<AssignRole>, role: Role) -> Result<()> {
+pub fn handler(ctx: Context<AssignRole>, role: Role, expires_at: Option<i64>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    if let Some(expires_at) = expires_at {
+        require!(expires_at > now, StablecoinError::RoleExpired);
+    }
+
     let assignment = &mut ctx.accounts.assignment;
     assignment.role = role.clone();
     assignment.account = ctx.accounts.account.key();
     assignment.assigned_by = ctx.accounts.authority.key();
-    assignment.assigned_at = Clock::get()?.unix_timestamp;
+    assignment.assigned_at = now;
     assignment.bump = ctx.bumps.assignment;
+    assignment.expires_at = expires_at;
 
-    let role_name = match role {
-        Role::Master => "Master",
-        Role::Minter => "Minter",
-        Role::Burner => "Burner",
-        Role::Blacklister => "Blacklister",
-        Role::Pauser => "Pauser",
-        Role::Seizer => "Seizer",
-    };
+    let state = &mut ctx.accounts.state;
+    state.role_count = state
+        .role_count
+        .checked_add(1)
+        .ok_or(StablecoinError::MathOverflow)?;
 
-    emit!(RoleAssigned {
+    emit_event(RoleAssigned {
         stablecoin: ctx.accounts.state.key(),
-        role: role_name.to_string(),
+        role: role.name().to_string(),
         account: ctx.accounts.account.key(),
         assigned_by: ctx.accounts.authority.key(),
+        expires_at,
     });
     Ok(())
 }
@@ -59,6 +66,7 @@ pub struct RevokeRole<'info> {
     pub authority: Signer<'info>,
 
     #[account(
+        mut,
         has_one = authority @ StablecoinError::Unauthorized
     )]
     pub state: Account<'info, StablecoinState>,
@@ -66,29 +74,124 @@ pub struct RevokeRole<'info> {
     #[account(
         mut,
         close = authority,
-        seeds = [ROLE_SEED, state.key().as_ref(), assignment.account.as_ref()],
+        seeds = [
+            ROLE_SEED,
+            state.key().as_ref(),
+            assignment.account.as_ref(),
+            assignment.role.seed(),
+        ],
         bump = assignment.bump
     )]
     pub assignment: Account<'info, RoleAssignment>,
 }
 
 pub fn revoke_handler(ctx: Context<RevokeRole>) -> Result<()> {
-    let role_name = match ctx.accounts.assignment.role {
-        Role::Master => "Master",
-        Role::Minter => "Minter",
-        Role::Burner => "Burner",
-        Role::Blacklister => "Blacklister",
-        Role::Pauser => "Pauser",
-        Role::Seizer => "Seizer",
-    };
-
     let account = ctx.accounts.assignment.account;
 
-    emit!(RoleRevoked {
+    // Roles assigned before the counter existed are not tracked, so never underflow
+    let state = &mut ctx.accounts.state;
+    state.role_count = state.role_count.saturating_sub(1);
+
+    emit_event(RoleRevoked {
         stablecoin: ctx.accounts.state.key(),
-        role: role_name.to_string(),
+        role: ctx.accounts.assignment.role.name().to_string(),
         account,
+        revoked_by: ctx.accounts.authority.key(),
     });
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[derive(Accounts)]
+pub struct CleanupExpiredRole<'info> {
+    /// Anyone may clean up; rent goes back to whoever paid for the assignment
+    pub caller: Signer<'info>,
+
+    #[account(mut)]
+    pub state: Account<'info, StablecoinState>,
+
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [
+            ROLE_SEED,
+            state.key().as_ref(),
+            assignment.account.as_ref(),
+            assignment.role.seed(),
+        ],
+        bump = assignment.bump
+    )]
+    pub assignment: Account<'info, RoleAssignment>,
+
+    /// CHECK: Receives the rent; must be the assignment's `assigned_by`
+    #[account(mut, address = assignment.assigned_by)]
+    pub rent_receiver: AccountInfo<'info>,
+}
+
+pub fn cleanup_expired_handler(ctx: Context<CleanupExpiredRole>) -> Result<()> {
+    require!(
+        ctx.accounts
+            .assignment
+            .is_expired(Clock::get()?.unix_timestamp),
+        StablecoinError::RoleNotExpired
+    );
+    let state = &mut ctx.accounts.state;
+    state.role_count = state.role_count.saturating_sub(1);
+
+    let assignment = &ctx.accounts.assignment;
+
+    emit_event(ExpiredRoleCleaned {
+        stablecoin: ctx.accounts.state.key(),
+        role: assignment.role.name().to_string(),
+        account: assignment.account,
+        cleaned_by: ctx.accounts.caller.key(),
+    });
+
+    Ok(())
+}
+
+/// Backfill `role_count` for deployments created before the counter existed,
+/// so `close_stablecoin` cannot run while untracked role assignments remain.
+/// Existing `RoleAssignment` accounts are passed as remaining accounts; large sets
+/// can be split across transactions with `reset` set only on the first one.
+pub fn recount_handler(ctx: Context<Admin>, reset: bool) -> Result<()> {
+    let state_key = ctx.accounts.state.key();
+    let mut counted: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+
+    for info in ctx.remaining_accounts.iter() {
+        require_keys_eq!(*info.owner, crate::ID, StablecoinError::InvalidRoleAssignment);
+        let data = info.try_borrow_data()?;
+        let assignment = RoleAssignment::try_deserialize(&mut &data[..])
+            .map_err(|_| error!(StablecoinError::InvalidRoleAssignment))?;
+
+        let expected = Pubkey::create_program_address(
+            &[
+                ROLE_SEED,
+                state_key.as_ref(),
+                assignment.account.as_ref(),
+                assignment.role.seed(),
+                &[assignment.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| error!(StablecoinError::InvalidRoleAssignment))?;
+        require_keys_eq!(expected, info.key(), StablecoinError::InvalidRoleAssignment);
+        require!(!counted.contains(info.key), StablecoinError::InvalidRoleAssignment);
+        counted.push(info.key());
+    }
+
+    let state = &mut ctx.accounts.state;
+    let base = if reset { 0 } else { state.role_count };
+    state.role_count = base
+        .checked_add(counted.len() as u32)
+        .ok_or(StablecoinError::MathOverflow)?;
+    if reset {
+        state.role_count_verified = true;
+    }
+
+    emit_event(RolesRecounted {
+        stablecoin: state_key,
+        role_count: state.role_count,
+    });
+    Ok(())
+}
@@ -1,9 +1,11 @@
+use crate::blacklist::require_blacklisted;
 use crate::constants::{ROLE_SEED, VAULT_SEED};
 use crate::error::StablecoinError;
 use crate::events::*;
+use crate::math::{validate_balance, validate_token_account_mint};
 use crate::state::*;
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::{self, TransferChecked};
+use anchor_spl::token_2022::{self, Burn as SplBurn, MintTo};
 use anchor_spl::token_interface::{Mint as TokenMint, TokenAccount, TokenInterface};
 
 #[derive(Accounts)]
@@ -18,8 +20,13 @@ pub struct Seize<'info> {
     pub state: Account<'info, StablecoinState>,
 
     #[account(
-        seeds = [ROLE_SEED, state.key().as_ref(), authority.key().as_ref()],
-        bump,
+        seeds = [
+            ROLE_SEED,
+            state.key().as_ref(),
+            authority.key().as_ref(),
+            role_assignment.role.seed(),
+        ],
+        bump = role_assignment.bump,
     )]
     pub role_assignment: Option<Account<'info, RoleAssignment>>,
 
@@ -32,34 +39,67 @@ pub struct Seize<'info> {
     #[account(mut)]
     pub to: InterfaceAccount<'info, TokenAccount>,
 
+    /// CHECK: Blacklist entry for `from` or its owner (may not exist); validated in the handler
+    pub blacklist_entry: UncheckedAccount<'info>,
+
     pub token_program: Interface<'info, TokenInterface>,
 }
 
+/// Move tokens out of a blacklisted holder's account into `to`.
+/// The tokens are burned from `from` (the state PDA is the permanent delegate)
+/// and minted to `to`, rather than transferred: a transfer would run the
+/// transfer hook, which is this program, and Token-2022 calling back into the
+/// program that invoked it is rejected as reentrancy. Total supply is unchanged.
 pub fn handler(ctx: Context<Seize>, amount: u64) -> Result<()> {
     let state = &ctx.accounts.state;
 
     // RBAC Check: Must be Master or have Seizer role
     let is_master = ctx.accounts.authority.key() == state.authority;
-    let is_seizer = if let Some(assignment) = &ctx.accounts.role_assignment {
-        assignment.role == Role::Seizer || assignment.role == Role::Master
-    } else {
-        false
-    };
-
-    require!(is_master || is_seizer, StablecoinError::Unauthorized);
+    require_permission(
+        is_master,
+        ctx.accounts.role_assignment.as_deref(),
+        Action::Seize,
+        Clock::get()?.unix_timestamp,
+    )?;
     require!(amount > 0, StablecoinError::ZeroAmount);
-    require!(!state.paused, StablecoinError::VaultPaused);
+    require!(
+        !state.is_paused(Clock::get()?.unix_timestamp),
+        StablecoinError::VaultPaused
+    );
     require!(
         state.compliance_enabled,
         StablecoinError::ComplianceNotEnabled
     );
 
+    // SSS-2: only blacklisted holders can have their tokens seized
+    require_blacklisted(
+        &ctx.accounts.blacklist_entry,
+        &state.key(),
+        &ctx.accounts.from.key(),
+        &ctx.accounts.from.owner,
+    )?;
+    validate_balance(ctx.accounts.from.amount, amount)?;
+    validate_token_account_mint(&ctx.accounts.to.mint, &state.asset_mint)?;
+
     let asset_mint_key = state.asset_mint.key();
     let authority_seeds = &[VAULT_SEED, asset_mint_key.as_ref(), &[state.bump]];
     let signer = &[&authority_seeds[..]];
 
-    let cpi_accounts = TransferChecked {
+    let cpi_accounts = SplBurn {
+        mint: ctx.accounts.asset_mint.to_account_info(),
         from: ctx.accounts.from.to_account_info(),
+        authority: state.to_account_info(),
+    };
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+
+    token_2022::burn(cpi_ctx, amount)?;
+
+    let cpi_accounts = MintTo {
         mint: ctx.accounts.asset_mint.to_account_info(),
         to: ctx.accounts.to.to_account_info(),
         authority: state.to_account_info(),
@@ -71,13 +111,18 @@ pub fn handler(ctx: Context<Seize>, amount: u64) -> Result<()> {
         signer,
     );
 
-    token_2022::transfer_checked(cpi_ctx, amount, ctx.accounts.asset_mint.decimals)?;
+    token_2022::mint_to(cpi_ctx, amount)?;
+
+    // Re-read the source account to report what is left after a partial seizure
+    ctx.accounts.from.reload()?;
 
-    emit!(Seized {
-        stablecoin: state.key(),
+    emit_event(Seized {
+        stablecoin: ctx.accounts.state.key(),
         from: ctx.accounts.from.key(),
         to: ctx.accounts.to.key(),
         amount,
+        remaining_balance: ctx.accounts.from.amount,
+        authority: ctx.accounts.authority.key(),
     });
     Ok(())
 }
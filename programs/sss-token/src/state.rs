@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{MAX_REASON_LENGTH, NO_PAUSE_EXPIRY, UNLIMITED_QUOTA};
+use crate::error::StablecoinError;
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct StablecoinConfig {
     pub name: String,
@@ -21,14 +24,83 @@ pub struct StablecoinState {
     pub preset: u8,
     pub compliance_enabled: bool,
     pub bump: u8,
-    #[max_len(64)]
-    pub _reserved: [u8; 64],
+    /// Maximum amount allowed in a single transfer (0 = unlimited)
+    pub max_transfer_amount: u64,
+    /// Fee charged on mint and burn, in basis points (0 = no fee)
+    pub fee_bps: u16,
+    /// Token account that receives mint/burn fees
+    pub fee_treasury: Pubkey,
+    /// Number of active MinterInfo accounts
+    pub minter_count: u32,
+    /// Number of active BlacklistEntry accounts
+    pub blacklist_count: u32,
+    // Every field is fixed-size, so none of them moves when another changes.
+    // The fields below were appended to the original `LEGACY_STATE_SPACE`-byte
+    // layout; `migrate_state` grows older accounts and their new bytes read as zero.
+    /// Authorized supply ceiling; `UNCAPPED_SUPPLY` (0) means no cap
+    pub max_supply: u64,
+    /// Smallest amount a single burn may request; `NO_MINIMUM_AMOUNT` (0) means no floor
+    pub min_burn_amount: u64,
+    /// Smallest amount a single mint may request; `NO_MINIMUM_AMOUNT` (0) means no floor
+    pub min_mint_amount: u64,
+    /// Mints must stay within a fresh `ReserveAttestation`
+    pub reserve_backed: bool,
+    /// The transfer hook only lets tokens move between accounts with an `AllowlistEntry`
+    pub allowlist_mode: bool,
+    /// Who paused the stablecoin (default pubkey while unpaused)
+    pub paused_by: Pubkey,
+    /// When the stablecoin was paused (0 while unpaused)
+    pub paused_at: i64,
+    /// When the current pause lifts on its own; `NO_PAUSE_EXPIRY` (0) means it lasts until `unpause`
+    pub pause_until: i64,
+    /// Length in bytes of `pause_reason`
+    pub pause_reason_len: u8,
+    /// Operator-supplied pause reason, zero-padded
+    /// (empty while unpaused); read it with `pause_reason_str`
+    pub pause_reason: [u8; MAX_REASON_LENGTH],
+    /// Number of active RoleAssignment accounts
+    pub role_count: u32,
+    /// `minter_count` tracks every MinterInfo: set at initialize or by `recount_minters`.
+    /// Carved out of `_reserved`, so older states read `false` until recounted
+    pub minter_count_verified: bool,
+    /// `role_count` tracks every RoleAssignment: set at initialize or by `recount_roles`
+    pub role_count_verified: bool,
+    /// Room for future fields without another migration
+    pub _reserved: [u8; 58],
+}
+
+impl StablecoinState {
+    /// Whether operations are paused at `now`. A scheduled pause stops
+    /// applying at `pause_until` even though `paused` stays set until `unpause`.
+    pub fn is_paused(&self, now: i64) -> bool {
+        self.paused && (self.pause_until == NO_PAUSE_EXPIRY || now < self.pause_until)
+    }
+
+    /// The pause reason as text
+    pub fn pause_reason_str(&self) -> &str {
+        let len = usize::from(self.pause_reason_len).min(self.pause_reason.len());
+        std::str::from_utf8(&self.pause_reason[..len]).unwrap_or_default()
+    }
+
+    /// Store `reason`, zeroing the whole buffer first so no byte of an
+    /// earlier, longer reason survives
+    pub fn set_pause_reason(&mut self, reason: &str) -> Result<()> {
+        require!(
+            reason.len() <= MAX_REASON_LENGTH,
+            StablecoinError::ReasonTooLong
+        );
+        self.pause_reason = [0; MAX_REASON_LENGTH];
+        self.pause_reason[..reason.len()].copy_from_slice(reason.as_bytes());
+        self.pause_reason_len = reason.len() as u8;
+        Ok(())
+    }
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct MinterInfo {
     pub minter: Pubkey,
+    /// Maximum cumulative mint amount; `UNLIMITED_QUOTA` (0) means no limit
     pub quota: u64,
     pub minted_amount: u64,
     pub bump: u8,
@@ -36,6 +108,32 @@ pub struct MinterInfo {
     pub _reserved: [u8; 32],
 }
 
+impl MinterInfo {
+    /// Amount this minter can still mint; `u64::MAX` when the quota is unlimited
+    pub fn remaining(&self) -> u64 {
+        if self.quota == UNLIMITED_QUOTA {
+            u64::MAX
+        } else {
+            self.quota.saturating_sub(self.minted_amount)
+        }
+    }
+
+    /// Count `amount` against the quota.
+    /// Leaves `minted_amount` untouched if the mint would exceed a non-zero quota.
+    pub fn record_mint(&mut self, amount: u64) -> Result<()> {
+        let minted_amount = self
+            .minted_amount
+            .checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        require!(
+            self.quota == UNLIMITED_QUOTA || minted_amount <= self.quota,
+            StablecoinError::QuotaExceeded
+        );
+        self.minted_amount = minted_amount;
+        Ok(())
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct RoleAssignment {
@@ -44,8 +142,71 @@ pub struct RoleAssignment {
     pub assigned_by: Pubkey,
     pub assigned_at: i64,
     pub bump: u8,
-    #[max_len(32)]
-    pub _reserved: [u8; 32],
+    /// When the assignment stops granting its role; `None` never expires.
+    /// Carved out of `_reserved`, so older accounts read `None`
+    pub expires_at: Option<i64>,
+    #[max_len(23)]
+    pub _reserved: [u8; 23],
+}
+
+impl RoleAssignment {
+    /// Whether `expires_at` has passed at time `now`
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+}
+
+/// Operations a role assignment can authorize. Every other instruction is
+/// reserved to the Master authority through `has_one = authority`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Mint,
+    Burn,
+    Freeze,
+    Seize,
+    Confiscate,
+}
+
+impl Action {
+    pub const ALL: [Action; 5] = [
+        Action::Mint,
+        Action::Burn,
+        Action::Freeze,
+        Action::Seize,
+        Action::Confiscate,
+    ];
+}
+
+/// The permission matrix: whether an assignment of `role` authorizes `action`.
+/// Master grants everything; each other role grants only its own operations.
+pub fn can_perform(role: &Role, action: Action) -> bool {
+    matches!(
+        (role, action),
+        (Role::Master, _)
+            | (Role::Minter, Action::Mint)
+            | (Role::Burner, Action::Burn)
+            | (Role::Blacklister, Action::Freeze)
+            | (Role::Seizer, Action::Seize | Action::Confiscate)
+    )
+}
+
+/// RBAC check shared by the role-gated instructions: the caller must be the
+/// Master authority or hold an unexpired assignment whose role `can_perform`
+/// `action`. An expired assignment grants nothing and fails with `RoleExpired`.
+pub fn require_permission(
+    is_master: bool,
+    assignment: Option<&RoleAssignment>,
+    action: Action,
+    now: i64,
+) -> Result<()> {
+    if is_master {
+        return Ok(());
+    }
+    let assignment = assignment
+        .filter(|assignment| can_perform(&assignment.role, action))
+        .ok_or(StablecoinError::Unauthorized)?;
+    require!(!assignment.is_expired(now), StablecoinError::RoleExpired);
+    Ok(())
 }
 
 #[account]
@@ -61,6 +222,65 @@ pub struct BlacklistEntry {
     pub _reserved: [u8; 32],
 }
 
+/// Pre-approval of a token account, checked by the transfer hook in allowlist mode
+#[account]
+#[derive(InitSpace)]
+pub struct AllowlistEntry {
+    pub account: Pubkey,
+    pub added_by: Pubkey,
+    pub added_at: i64,
+    pub bump: u8,
+    #[max_len(32)]
+    pub _reserved: [u8; 32],
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct FreezeRecord {
+    pub account: Pubkey,
+    /// Paid the record's rent, which `thaw_account` returns to them
+    pub frozen_by: Pubkey,
+    pub frozen_at: i64,
+    #[max_len(200)]
+    pub reason: String,
+    pub bump: u8,
+    #[max_len(32)]
+    pub _reserved: [u8; 32],
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ReserveAttestation {
+    pub stablecoin: Pubkey,
+    /// Only signer allowed to attest reserves, set by the authority
+    pub attester: Pubkey,
+    /// Off-chain reserves backing the supply, in base units of the stablecoin
+    pub reserve_amount: u64,
+    /// When `reserve_amount` was last attested (0 until the first attestation)
+    pub updated_at: i64,
+    /// Seconds an attestation stays valid for minting
+    pub max_staleness: i64,
+    pub bump: u8,
+    #[max_len(32)]
+    pub _reserved: [u8; 32],
+}
+
+impl ReserveAttestation {
+    /// Check that a fresh attestation covers `new_supply` at time `now`
+    pub fn check_backing(&self, new_supply: u64, now: i64) -> Result<()> {
+        let age = now.saturating_sub(self.updated_at);
+        require!(
+            self.updated_at > 0 && age <= self.max_staleness,
+            StablecoinError::ReserveAttestationStale
+        );
+        require!(
+            new_supply <= self.reserve_amount,
+            StablecoinError::InsufficientReserves
+        );
+        Ok(())
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
 pub enum Role {
     Master,
@@ -70,3 +290,261 @@ pub enum Role {
     Pauser,
     Seizer,
 }
+
+impl Role {
+    /// Every role, in `to_u8` order
+    pub const ALL: [Role; 6] = [
+        Role::Master,
+        Role::Minter,
+        Role::Burner,
+        Role::Blacklister,
+        Role::Pauser,
+        Role::Seizer,
+    ];
+
+    /// Wire value of the role, the same as its Borsh variant index.
+    /// The authoritative mapping for off-chain clients and tests
+    pub fn to_u8(&self) -> u8 {
+        match self {
+            Role::Master => 0,
+            Role::Minter => 1,
+            Role::Burner => 2,
+            Role::Blacklister => 3,
+            Role::Pauser => 4,
+            Role::Seizer => 5,
+        }
+    }
+
+    /// Role with wire value `value`, or `None` if it is out of range
+    pub fn from_u8(value: u8) -> Option<Role> {
+        match value {
+            0 => Some(Role::Master),
+            1 => Some(Role::Minter),
+            2 => Some(Role::Burner),
+            3 => Some(Role::Blacklister),
+            4 => Some(Role::Pauser),
+            5 => Some(Role::Seizer),
+            _ => None,
+        }
+    }
+
+    /// Last seed of the role's `RoleAssignment` PDA, so one account can hold
+    /// several roles: `["role", stablecoin, account, seed]`
+    pub fn seed(&self) -> &'static [u8] {
+        match self {
+            Role::Master => b"master",
+            Role::Minter => b"minter",
+            Role::Burner => b"burner",
+            Role::Blacklister => b"blacklister",
+            Role::Pauser => b"pauser",
+            Role::Seizer => b"seizer",
+        }
+    }
+
+    /// Name used in role events
+    pub fn name(&self) -> &'static str {
+        match self {
+            Role::Master => "Master",
+            Role::Minter => "Minter",
+            Role::Burner => "Burner",
+            Role::Blacklister => "Blacklister",
+            Role::Pauser => "Pauser",
+            Role::Seizer => "Seizer",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minter_with_quota(quota: u64, minted_amount: u64) -> MinterInfo {
+        MinterInfo {
+            minter: Pubkey::default(),
+            quota,
+            minted_amount,
+            bump: 255,
+            _reserved: [0; 32],
+        }
+    }
+
+    #[test]
+    fn test_remaining() {
+        assert_eq!(minter_with_quota(500, 200).remaining(), 300);
+        assert_eq!(minter_with_quota(500, 500).remaining(), 0);
+        // Lowering the quota below what was already minted leaves nothing
+        assert_eq!(minter_with_quota(100, 200).remaining(), 0);
+    }
+
+    #[test]
+    fn test_remaining_unlimited() {
+        assert_eq!(minter_with_quota(0, 0).remaining(), u64::MAX);
+        assert_eq!(minter_with_quota(0, 1_000_000).remaining(), u64::MAX);
+    }
+
+    #[test]
+    fn test_record_mint_within_quota() {
+        let mut minter = minter_with_quota(500, 200);
+        minter.record_mint(300).expect("should fit the quota exactly");
+        assert_eq!(minter.minted_amount, 500);
+        assert_eq!(minter.remaining(), 0);
+    }
+
+    #[test]
+    fn test_record_mint_exceeds_quota() {
+        let mut minter = minter_with_quota(500, 200);
+        let err = minter.record_mint(301).unwrap_err();
+        assert_eq!(err, StablecoinError::QuotaExceeded.into());
+        assert_eq!(minter.minted_amount, 200);
+    }
+
+    #[test]
+    fn test_record_mint_unlimited() {
+        let mut minter = minter_with_quota(0, 0);
+        minter.record_mint(1_000_000).expect("unlimited quota");
+        minter.record_mint(u64::MAX - 1_000_000).expect("unlimited quota");
+        assert_eq!(minter.minted_amount, u64::MAX);
+        assert_eq!(minter.remaining(), u64::MAX);
+    }
+
+    #[test]
+    fn test_record_mint_overflow() {
+        let mut minter = minter_with_quota(0, u64::MAX);
+        let err = minter.record_mint(1).unwrap_err();
+        assert_eq!(err, StablecoinError::MathOverflow.into());
+        assert_eq!(minter.minted_amount, u64::MAX);
+    }
+
+    fn attestation(reserve_amount: u64, updated_at: i64) -> ReserveAttestation {
+        ReserveAttestation {
+            stablecoin: Pubkey::default(),
+            attester: Pubkey::default(),
+            reserve_amount,
+            updated_at,
+            max_staleness: 3_600,
+            bump: 255,
+            _reserved: [0; 32],
+        }
+    }
+
+    #[test]
+    fn test_check_backing() {
+        let attestation = attestation(1_000, 10_000);
+        attestation.check_backing(1_000, 10_000).expect("fully backed");
+        attestation.check_backing(500, 13_600).expect("at the staleness limit");
+        let err = attestation.check_backing(1_001, 10_000).unwrap_err();
+        assert_eq!(err, StablecoinError::InsufficientReserves.into());
+    }
+
+    #[test]
+    fn test_check_backing_stale() {
+        let err = attestation(1_000, 10_000).check_backing(1, 13_601).unwrap_err();
+        assert_eq!(err, StablecoinError::ReserveAttestationStale.into());
+        // Never attested
+        let err = attestation(1_000, 0).check_backing(1, 100).unwrap_err();
+        assert_eq!(err, StablecoinError::ReserveAttestationStale.into());
+    }
+
+    fn assignment(role: Role, expires_at: Option<i64>) -> RoleAssignment {
+        RoleAssignment {
+            role,
+            account: Pubkey::default(),
+            assigned_by: Pubkey::default(),
+            assigned_at: 0,
+            bump: 255,
+            expires_at,
+            _reserved: [0; 23],
+        }
+    }
+
+    #[test]
+    fn test_require_permission() {
+        let minter = assignment(Role::Minter, None);
+        require_permission(true, None, Action::Mint, 0).expect("master needs no assignment");
+        require_permission(false, Some(&minter), Action::Mint, 0).expect("matching role");
+        require_permission(false, Some(&assignment(Role::Master, None)), Action::Seize, 0)
+            .expect("master role grants every action");
+
+        let err = require_permission(false, Some(&minter), Action::Burn, 0).unwrap_err();
+        assert_eq!(err, StablecoinError::Unauthorized.into());
+        let err = require_permission(false, None, Action::Mint, 0).unwrap_err();
+        assert_eq!(err, StablecoinError::Unauthorized.into());
+    }
+
+    #[test]
+    fn test_require_permission_expired() {
+        let minter = assignment(Role::Minter, Some(1_000));
+        require_permission(false, Some(&minter), Action::Mint, 999).expect("not yet expired");
+        let err = require_permission(false, Some(&minter), Action::Mint, 1_000).unwrap_err();
+        assert_eq!(err, StablecoinError::RoleExpired.into());
+        // The Master authority is unaffected by an expired assignment it passes along
+        require_permission(true, Some(&minter), Action::Mint, 1_000).expect("master");
+        // A wrong role is still just unauthorized
+        let err = require_permission(false, Some(&minter), Action::Burn, 1_000).unwrap_err();
+        assert_eq!(err, StablecoinError::Unauthorized.into());
+    }
+
+    #[test]
+    fn test_permission_matrix() {
+        // Rows follow `Role::ALL`, columns `Action::ALL`:
+        //        Mint   Burn   Freeze Seize  Confiscate
+        let expected = [
+            [true, true, true, true, true],       // Master
+            [true, false, false, false, false],   // Minter
+            [false, true, false, false, false],   // Burner
+            [false, false, true, false, false],   // Blacklister
+            [false, false, false, false, false],  // Pauser
+            [false, false, false, true, true],    // Seizer
+        ];
+        for (role, row) in Role::ALL.iter().zip(expected) {
+            for (action, allowed) in Action::ALL.into_iter().zip(row) {
+                assert_eq!(
+                    can_perform(role, action),
+                    allowed,
+                    "{} / {:?}",
+                    role.name(),
+                    action
+                );
+                // The handler check agrees with the matrix for an unexpired assignment
+                let result = require_permission(false, Some(&assignment(role.clone(), None)), action, 0);
+                assert_eq!(result.is_ok(), allowed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_role_seeds_are_distinct() {
+        let roles = Role::ALL;
+        for (i, a) in roles.iter().enumerate() {
+            for b in &roles[i + 1..] {
+                assert_ne!(a.seed(), b.seed());
+            }
+        }
+    }
+
+    #[test]
+    fn test_role_u8_round_trip() {
+        for (i, role) in Role::ALL.iter().enumerate() {
+            assert_eq!(role.to_u8() as usize, i);
+            assert!(Role::from_u8(role.to_u8()) == Some(role.clone()));
+
+            // The wire value is the Borsh variant index
+            let mut encoded = Vec::new();
+            role.serialize(&mut encoded).unwrap();
+            assert_eq!(encoded, vec![role.to_u8()]);
+        }
+        assert!(Role::from_u8(Role::ALL.len() as u8).is_none());
+        assert!(Role::from_u8(u8::MAX).is_none());
+    }
+
+    #[test]
+    fn test_role_pda_known_vector() {
+        // Same vector as the CLI and backend tests: all three derivations must agree
+        let stablecoin = "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU".parse::<Pubkey>().unwrap();
+        let account = "9xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU".parse::<Pubkey>().unwrap();
+        let (pda, bump) =
+            crate::constants::role_pda(&stablecoin, &account, Role::Minter.seed(), &crate::ID);
+        assert_eq!(pda, "3ECT1sR2nYBKiJTUbELEtYuCzgTpANnFpcrY5sQjjS4G".parse::<Pubkey>().unwrap());
+        assert_eq!(bump, 251);
+    }
+}
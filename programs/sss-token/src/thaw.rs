@@ -1,6 +1,8 @@
-use crate::constants::VAULT_SEED;
+use crate::constants::{FREEZE_SEED, VAULT_SEED};
 use crate::error::StablecoinError;
 use crate::events::*;
+use crate::freeze::require_freeze_authority;
+use crate::math::validate_token_account_mint;
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token_2022::{self, ThawAccount as SplThaw};
@@ -8,6 +10,7 @@ use anchor_spl::token_interface::{Mint as TokenMint, TokenAccount, TokenInterfac
 
 #[derive(Accounts)]
 pub struct ThawAccount<'info> {
+    #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
@@ -23,12 +26,31 @@ pub struct ThawAccount<'info> {
     pub account: InterfaceAccount<'info, TokenAccount>,
 
     pub token_program: Interface<'info, TokenInterface>,
+
+    /// Accounts frozen before records existed get one by freezing them again
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [FREEZE_SEED, state.key().as_ref(), account.key().as_ref()],
+        bump = freeze_record.bump,
+    )]
+    pub freeze_record: Account<'info, FreezeRecord>,
+
+    /// CHECK: Receives the record's rent; must be the record's `frozen_by`, who paid it
+    #[account(mut, address = freeze_record.frozen_by)]
+    pub rent_receiver: AccountInfo<'info>,
 }
 
 pub fn handler(ctx: Context<ThawAccount>) -> Result<()> {
-    require!(!ctx.accounts.state.paused, StablecoinError::VaultPaused);
+    require!(
+        !ctx.accounts.state.is_paused(Clock::get()?.unix_timestamp),
+        StablecoinError::VaultPaused
+    );
 
     let state = &ctx.accounts.state;
+    require_freeze_authority(&ctx.accounts.asset_mint, &state.key())?;
+    validate_token_account_mint(&ctx.accounts.account.mint, &state.asset_mint)?;
+
     let asset_mint_key = state.asset_mint.key();
     let authority_seeds = &[VAULT_SEED, asset_mint_key.as_ref(), &[state.bump]];
     let signer = &[&authority_seeds[..]];
@@ -48,9 +70,15 @@ pub fn handler(ctx: Context<ThawAccount>) -> Result<()> {
 
     token_2022::thaw_account(cpi_ctx)?;
 
-    emit!(Thawed {
+    let record = &ctx.accounts.freeze_record;
+
+    emit_event(Thawed {
         stablecoin: state.key(),
         account: ctx.accounts.account.key(),
+        thawed_by: ctx.accounts.authority.key(),
+        thawed_at: Clock::get()?.unix_timestamp,
+        frozen_by: Some(record.frozen_by),
+        frozen_at: Some(record.frozen_at),
     });
 
     Ok(())
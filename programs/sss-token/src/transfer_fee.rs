@@ -0,0 +1,113 @@
+use crate::constants::{BPS_DENOMINATOR, VAULT_SEED};
+use crate::error::StablecoinError;
+use crate::events::*;
+use crate::extensions;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::TokenAccount;
+
+#[derive(Accounts)]
+pub struct UpdateTransferFee<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority @ StablecoinError::Unauthorized,
+        has_one = asset_mint
+    )]
+    pub state: Account<'info, StablecoinState>,
+
+    /// CHECK: Token-2022 mint with the transfer fee extension, validated by the token program
+    #[account(mut)]
+    pub asset_mint: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn update_handler(
+    ctx: Context<UpdateTransferFee>,
+    transfer_fee_bps: u16,
+    max_fee: u64,
+) -> Result<()> {
+    require!(
+        transfer_fee_bps <= BPS_DENOMINATOR,
+        StablecoinError::InvalidBasisPoints
+    );
+
+    let state = &ctx.accounts.state;
+    let asset_mint_key = state.asset_mint;
+    let authority_seeds = &[VAULT_SEED, asset_mint_key.as_ref(), &[state.bump]];
+
+    extensions::set_transfer_fee(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.asset_mint.to_account_info(),
+        &state.to_account_info(),
+        &[&authority_seeds[..]],
+        transfer_fee_bps,
+        max_fee,
+    )?;
+
+    emit_event(TransferFeeUpdated {
+        stablecoin: state.key(),
+        transfer_fee_bps,
+        max_fee,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawWithheldFees<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority @ StablecoinError::Unauthorized,
+        has_one = asset_mint
+    )]
+    pub state: Account<'info, StablecoinState>,
+
+    /// CHECK: Token-2022 mint with the transfer fee extension, validated by the token program
+    #[account(mut)]
+    pub asset_mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = asset_mint,
+        token::token_program = token_program
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Withdraw fees that have been harvested to the mint.
+/// Harvesting withheld fees from token accounts to the mint is permissionless.
+pub fn withdraw_handler(ctx: Context<WithdrawWithheldFees>) -> Result<()> {
+    let balance_before = ctx.accounts.to.amount;
+
+    let state = &ctx.accounts.state;
+    let asset_mint_key = state.asset_mint;
+    let authority_seeds = &[VAULT_SEED, asset_mint_key.as_ref(), &[state.bump]];
+
+    extensions::withdraw_withheld_from_mint(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.asset_mint.to_account_info(),
+        &ctx.accounts.to.to_account_info(),
+        &state.to_account_info(),
+        &[&authority_seeds[..]],
+    )?;
+
+    ctx.accounts.to.reload()?;
+    let amount = ctx
+        .accounts
+        .to
+        .amount
+        .checked_sub(balance_before)
+        .ok_or(StablecoinError::MathOverflow)?;
+
+    emit_event(WithheldFeesWithdrawn {
+        stablecoin: ctx.accounts.state.key(),
+        to: ctx.accounts.to.key(),
+        amount,
+    });
+    Ok(())
+}
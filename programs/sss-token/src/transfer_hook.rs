@@ -1,15 +1,79 @@
-use crate::constants::BLACKLIST_SEED;
+use crate::constants::{ALLOWLIST_SEED, BLACKLIST_SEED, EXTRA_ACCOUNT_METAS_SEED};
 use crate::error::StablecoinError;
+use crate::events::*;
 use crate::state::*;
 use anchor_lang::prelude::*;
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+};
+use spl_token_2022::extension::StateWithExtensions;
+use spl_token_2022::state::Account as SplTokenAccount;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
 
-pub fn enforce_transfer(ctx: Context<TransferHook>, _amount: u64) -> Result<()> {
+/// Number of accounts `extra_account_metas` returns
+const EXTRA_ACCOUNT_COUNT: usize = 7;
+/// Extra accounts after the state and the two token-account blacklist entries
+/// that `enforce_transfer` reads from `remaining_accounts`
+const REMAINING_EXTRA_COUNT: usize = EXTRA_ACCOUNT_COUNT - 3;
+
+/// Interface account indices, as Token-2022 orders them before the extras
+const SOURCE_INDEX: u8 = 0;
+const DESTINATION_INDEX: u8 = 2;
+/// The stablecoin state is the first extra account
+const STATE_INDEX: u8 = 5;
+/// Offset and length of the owner in token account data
+const TOKEN_ACCOUNT_OWNER_OFFSET: u8 = 32;
+const PUBKEY_LEN: u8 = 32;
+
+pub fn enforce_transfer(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
     let state = &ctx.accounts.state;
 
+    require!(
+        !state.is_paused(Clock::get()?.unix_timestamp),
+        StablecoinError::VaultPaused
+    );
+
+    // Token-2022 already rejects frozen accounts before invoking the hook;
+    // checking here keeps the hook correct when called directly
+    require!(
+        !is_frozen(&ctx.accounts.source) && !is_frozen(&ctx.accounts.destination),
+        StablecoinError::AccountFrozen
+    );
+
+    // Per-transfer cap applies to every preset; 0 disables it
+    if state.max_transfer_amount > 0 {
+        require!(
+            amount <= state.max_transfer_amount,
+            StablecoinError::TransferAmountExceeded
+        );
+    }
+
+    // Allowlist entries follow the declared accounts. Meta lists created before
+    // allowlist mode existed don't resolve them, so those transfers fail closed
+    if state.allowlist_mode {
+        require_allowlisted(
+            state.key(),
+            ctx.accounts.source.key(),
+            ctx.remaining_accounts.first(),
+        )?;
+        require_allowlisted(
+            state.key(),
+            ctx.accounts.destination.key(),
+            ctx.remaining_accounts.get(1),
+        )?;
+    }
+
     if !state.compliance_enabled {
         return Ok(());
     }
 
+    // Meta lists created before wallet entries existed don't resolve them;
+    // fail closed until `update_extra_account_metas` rewrites the list
+    require!(
+        ctx.remaining_accounts.len() >= REMAINING_EXTRA_COUNT,
+        StablecoinError::ExtraAccountMetasOutdated
+    );
+
     let (sender_blacklist_pda, _) = find_blacklist_pda(state.key(), ctx.accounts.source.key());
     if ctx.accounts.sender_blacklist.key == &sender_blacklist_pda {
         require!(
@@ -27,9 +91,174 @@ pub fn enforce_transfer(ctx: Context<TransferHook>, _amount: u64) -> Result<()>
         );
     }
 
+    // Entries keyed on the wallets that own the token accounts follow the
+    // allowlist entries
+    require_owner_not_blacklisted(
+        state.key(),
+        &ctx.accounts.source,
+        &ctx.remaining_accounts[2],
+    )?;
+    require_owner_not_blacklisted(
+        state.key(),
+        &ctx.accounts.destination,
+        &ctx.remaining_accounts[3],
+    )?;
+
+    Ok(())
+}
+
+/// Extra accounts Token-2022 resolves for every transfer, in `TransferHook` order:
+/// the stablecoin state, the blacklist entries of the source and destination
+/// token accounts, their allowlist entries, then the blacklist entries of the
+/// wallets that own them
+pub fn extra_account_metas(state: &Pubkey) -> Result<Vec<ExtraAccountMeta>> {
+    let entry = |seed: &[u8], token_account_index: u8| {
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: seed.to_vec() },
+                Seed::AccountKey { index: STATE_INDEX },
+                Seed::AccountKey { index: token_account_index },
+            ],
+            false,
+            false,
+        )
+    };
+    let owner_entry = |token_account_index: u8| {
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: BLACKLIST_SEED.to_vec() },
+                Seed::AccountKey { index: STATE_INDEX },
+                Seed::AccountData {
+                    account_index: token_account_index,
+                    data_index: TOKEN_ACCOUNT_OWNER_OFFSET,
+                    length: PUBKEY_LEN,
+                },
+            ],
+            false,
+            false,
+        )
+    };
+    Ok(vec![
+        ExtraAccountMeta::new_with_pubkey(state, false, false)?,
+        entry(BLACKLIST_SEED, SOURCE_INDEX)?,
+        entry(BLACKLIST_SEED, DESTINATION_INDEX)?,
+        entry(ALLOWLIST_SEED, SOURCE_INDEX)?,
+        entry(ALLOWLIST_SEED, DESTINATION_INDEX)?,
+        owner_entry(SOURCE_INDEX)?,
+        owner_entry(DESTINATION_INDEX)?,
+    ])
+}
+
+/// Create the mint's ExtraAccountMetaList so Token-2022 can pass the hook its accounts
+pub fn initialize_extra_account_metas(ctx: Context<InitializeExtraAccountMetas>) -> Result<()> {
+    let state = ctx.accounts.state.key();
+    let metas = extra_account_metas(&state)?;
+    let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas)?;
+
+    emit_event(TransferHookInitialized {
+        stablecoin: state,
+        asset_mint: ctx.accounts.asset_mint.key(),
+        extra_account_meta_list: ctx.accounts.extra_account_meta_list.key(),
+    });
+
+    Ok(())
+}
+
+/// Rewrite an existing ExtraAccountMetaList with the current `extra_account_metas`,
+/// growing it first if needed. Lists created by older program versions miss
+/// accounts the hook now reads, and their transfers fail until updated.
+/// The authority pays any extra rent.
+pub fn update_extra_account_metas(ctx: Context<UpdateExtraAccountMetas>) -> Result<()> {
+    let state = ctx.accounts.state.key();
+    let meta_list = &ctx.accounts.extra_account_meta_list;
+    let metas = extra_account_metas(&state)?;
+    let new_len = ExtraAccountMetaList::size_of(metas.len())?;
+
+    if meta_list.data_len() < new_len {
+        let shortfall = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(meta_list.lamports());
+        if shortfall > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: meta_list.to_account_info(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        meta_list.resize(new_len)?;
+    }
+
+    let mut data = meta_list.try_borrow_mut_data()?;
+    ExtraAccountMetaList::update::<ExecuteInstruction>(&mut data, &metas)?;
+
+    emit_event(TransferHookUpdated {
+        stablecoin: state,
+        asset_mint: ctx.accounts.asset_mint.key(),
+        extra_account_meta_list: meta_list.key(),
+    });
+
     Ok(())
 }
 
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetas<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority @ StablecoinError::Unauthorized,
+        has_one = asset_mint
+    )]
+    pub state: Account<'info, StablecoinState>,
+
+    /// CHECK: Matched against `state.asset_mint`
+    pub asset_mint: AccountInfo<'info>,
+
+    /// CHECK: ExtraAccountMetaList PDA, written by `ExtraAccountMetaList::init`
+    #[account(
+        init,
+        payer = authority,
+        space = ExtraAccountMetaList::size_of(EXTRA_ACCOUNT_COUNT)?,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, asset_mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateExtraAccountMetas<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority @ StablecoinError::Unauthorized,
+        has_one = asset_mint
+    )]
+    pub state: Account<'info, StablecoinState>,
+
+    /// CHECK: Matched against `state.asset_mint`
+    pub asset_mint: AccountInfo<'info>,
+
+    /// CHECK: Existing ExtraAccountMetaList PDA, rewritten by `ExtraAccountMetaList::update`
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, asset_mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
 /// TransferHook accounts for SPL Token-2022 transfer hook interface.
 /// Note: This uses manual account validation because the SPL transfer hook
 /// interface requires specific account ordering that doesn't fit Anchor's
@@ -54,9 +283,101 @@ pub struct TransferHook<'info> {
     pub recipient_blacklist: AccountInfo<'info>,
 }
 
+/// Whether a token account is frozen. Accounts that don't parse as token
+/// accounts are treated as not frozen.
+fn is_frozen(account: &AccountInfo) -> bool {
+    let Ok(data) = account.try_borrow_data() else {
+        return false;
+    };
+    StateWithExtensions::<SplTokenAccount>::unpack(&data)
+        .map(|token_account| token_account.base.is_frozen())
+        .unwrap_or(false)
+}
+
+/// Owner of a token account, if it parses as one
+fn token_account_owner(account: &AccountInfo) -> Option<Pubkey> {
+    let data = account.try_borrow_data().ok()?;
+    StateWithExtensions::<SplTokenAccount>::unpack(&data)
+        .map(|token_account| token_account.base.owner)
+        .ok()
+}
+
+/// Reject the transfer when `entry` is the live blacklist entry of the wallet
+/// that owns `token_account`
+fn require_owner_not_blacklisted(
+    stablecoin: Pubkey,
+    token_account: &AccountInfo,
+    entry: &AccountInfo,
+) -> Result<()> {
+    let Some(owner) = token_account_owner(token_account) else {
+        return Ok(());
+    };
+    let (expected, _) = find_blacklist_pda(stablecoin, owner);
+    if entry.key == &expected {
+        require!(entry.data_is_empty(), StablecoinError::BlacklistViolation);
+    }
+    Ok(())
+}
+
 fn find_blacklist_pda(stablecoin: Pubkey, account: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[BLACKLIST_SEED, stablecoin.as_ref(), account.as_ref()],
         &crate::ID,
     )
-}
\ No newline at end of file
+}
+/// Require `entry` to be the live allowlist entry of the token `account`
+fn require_allowlisted(
+    stablecoin: Pubkey,
+    account: Pubkey,
+    entry: Option<&AccountInfo>,
+) -> Result<()> {
+    let entry = entry.ok_or(StablecoinError::NotAllowlisted)?;
+    let (expected, _) = Pubkey::find_program_address(
+        &[ALLOWLIST_SEED, stablecoin.as_ref(), account.as_ref()],
+        &crate::ID,
+    );
+    require!(
+        entry.key == &expected && entry.owner == &crate::ID && !entry.data_is_empty(),
+        StablecoinError::NotAllowlisted
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::TRANSFER_HOOK_EXECUTE_DISCRIMINATOR;
+    use anchor_lang::InstructionData;
+    use spl_transfer_hook_interface::instruction::TransferHookInstruction;
+
+    #[test]
+    fn test_execute_uses_interface_discriminator() {
+        let data = TransferHookInstruction::Execute { amount: 7 }.pack();
+        assert_eq!(&data[..8], TRANSFER_HOOK_EXECUTE_DISCRIMINATOR);
+        assert_eq!(
+            crate::instruction::ExecuteTransferHook::DISCRIMINATOR,
+            TRANSFER_HOOK_EXECUTE_DISCRIMINATOR
+        );
+        // Token-2022's amount encoding matches the instruction's `u64` argument
+        assert_eq!(
+            data,
+            crate::instruction::ExecuteTransferHook { amount: 7 }.data()
+        );
+    }
+
+    #[test]
+    fn test_extra_account_metas_resolve_owner_entries() {
+        let metas = extra_account_metas(&Pubkey::new_unique()).unwrap();
+        assert_eq!(metas.len(), EXTRA_ACCOUNT_COUNT);
+
+        // Blacklist seeds: literal "blacklist", state, then the 32 owner bytes
+        // at offset 32 of the source and destination token accounts
+        let source = &metas[5].address_config;
+        assert_eq!(&source[..11], b"\x01\x09blacklist");
+        assert_eq!(&source[11..17], &[3, STATE_INDEX, 4, SOURCE_INDEX, 32, 32]);
+        assert_eq!(
+            &metas[6].address_config[11..17],
+            &[3, STATE_INDEX, 4, DESTINATION_INDEX, 32, 32]
+        );
+    }
+}
@@ -1,4 +1,5 @@
 import { PublicKey } from '@solana/web3.js';
+import { Role } from './types';
 
 const PROGRAM_ID = new PublicKey('SSSToken11111111111111111111111111111111111');
 
@@ -10,8 +11,11 @@ export function findMinterPda(stablecoin: PublicKey, minter: PublicKey): [Public
   return PublicKey.findProgramAddressSync([Buffer.from('minter'), stablecoin.toBuffer(), minter.toBuffer()], PROGRAM_ID);
 }
 
-export function findRolePda(stablecoin: PublicKey, account: PublicKey): [PublicKey, number] {
-  return PublicKey.findProgramAddressSync([Buffer.from('role'), stablecoin.toBuffer(), account.toBuffer()], PROGRAM_ID);
+export function findRolePda(stablecoin: PublicKey, account: PublicKey, role: Role): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('role'), stablecoin.toBuffer(), account.toBuffer(), Buffer.from(role.toLowerCase())],
+    PROGRAM_ID
+  );
 }
 
 export function findBlacklistPda(stablecoin: PublicKey, account: PublicKey): [PublicKey, number] {
@@ -33,6 +33,7 @@ export interface RoleAssignmentAccount {
   assignedBy: PublicKey;
   assignedAt: BN;
   bump: number;
+  expiresAt: BN | null;
 }
 
 /**
@@ -121,4 +122,6 @@ export interface RoleAssignment {
   assignedBy: PublicKey;
   assignedAt: number;
   bump: number;
+  /** Unix timestamp after which the role no longer applies; null never expires */
+  expiresAt: number | null;
 }
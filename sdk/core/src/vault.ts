@@ -1,7 +1,7 @@
 import { Connection, PublicKey, Signer, SystemProgram } from '@solana/web3.js';
 import { Program, AnchorProvider, BN } from '@coral-xyz/anchor';
-import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
-import { Role, MinterInfoAccount } from './types';
+import { TOKEN_2022_PROGRAM_ID, getAccount } from '@solana/spl-token';
+import { Role, MinterInfoAccount, RoleAssignmentAccount } from './types';
 
 export enum Presets {
   SSS_1 = 1,
@@ -14,6 +14,16 @@ export interface StablecoinConfig {
   uri: string;
   decimals: number;
   preset?: Presets;
+  /**
+   * Enable the Token-2022 interest-bearing extension at this rate (basis points).
+   * The asset mint must be allocated for the extension but not yet initialized.
+   */
+  interestRateBps?: number;
+  /**
+   * Enable the Token-2022 transfer fee extension. Like the interest-bearing
+   * extension, the asset mint must not be initialized yet.
+   */
+  transferFee?: { transferFeeBps: number; maxFee: number };
 }
 
 /**
@@ -75,13 +85,19 @@ export class SolanaStablecoin {
         config.name,
         config.symbol,
         config.uri,
-        config.decimals
+        config.decimals,
+        config.interestRateBps ?? null,
+        config.transferFee
+          ? { transferFeeBps: config.transferFee.transferFeeBps, maxFee: new BN(config.transferFee.maxFee) }
+          : null
       )
       .accounts({
         authority: config.authority.publicKey,
         state: stablecoinPda,
         assetMint: config.assetMint,
         systemProgram: SystemProgram.programId,
+        tokenProgram:
+          config.interestRateBps != null || config.transferFee ? TOKEN_2022_PROGRAM_ID : null,
       })
       .signers([config.authority])
       .rpc();
@@ -94,17 +110,18 @@ export class SolanaStablecoin {
    * @param authority - The authority signer (must be Master role)
    * @param targetAccount - The account to assign the role to
    * @param role - The role to assign (Master, Minter, Burner, Blacklister, Pauser, Seizer)
+   * @param expiresAt - Optional Unix timestamp after which the role no longer applies
    */
-  async assignRole(authority: Signer, targetAccount: PublicKey, role: Role): Promise<string> {
+  async assignRole(authority: Signer, targetAccount: PublicKey, role: Role, expiresAt?: number): Promise<string> {
     const [assignmentPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('role'), this.stablecoinPda.toBuffer(), targetAccount.toBuffer()],
+      [Buffer.from('role'), this.stablecoinPda.toBuffer(), targetAccount.toBuffer(), Buffer.from(role.toLowerCase())],
       this.program.programId
     );
 
     const anchorRole = { [role.toLowerCase()]: {} };
 
     return this.program.methods
-      .assignRole(anchorRole)
+      .assignRole(anchorRole, expiresAt != null ? new BN(expiresAt) : null)
       .accounts({
         authority: authority.publicKey,
         state: this.stablecoinPda,
@@ -166,19 +183,22 @@ export class SolanaStablecoin {
    * @param authority - The blacklister/pauser authority signer
    * @param account - The token account to freeze
    * @param roleAssignment - Optional role assignment PDA for verification
+   * @param reason - Reason stored in the on-chain freeze record
    */
-  async freeze(authority: Signer, account: PublicKey, roleAssignment?: PublicKey): Promise<string> {
+  async freeze(authority: Signer, account: PublicKey, roleAssignment?: PublicKey, reason: string = ''): Promise<string> {
     const accounts = {
       authority: authority.publicKey,
       state: this.stablecoinPda,
       assetMint: this.assetMint,
       account,
+      freezeRecord: this.findFreezeRecordPda(account),
       tokenProgram: TOKEN_2022_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
       ...(roleAssignment && { roleAssignment }),
     };
 
     return this.program.methods
-      .freezeAccount()
+      .freezeAccount(reason)
       .accounts(accounts)
       .signers([authority])
       .rpc();
@@ -190,6 +210,10 @@ export class SolanaStablecoin {
    * @param account - The token account to thaw
    */
   async thaw(authority: Signer, account: PublicKey): Promise<string> {
+    // Accounts frozen by default have no freeze record to close
+    const freezeRecord = this.findFreezeRecordPda(account);
+    const hasRecord = (await this.connection.getAccountInfo(freezeRecord)) !== null;
+
     return this.program.methods
       .thawAccount()
       .accounts({
@@ -198,13 +222,27 @@ export class SolanaStablecoin {
         assetMint: this.assetMint,
         account,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
+        freezeRecord: hasRecord ? freezeRecord : null,
       })
       .signers([authority])
       .rpc();
   }
 
+  /**
+   * Derive the freeze record PDA for a token account.
+   * @param account - The frozen token account
+   */
+  findFreezeRecordPda(account: PublicKey): PublicKey {
+    const [freezeRecord] = PublicKey.findProgramAddressSync(
+      [Buffer.from('freeze'), this.stablecoinPda.toBuffer(), account.toBuffer()],
+      this.program.programId
+    );
+    return freezeRecord;
+  }
+
   /**
    * Seize tokens from one account to another (for compliance/enforcement).
+   * The owner of `from` must be blacklisted.
    * @param authority - The seizer authority signer
    * @param from - The token account to seize from
    * @param to - The token account to transfer seized tokens to
@@ -212,12 +250,19 @@ export class SolanaStablecoin {
    * @param roleAssignment - Optional role assignment PDA for verification
    */
   async seize(authority: Signer, from: PublicKey, to: PublicKey, amount: number, roleAssignment?: PublicKey): Promise<string> {
+    const fromAccount = await getAccount(this.connection, from, undefined, TOKEN_2022_PROGRAM_ID);
+    const [blacklistEntry] = PublicKey.findProgramAddressSync(
+      [Buffer.from('blacklist'), this.stablecoinPda.toBuffer(), fromAccount.owner.toBuffer()],
+      this.program.programId
+    );
+
     const accounts = {
       authority: authority.publicKey,
       state: this.stablecoinPda,
       assetMint: this.assetMint,
       from,
       to,
+      blacklistEntry,
       tokenProgram: TOKEN_2022_PROGRAM_ID,
       ...(roleAssignment && { roleAssignment }),
     };
@@ -232,10 +277,12 @@ export class SolanaStablecoin {
   /**
    * Pause all stablecoin operations.
    * @param authority - The master authority signer
+   * @param reason - Why operations are paused (max 200 chars), recorded on-chain
+   * @param until - Unix time at which the pause lifts on its own (0 = until unpaused)
    */
-  async pause(authority: Signer): Promise<string> {
+  async pause(authority: Signer, reason = '', until = 0): Promise<string> {
     return this.program.methods
-      .pause()
+      .pause(reason, new BN(until))
       .accounts({
         authority: authority.publicKey,
         state: this.stablecoinPda,
@@ -259,6 +306,62 @@ export class SolanaStablecoin {
       .rpc();
   }
 
+  /**
+   * Update the interest-bearing mint rate.
+   * @param authority - The master authority signer
+   * @param rateBps - The new rate in basis points (may be negative)
+   */
+  async updateInterestRate(authority: Signer, rateBps: number): Promise<string> {
+    return this.program.methods
+      .updateInterestRate(rateBps)
+      .accounts({
+        authority: authority.publicKey,
+        state: this.stablecoinPda,
+        assetMint: this.assetMint,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  /**
+   * Update the Token-2022 transfer fee.
+   * @param authority - The master authority signer
+   * @param transferFeeBps - The new fee in basis points
+   * @param maxFee - The maximum fee per transfer, in base units
+   */
+  async updateTransferFee(authority: Signer, transferFeeBps: number, maxFee: number): Promise<string> {
+    return this.program.methods
+      .updateTransferFee(transferFeeBps, new BN(maxFee))
+      .accounts({
+        authority: authority.publicKey,
+        state: this.stablecoinPda,
+        assetMint: this.assetMint,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  /**
+   * Withdraw transfer fees that have been harvested to the mint.
+   * @param authority - The master authority signer
+   * @param to - The token account that receives the fees
+   */
+  async withdrawWithheldFees(authority: Signer, to: PublicKey): Promise<string> {
+    return this.program.methods
+      .withdrawWithheldFees()
+      .accounts({
+        authority: authority.publicKey,
+        state: this.stablecoinPda,
+        assetMint: this.assetMint,
+        to,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
   /**
    * Transfer the master authority to a new account.
    * @param authority - The current master authority signer
@@ -279,7 +382,7 @@ export class SolanaStablecoin {
    * Add a minter with a specified quota.
    * @param authority - The authority signer (must be Master)
    * @param minter - The public key of the minter to add
-   * @param quota - The minting quota for this minter (in smallest units)
+   * @param quota - The minting quota for this minter (in smallest units, 0 = unlimited)
    */
   async addMinter(authority: Signer, minter: PublicKey, quota: number): Promise<string> {
     const [minterInfoPda] = PublicKey.findProgramAddressSync(
@@ -326,7 +429,7 @@ export class SolanaStablecoin {
    * Update the quota for an existing minter.
    * @param authority - The authority signer (must be Master)
    * @param minter - The public key of the minter to update
-   * @param newQuota - The new minting quota (in smallest units)
+   * @param newQuota - The new minting quota (in smallest units, 0 = unlimited)
    */
   async setQuota(authority: Signer, minter: PublicKey, newQuota: number): Promise<string> {
     const [minterInfoPda] = PublicKey.findProgramAddressSync(
@@ -385,10 +488,11 @@ export class SolanaStablecoin {
    * Revoke a role from a target account.
    * @param authority - The authority signer (must be Master)
    * @param targetAccount - The account to revoke the role from
+   * @param role - The role to revoke (each role has its own assignment account)
    */
-  async revokeRole(authority: Signer, targetAccount: PublicKey): Promise<string> {
+  async revokeRole(authority: Signer, targetAccount: PublicKey, role: Role): Promise<string> {
     const [assignmentPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('role'), this.stablecoinPda.toBuffer(), targetAccount.toBuffer()],
+      [Buffer.from('role'), this.stablecoinPda.toBuffer(), targetAccount.toBuffer(), Buffer.from(role.toLowerCase())],
       this.program.programId
     );
 
@@ -404,6 +508,34 @@ export class SolanaStablecoin {
       .rpc();
   }
 
+  /**
+   * Close an expired role assignment. Anyone may call this; the rent goes back
+   * to the account that assigned the role.
+   * @param caller - Any signer paying the transaction fee
+   * @param targetAccount - The account whose role expired
+   * @param role - The expired role
+   */
+  async cleanupExpiredRole(caller: Signer, targetAccount: PublicKey, role: Role): Promise<string> {
+    const [assignmentPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('role'), this.stablecoinPda.toBuffer(), targetAccount.toBuffer(), Buffer.from(role.toLowerCase())],
+      this.program.programId
+    );
+
+    const accountFetcher = this.program.account as Record<string, { fetch: (pubkey: PublicKey) => Promise<RoleAssignmentAccount> }>;
+    const assignment = await accountFetcher['roleAssignment'].fetch(assignmentPda);
+
+    return this.program.methods
+      .cleanupExpiredRole()
+      .accounts({
+        caller: caller.publicKey,
+        state: this.stablecoinPda,
+        assignment: assignmentPda,
+        rentReceiver: assignment.assignedBy,
+      })
+      .signers([caller])
+      .rpc();
+  }
+
   /**
    * Fetch the on-chain stablecoin state.
    */
@@ -1,6 +1,7 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { SssToken } from "../target/types/sss_token";
+import { createMint } from "@solana/spl-token";
 import { expect } from "chai";
 
 describe("Error Cases", () => {
@@ -47,7 +48,7 @@ describe("Error Cases", () => {
 
       try {
         await program.methods
-          .initialize(invalidPreset, NAME, SYMBOL, URI, DECIMALS)
+          .initialize(invalidPreset, NAME, SYMBOL, URI, DECIMALS, null, null)
           .accounts({
             authority: newAuthority.publicKey,
             state: pda,
@@ -72,7 +73,7 @@ describe("Error Cases", () => {
 
       try {
         await program.methods
-          .initialize(invalidPreset, NAME, SYMBOL, URI, DECIMALS)
+          .initialize(invalidPreset, NAME, SYMBOL, URI, DECIMALS, null, null)
           .accounts({
             authority: newAuthority.publicKey,
             state: pda,
@@ -97,7 +98,7 @@ describe("Error Cases", () => {
 
       try {
         await program.methods
-          .initialize(invalidPreset, NAME, SYMBOL, URI, DECIMALS)
+          .initialize(invalidPreset, NAME, SYMBOL, URI, DECIMALS, null, null)
           .accounts({
             authority: newAuthority.publicKey,
             state: pda,
@@ -122,7 +123,7 @@ describe("Error Cases", () => {
 
       try {
         await program.methods
-          .initialize(PRESET_SSS_1, longName, SYMBOL, URI, DECIMALS)
+          .initialize(PRESET_SSS_1, longName, SYMBOL, URI, DECIMALS, null, null)
           .accounts({
             authority: newAuthority.publicKey,
             state: pda,
@@ -147,7 +148,7 @@ describe("Error Cases", () => {
 
       try {
         await program.methods
-          .initialize(PRESET_SSS_1, NAME, longSymbol, URI, DECIMALS)
+          .initialize(PRESET_SSS_1, NAME, longSymbol, URI, DECIMALS, null, null)
           .accounts({
             authority: newAuthority.publicKey,
             state: pda,
@@ -172,7 +173,7 @@ describe("Error Cases", () => {
 
       try {
         await program.methods
-          .initialize(PRESET_SSS_1, NAME, SYMBOL, longUri, DECIMALS)
+          .initialize(PRESET_SSS_1, NAME, SYMBOL, longUri, DECIMALS, null, null)
           .accounts({
             authority: newAuthority.publicKey,
             state: pda,
@@ -197,7 +198,7 @@ describe("Error Cases", () => {
 
       try {
         await program.methods
-          .initialize(PRESET_SSS_1, NAME, SYMBOL, URI, invalidDecimals)
+          .initialize(PRESET_SSS_1, NAME, SYMBOL, URI, invalidDecimals, null, null)
           .accounts({
             authority: newAuthority.publicKey,
             state: pda,
@@ -222,7 +223,7 @@ describe("Error Cases", () => {
 
       try {
         await program.methods
-          .initialize(PRESET_SSS_1, NAME, SYMBOL, URI, invalidDecimals)
+          .initialize(PRESET_SSS_1, NAME, SYMBOL, URI, invalidDecimals, null, null)
           .accounts({
             authority: newAuthority.publicKey,
             state: pda,
@@ -236,13 +237,94 @@ describe("Error Cases", () => {
         expect(e.error?.errorCode?.code).to.equal("InvalidDecimals");
       }
     });
+
+    it("Fails when decimals differ from the existing mint", async () => {
+      const existingMint = await createMint(
+        provider.connection,
+        (authority as anchor.Wallet).payer,
+        authority.publicKey,
+        null,
+        DECIMALS + 2
+      );
+      const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("stablecoin"), existingMint.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .initialize(PRESET_SSS_1, NAME, SYMBOL, URI, DECIMALS, null, null)
+          .accounts({
+            authority: authority.publicKey,
+            state: pda,
+            assetMint: existingMint,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have thrown DecimalsMismatch error");
+      } catch (e: any) {
+        expect(e.error?.errorCode?.code).to.equal("DecimalsMismatch");
+      }
+    });
+
+    it("Fails when the existing mint authority is not the stablecoin PDA", async () => {
+      const existingMint = await createMint(
+        provider.connection,
+        (authority as anchor.Wallet).payer,
+        authority.publicKey,
+        null,
+        DECIMALS
+      );
+      const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("stablecoin"), existingMint.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .initialize(PRESET_SSS_1, NAME, SYMBOL, URI, DECIMALS, null, null)
+          .accounts({
+            authority: authority.publicKey,
+            state: pda,
+            assetMint: existingMint,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have thrown MintAuthorityMismatch error");
+      } catch (e: any) {
+        expect(e.error?.errorCode?.code).to.equal("MintAuthorityMismatch");
+      }
+    });
+
+    it("Fails when the asset mint is not a token mint", async () => {
+      const notAMint = anchor.web3.Keypair.generate().publicKey;
+      const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("stablecoin"), notAMint.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .initialize(PRESET_SSS_1, NAME, SYMBOL, URI, DECIMALS, null, null)
+          .accounts({
+            authority: authority.publicKey,
+            state: pda,
+            assetMint: notAMint,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have thrown InvalidMint error");
+      } catch (e: any) {
+        expect(e.error?.errorCode?.code).to.equal("InvalidMint");
+      }
+    });
   });
 
   describe("Mint Errors", () => {
     before(async () => {
       try {
         await program.methods
-          .initialize(PRESET_SSS_1, NAME, SYMBOL, URI, DECIMALS)
+          .initialize(PRESET_SSS_1, NAME, SYMBOL, URI, DECIMALS, null, null)
           .accounts({
             authority: authority.publicKey,
             state: stablecoinPda,
@@ -338,7 +420,7 @@ describe("Error Cases", () => {
 
       try {
         await program.methods
-          .pause()
+          .pause("", new anchor.BN(0))
           .accounts({
             authority: unauthorized.publicKey,
             state: stablecoinPda,
@@ -585,14 +667,15 @@ describe("Quota Exceeded Error Cases", () => {
     minter = anchor.web3.Keypair.generate();
   });
 
-  it("Minter with zero quota cannot mint", async () => {
-    const quota = new anchor.BN(0);
+  it("Minter cannot mint beyond quota", async () => {
+    // A quota of 0 means unlimited, so use the smallest real cap
+    const quota = new anchor.BN(1);
     const [minterInfoPda] = anchor.web3.PublicKey.findProgramAddressSync(
       [Buffer.from("minter"), stablecoinPda.toBuffer(), minter.publicKey.toBuffer()],
       program.programId
     );
 
-    // Add minter with zero quota
+    // Add minter with a quota of 1
     try {
       await program.methods
         .addMinter(quota)
@@ -608,9 +691,9 @@ describe("Quota Exceeded Error Cases", () => {
       // May already exist
     }
 
-    // Try to mint - should fail with quota exceeded
+    // Try to mint past the quota - should fail with quota exceeded
     const recipient = anchor.web3.Keypair.generate();
-    const amount = new anchor.BN(1);
+    const amount = new anchor.BN(2);
 
     try {
       await program.methods
@@ -627,6 +710,44 @@ describe("Quota Exceeded Error Cases", () => {
       expect(e.error?.errorCode?.code).to.equal("QuotaExceeded");
     }
   });
+
+  it("Minter cannot skip the quota by omitting its MinterInfo", async () => {
+    const [assignmentPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("role"), stablecoinPda.toBuffer(), minter.publicKey.toBuffer(), Buffer.from("minter")],
+      program.programId
+    );
+    try {
+      await program.methods
+        .assignRole({ minter: {} }, null)
+        .accounts({
+          authority: authority.publicKey,
+          state: stablecoinPda,
+          assignment: assignmentPda,
+          account: minter.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    } catch (e) {
+      // May already exist
+    }
+
+    const recipient = anchor.web3.Keypair.generate();
+    try {
+      await program.methods
+        .mint(recipient.publicKey, new anchor.BN(2))
+        .accounts({
+          authority: minter.publicKey,
+          state: stablecoinPda,
+          roleAssignment: assignmentPda,
+          minterInfo: null,
+        })
+        .signers([minter])
+        .rpc();
+      expect.fail("Should have thrown MinterInfoRequired error");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code).to.equal("MinterInfoRequired");
+    }
+  });
 });
 
 describe("Transfer Hook Error Cases", () => {
@@ -673,7 +794,7 @@ describe("Edge Cases", () => {
 
     // This should succeed
     await program.methods
-      .initialize(1, maxName, "TST", "https://example.com", 6)
+      .initialize(1, maxName, "TST", "https://example.com", 6, null, null)
       .accounts({
         authority: newAuthority.publicKey,
         state: pda,
@@ -698,7 +819,7 @@ describe("Edge Cases", () => {
 
     // This should succeed
     await program.methods
-      .initialize(1, "Test", maxSymbol, "https://example.com", 6)
+      .initialize(1, "Test", maxSymbol, "https://example.com", 6, null, null)
       .accounts({
         authority: newAuthority.publicKey,
         state: pda,
@@ -722,7 +843,7 @@ describe("Edge Cases", () => {
 
     // This should succeed
     await program.methods
-      .initialize(1, "Test", "TST", "https://example.com", maxDecimals)
+      .initialize(1, "Test", "TST", "https://example.com", maxDecimals, null, null)
       .accounts({
         authority: newAuthority.publicKey,
         state: pda,
@@ -746,7 +867,7 @@ describe("Edge Cases", () => {
 
     // This should succeed
     await program.methods
-      .initialize(1, "Test", "TST", "https://example.com", zeroDecimals)
+      .initialize(1, "Test", "TST", "https://example.com", zeroDecimals, null, null)
       .accounts({
         authority: newAuthority.publicKey,
         state: pda,
@@ -768,7 +889,7 @@ describe("Edge Cases", () => {
     );
 
     await program.methods
-      .initialize(2, "Compliant", "CUSDT", "https://example.com", 6)
+      .initialize(2, "Compliant", "CUSDT", "https://example.com", 6, null, null)
       .accounts({
         authority: newAuthority.publicKey,
         state: pda,
@@ -31,7 +31,7 @@ describe("Minter Management", () => {
 
   it("Initializes stablecoin for minter tests", async () => {
     await program.methods
-      .initialize(PRESET_SSS_1, NAME, SYMBOL, URI, DECIMALS)
+      .initialize(PRESET_SSS_1, NAME, SYMBOL, URI, DECIMALS, null, null)
       .accounts({
         authority: authority.publicKey,
         state: stablecoinPda,
@@ -310,4 +310,95 @@ describe("Minter Management", () => {
     const minterInfo = await program.account.minterInfo.fetch(minterInfoPda);
     expect(minterInfo.mintedAmount.toNumber()).to.equal(0); // Should reset minted amount
   });
+
+  it("Rotates a minter to a new key", async () => {
+    const minterInfoPda = (minter: anchor.web3.PublicKey) =>
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("minter"), stablecoinPda.toBuffer(), minter.toBuffer()],
+        program.programId
+      )[0];
+    const newKey = anchor.web3.Keypair.generate();
+    const oldInfo = await program.account.minterInfo.fetch(minterInfoPda(minter1.publicKey));
+    const stateBefore = await program.account.stablecoinState.fetch(stablecoinPda);
+
+    await program.methods
+      .rotateMinter()
+      .accounts({
+        authority: authority.publicKey,
+        state: stablecoinPda,
+        oldMinterInfo: minterInfoPda(minter1.publicKey),
+        newMinterInfo: minterInfoPda(newKey.publicKey),
+        newMinter: newKey.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const newInfo = await program.account.minterInfo.fetch(minterInfoPda(newKey.publicKey));
+    expect(newInfo.minter.toString()).to.equal(newKey.publicKey.toString());
+    expect(newInfo.quota.toString()).to.equal(oldInfo.quota.toString());
+    expect(newInfo.mintedAmount.toString()).to.equal(oldInfo.mintedAmount.toString());
+    expect(await provider.connection.getAccountInfo(minterInfoPda(minter1.publicKey))).to.be.null;
+
+    const stateAfter = await program.account.stablecoinState.fetch(stablecoinPda);
+    expect(stateAfter.minterCount).to.equal(stateBefore.minterCount);
+  });
+
+  it("Moves the Minter role when rotating", async () => {
+    const minterInfoPda = (minter: anchor.web3.PublicKey) =>
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("minter"), stablecoinPda.toBuffer(), minter.toBuffer()],
+        program.programId
+      )[0];
+    const rolePda = (account: anchor.web3.PublicKey) =>
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("role"), stablecoinPda.toBuffer(), account.toBuffer(), Buffer.from("minter")],
+        program.programId
+      )[0];
+    const oldKey = minter2;
+    const newKey = anchor.web3.Keypair.generate();
+
+    await program.methods
+      .addMinter(new anchor.BN(1_000_000))
+      .accounts({
+        authority: authority.publicKey,
+        state: stablecoinPda,
+        minterInfo: minterInfoPda(oldKey.publicKey),
+        minter: oldKey.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    await program.methods
+      .assignRole({ minter: {} }, null)
+      .accounts({
+        authority: authority.publicKey,
+        state: stablecoinPda,
+        assignment: rolePda(oldKey.publicKey),
+        account: oldKey.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    const stateBefore = await program.account.stablecoinState.fetch(stablecoinPda);
+
+    await program.methods
+      .rotateMinter()
+      .accounts({
+        authority: authority.publicKey,
+        state: stablecoinPda,
+        oldMinterInfo: minterInfoPda(oldKey.publicKey),
+        newMinterInfo: minterInfoPda(newKey.publicKey),
+        newMinter: newKey.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        oldRoleAssignment: rolePda(oldKey.publicKey),
+        newRoleAssignment: rolePda(newKey.publicKey),
+      })
+      .rpc();
+
+    expect(await provider.connection.getAccountInfo(rolePda(oldKey.publicKey))).to.be.null;
+    const moved = await program.account.roleAssignment.fetch(rolePda(newKey.publicKey));
+    expect(moved.account.toString()).to.equal(newKey.publicKey.toString());
+    expect(moved.role).to.deep.equal({ minter: {} });
+
+    const stateAfter = await program.account.stablecoinState.fetch(stablecoinPda);
+    expect(stateAfter.roleCount).to.equal(stateBefore.roleCount);
+  });
 });
@@ -28,7 +28,7 @@ describe("Role Management", () => {
 
   it("Initializes stablecoin", async () => {
     await program.methods
-      .initialize(1, "Test", "TST", "https://example.com", 6)
+      .initialize(1, "Test", "TST", "https://example.com", 6, null, null)
       .accounts({
         authority: authority.publicKey,
         state: stablecoinPda,
@@ -41,12 +41,12 @@ describe("Role Management", () => {
   it("Assigns minter role", async () => {
     const role = { minter: {} };
     const [assignmentPda] = await anchor.web3.PublicKey.findProgramAddress(
-      [Buffer.from("role"), stablecoinPda.toBuffer(), minter.publicKey.toBuffer()],
+      [Buffer.from("role"), stablecoinPda.toBuffer(), minter.publicKey.toBuffer(), Buffer.from("minter")],
       program.programId
     );
 
     await program.methods
-      .assignRole(role)
+      .assignRole(role, null)
       .accounts({
         authority: authority.publicKey,
         state: stablecoinPda,
@@ -63,12 +63,12 @@ describe("Role Management", () => {
   it("Assigns burner role", async () => {
     const role = { burner: {} };
     const [assignmentPda] = await anchor.web3.PublicKey.findProgramAddress(
-      [Buffer.from("role"), stablecoinPda.toBuffer(), burner.publicKey.toBuffer()],
+      [Buffer.from("role"), stablecoinPda.toBuffer(), burner.publicKey.toBuffer(), Buffer.from("burner")],
       program.programId
     );
 
     await program.methods
-      .assignRole(role)
+      .assignRole(role, null)
       .accounts({
         authority: authority.publicKey,
         state: stablecoinPda,
@@ -84,12 +84,12 @@ describe("Role Management", () => {
   it("Assigns pauser role", async () => {
     const role = { pauser: {} };
     const [assignmentPda] = await anchor.web3.PublicKey.findProgramAddress(
-      [Buffer.from("role"), stablecoinPda.toBuffer(), pauser.publicKey.toBuffer()],
+      [Buffer.from("role"), stablecoinPda.toBuffer(), pauser.publicKey.toBuffer(), Buffer.from("pauser")],
       program.programId
     );
 
     await program.methods
-      .assignRole(role)
+      .assignRole(role, null)
       .accounts({
         authority: authority.publicKey,
         state: stablecoinPda,
@@ -127,9 +127,29 @@ describe("Role Management", () => {
       .rpc();
   });
 
+  it("Rejects transferring authority to the default pubkey or to itself", async () => {
+    for (const target of [anchor.web3.PublicKey.default, authority.publicKey]) {
+      try {
+        await program.methods
+          .transferAuthority(target)
+          .accounts({
+            authority: authority.publicKey,
+            state: stablecoinPda,
+          })
+          .rpc();
+        expect.fail("Should have thrown InvalidAuthority error");
+      } catch (e: any) {
+        expect(e.error?.errorCode?.code).to.equal("InvalidAuthority");
+      }
+    }
+
+    const state = await program.account.stablecoinState.fetch(stablecoinPda);
+    expect(state.authority.toString()).to.equal(authority.publicKey.toString());
+  });
+
   it("Revokes minter role", async () => {
     const [assignmentPda] = await anchor.web3.PublicKey.findProgramAddress(
-      [Buffer.from("role"), stablecoinPda.toBuffer(), minter.publicKey.toBuffer()],
+      [Buffer.from("role"), stablecoinPda.toBuffer(), minter.publicKey.toBuffer(), Buffer.from("minter")],
       program.programId
     );
 
@@ -158,7 +178,7 @@ describe("Role Management", () => {
 
   it("Revokes burner role", async () => {
     const [assignmentPda] = await anchor.web3.PublicKey.findProgramAddress(
-      [Buffer.from("role"), stablecoinPda.toBuffer(), burner.publicKey.toBuffer()],
+      [Buffer.from("role"), stablecoinPda.toBuffer(), burner.publicKey.toBuffer(), Buffer.from("burner")],
       program.programId
     );
 
@@ -181,7 +201,7 @@ describe("Role Management", () => {
 
   it("Revokes pauser role", async () => {
     const [assignmentPda] = await anchor.web3.PublicKey.findProgramAddress(
-      [Buffer.from("role"), stablecoinPda.toBuffer(), pauser.publicKey.toBuffer()],
+      [Buffer.from("role"), stablecoinPda.toBuffer(), pauser.publicKey.toBuffer(), Buffer.from("pauser")],
       program.programId
     );
 
@@ -209,12 +229,12 @@ describe("Role Management", () => {
 
     // First assign a role
     const [assignmentPda] = await anchor.web3.PublicKey.findProgramAddress(
-      [Buffer.from("role"), stablecoinPda.toBuffer(), newUser.publicKey.toBuffer()],
+      [Buffer.from("role"), stablecoinPda.toBuffer(), newUser.publicKey.toBuffer(), Buffer.from("minter")],
       program.programId
     );
 
     await program.methods
-      .assignRole(role)
+      .assignRole(role, null)
       .accounts({
         authority: authority.publicKey,
         state: stablecoinPda,
@@ -245,12 +265,12 @@ describe("Role Management", () => {
     const blacklister = anchor.web3.Keypair.generate();
     const role = { blacklister: {} };
     const [assignmentPda] = await anchor.web3.PublicKey.findProgramAddress(
-      [Buffer.from("role"), stablecoinPda.toBuffer(), blacklister.publicKey.toBuffer()],
+      [Buffer.from("role"), stablecoinPda.toBuffer(), blacklister.publicKey.toBuffer(), Buffer.from("blacklister")],
       program.programId
     );
 
     await program.methods
-      .assignRole(role)
+      .assignRole(role, null)
       .accounts({
         authority: authority.publicKey,
         state: stablecoinPda,
@@ -268,12 +288,12 @@ describe("Role Management", () => {
     const seizer = anchor.web3.Keypair.generate();
     const role = { seizer: {} };
     const [assignmentPda] = await anchor.web3.PublicKey.findProgramAddress(
-      [Buffer.from("role"), stablecoinPda.toBuffer(), seizer.publicKey.toBuffer()],
+      [Buffer.from("role"), stablecoinPda.toBuffer(), seizer.publicKey.toBuffer(), Buffer.from("seizer")],
       program.programId
     );
 
     await program.methods
-      .assignRole(role)
+      .assignRole(role, null)
       .accounts({
         authority: authority.publicKey,
         state: stablecoinPda,
@@ -291,12 +311,12 @@ describe("Role Management", () => {
     const master = anchor.web3.Keypair.generate();
     const role = { master: {} };
     const [assignmentPda] = await anchor.web3.PublicKey.findProgramAddress(
-      [Buffer.from("role"), stablecoinPda.toBuffer(), master.publicKey.toBuffer()],
+      [Buffer.from("role"), stablecoinPda.toBuffer(), master.publicKey.toBuffer(), Buffer.from("master")],
       program.programId
     );
 
     await program.methods
-      .assignRole(role)
+      .assignRole(role, null)
       .accounts({
         authority: authority.publicKey,
         state: stablecoinPda,
@@ -309,4 +329,102 @@ describe("Role Management", () => {
     const assignment = await program.account.roleAssignment.fetch(assignmentPda);
     expect(assignment.account.toString()).to.equal(master.publicKey.toString());
   });
+
+  it("Lets one account hold several roles", async () => {
+    const officer = anchor.web3.Keypair.generate();
+    const roles = [
+      { seed: "blacklister", role: { blacklister: {} } },
+      { seed: "seizer", role: { seizer: {} } },
+    ];
+
+    for (const { seed, role } of roles) {
+      const [assignmentPda] = await anchor.web3.PublicKey.findProgramAddress(
+        [Buffer.from("role"), stablecoinPda.toBuffer(), officer.publicKey.toBuffer(), Buffer.from(seed)],
+        program.programId
+      );
+
+      await program.methods
+        .assignRole(role, null)
+        .accounts({
+          authority: authority.publicKey,
+          state: stablecoinPda,
+          assignment: assignmentPda,
+          account: officer.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      const assignment = await program.account.roleAssignment.fetch(assignmentPda);
+      expect(assignment.account.toString()).to.equal(officer.publicKey.toString());
+      expect(assignment.role).to.deep.equal(role);
+    }
+  });
+
+  it("Expires roles and lets anyone clean them up", async () => {
+    const contractor = anchor.web3.Keypair.generate();
+    const [assignmentPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("role"), stablecoinPda.toBuffer(), contractor.publicKey.toBuffer(), Buffer.from("minter")],
+      program.programId
+    );
+    const assign = (expiresAt: number) =>
+      program.methods
+        .assignRole({ minter: {} }, new anchor.BN(expiresAt))
+        .accounts({
+          authority: authority.publicKey,
+          state: stablecoinPda,
+          assignment: assignmentPda,
+          account: contractor.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    const cleanup = () =>
+      program.methods
+        .cleanupExpiredRole()
+        .accounts({
+          caller: provider.wallet.publicKey,
+          state: stablecoinPda,
+          assignment: assignmentPda,
+          rentReceiver: authority.publicKey,
+        })
+        .rpc();
+
+    try {
+      await assign(1);
+      expect.fail("Should have rejected an already expired assignment");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code).to.equal("RoleExpired");
+    }
+
+    const expiresAt = Math.floor(Date.now() / 1000) + 3;
+    await assign(expiresAt);
+    const assignment = await program.account.roleAssignment.fetch(assignmentPda);
+    expect(assignment.expiresAt.toNumber()).to.equal(expiresAt);
+
+    try {
+      await cleanup();
+      expect.fail("Should have rejected cleanup of an active assignment");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code).to.equal("RoleNotExpired");
+    }
+
+    await new Promise((resolve) => setTimeout(resolve, 5_000));
+
+    try {
+      await program.methods
+        .mint(new anchor.BN(1))
+        .accounts({
+          authority: contractor.publicKey,
+          state: stablecoinPda,
+          roleAssignment: assignmentPda,
+        })
+        .signers([contractor])
+        .rpc();
+      expect.fail("Should have rejected a mint with an expired role");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code).to.equal("RoleExpired");
+    }
+
+    await cleanup();
+    expect(await provider.connection.getAccountInfo(assignmentPda)).to.be.null;
+  });
 });
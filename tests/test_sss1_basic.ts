@@ -2,6 +2,14 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { SssToken } from "../target/types/sss_token";
 import { expect } from "chai";
+import {
+  createAccount,
+  createMint,
+  getAccount,
+  getMint,
+  transferChecked,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
 
 describe("SSS-1: Basic Operations", () => {
   const provider = anchor.AnchorProvider.env();
@@ -27,7 +35,7 @@ describe("SSS-1: Basic Operations", () => {
 
   it("Initializes SSS-1 stablecoin", async () => {
     await program.methods
-      .initialize(PRESET_SSS_1, NAME, SYMBOL, URI, DECIMALS)
+      .initialize(PRESET_SSS_1, NAME, SYMBOL, URI, DECIMALS, null, null)
       .accounts({
         authority: authority.publicKey,
         state: stablecoinPda,
@@ -76,7 +84,7 @@ describe("SSS-1: Basic Operations", () => {
 
   it("Pauses operations", async () => {
     await program.methods
-      .pause()
+      .pause("Scheduled maintenance", new anchor.BN(0))
       .accounts({
         authority: authority.publicKey,
         state: stablecoinPda,
@@ -85,6 +93,11 @@ describe("SSS-1: Basic Operations", () => {
 
     const state = await program.account.stablecoinState.fetch(stablecoinPda);
     expect(state.paused).to.be.true;
+    expect(state.pausedBy.toBase58()).to.equal(authority.publicKey.toBase58());
+    expect(state.pausedAt.toNumber()).to.be.greaterThan(0);
+    expect(
+      Buffer.from(state.pauseReason.slice(0, state.pauseReasonLen)).toString()
+    ).to.equal("Scheduled maintenance");
   });
 
   it("Unpauses operations", async () => {
@@ -98,6 +111,10 @@ describe("SSS-1: Basic Operations", () => {
 
     const state = await program.account.stablecoinState.fetch(stablecoinPda);
     expect(state.paused).to.be.false;
+    expect(state.pausedBy.toBase58()).to.equal(anchor.web3.PublicKey.default.toBase58());
+    expect(state.pausedAt.toNumber()).to.equal(0);
+    expect(state.pauseReasonLen).to.equal(0);
+    expect(state.pauseReason.every((byte: number) => byte === 0)).to.be.true;
   });
 
   it("Freezes account", async () => {
@@ -175,7 +192,7 @@ describe("SSS-1: Basic Operations", () => {
   it("Toggles pause multiple times", async () => {
     // Pause
     await program.methods
-      .pause()
+      .pause("", new anchor.BN(0))
       .accounts({
         authority: authority.publicKey,
         state: stablecoinPda,
@@ -199,7 +216,7 @@ describe("SSS-1: Basic Operations", () => {
 
     // Pause again
     await program.methods
-      .pause()
+      .pause("", new anchor.BN(0))
       .accounts({
         authority: authority.publicKey,
         state: stablecoinPda,
@@ -308,6 +325,35 @@ describe("SSS-1: Basic Operations", () => {
     expect(state.totalSupply.toNumber()).to.be.greaterThan(1_000_000_000_000);
   });
 
+  it("Initializes with a newly created Token-2022 mint", async () => {
+    const mint = anchor.web3.Keypair.generate();
+    const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("stablecoin"), mint.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeWithNewMint(PRESET_SSS_1, NAME, SYMBOL, URI, DECIMALS, null, null)
+      .accounts({
+        authority: authority.publicKey,
+        state: pda,
+        assetMint: mint.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([mint])
+      .rpc();
+
+    const state = await program.account.stablecoinState.fetch(pda);
+    expect(state.assetMint.toBase58()).to.equal(mint.publicKey.toBase58());
+
+    // The stablecoin PDA controls both minting and freezing
+    const mintInfo = await getMint(provider.connection, mint.publicKey, undefined, TOKEN_2022_PROGRAM_ID);
+    expect(mintInfo.decimals).to.equal(DECIMALS);
+    expect(mintInfo.mintAuthority?.toBase58()).to.equal(pda.toBase58());
+    expect(mintInfo.freezeAuthority?.toBase58()).to.equal(pda.toBase58());
+  });
+
   it("Verifies state persistence", async () => {
     const state = await program.account.stablecoinState.fetch(stablecoinPda);
     
@@ -316,4 +362,358 @@ describe("SSS-1: Basic Operations", () => {
     expect(state.complianceEnabled).to.be.false;
     expect(state.bump).to.be.a("number");
   });
+
+  it("Enables and disables compliance after initialize", async () => {
+    await program.methods
+      .setCompliance(true, true)
+      .accounts({
+        authority: authority.publicKey,
+        state: stablecoinPda,
+      })
+      .rpc();
+
+    let state = await program.account.stablecoinState.fetch(stablecoinPda);
+    expect(state.complianceEnabled).to.be.true;
+    expect(state.preset).to.equal(2);
+
+    try {
+      await program.methods
+        .setCompliance(true, false)
+        .accounts({
+          authority: authority.publicKey,
+          state: stablecoinPda,
+        })
+        .rpc();
+      expect.fail("Should have rejected an unchanged compliance flag");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code).to.equal("ComplianceUnchanged");
+    }
+
+    // With no blacklist entries, compliance can be turned back off; the preset is kept
+    await program.methods
+      .setCompliance(false, false)
+      .accounts({
+        authority: authority.publicKey,
+        state: stablecoinPda,
+      })
+      .rpc();
+
+    state = await program.account.stablecoinState.fetch(stablecoinPda);
+    expect(state.complianceEnabled).to.be.false;
+    expect(state.preset).to.equal(2);
+  });
+
+  it("Rejects burns below the minimum burn amount", async () => {
+    await program.methods
+      .setMinBurn(new anchor.BN(1_000))
+      .accounts({
+        authority: authority.publicKey,
+        state: stablecoinPda,
+      })
+      .rpc();
+
+    let state = await program.account.stablecoinState.fetch(stablecoinPda);
+    expect(state.minBurnAmount.toNumber()).to.equal(1_000);
+    expect(state.minMintAmount.toNumber()).to.equal(0);
+
+    try {
+      await program.methods
+        .burn(new anchor.BN(999))
+        .accounts({
+          authority: authority.publicKey,
+          state: stablecoinPda,
+        })
+        .rpc();
+      expect.fail("Should have rejected a burn below the minimum");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code).to.equal("BelowMinimum");
+    }
+
+    // 0 removes the floor again
+    await program.methods
+      .setMinBurn(new anchor.BN(0))
+      .accounts({
+        authority: authority.publicKey,
+        state: stablecoinPda,
+      })
+      .rpc();
+
+    state = await program.account.stablecoinState.fetch(stablecoinPda);
+    expect(state.minBurnAmount.toNumber()).to.equal(0);
+  });
+
+  it("Rejects burns above the source balance", async () => {
+    try {
+      await program.methods
+        .burn(new anchor.BN("18446744073709551615"))
+        .accounts({
+          authority: authority.publicKey,
+          state: stablecoinPda,
+        })
+        .rpc();
+      expect.fail("Should have rejected a burn above the balance");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code).to.equal("InsufficientBalance");
+    }
+  });
+
+  it("Rejects mints beyond the attested reserves", async () => {
+    const attester = anchor.web3.Keypair.generate();
+    const [reserveAttestationPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("reserve"), stablecoinPda.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .configureReserves(attester.publicKey, new anchor.BN(3_600))
+      .accounts({
+        authority: authority.publicKey,
+        state: stablecoinPda,
+        reserveAttestation: reserveAttestationPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    let state = await program.account.stablecoinState.fetch(stablecoinPda);
+    expect(state.reserveBacked).to.be.true;
+
+    const recipient = anchor.web3.Keypair.generate();
+    const mint = (amount: number) =>
+      program.methods
+        .mint(recipient.publicKey, new anchor.BN(amount))
+        .accounts({
+          authority: authority.publicKey,
+          state: stablecoinPda,
+          reserveAttestation: reserveAttestationPda,
+        })
+        .rpc();
+
+    // Nothing attested yet
+    try {
+      await mint(1);
+      expect.fail("Should have rejected a mint without an attestation");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code).to.equal("ReserveAttestationStale");
+    }
+
+    await program.methods
+      .attestReserves(state.totalSupply.add(new anchor.BN(1_000)))
+      .accounts({
+        attester: attester.publicKey,
+        state: stablecoinPda,
+        reserveAttestation: reserveAttestationPda,
+      })
+      .signers([attester])
+      .rpc();
+
+    const attestation = await program.account.reserveAttestation.fetch(reserveAttestationPda);
+    expect(attestation.updatedAt.toNumber()).to.be.greaterThan(0);
+
+    try {
+      await mint(1_001);
+      expect.fail("Should have rejected a mint beyond the reserves");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code).to.equal("InsufficientReserves");
+    }
+
+    await mint(1_000);
+    const after = await program.account.stablecoinState.fetch(stablecoinPda);
+    expect(after.totalSupply.toString()).to.equal(attestation.reserveAmount.toString());
+  });
+
+  it("Clears the attestation on attester rotation and disables reserves", async () => {
+    const newAttester = anchor.web3.Keypair.generate();
+    const [reserveAttestationPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("reserve"), stablecoinPda.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .configureReserves(newAttester.publicKey, new anchor.BN(3_600))
+      .accounts({
+        authority: authority.publicKey,
+        state: stablecoinPda,
+        reserveAttestation: reserveAttestationPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const attestation = await program.account.reserveAttestation.fetch(reserveAttestationPda);
+    expect(attestation.attester.toBase58()).to.equal(newAttester.publicKey.toBase58());
+    expect(attestation.reserveAmount.toNumber()).to.equal(0);
+    expect(attestation.updatedAt.toNumber()).to.equal(0);
+
+    await program.methods
+      .disableReserves()
+      .accounts({
+        authority: authority.publicKey,
+        state: stablecoinPda,
+        reserveAttestation: reserveAttestationPda,
+      })
+      .rpc();
+
+    const state = await program.account.stablecoinState.fetch(stablecoinPda);
+    expect(state.reserveBacked).to.be.false;
+    expect(await provider.connection.getAccountInfo(reserveAttestationPda)).to.be.null;
+  });
+
+  it("Rejects mint and burn token accounts of another mint", async () => {
+    const payer = (authority as anchor.Wallet).payer;
+    const otherMint = await createMint(
+      provider.connection,
+      payer,
+      authority.publicKey,
+      null,
+      DECIMALS,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const foreignAccount = await createAccount(
+      provider.connection,
+      payer,
+      otherMint,
+      authority.publicKey,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    try {
+      await program.methods
+        .mint(new anchor.BN(1_000))
+        .accounts({
+          authority: authority.publicKey,
+          state: stablecoinPda,
+          recipient: foreignAccount,
+        })
+        .rpc();
+      expect.fail("Should have rejected a recipient of another mint");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code).to.equal("TokenAccountMintMismatch");
+    }
+
+    try {
+      await program.methods
+        .burn(new anchor.BN(1_000))
+        .accounts({
+          authority: authority.publicKey,
+          state: stablecoinPda,
+          from: foreignAccount,
+        })
+        .rpc();
+      expect.fail("Should have rejected a source account of another mint");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code).to.equal("TokenAccountMintMismatch");
+    }
+  });
+
+  it("Blocks transfers out of a frozen account at the token level", async () => {
+    const payer = (authority as anchor.Wallet).payer;
+    const mint = anchor.web3.Keypair.generate();
+    const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("stablecoin"), mint.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeWithNewMint(PRESET_SSS_1, NAME, SYMBOL, URI, DECIMALS, null, null)
+      .accounts({
+        authority: authority.publicKey,
+        state: pda,
+        assetMint: mint.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([mint])
+      .rpc();
+
+    const holder = anchor.web3.Keypair.generate();
+    const createTokenAccount = (owner: anchor.web3.PublicKey) =>
+      createAccount(
+        provider.connection,
+        payer,
+        mint.publicKey,
+        owner,
+        anchor.web3.Keypair.generate(),
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+    const source = await createTokenAccount(holder.publicKey);
+    const destination = await createTokenAccount(authority.publicKey);
+
+    await program.methods
+      .mint(new anchor.BN(1_000))
+      .accounts({
+        authority: authority.publicKey,
+        state: pda,
+        assetMint: mint.publicKey,
+        recipient: source,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    const [freezeRecord] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("freeze"), pda.toBuffer(), source.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .freezeAccount("Incident response")
+      .accounts({
+        authority: authority.publicKey,
+        state: pda,
+        assetMint: mint.publicKey,
+        account: source,
+        freezeRecord,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const frozen = await getAccount(provider.connection, source, undefined, TOKEN_2022_PROGRAM_ID);
+    expect(frozen.isFrozen).to.be.true;
+
+    const transfer = () =>
+      transferChecked(
+        provider.connection,
+        payer,
+        source,
+        mint.publicKey,
+        destination,
+        holder,
+        100,
+        DECIMALS,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+    let transferred = false;
+    try {
+      await transfer();
+      transferred = true;
+    } catch (e: any) {
+      // Token program AccountFrozen (0x11)
+      expect(String(e.logs ?? e)).to.match(/frozen|0x11/i);
+    }
+    expect(transferred, "transfer out of a frozen account").to.be.false;
+
+    await program.methods
+      .thawAccount()
+      .accounts({
+        authority: authority.publicKey,
+        state: pda,
+        assetMint: mint.publicKey,
+        account: source,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        freezeRecord,
+        rentReceiver: authority.publicKey,
+      })
+      .rpc();
+    expect(await provider.connection.getAccountInfo(freezeRecord)).to.be.null;
+
+    await transfer();
+    const received = await getAccount(provider.connection, destination, undefined, TOKEN_2022_PROGRAM_ID);
+    expect(received.amount.toString()).to.equal("100");
+  });
 });
@@ -2,6 +2,12 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { SssToken } from "../target/types/sss_token";
 import { expect } from "chai";
+import {
+  createAccount,
+  getAccount,
+  transferCheckedWithTransferHook,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
 
 describe("SSS-2: Compliance Operations", () => {
   const provider = anchor.AnchorProvider.env();
@@ -31,7 +37,7 @@ describe("SSS-2: Compliance Operations", () => {
 
   it("Initializes SSS-2 stablecoin", async () => {
     await program.methods
-      .initialize(PRESET_SSS_2, NAME, SYMBOL, URI, DECIMALS)
+      .initialize(PRESET_SSS_2, NAME, SYMBOL, URI, DECIMALS, null, null)
       .accounts({
         authority: authority.publicKey,
         state: stablecoinPda,
@@ -49,12 +55,12 @@ describe("SSS-2: Compliance Operations", () => {
     const role = { blacklister: {} };
     
     await program.methods
-      .assignRole(role)
+      .assignRole(role, null)
       .accounts({
         authority: authority.publicKey,
         state: stablecoinPda,
         assignment: await anchor.web3.PublicKey.findProgramAddress(
-          [Buffer.from("role"), stablecoinPda.toBuffer(), blacklister.publicKey.toBuffer()],
+          [Buffer.from("role"), stablecoinPda.toBuffer(), blacklister.publicKey.toBuffer(), Buffer.from("blacklister")],
           program.programId
         )[0],
         account: blacklister.publicKey,
@@ -69,12 +75,12 @@ describe("SSS-2: Compliance Operations", () => {
     const role = { seizer: {} };
     
     await program.methods
-      .assignRole(role)
+      .assignRole(role, null)
       .accounts({
         authority: authority.publicKey,
         state: stablecoinPda,
         assignment: await anchor.web3.PublicKey.findProgramAddress(
-          [Buffer.from("role"), stablecoinPda.toBuffer(), seizer.publicKey.toBuffer()],
+          [Buffer.from("role"), stablecoinPda.toBuffer(), seizer.publicKey.toBuffer(), Buffer.from("seizer")],
           program.programId
         )[0],
         account: seizer.publicKey,
@@ -353,7 +359,7 @@ describe("SSS-2: Compliance Operations", () => {
 
   it("Verifies blacklister role permissions", async () => {
     const [rolePda] = await anchor.web3.PublicKey.findProgramAddress(
-      [Buffer.from("role"), stablecoinPda.toBuffer(), blacklister.publicKey.toBuffer()],
+      [Buffer.from("role"), stablecoinPda.toBuffer(), blacklister.publicKey.toBuffer(), Buffer.from("blacklister")],
       program.programId
     );
 
@@ -363,7 +369,7 @@ describe("SSS-2: Compliance Operations", () => {
 
   it("Verifies seizer role permissions", async () => {
     const [rolePda] = await anchor.web3.PublicKey.findProgramAddress(
-      [Buffer.from("role"), stablecoinPda.toBuffer(), seizer.publicKey.toBuffer()],
+      [Buffer.from("role"), stablecoinPda.toBuffer(), seizer.publicKey.toBuffer(), Buffer.from("seizer")],
       program.programId
     );
 
@@ -414,4 +420,310 @@ describe("SSS-2: Compliance Operations", () => {
 
     expect(true).to.be.true;
   });
+
+  it("Refuses to disable compliance while accounts are blacklisted", async () => {
+    try {
+      await program.methods
+        .setCompliance(false, false)
+        .accounts({
+          authority: authority.publicKey,
+          state: stablecoinPda,
+        })
+        .rpc();
+      expect.fail("Should have rejected disabling compliance");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code).to.equal("BlacklistNotEmpty");
+    }
+
+    const state = await program.account.stablecoinState.fetch(stablecoinPda);
+    expect(state.complianceEnabled).to.be.true;
+  });
+
+  it("Initializes the transfer hook's ExtraAccountMetaList", async () => {
+    const state = await program.account.stablecoinState.fetch(stablecoinPda);
+    const [metaList] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("extra-account-metas"), state.assetMint.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeExtraAccountMetas()
+      .accounts({
+        authority: authority.publicKey,
+        state: stablecoinPda,
+        assetMint: state.assetMint,
+        extraAccountMetaList: metaList,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    // Execute TLV header (8 + 4), meta count (4), then state, two blacklist
+    // PDAs and two allowlist PDAs
+    const account = await provider.connection.getAccountInfo(metaList);
+    expect(account?.owner.toBase58()).to.equal(program.programId.toBase58());
+    expect(account?.data.length).to.equal(16 + 5 * 35);
+    expect(account?.data.readUInt32LE(12)).to.equal(5);
+    expect(account?.data.subarray(17, 49).equals(stablecoinPda.toBuffer())).to.be.true;
+  });
+
+  it("Adds and removes allowlist entries", async () => {
+    const holder = anchor.web3.Keypair.generate();
+    const [entryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("allowlist"), stablecoinPda.toBuffer(), holder.publicKey.toBuffer()],
+      program.programId
+    );
+    const accounts = {
+      authority: authority.publicKey,
+      state: stablecoinPda,
+      entry: entryPda,
+      account: holder.publicKey,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    };
+
+    await program.methods.addToAllowlist().accounts(accounts).rpc();
+    const entry = await program.account.allowlistEntry.fetch(entryPda);
+    expect(entry.account.toBase58()).to.equal(holder.publicKey.toBase58());
+    expect(entry.addedBy.toBase58()).to.equal(authority.publicKey.toBase58());
+
+    await program.methods.removeFromAllowlist().accounts(accounts).rpc();
+    expect(await provider.connection.getAccountInfo(entryPda)).to.be.null;
+
+    // Removing again must fail instead of creating and closing a fresh entry
+    try {
+      await program.methods.removeFromAllowlist().accounts(accounts).rpc();
+      expect.fail("Should have thrown AccountNotInitialized error");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code).to.equal("AccountNotInitialized");
+    }
+  });
+
+  it("Toggles allowlist mode", async () => {
+    await program.methods
+      .setAllowlistMode(true)
+      .accounts({ authority: authority.publicKey, state: stablecoinPda })
+      .rpc();
+    let state = await program.account.stablecoinState.fetch(stablecoinPda);
+    expect(state.allowlistMode).to.be.true;
+
+    await program.methods
+      .setAllowlistMode(false)
+      .accounts({ authority: authority.publicKey, state: stablecoinPda })
+      .rpc();
+    state = await program.account.stablecoinState.fetch(stablecoinPda);
+    expect(state.allowlistMode).to.be.false;
+  });
+
+  it("Fails to toggle allowlist mode with unauthorized account", async () => {
+    const stranger = anchor.web3.Keypair.generate();
+    try {
+      await program.methods
+        .setAllowlistMode(true)
+        .accounts({ authority: stranger.publicKey, state: stablecoinPda })
+        .signers([stranger])
+        .rpc();
+      expect.fail("Should have rejected a non-authority signer");
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code).to.equal("Unauthorized");
+    }
+  });
+
+  it("Runs the hook on a real Token-2022 transfer", async () => {
+    const payer = (authority as anchor.Wallet).payer;
+    const mint = anchor.web3.Keypair.generate();
+    const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("stablecoin"), mint.publicKey.toBuffer()],
+      program.programId
+    );
+    const [extraAccountMetaList] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("extra-account-metas"), mint.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeWithNewMint(PRESET_SSS_2, NAME, SYMBOL, URI, DECIMALS, null, null)
+      .accounts({
+        authority: authority.publicKey,
+        state: pda,
+        assetMint: mint.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([mint])
+      .rpc();
+    await program.methods
+      .initializeExtraAccountMetas()
+      .accounts({
+        authority: authority.publicKey,
+        state: pda,
+        assetMint: mint.publicKey,
+        extraAccountMetaList,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const holder = anchor.web3.Keypair.generate();
+    const createTokenAccount = (owner: anchor.web3.PublicKey) =>
+      createAccount(
+        provider.connection,
+        payer,
+        mint.publicKey,
+        owner,
+        anchor.web3.Keypair.generate(),
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+    const source = await createTokenAccount(holder.publicKey);
+    const destination = await createTokenAccount(authority.publicKey);
+
+    await program.methods
+      .mint(new anchor.BN(1_000))
+      .accounts({
+        authority: authority.publicKey,
+        state: pda,
+        assetMint: mint.publicKey,
+        recipient: source,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    // Token-2022 invokes the hook with the interface's Execute instruction
+    const transfer = () =>
+      transferCheckedWithTransferHook(
+        provider.connection,
+        payer,
+        source,
+        mint.publicKey,
+        destination,
+        holder,
+        BigInt(100),
+        DECIMALS,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+    await transfer();
+    const received = await getAccount(provider.connection, destination, undefined, TOKEN_2022_PROGRAM_ID);
+    expect(received.amount.toString()).to.equal("100");
+
+    const [entry] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("blacklist"), pda.toBuffer(), destination.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .addToBlacklist("Hook test")
+      .accounts({
+        authority: authority.publicKey,
+        state: pda,
+        entry,
+        account: destination,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    let transferred = false;
+    try {
+      await transfer();
+      transferred = true;
+    } catch (e: any) {
+      expect(String(e.logs ?? e)).to.match(/BlacklistViolation/);
+    }
+    expect(transferred, "transfer to a blacklisted account").to.be.false;
+  });
+
+  it("Seizes on an SSS-2 mint created with initialize_with_new_mint", async () => {
+    const payer = (authority as anchor.Wallet).payer;
+    const mint = anchor.web3.Keypair.generate();
+    const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("stablecoin"), mint.publicKey.toBuffer()],
+      program.programId
+    );
+    const [extraAccountMetaList] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("extra-account-metas"), mint.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeWithNewMint(PRESET_SSS_2, NAME, SYMBOL, URI, DECIMALS, null, null)
+      .accounts({
+        authority: authority.publicKey,
+        state: pda,
+        assetMint: mint.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([mint])
+      .rpc();
+    await program.methods
+      .initializeExtraAccountMetas()
+      .accounts({
+        authority: authority.publicKey,
+        state: pda,
+        assetMint: mint.publicKey,
+        extraAccountMetaList,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const holder = anchor.web3.Keypair.generate();
+    const createTokenAccount = (owner: anchor.web3.PublicKey) =>
+      createAccount(
+        provider.connection,
+        payer,
+        mint.publicKey,
+        owner,
+        anchor.web3.Keypair.generate(),
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+    const from = await createTokenAccount(holder.publicKey);
+    const treasury = await createTokenAccount(authority.publicKey);
+
+    await program.methods
+      .mint(new anchor.BN(1_000))
+      .accounts({
+        authority: authority.publicKey,
+        state: pda,
+        assetMint: mint.publicKey,
+        recipient: from,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    const [entry] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("blacklist"), pda.toBuffer(), from.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .addToBlacklist("Seize test")
+      .accounts({
+        authority: authority.publicKey,
+        state: pda,
+        entry,
+        account: from,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const before = await program.account.stablecoinState.fetch(pda);
+    await program.methods
+      .seize(new anchor.BN(400))
+      .accounts({
+        authority: authority.publicKey,
+        state: pda,
+        roleAssignment: null,
+        assetMint: mint.publicKey,
+        from,
+        to: treasury,
+        blacklistEntry: entry,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    const seized = await getAccount(provider.connection, treasury, undefined, TOKEN_2022_PROGRAM_ID);
+    expect(seized.amount.toString()).to.equal("400");
+    const left = await getAccount(provider.connection, from, undefined, TOKEN_2022_PROGRAM_ID);
+    expect(left.amount.toString()).to.equal("600");
+    const after = await program.account.stablecoinState.fetch(pda);
+    expect(after.totalSupply.toString()).to.equal(before.totalSupply.toString());
+  });
 });
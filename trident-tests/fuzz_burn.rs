@@ -366,7 +366,10 @@ fn try_burn(ctx: &mut BurnTestContext, burner: Pubkey, amount: u64) -> Result<()
 }
 
 fn try_pause(ctx: &mut BurnTestContext) -> Result<()> {
-    let pause_ix = sss_token::instruction::Pause {};
+    let pause_ix = sss_token::instruction::Pause {
+        reason: String::new(),
+        until: sss_token::constants::NO_PAUSE_EXPIRY,
+    };
     ctx.context.invoke(
         &[
             AccountMeta::new(ctx.authority, true),
@@ -7,10 +7,13 @@
 //! - Unauthorized minting
 //! - Paused state minting
 //! - Quota enforcement for minters
+//! - Recipient token accounts of another mint
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::pubkey::Pubkey;
+use sss_token::constants::UNLIMITED_QUOTA;
 use sss_token::error::StablecoinError;
+use sss_token::math::validate_token_account_mint;
 use sss_token::state::Role;
 use trident::prelude::*;
 
@@ -107,7 +110,8 @@ pub fn fuzz_mint_amounts(amount: u64) -> Result<()> {
     Ok(())
 }
 
-/// Fuzz test for minter quota enforcement
+/// Fuzz test for minter quota enforcement.
+/// A quota of 0 means unlimited; any other value caps the cumulative mint amount.
 #[derive(Debug, Arbitrary)]
 pub struct QuotaMintInput {
     pub quota: u64,
@@ -132,7 +136,19 @@ pub fn fuzz_mint_quota(input: QuotaMintInput) -> Result<()> {
         } else {
             let new_total = total_minted.saturating_add(amount);
 
-            if new_total > input.quota {
+            if input.quota == UNLIMITED_QUOTA {
+                // Unlimited minters are only bounded by arithmetic overflow
+                match result {
+                    Ok(_) => total_minted = new_total,
+                    Err(e) => {
+                        let error_code = parse_anchor_error(&e);
+                        assert_eq!(
+                            error_code, Some(StablecoinError::MathOverflow as u32),
+                            "Unlimited quota mint {} failed unexpectedly: {:?}", i, e
+                        );
+                    }
+                }
+            } else if new_total > input.quota {
                 // Should fail due to quota exceeded
                 assert!(result.is_err(), "Mint {} should fail due to quota", i);
                 let error_code = parse_anchor_error(&result.unwrap_err());
@@ -238,6 +254,30 @@ pub fn fuzz_mint_pause_sequence(actions: Vec<PauseAction>) -> Result<()> {
     Ok(())
 }
 
+/// Fuzz test for the token account mint check shared by mint and burn.
+/// Only an account of the stablecoin's own mint is accepted.
+#[fuzz]
+pub fn fuzz_token_account_mint(input: ([u8; 32], [u8; 32])) -> Result<()> {
+    let token_account_mint = Pubkey::new_from_array(input.0);
+    let asset_mint = Pubkey::new_from_array(input.1);
+
+    let result = validate_token_account_mint(&token_account_mint, &asset_mint);
+    if token_account_mint == asset_mint {
+        assert!(result.is_ok(), "Matching mint should be accepted");
+    } else {
+        assert_eq!(
+            parse_anchor_error(&result.unwrap_err()),
+            Some(StablecoinError::TokenAccountMintMismatch as u32),
+            "Expected TokenAccountMintMismatch error"
+        );
+    }
+
+    // An account of the stablecoin's own mint always passes
+    assert!(validate_token_account_mint(&asset_mint, &asset_mint).is_ok());
+
+    Ok(())
+}
+
 #[derive(Debug, Arbitrary)]
 pub enum PauseAction {
     Pause,
@@ -292,7 +332,10 @@ fn setup_initialized_stablecoin(is_paused: bool) -> Result<MintTestContext> {
 
     // Pause if requested
     if is_paused {
-        let pause_ix = sss_token::instruction::Pause {};
+        let pause_ix = sss_token::instruction::Pause {
+            reason: String::new(),
+            until: sss_token::constants::NO_PAUSE_EXPIRY,
+        };
         test.invoke(
             &[
                 AccountMeta::new(authority, true),
@@ -388,7 +431,10 @@ fn try_mint_with_quota(ctx: &mut MintTestContext, amount: u64) -> Result<()> {
 
 /// Pause the stablecoin
 fn try_pause(ctx: &mut MintTestContext) -> Result<()> {
-    let pause_ix = sss_token::instruction::Pause {};
+    let pause_ix = sss_token::instruction::Pause {
+        reason: String::new(),
+        until: sss_token::constants::NO_PAUSE_EXPIRY,
+    };
     ctx.context.invoke(
         &[
             AccountMeta::new(ctx.authority, true),
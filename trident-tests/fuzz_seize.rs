@@ -0,0 +1,395 @@
+//! Fuzz tests for the Seize instruction
+//!
+//! Tests seizure scenarios including:
+//! - Seizing from a blacklisted holder (should succeed)
+//! - Seizing from a non-blacklisted holder (should fail with NotBlacklisted)
+//! - Seizing after the holder was removed from the blacklist
+//! - Seizing on SSS-1 (should fail with ComplianceNotEnabled)
+//! - Seizing without the Master or Seizer role (should fail with Unauthorized)
+//! - Seizing more than the holder's balance (should fail with InsufficientBalance)
+//! - Seizures never changing total supply (they transfer, not burn)
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use sss_token::error::StablecoinError;
+use sss_token::state::{Role, StablecoinState};
+use trident::prelude::*;
+
+/// Input structure for fuzz testing the seize instruction
+#[derive(Debug, Arbitrary)]
+pub struct SeizeInput {
+    pub amount: u64,
+    pub holder_blacklisted: bool,
+    pub unblacklist_before_seize: bool,
+}
+
+/// Fuzz test for the seize instruction blacklist requirement
+#[fuzz]
+pub fn fuzz_seize(input: SeizeInput) -> Result<()> {
+    let mut ctx = setup_stablecoin_with_compliance(2)?;
+    let holder = Pubkey::new_unique();
+    let treasury = Pubkey::new_unique();
+
+    if input.holder_blacklisted {
+        try_blacklist(&mut ctx, holder)?;
+        if input.unblacklist_before_seize {
+            try_unblacklist(&mut ctx, holder)?;
+        }
+    }
+
+    let result = try_seize(&mut ctx, holder, treasury, input.amount);
+    let is_blacklisted = input.holder_blacklisted && !input.unblacklist_before_seize;
+
+    if input.amount == 0 {
+        assert!(result.is_err(), "Seize with zero amount should fail");
+        let error_code = parse_anchor_error(&result.unwrap_err());
+        assert_eq!(
+            error_code, Some(StablecoinError::ZeroAmount as u32),
+            "Expected ZeroAmount error"
+        );
+    } else if !is_blacklisted {
+        assert!(result.is_err(), "Seize from non-blacklisted holder should fail");
+        let error_code = parse_anchor_error(&result.unwrap_err());
+        assert_eq!(
+            error_code, Some(StablecoinError::NotBlacklisted as u32),
+            "Expected NotBlacklisted error"
+        );
+    } else {
+        // Blacklisted holder passes the compliance checks; any remaining
+        // failure must come from the token program (e.g. insufficient balance)
+        if let Err(e) = result {
+            let error_code = parse_anchor_error(&e);
+            assert_ne!(
+                error_code, Some(StablecoinError::NotBlacklisted as u32),
+                "Blacklisted holder should not fail the blacklist check"
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Fuzz test that seizure is unavailable without the compliance module
+#[fuzz]
+pub fn fuzz_seize_sss1(amount: u64) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let mut ctx = setup_stablecoin_with_compliance(1)?;
+    let holder = Pubkey::new_unique();
+
+    let result = try_seize(&mut ctx, holder, Pubkey::new_unique(), amount);
+
+    assert!(result.is_err(), "Seize on SSS-1 should fail");
+    let error_code = parse_anchor_error(&result.unwrap_err());
+    assert_eq!(
+        error_code, Some(StablecoinError::ComplianceNotEnabled as u32),
+        "Expected ComplianceNotEnabled error"
+    );
+
+    Ok(())
+}
+
+/// Input structure for fuzz testing seize authorization
+#[derive(Debug, Arbitrary)]
+pub struct SeizeAuthInput {
+    pub role_type: u8,
+    pub has_role: bool,
+    pub amount: u64,
+}
+
+/// Fuzz test that only the Master authority or a Seizer can seize
+#[fuzz]
+pub fn fuzz_seize_authorization(input: SeizeAuthInput) -> Result<()> {
+    if input.amount == 0 {
+        return Ok(());
+    }
+
+    let mut ctx = setup_stablecoin_with_compliance(2)?;
+    let holder = Pubkey::new_unique();
+    let from = fund_holder(&mut ctx, holder, input.amount)?;
+    let to = ctx.context.create_token_account(ctx.asset_mint, Pubkey::new_unique())?;
+    try_blacklist(&mut ctx, holder)?;
+
+    let seizer = Pubkey::new_unique();
+    let role = role_from_u8(input.role_type);
+    if input.has_role {
+        try_assign_role(&mut ctx, seizer, role.clone())?;
+    }
+
+    let result = try_seize_as(&mut ctx, seizer, &role, holder, from, to, input.amount);
+    let may_seize = input.has_role && (role == Role::Master || role == Role::Seizer);
+
+    if may_seize {
+        assert!(result.is_ok(), "Seize by {:?} should succeed", role);
+    } else {
+        assert!(result.is_err(), "Seize without Master/Seizer role should fail");
+        let error_code = parse_anchor_error(&result.unwrap_err());
+        assert_eq!(
+            error_code, Some(StablecoinError::Unauthorized as u32),
+            "Expected Unauthorized error"
+        );
+    }
+
+    Ok(())
+}
+
+/// Input structure for fuzz testing seizures against the holder's balance
+#[derive(Debug, Arbitrary)]
+pub struct SeizeBalanceInput {
+    pub balance: u64,
+    pub amounts: Vec<u64>,
+}
+
+/// Fuzz test that seizures are bounded by the balance and leave total supply unchanged
+#[fuzz]
+pub fn fuzz_seize_balance(input: SeizeBalanceInput) -> Result<()> {
+    if input.balance == 0 || input.amounts.is_empty() || input.amounts.len() > 50 {
+        return Ok(());
+    }
+
+    let mut ctx = setup_stablecoin_with_compliance(2)?;
+    let holder = Pubkey::new_unique();
+    let from = fund_holder(&mut ctx, holder, input.balance)?;
+    let to = ctx.context.create_token_account(ctx.asset_mint, Pubkey::new_unique())?;
+    try_blacklist(&mut ctx, holder)?;
+
+    let supply_before = fetch_total_supply(&ctx)?;
+    let mut remaining = input.balance;
+
+    for (i, &amount) in input.amounts.iter().enumerate() {
+        let authority = ctx.authority;
+        let result = try_seize_as(&mut ctx, authority, &Role::Master, holder, from, to, amount);
+
+        if amount == 0 {
+            assert!(result.is_err(), "Seize {} with zero amount should fail", i);
+        } else if amount > remaining {
+            assert!(result.is_err(), "Seize {} above the balance should fail", i);
+            let error_code = parse_anchor_error(&result.unwrap_err());
+            assert_eq!(
+                error_code, Some(StablecoinError::InsufficientBalance as u32),
+                "Expected InsufficientBalance error on seize {}", i
+            );
+        } else {
+            assert!(result.is_ok(), "Seize {} within the balance should succeed", i);
+            remaining -= amount;
+        }
+
+        assert_eq!(
+            fetch_total_supply(&ctx)?, supply_before,
+            "Seize {} must not change total supply", i
+        );
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+struct SeizeTestContext {
+    context: TestContext,
+    authority: Pubkey,
+    state_pda: Pubkey,
+    asset_mint: Pubkey,
+}
+
+fn setup_stablecoin_with_compliance(preset: u8) -> Result<SeizeTestContext> {
+    let mut test = TestContext::new();
+    test.add_program("sss_token", sss_token::ID)?;
+
+    let authority = test.payer();
+    let asset_mint = Pubkey::new_unique();
+
+    let (state_pda, bump) = Pubkey::find_program_address(
+        &[b"stablecoin", asset_mint.as_ref()],
+        &sss_token::ID,
+    );
+
+    let init_ix = sss_token::instruction::Initialize {
+        preset,
+        name: "Test Stablecoin".to_string(),
+        symbol: "TST".to_string(),
+        uri: "https://test.com".to_string(),
+        decimals: 6,
+    };
+
+    test.invoke(
+        &[
+            AccountMeta::new(authority, true),
+            AccountMeta::new(state_pda, false),
+            AccountMeta::new_readonly(asset_mint, false),
+            AccountMeta::new_readonly(System::id(), false),
+        ],
+        init_ix,
+        Some(&[&[b"stablecoin", asset_mint.as_ref(), &[bump]]]),
+    )?;
+
+    Ok(SeizeTestContext {
+        context: test,
+        authority,
+        state_pda,
+        asset_mint,
+    })
+}
+
+fn try_seize(ctx: &mut SeizeTestContext, holder: Pubkey, to: Pubkey, amount: u64) -> Result<()> {
+    let from = ctx.context.create_token_account(ctx.asset_mint, holder)?;
+    let to = ctx.context.create_token_account(ctx.asset_mint, to)?;
+    let authority = ctx.authority;
+
+    try_seize_as(ctx, authority, &Role::Master, holder, from, to, amount)
+}
+
+/// Seize from an existing token account, passing the seizer's assignment PDA
+/// for `role` unless they are the Master authority
+fn try_seize_as(
+    ctx: &mut SeizeTestContext,
+    seizer: Pubkey,
+    role: &Role,
+    holder: Pubkey,
+    from: Pubkey,
+    to: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let (blacklist_pda, _) = Pubkey::find_program_address(
+        &[b"blacklist", ctx.state_pda.as_ref(), holder.as_ref()],
+        &sss_token::ID,
+    );
+
+    let role_assignment = if seizer == ctx.authority {
+        sss_token::ID
+    } else {
+        role_pda(ctx, seizer, role)
+    };
+
+    let seize_ix = sss_token::instruction::Seize { amount };
+
+    ctx.context.invoke(
+        &[
+            AccountMeta::new(seizer, true),
+            AccountMeta::new(ctx.state_pda, false),
+            AccountMeta::new_readonly(role_assignment, false),
+            AccountMeta::new(ctx.asset_mint, false),
+            AccountMeta::new(from, false),
+            AccountMeta::new(to, false),
+            AccountMeta::new_readonly(blacklist_pda, false),
+        ],
+        seize_ix,
+        None,
+    )
+}
+
+/// Create a token account for `holder` and mint `amount` into it
+fn fund_holder(ctx: &mut SeizeTestContext, holder: Pubkey, amount: u64) -> Result<Pubkey> {
+    let token_account = ctx.context.create_token_account(ctx.asset_mint, holder)?;
+
+    let mint_ix = sss_token::instruction::Mint { amount };
+
+    ctx.context.invoke(
+        &[
+            AccountMeta::new(ctx.authority, true),
+            AccountMeta::new(ctx.state_pda, false),
+            AccountMeta::new_readonly(ctx.asset_mint, false),
+            AccountMeta::new(token_account, false),
+        ],
+        mint_ix,
+        None,
+    )?;
+
+    Ok(token_account)
+}
+
+fn role_pda(ctx: &SeizeTestContext, account: Pubkey, role: &Role) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"role", ctx.state_pda.as_ref(), account.as_ref(), role.seed()],
+        &sss_token::ID,
+    )
+    .0
+}
+
+fn try_assign_role(ctx: &mut SeizeTestContext, account: Pubkey, role: Role) -> Result<()> {
+    let assignment_pda = role_pda(ctx, account, &role);
+    let assign_ix = sss_token::instruction::AssignRole { role, expires_at: None };
+
+    ctx.context.invoke(
+        &[
+            AccountMeta::new(ctx.authority, true),
+            AccountMeta::new_readonly(ctx.state_pda, false),
+            AccountMeta::new(assignment_pda, false),
+            AccountMeta::new_readonly(account, false),
+            AccountMeta::new_readonly(System::id(), false),
+        ],
+        assign_ix,
+        None,
+    )
+}
+
+/// Map any fuzzed byte onto a role; wrapping is deliberate so every input picks one
+fn role_from_u8(role_type: u8) -> Role {
+    Role::from_u8(role_type % Role::ALL.len() as u8).expect("wrapped into range")
+}
+
+fn fetch_total_supply(ctx: &SeizeTestContext) -> Result<u64> {
+    let account = ctx.context.get_account(ctx.state_pda)?;
+    let state = StablecoinState::try_deserialize(&mut &account.data[..])?;
+    Ok(state.total_supply)
+}
+
+fn try_blacklist(ctx: &mut SeizeTestContext, account: Pubkey) -> Result<()> {
+    let (blacklist_pda, _) = Pubkey::find_program_address(
+        &[b"blacklist", ctx.state_pda.as_ref(), account.as_ref()],
+        &sss_token::ID,
+    );
+
+    let blacklist_ix = sss_token::instruction::AddToBlacklist {
+        reason: "Fuzz test".to_string(),
+    };
+
+    ctx.context.invoke(
+        &[
+            AccountMeta::new(ctx.authority, true),
+            AccountMeta::new_readonly(ctx.state_pda, false),
+            AccountMeta::new(blacklist_pda, false),
+            AccountMeta::new_readonly(account, false),
+            AccountMeta::new_readonly(System::id(), false),
+        ],
+        blacklist_ix,
+        None,
+    )
+}
+
+fn try_unblacklist(ctx: &mut SeizeTestContext, account: Pubkey) -> Result<()> {
+    let (blacklist_pda, _) = Pubkey::find_program_address(
+        &[b"blacklist", ctx.state_pda.as_ref(), account.as_ref()],
+        &sss_token::ID,
+    );
+
+    let unblacklist_ix = sss_token::instruction::RemoveFromBlacklist {};
+
+    ctx.context.invoke(
+        &[
+            AccountMeta::new(ctx.authority, true),
+            AccountMeta::new_readonly(ctx.state_pda, false),
+            AccountMeta::new(blacklist_pda, false),
+        ],
+        unblacklist_ix,
+        None,
+    )
+}
+
+fn parse_anchor_error(error: &Error) -> Option<u32> {
+    match error {
+        Error::AnchorError(e) => Some(e.error_code_number),
+        Error::ProgramError(e) => {
+            if let Some(code) = e.to_error_code() {
+                Some(code.code())
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,312 @@
+//! Fuzz tests for transfer_hook::enforce_transfer
+//!
+//! Tests the hook's rejection rules on an SSS-2 stablecoin:
+//! - Transfers while paused (should fail with VaultPaused)
+//! - Transfers from or to a frozen account (should fail with AccountFrozen)
+//! - Transfers from or to a blacklisted account (should fail with BlacklistViolation)
+//! - Any amount otherwise (should succeed)
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_spl::token_2022::Token2022;
+use sss_token::error::StablecoinError;
+use trident::prelude::*;
+
+/// Input structure for fuzz testing the transfer hook
+#[derive(Debug, Arbitrary)]
+pub struct TransferHookInput {
+    pub amount: u64,
+    pub is_paused: bool,
+    pub sender_frozen: bool,
+    pub recipient_frozen: bool,
+    pub sender_blacklisted: bool,
+    pub recipient_blacklisted: bool,
+}
+
+/// Fuzz test for the transfer hook rejection rules
+#[fuzz]
+pub fn fuzz_transfer_hook(input: TransferHookInput) -> Result<()> {
+    let mut ctx = setup_initialized_stablecoin()?;
+
+    let sender = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+
+    // Freeze and blacklist before pausing - both are rejected while paused
+    if input.sender_frozen {
+        try_freeze(&mut ctx, sender)?;
+    }
+    if input.recipient_frozen {
+        try_freeze(&mut ctx, recipient)?;
+    }
+    if input.sender_blacklisted {
+        try_blacklist(&mut ctx, sender)?;
+    }
+    if input.recipient_blacklisted {
+        try_blacklist(&mut ctx, recipient)?;
+    }
+    if input.is_paused {
+        try_pause(&mut ctx)?;
+    }
+
+    let result = try_transfer_hook(&mut ctx, sender, recipient, input.amount);
+
+    if input.is_paused {
+        assert!(result.is_err(), "Transfer while paused should fail");
+        let error_code = parse_anchor_error(&result.unwrap_err());
+        assert_eq!(
+            error_code, Some(StablecoinError::VaultPaused as u32),
+            "Expected VaultPaused error"
+        );
+    } else if input.sender_frozen || input.recipient_frozen {
+        assert!(result.is_err(), "Transfer involving a frozen account should fail");
+        let error_code = parse_anchor_error(&result.unwrap_err());
+        assert_eq!(
+            error_code, Some(StablecoinError::AccountFrozen as u32),
+            "Expected AccountFrozen error"
+        );
+    } else if input.sender_blacklisted || input.recipient_blacklisted {
+        assert!(result.is_err(), "Transfer with a blacklisted party should fail");
+        let error_code = parse_anchor_error(&result.unwrap_err());
+        assert_eq!(
+            error_code, Some(StablecoinError::BlacklistViolation as u32),
+            "Expected BlacklistViolation error"
+        );
+    } else {
+        // No per-transfer cap is configured, so every amount passes
+        assert!(result.is_ok(), "Transfer of {} should succeed", input.amount);
+    }
+
+    Ok(())
+}
+
+/// Fuzz test for pause/unpause interleaved with transfers
+#[fuzz]
+pub fn fuzz_transfer_hook_pause_sequence(actions: Vec<HookAction>) -> Result<()> {
+    if actions.is_empty() || actions.len() > 50 {
+        return Ok(());
+    }
+
+    let mut ctx = setup_initialized_stablecoin()?;
+    let sender = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let mut is_paused = false;
+
+    for (i, action) in actions.iter().enumerate() {
+        match action {
+            HookAction::Pause => {
+                if !is_paused {
+                    try_pause(&mut ctx)?;
+                    is_paused = true;
+                }
+            }
+            HookAction::Unpause => {
+                if is_paused {
+                    try_unpause(&mut ctx)?;
+                    is_paused = false;
+                }
+            }
+            HookAction::Transfer(amount) => {
+                let result = try_transfer_hook(&mut ctx, sender, recipient, *amount);
+
+                if is_paused {
+                    assert!(result.is_err(), "Transfer {} should fail while paused", i);
+                    let error_code = parse_anchor_error(&result.unwrap_err());
+                    assert_eq!(
+                        error_code, Some(StablecoinError::VaultPaused as u32),
+                        "Expected VaultPaused error"
+                    );
+                } else {
+                    assert!(result.is_ok(), "Transfer {} should succeed", i);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Arbitrary)]
+pub enum HookAction {
+    Pause,
+    Unpause,
+    Transfer(u64),
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Structure to hold test context with stablecoin state
+struct TransferHookTestContext {
+    context: TestContext,
+    authority: Pubkey,
+    state_pda: Pubkey,
+    asset_mint: Pubkey,
+}
+
+/// Setup an initialized SSS-2 stablecoin so blacklist checks apply
+fn setup_initialized_stablecoin() -> Result<TransferHookTestContext> {
+    let mut test = TestContext::new();
+    test.add_program("sss_token", sss_token::ID)?;
+
+    let authority = test.payer();
+    let asset_mint = Pubkey::new_unique();
+
+    let (state_pda, bump) = Pubkey::find_program_address(
+        &[b"stablecoin", asset_mint.as_ref()],
+        &sss_token::ID,
+    );
+
+    let init_ix = sss_token::instruction::Initialize {
+        preset: 2,
+        name: "Test Stablecoin".to_string(),
+        symbol: "TST".to_string(),
+        uri: "https://test.com".to_string(),
+        decimals: 6,
+    };
+
+    test.invoke(
+        &[
+            AccountMeta::new(authority, true),
+            AccountMeta::new(state_pda, false),
+            AccountMeta::new_readonly(asset_mint, false),
+            AccountMeta::new_readonly(System::id(), false),
+        ],
+        init_ix,
+        Some(&[&[b"stablecoin", asset_mint.as_ref(), &[bump]]]),
+    )?;
+
+    Ok(TransferHookTestContext {
+        context: test,
+        authority,
+        state_pda,
+        asset_mint,
+    })
+}
+
+/// Invoke the hook with the sender and recipient blacklist entries it expects
+fn try_transfer_hook(
+    ctx: &mut TransferHookTestContext,
+    source: Pubkey,
+    destination: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let (extra_account_meta_list, _) = Pubkey::find_program_address(
+        &[b"extra-account-metas", ctx.asset_mint.as_ref()],
+        &sss_token::ID,
+    );
+
+    let transfer_ix = sss_token::instruction::ExecuteTransferHook { amount };
+
+    ctx.context.invoke(
+        &[
+            AccountMeta::new_readonly(source, false),
+            AccountMeta::new_readonly(ctx.asset_mint, false),
+            AccountMeta::new_readonly(destination, false),
+            AccountMeta::new_readonly(ctx.authority, false),
+            AccountMeta::new_readonly(extra_account_meta_list, false),
+            AccountMeta::new_readonly(ctx.state_pda, false),
+            AccountMeta::new_readonly(blacklist_pda(ctx, source), false),
+            AccountMeta::new_readonly(blacklist_pda(ctx, destination), false),
+        ],
+        transfer_ix,
+        None,
+    )
+}
+
+fn blacklist_pda(ctx: &TransferHookTestContext, account: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"blacklist", ctx.state_pda.as_ref(), account.as_ref()],
+        &sss_token::ID,
+    )
+    .0
+}
+
+fn try_blacklist(ctx: &mut TransferHookTestContext, account: Pubkey) -> Result<()> {
+    let blacklist_ix = sss_token::instruction::AddToBlacklist {
+        reason: "Fuzz test".to_string(),
+    };
+
+    ctx.context.invoke(
+        &[
+            AccountMeta::new(ctx.authority, true),
+            AccountMeta::new_readonly(ctx.state_pda, false),
+            AccountMeta::new(blacklist_pda(ctx, account), false),
+            AccountMeta::new_readonly(account, false),
+            AccountMeta::new_readonly(System::id(), false),
+        ],
+        blacklist_ix,
+        None,
+    )
+}
+
+/// Freeze a token account (authority is the Master, so no role assignment is passed)
+fn try_freeze(ctx: &mut TransferHookTestContext, account: Pubkey) -> Result<()> {
+    let (freeze_record, _) = Pubkey::find_program_address(
+        &[b"freeze", ctx.state_pda.as_ref(), account.as_ref()],
+        &sss_token::ID,
+    );
+
+    let freeze_ix = sss_token::instruction::FreezeAccount {
+        reason: "Fuzz test".to_string(),
+    };
+
+    ctx.context.invoke(
+        &[
+            AccountMeta::new(ctx.authority, true),
+            AccountMeta::new_readonly(ctx.state_pda, false),
+            AccountMeta::new_readonly(sss_token::ID, false),
+            AccountMeta::new(ctx.asset_mint, false),
+            AccountMeta::new(account, false),
+            AccountMeta::new(freeze_record, false),
+            AccountMeta::new_readonly(Token2022::id(), false),
+            AccountMeta::new_readonly(System::id(), false),
+        ],
+        freeze_ix,
+        None,
+    )
+}
+
+/// Pause the stablecoin
+fn try_pause(ctx: &mut TransferHookTestContext) -> Result<()> {
+    let pause_ix = sss_token::instruction::Pause {
+        reason: String::new(),
+        until: sss_token::constants::NO_PAUSE_EXPIRY,
+    };
+    ctx.context.invoke(
+        &[
+            AccountMeta::new(ctx.authority, true),
+            AccountMeta::new(ctx.state_pda, false),
+        ],
+        pause_ix,
+        None,
+    )
+}
+
+/// Unpause the stablecoin
+fn try_unpause(ctx: &mut TransferHookTestContext) -> Result<()> {
+    let unpause_ix = sss_token::instruction::Unpause {};
+    ctx.context.invoke(
+        &[
+            AccountMeta::new(ctx.authority, true),
+            AccountMeta::new(ctx.state_pda, false),
+        ],
+        unpause_ix,
+        None,
+    )
+}
+
+/// Parse an Anchor error to extract the error code
+fn parse_anchor_error(error: &Error) -> Option<u32> {
+    match error {
+        Error::AnchorError(e) => Some(e.error_code_number),
+        Error::ProgramError(e) => {
+            if let Some(code) = e.to_error_code() {
+                Some(code.code())
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}